@@ -9,6 +9,10 @@ pub enum Command_type {
     Clippy,
     Check,
     Expand,
+    Push,
+    Pull,
+    List,
+    Remove,
     Help,
 }
 
@@ -26,6 +30,10 @@ impl TryFrom<&str> for Command_type {
             "clippy" => Ok(Command_type::Clippy),
             "check" => Ok(Command_type::Check),
             "expand" => Ok(Command_type::Expand),
+            "push" => Ok(Command_type::Push),
+            "pull" => Ok(Command_type::Pull),
+            "list" => Ok(Command_type::List),
+            "remove" => Ok(Command_type::Remove),
             "help" => Ok(Command_type::Help),
             _ => Err(format!("Unknown command : {}", s)),
         }
@@ -50,9 +58,14 @@ impl Command_type {
 
     pub fn Is_target_needed(&self) -> bool {
         match self {
-            Command_type::Clean | Command_type::Format | Command_type::Doc | Command_type::Help => {
-                false
-            }
+            Command_type::Clean
+            | Command_type::Format
+            | Command_type::Doc
+            | Command_type::Push
+            | Command_type::Pull
+            | Command_type::List
+            | Command_type::Remove
+            | Command_type::Help => false,
             Command_type::Build
             | Command_type::Run
             | Command_type::Test
@@ -61,4 +74,12 @@ impl Command_type {
             | Command_type::Expand => true,
         }
     }
+
+    /// Check if the command talks to a running instance instead of running cargo.
+    pub fn Is_transfer(&self) -> bool {
+        matches!(
+            self,
+            Command_type::Push | Command_type::Pull | Command_type::List | Command_type::Remove
+        )
+    }
 }
@@ -0,0 +1,247 @@
+//! Client of the file transfer service of a running instance (`/Binaries/File_transfer`).
+//!
+//! The framing must match the one of `Modules/Executables/File_transfer/src/Protocol.rs`.
+
+use std::{
+    fs,
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::Path,
+};
+
+use crate::Command::Command_type;
+
+const Default_host: &str = "127.0.0.1";
+
+const Default_port: u16 = 2324;
+
+const Header_size: usize = 5;
+
+const Maximum_payload_size: usize = 64 * 1024;
+
+const Chunk_size: usize = 4 * 1024;
+
+const Authenticate: u8 = 0x01;
+const Get: u8 = 0x02;
+const Put: u8 = 0x03;
+const List: u8 = 0x04;
+const Remove: u8 = 0x05;
+const Data: u8 = 0x10;
+const Success: u8 = 0x20;
+const Failure: u8 = 0x21;
+
+struct Client_type {
+    Stream: TcpStream,
+}
+
+impl Client_type {
+    fn Connect(Address: &str, User_name: &str, Password: &str) -> Result<Self, String> {
+        let Stream = TcpStream::connect(Address)
+            .map_err(|Error| format!("Failed to connect to {} : {}", Address, Error))?;
+
+        let mut Client = Self { Stream };
+
+        let mut Credentials = User_name.as_bytes().to_vec();
+        Credentials.push(0);
+        Credentials.extend_from_slice(Password.as_bytes());
+
+        Client.Send_frame(Authenticate, &Credentials)?;
+        Client.Receive_status()?;
+
+        Ok(Client)
+    }
+
+    fn Send_frame(&mut self, Kind: u8, Payload: &[u8]) -> Result<(), String> {
+        let mut Frame = Vec::with_capacity(Header_size + Payload.len());
+
+        Frame.push(Kind);
+        Frame.extend_from_slice(&(Payload.len() as u32).to_be_bytes());
+        Frame.extend_from_slice(Payload);
+
+        self.Stream
+            .write_all(&Frame)
+            .map_err(|Error| format!("Failed to send : {}", Error))
+    }
+
+    fn Receive_frame(&mut self) -> Result<(u8, Vec<u8>), String> {
+        let mut Header = [0; Header_size];
+
+        self.Stream
+            .read_exact(&mut Header)
+            .map_err(|Error| format!("Failed to receive : {}", Error))?;
+
+        let Size = u32::from_be_bytes([Header[1], Header[2], Header[3], Header[4]]) as usize;
+
+        if Size > Maximum_payload_size {
+            return Err("Frame too large".to_string());
+        }
+
+        let mut Payload = vec![0; Size];
+
+        self.Stream
+            .read_exact(&mut Payload)
+            .map_err(|Error| format!("Failed to receive : {}", Error))?;
+
+        Ok((Header[0], Payload))
+    }
+
+    /// Get the payload of a success, or the message of a failure as an error.
+    fn Receive_status(&mut self) -> Result<Vec<u8>, String> {
+        match self.Receive_frame()? {
+            (Success, Payload) => Ok(Payload),
+            (Failure, Message) => Err(String::from_utf8_lossy(&Message).into_owned()),
+            (Kind, _) => Err(format!("Unexpected frame : {:#04x}", Kind)),
+        }
+    }
+
+    fn Request(&mut self, Kind: u8, Path: &str) -> Result<Vec<u8>, String> {
+        self.Send_frame(Kind, Path.as_bytes())?;
+        self.Receive_status()
+    }
+
+    fn Put(&mut self, Path: &str, Source: &mut impl Read) -> Result<(), String> {
+        self.Request(Put, Path)?;
+
+        let mut Buffer = vec![0; Chunk_size];
+
+        loop {
+            let Size = Source
+                .read(&mut Buffer)
+                .map_err(|Error| format!("Failed to read : {}", Error))?;
+
+            // - The empty data frame ends the file.
+            self.Send_frame(Data, &Buffer[..Size])?;
+
+            if Size == 0 {
+                break;
+            }
+        }
+
+        self.Receive_status()?;
+
+        Ok(())
+    }
+
+    fn Get(&mut self, Path: &str, Destination: &mut impl Write) -> Result<(), String> {
+        self.Request(Get, Path)?;
+
+        loop {
+            match self.Receive_frame()? {
+                (Data, Payload) if Payload.is_empty() => return Ok(()),
+                (Data, Payload) => Destination
+                    .write_all(&Payload)
+                    .map_err(|Error| format!("Failed to write : {}", Error))?,
+                (Failure, Message) => return Err(String::from_utf8_lossy(&Message).into_owned()),
+                (Kind, _) => return Err(format!("Unexpected frame : {:#04x}", Kind)),
+            }
+        }
+    }
+}
+
+/// Get the address of the instance from `XILA_HOST` (`host[:port]`).
+fn Get_address() -> String {
+    let Host = std::env::var("XILA_HOST").unwrap_or_else(|_| Default_host.to_string());
+
+    if Host.contains(':') {
+        Host
+    } else {
+        format!("{}:{}", Host, Default_port)
+    }
+}
+
+/// Get a credential from the environment, or ask for it.
+fn Get_credential(Variable: &str, Prompt: &str) -> Result<String, String> {
+    if let Ok(Value) = std::env::var(Variable) {
+        return Ok(Value);
+    }
+
+    print!("{}", Prompt);
+    io::stdout()
+        .flush()
+        .map_err(|Error| format!("Failed to write : {}", Error))?;
+
+    let mut Value = String::new();
+    io::stdin()
+        .read_line(&mut Value)
+        .map_err(|Error| format!("Failed to read : {}", Error))?;
+
+    Ok(Value.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Get the name of the last component of a path.
+fn Get_file_name(Path: &str) -> Result<&str, String> {
+    Path.rsplit(['/', '\\'])
+        .find(|Name| !Name.is_empty())
+        .ok_or_else(|| format!("No file name in : {}", Path))
+}
+
+/// Execute a transfer command against a running instance.
+///
+/// - `push <local file> <remote path>` : a remote path ending with `/` is a directory.
+/// - `pull <remote path> [local path]` : the file name is kept by default.
+/// - `list <remote directory>`
+/// - `remove <remote path>`
+pub fn Execute(Command: Command_type, Arguments: &[String]) -> Result<(), String> {
+    let Arguments: Vec<&str> = Arguments.iter().map(String::as_str).collect();
+
+    // - Check the arguments before asking for the credentials.
+    match (Command, &Arguments[..]) {
+        (Command_type::Push, [_, _])
+        | (Command_type::Pull, [_] | [_, _])
+        | (Command_type::List, [_])
+        | (Command_type::Remove, [_]) => {}
+        _ => return Err(format!("Invalid arguments for `{:?}` command.", Command)),
+    }
+
+    let Address = Get_address();
+    let User_name = Get_credential("XILA_USER", "Username: ")?;
+    let Password = Get_credential("XILA_PASSWORD", "Password: ")?;
+
+    let mut Client = Client_type::Connect(&Address, &User_name, &Password)?;
+
+    match (Command, &Arguments[..]) {
+        (Command_type::Push, [Local, Remote]) => {
+            let mut File = fs::File::open(Local)
+                .map_err(|Error| format!("Failed to open {} : {}", Local, Error))?;
+
+            let Remote = if Remote.ends_with('/') {
+                format!("{}{}", Remote, Get_file_name(Local)?)
+            } else {
+                Remote.to_string()
+            };
+
+            Client.Put(&Remote, &mut File)?;
+
+            log::info!("{} pushed to {}:{}", Local, Address, Remote);
+        }
+        (Command_type::Pull, [Remote, Local @ ..]) => {
+            let Local = match Local {
+                [Local] => Local,
+                _ => Get_file_name(Remote)?,
+            };
+
+            let mut Content = Vec::new();
+
+            Client.Get(Remote, &mut Content)?;
+
+            // - The local file is only written if the whole file was received.
+            fs::write(Path::new(Local), Content)
+                .map_err(|Error| format!("Failed to write {} : {}", Local, Error))?;
+
+            log::info!("{}:{} pulled to {}", Address, Remote, Local);
+        }
+        (Command_type::List, [Remote]) => {
+            let Listing = Client.Request(List, Remote)?;
+
+            print!("{}", String::from_utf8_lossy(&Listing));
+        }
+        (Command_type::Remove, [Remote]) => {
+            Client.Request(Remove, Remote)?;
+
+            log::info!("{}:{} removed", Address, Remote);
+        }
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
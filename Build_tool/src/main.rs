@@ -11,6 +11,8 @@ mod Target;
 
 mod Command;
 
+mod Transfer;
+
 fn Initialize_logger() {
     Builder::from_env(Env::default().default_filter_or("info"))
         .format(|buf, record| {
@@ -36,6 +38,10 @@ fn Get_usage() -> &'static str {
         test        Run the tests.
         format      Format the code.
         doc         Generate the documentation.
+        push        Send a file to a running instance : push <local file> <remote path>
+        pull        Get a file from a running instance : pull <remote path> [local path]
+        list        List a directory of a running instance : list <remote directory>
+        remove      Remove a file of a running instance : remove <remote path>
     Targets:
         esp32
         esp32s3
@@ -44,12 +50,17 @@ fn Get_usage() -> &'static str {
         native
     Arguments:
         Any arguments after the target are passed to corresponding cargo command.
+    Transfer:
+        The instance runs `/Binaries/File_transfer`, its address is read from XILA_HOST
+        (host[:port], 127.0.0.1:2324 by default). The credentials are read from XILA_USER and
+        XILA_PASSWORD, or asked.
     Example:
         xila build esp32 --release
         xila run esp32
         xila test
         xila format
         xila doc
+        xila push Logo.png /Data/
     "
 }
 
@@ -125,6 +136,12 @@ fn main() -> Result<(), ()> {
         return Ok(());
     }
 
+    if Command.Is_transfer() {
+        return Transfer::Execute(Command, &Arguments).map_err(|Error| {
+            log::error!("Failed to execute `{:?}` command : {}", Command, Error);
+        });
+    }
+
     // Create a new process::Command
     let mut Shell_command = process::Command::new("cargo");
 
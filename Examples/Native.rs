@@ -36,22 +36,30 @@ fn main() {
     // - Initialize the file system
     // Create a memory device
     let Drive = Create_device!(Drivers::Native::File_drive_device_type::New(&"./Drive.img"));
-    // Mount the file system
-    let File_system = match LittleFS::File_system_type::New(Drive.clone(), 256) {
-        Ok(File_system) => File_system,
-        // If the file system is not found, format it
-        Err(_) => {
-            Drive
-                .Set_position(&File_system::Position_type::Start(0))
-                .unwrap();
-
-            LittleFS::File_system_type::Format(Drive.clone(), 256).unwrap();
-
-            LittleFS::File_system_type::New(Drive, 256).unwrap()
-        }
-    };
+    // Mount the file system, repairing it if needed (it is only formatted if it can't be mounted at all)
+    let (File_system, Report) =
+        LittleFS::File_system_type::Mount_with_recovery(Drive, 256, true).unwrap();
+
+    if Report.Is_formatted() {
+        println!("The drive couldn't be mounted and has been formatted.");
+    }
+
+    for Issue in Report.Get_issues() {
+        println!(
+            "File system check : {} : {} (repaired : {})",
+            Issue.Get_path().As_str(),
+            Issue.Get_kind(),
+            Issue.Is_repaired()
+        );
+    }
     // Initialize the virtual file system
-    Virtual_file_system::Initialize(Create_file_system!(File_system)).unwrap();
+    Virtual_file_system::Initialize(
+        Create_file_system!(File_system),
+        Some(Box::leak(Box::new(
+            Drivers::Std::Network::Network_socket_driver_type::New(),
+        ))),
+    )
+    .unwrap();
 
     // - - Mount the devices
     let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
@@ -60,6 +68,23 @@ fn main() {
     let _ =
         Virtual_file_system::Create_default_hierarchy(Virtual_file_system::Get_instance(), Task);
 
+    // - - Restore the last known time (for boards without real time clock)
+    let _ = Virtual_file_system::Restore_time(Virtual_file_system::Get_instance());
+
+    Virtual_file_system::Start_time_saver(
+        Virtual_file_system::Get_instance(),
+        Virtual_file_system::Default_time_saving_period,
+    )
+    .unwrap();
+
+    // - - Initialize the network manager and apply the saved configuration of the interfaces
+    Network::Initialize(Box::leak(Box::new(
+        Drivers::Std::Network::Network_interface_driver_type::New(),
+    )))
+    .unwrap();
+
+    let _ = Virtual_file_system::Restore_network_configuration(Virtual_file_system::Get_instance());
+
     // - - Mount the devices
     Virtual_file_system::Clean_devices(Virtual_file_system::Get_instance()).unwrap();
 
@@ -110,6 +135,14 @@ fn main() {
                 Terminal::Terminal_executable_type::New(Virtual_file_system::Get_instance(), Task)
                     .unwrap()
             ),
+            (
+                &"/Binaries/Remote_shell",
+                Remote_shell::Remote_shell_executable_type
+            ),
+            (
+                &"/Binaries/File_transfer",
+                File_transfer::File_transfer_executable_type
+            ),
             (&"/Binaries/WASM", WASM::WASM_device_type)
         ]
     )
@@ -143,6 +176,9 @@ fn main() {
         None,
     );
 
+    // - - Make the system directories read-only now that they are populated
+    Virtual_file_system::Freeze_system(Virtual_file_system::Get_instance(), Task).unwrap();
+
     // - - Set the environment variables
     Task::Get_instance()
         .Set_environment_variable(Task, "Paths", "/")
@@ -152,10 +188,13 @@ fn main() {
         .Set_environment_variable(Task, "Host", "xila")
         .unwrap();
     // - - Execute the shell
-    let _ = Executable::Execute("/Binaries/Graphical_shell", "".to_string(), Standard)
+    let _ = Executable::Execute("/Binaries/Graphical_shell", vec![], Standard)
         .unwrap()
         .Join()
         .unwrap();
 
+    // - Save the time before shutting down
+    let _ = Virtual_file_system::Save_time(Virtual_file_system::Get_instance());
+
     Virtual_file_system::Uninitialize();
 }
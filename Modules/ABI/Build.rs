@@ -11,10 +11,20 @@ pub fn main() {
         ..Default::default()
     };
 
+    // - Taken as integers by the functions, to check their values.
+    let Export_configuration = cbindgen::ExportConfig {
+        include: vec![
+            "Xila_network_shutdown_type".to_string(),
+            "Xila_network_socket_option_type".to_string(),
+        ],
+        ..Default::default()
+    };
+
     let Configuration: cbindgen::Config = cbindgen::Config {
         language: cbindgen::Language::C,
         include_guard: Some("XILA_H_INCLUDED".to_string()),
         enumeration: Enumeration_configuration,
+        export: Export_configuration,
         ..Default::default()
     };
 
@@ -0,0 +1,300 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use std::num::NonZeroU32;
+
+use File_system::{Create_device, Create_file_system, Memory_file_system_type};
+use Virtual_file_system::Error_type;
+use ABI::*;
+
+fn Initialize() {
+    let Task_instance = Task::Initialize().unwrap();
+
+    unsafe {
+        let _ = Task_instance.Register_task();
+    }
+
+    let _ = Users::Initialize();
+
+    let _ = Time::Initialize(Create_device!(Drivers::Native::Time_driver_type::New()));
+
+    let _ = Virtual_file_system::Initialize(
+        Create_file_system!(Memory_file_system_type::New(1024 * 512)),
+        Some(Box::leak(Box::new(
+            Drivers::Std::Network::Network_socket_driver_type::New(),
+        ))),
+    );
+}
+
+const Localhost: Xila_network_ip_address_type = Xila_network_ip_address_type {
+    Is_IPv4: true,
+    IPv4: 0x7F000001,
+    IPv6: [0; 8],
+};
+
+fn Create_socket() -> Xila_unique_file_identifier_type {
+    let mut Socket = 0;
+
+    assert_eq!(
+        unsafe { Xila_file_system_create_socket(true, true, &mut Socket) },
+        0
+    );
+
+    Socket
+}
+
+#[test]
+fn Test_TCP_connection() {
+    Initialize();
+
+    let Server = Create_socket();
+
+    unsafe {
+        assert_eq!(Xila_file_system_bind(Server, &Localhost, 0), 0);
+    }
+    assert_eq!(Xila_file_system_listen(Server, 1), 0);
+
+    let mut Address = Xila_network_ip_address_type::default();
+    let mut Port = 0;
+
+    unsafe {
+        assert_eq!(
+            Xila_file_system_get_local_address(Server, &mut Address, &mut Port),
+            0
+        );
+    }
+    assert_eq!(Address, Localhost);
+    assert_ne!(Port, 0);
+
+    let Client = Create_socket();
+
+    unsafe {
+        assert_eq!(Xila_file_system_connect(Client, &Localhost, Port), 0);
+    }
+
+    let mut Accepted = 0;
+    let mut Peer_address = Xila_network_ip_address_type::default();
+    let mut Peer_port = 0;
+
+    unsafe {
+        assert_eq!(
+            Xila_file_system_accept(Server, &mut Accepted, &mut Peer_address, &mut Peer_port),
+            0
+        );
+    }
+    assert_eq!(Peer_address, Localhost);
+
+    let mut Client_port = 0;
+
+    unsafe {
+        assert_eq!(
+            Xila_file_system_get_local_address(Client, std::ptr::null_mut(), &mut Client_port),
+            0
+        );
+    }
+    assert_eq!(Peer_port, Client_port);
+
+    let Message = b"Hello";
+
+    unsafe {
+        assert_eq!(
+            Xila_file_system_send(Client, Message.as_ptr(), Message.len()),
+            0
+        );
+    }
+
+    let mut Buffer = [0_u8; 16];
+    let mut Received = 0;
+
+    unsafe {
+        assert_eq!(
+            Xila_file_system_receive(Accepted, Buffer.as_mut_ptr(), Buffer.len(), &mut Received),
+            0
+        );
+    }
+    assert_eq!(&Buffer[..Received], Message);
+
+    // - The enumerations are checked.
+    assert_eq!(
+        Xila_file_system_shutdown(Client, 3),
+        Xila_file_system_result_invalid_parameter
+    );
+    assert_eq!(
+        Xila_file_system_set_socket_option(Client, 6, 1),
+        Xila_file_system_result_invalid_parameter
+    );
+
+    assert_eq!(
+        Xila_file_system_set_socket_option(
+            Client,
+            Xila_network_socket_option_type::TCP_no_delay as u8,
+            1
+        ),
+        0
+    );
+
+    let mut Value = 0;
+
+    unsafe {
+        assert_eq!(
+            Xila_file_system_get_socket_option(
+                Client,
+                Xila_network_socket_option_type::TCP_no_delay as u8,
+                &mut Value
+            ),
+            0
+        );
+    }
+    assert_eq!(Value, 1);
+
+    assert_eq!(
+        Xila_file_system_shutdown(Client, Xila_network_shutdown_type::Write as u8),
+        0
+    );
+
+    // - The peer sees the end of the stream.
+    unsafe {
+        assert_eq!(
+            Xila_file_system_receive(Accepted, Buffer.as_mut_ptr(), Buffer.len(), &mut Received),
+            0
+        );
+    }
+    assert_eq!(Received, 0);
+
+    for Socket in [Accepted, Client, Server] {
+        assert_eq!(Xila_file_system_close(Socket), 0);
+    }
+}
+
+#[test]
+fn Test_result_codes() {
+    let Code = |Error: Error_type| NonZeroU32::from(Error).get();
+
+    let File_system_cases = [
+        (
+            Xila_file_system_result_permission_denied,
+            File_system::Error_type::Permission_denied,
+        ),
+        (
+            Xila_file_system_result_not_found,
+            File_system::Error_type::Not_found,
+        ),
+        (
+            Xila_file_system_result_invalid_identifier,
+            File_system::Error_type::Invalid_identifier,
+        ),
+        (
+            Xila_file_system_result_unsupported_operation,
+            File_system::Error_type::Unsupported_operation,
+        ),
+        (
+            Xila_file_system_result_no_memory,
+            File_system::Error_type::No_memory,
+        ),
+        (
+            Xila_file_system_result_broken_pipe,
+            File_system::Error_type::Broken_pipe,
+        ),
+    ];
+
+    for (Constant, Error) in File_system_cases {
+        assert_eq!(Constant, Code(Error.into()), "{:?}", Error);
+    }
+
+    assert_eq!(
+        Xila_file_system_result_invalid_parameter,
+        Code(Error_type::Invalid_parameter)
+    );
+    assert_eq!(
+        Xila_file_system_result_too_many_open_files,
+        Code(Error_type::Too_many_open_files)
+    );
+
+    use Network::Error_type as Network_error_type;
+
+    let Network_cases = [
+        (Xila_network_result_not_found, Network_error_type::Not_found),
+        (
+            Xila_network_result_permission_denied,
+            Network_error_type::Permission_denied,
+        ),
+        (
+            Xila_network_result_connection_refused,
+            Network_error_type::Connection_refused,
+        ),
+        (
+            Xila_network_result_connection_reset,
+            Network_error_type::Connection_reset,
+        ),
+        (
+            Xila_network_result_host_unreachable,
+            Network_error_type::Host_unreachable,
+        ),
+        (
+            Xila_network_result_network_unreachable,
+            Network_error_type::Network_unreachable,
+        ),
+        (
+            Xila_network_result_connection_aborted,
+            Network_error_type::Connection_aborted,
+        ),
+        (
+            Xila_network_result_not_connected,
+            Network_error_type::Not_connected,
+        ),
+        (
+            Xila_network_result_address_in_use,
+            Network_error_type::Address_in_use,
+        ),
+        (
+            Xila_network_result_address_not_available,
+            Network_error_type::Address_not_available,
+        ),
+        (
+            Xila_network_result_network_down,
+            Network_error_type::Network_down,
+        ),
+        (
+            Xila_network_result_broken_pipe,
+            Network_error_type::Broken_pipe,
+        ),
+        (
+            Xila_network_result_already_exists,
+            Network_error_type::Already_exists,
+        ),
+        (
+            Xila_network_result_would_block,
+            Network_error_type::Would_block,
+        ),
+        (
+            Xila_network_result_invalid_input,
+            Network_error_type::Invalid_input,
+        ),
+        (Xila_network_result_timed_out, Network_error_type::Timed_out),
+        (
+            Xila_network_result_interrupted,
+            Network_error_type::Interrupted,
+        ),
+        (
+            Xila_network_result_unsupported,
+            Network_error_type::Unsupported,
+        ),
+        (
+            Xila_network_result_out_of_memory,
+            Network_error_type::Out_of_memory,
+        ),
+        (
+            Xila_network_result_in_progress,
+            Network_error_type::In_progress,
+        ),
+        (
+            Xila_network_result_unsupported_protocol,
+            Network_error_type::Unsupported_protocol,
+        ),
+    ];
+
+    for (Constant, Error) in Network_cases {
+        assert_eq!(Constant, Code(Error.into()), "{:?}", Error);
+    }
+}
@@ -9,7 +9,7 @@ use core::{
 };
 
 use File_system::{
-    Error_type, File_identifier_type, Flags_type, Mode_type, Open_type, Status_type,
+    Error_type, Events_type, File_identifier_type, Flags_type, Mode_type, Open_type, Status_type,
 };
 
 use Task::Get_instance as Get_task_manager_instance;
@@ -18,8 +18,9 @@ use Virtual_file_system::Get_instance as Get_file_system_instance;
 use crate::{Into_position, Xila_time_type};
 
 use super::{
-    Xila_file_system_mode_type, Xila_file_system_open_type, Xila_file_system_result_type,
-    Xila_file_system_size_type, Xila_file_system_statistics_type, Xila_file_system_status_type,
+    Xila_file_system_mode_type, Xila_file_system_open_type, Xila_file_system_poll_type,
+    Xila_file_system_result_type, Xila_file_system_size_type, Xila_file_system_statistics_type,
+    Xila_file_system_status_type, Xila_file_system_volume_statistics_type,
     Xila_file_system_whence_type, Xila_unique_file_identifier_type,
 };
 
@@ -78,6 +79,65 @@ pub extern "C" fn Xila_file_system_get_statistics_from_path(
     todo!()
 }
 
+/// This function is used to get the capacity and free space of the file system of an open file.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_get_volume_statistics(
+    File: Xila_unique_file_identifier_type,
+    Statistics: *mut Xila_file_system_volume_statistics_type,
+) -> Xila_file_system_result_type {
+    Into_u32(move || {
+        let Task_identifier = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Statistics = Xila_file_system_volume_statistics_type::From_mutable_pointer(Statistics)
+            .ok_or(Error_type::Invalid_parameter)?;
+
+        let File = File_system::Unique_file_identifier_type::From_raw(File);
+
+        *Statistics = Xila_file_system_volume_statistics_type::From_statistics(
+            Get_file_system_instance()
+                .Get_file_system_statistics_from_file(File, Task_identifier)?,
+        );
+
+        Ok(())
+    })
+}
+
+/// This function is used to get the capacity and free space of the file system containing a path.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_get_volume_statistics_from_path(
+    Path: *const c_char,
+    Statistics: *mut Xila_file_system_volume_statistics_type,
+) -> Xila_file_system_result_type {
+    Into_u32(move || {
+        if Path.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Path = CStr::from_ptr(Path)
+            .to_str()
+            .map_err(|_| Error_type::Invalid_parameter)?;
+
+        let Statistics = Xila_file_system_volume_statistics_type::From_mutable_pointer(Statistics)
+            .ok_or(Error_type::Invalid_parameter)?;
+
+        *Statistics = Xila_file_system_volume_statistics_type::From_statistics(
+            Get_file_system_instance().Get_file_system_statistics(&Path)?,
+        );
+
+        Ok(())
+    })
+}
+
 /// This function is used to get the access mode of a file.
 ///
 /// # Safety
@@ -447,25 +507,110 @@ pub unsafe extern "C" fn Xila_file_system_open(
     })
 }
 
+/// This function is used to set the status flags of a file (e.g. to make it non-blocking).
 #[no_mangle]
 pub extern "C" fn Xila_file_system_set_flags(
-    _File: Xila_unique_file_identifier_type,
-    _Status: Xila_file_system_status_type,
+    File: Xila_unique_file_identifier_type,
+    Status: Xila_file_system_status_type,
 ) -> Xila_file_system_result_type {
-    todo!()
+    Into_u32(move || {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let File = File_system::Unique_file_identifier_type::From_raw(File);
+
+        Get_file_system_instance().Set_status(File, Status_type::From_u8(Status), Task)?;
+
+        Ok(())
+    })
 }
 
-/// This function is used to get the flags of a file.
+/// This function is used to get the status flags of a file.
 ///
 /// # Safety
 ///
 /// This function is unsafe because it dereferences raw pointers.
 #[no_mangle]
 pub unsafe extern "C" fn Xila_file_system_get_flags(
-    _File: Xila_unique_file_identifier_type,
-    _Status: *mut Xila_file_system_status_type,
+    File: Xila_unique_file_identifier_type,
+    Status: *mut Xila_file_system_status_type,
 ) -> Xila_file_system_result_type {
-    todo!()
+    Into_u32(move || {
+        if Status.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let File = File_system::Unique_file_identifier_type::From_raw(File);
+
+        *Status = Get_file_system_instance().Get_status(File, Task)?.As_u8();
+
+        Ok(())
+    })
+}
+
+/// This function is used to wait for events on several files (like POSIX `poll`).
+///
+/// The timeout is in milliseconds, a negative timeout waits indefinitely.
+/// The ready events are stored in each entry and the count of ready entries in `Ready`.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_poll(
+    Files: *mut Xila_file_system_poll_type,
+    Count: usize,
+    Timeout: i32,
+    Ready: *mut usize,
+) -> Xila_file_system_result_type {
+    Into_u32(move || {
+        if (Files.is_null() && Count != 0) || Ready.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Files = if Count == 0 {
+            &mut []
+        } else {
+            core::slice::from_raw_parts_mut(Files, Count)
+        };
+
+        let Requested = Files
+            .iter()
+            .map(|File| {
+                (
+                    File_system::Unique_file_identifier_type::From_raw(File.File),
+                    Events_type::From_u8(File.Events),
+                )
+            })
+            .collect::<alloc::vec::Vec<_>>();
+
+        let Timeout = u64::try_from(Timeout)
+            .ok()
+            .map(core::time::Duration::from_millis);
+
+        let Events = Get_file_system_instance().Poll(Task, &Requested, Timeout)?;
+
+        *Ready = 0;
+
+        for (File, Events) in Files.iter_mut().zip(Events) {
+            File.Returned_events = Events.As_u8();
+
+            if !Events.Is_empty() {
+                *Ready += 1;
+            }
+        }
+
+        Ok(())
+    })
 }
 
 /// This function is used to convert a path to a resolved path (i.e. a path without symbolic links or relative paths).
@@ -661,7 +806,11 @@ pub unsafe extern "C" fn Xila_file_system_remove(
             .to_str()
             .map_err(|_| Error_type::Invalid_parameter)?;
 
-        Get_file_system_instance().Remove(Path)?;
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        Get_file_system_instance().Remove(Path, Task)?;
 
         Ok(())
     })
@@ -1,6 +1,441 @@
+use core::ffi::{c_char, CStr};
+
+use Time::Duration_type;
+
 use super::{Into_u32, Xila_file_system_result_type, Xila_unique_file_identifier_type};
+use Network::{
+    Address_family_type, IP_type, Port_type, Protocol_type, Shutdown_type, Socket_option_kind_type,
+    Socket_option_type, TLS_configuration_type,
+};
 use Task::Get_instance as Get_task_manager_instance;
-use Virtual_file_system::{Error_type, Get_instance as Get_file_system_instance};
+use Virtual_file_system::{
+    Error_type, Get_instance as Get_file_system_instance, Socket_address_type,
+};
+
+use crate::Xila_network_ip_address_type;
+
+// - Results of the socket functions that the C libraries convert into their own error codes (e.g. `errno`).
+// - The file system errors are offset by `0xFF` and the network errors by `0x200` (see [`Error_type`]).
+
+pub const Xila_file_system_result_invalid_parameter: Xila_file_system_result_type = 0x5;
+pub const Xila_file_system_result_too_many_open_files: Xila_file_system_result_type = 0x6;
+pub const Xila_file_system_result_permission_denied: Xila_file_system_result_type = 0x101;
+pub const Xila_file_system_result_not_found: Xila_file_system_result_type = 0x102;
+pub const Xila_file_system_result_invalid_identifier: Xila_file_system_result_type = 0x10C;
+pub const Xila_file_system_result_unsupported_operation: Xila_file_system_result_type = 0x114;
+pub const Xila_file_system_result_no_memory: Xila_file_system_result_type = 0x124;
+pub const Xila_file_system_result_broken_pipe: Xila_file_system_result_type = 0x128;
+
+pub const Xila_network_result_not_found: Xila_file_system_result_type = 0x201;
+pub const Xila_network_result_permission_denied: Xila_file_system_result_type = 0x202;
+pub const Xila_network_result_connection_refused: Xila_file_system_result_type = 0x203;
+pub const Xila_network_result_connection_reset: Xila_file_system_result_type = 0x204;
+pub const Xila_network_result_host_unreachable: Xila_file_system_result_type = 0x205;
+pub const Xila_network_result_network_unreachable: Xila_file_system_result_type = 0x206;
+pub const Xila_network_result_connection_aborted: Xila_file_system_result_type = 0x207;
+pub const Xila_network_result_not_connected: Xila_file_system_result_type = 0x208;
+pub const Xila_network_result_address_in_use: Xila_file_system_result_type = 0x209;
+pub const Xila_network_result_address_not_available: Xila_file_system_result_type = 0x20A;
+pub const Xila_network_result_network_down: Xila_file_system_result_type = 0x20B;
+pub const Xila_network_result_broken_pipe: Xila_file_system_result_type = 0x20C;
+pub const Xila_network_result_already_exists: Xila_file_system_result_type = 0x20D;
+pub const Xila_network_result_would_block: Xila_file_system_result_type = 0x20E;
+pub const Xila_network_result_invalid_input: Xila_file_system_result_type = 0x20F;
+pub const Xila_network_result_timed_out: Xila_file_system_result_type = 0x211;
+pub const Xila_network_result_interrupted: Xila_file_system_result_type = 0x216;
+pub const Xila_network_result_unsupported: Xila_file_system_result_type = 0x217;
+pub const Xila_network_result_out_of_memory: Xila_file_system_result_type = 0x219;
+pub const Xila_network_result_in_progress: Xila_file_system_result_type = 0x21A;
+pub const Xila_network_result_unsupported_protocol: Xila_file_system_result_type = 0x21C;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Xila_network_shutdown_type {
+    Read,
+    Write,
+    Both,
+}
+
+impl TryFrom<u8> for Xila_network_shutdown_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Read),
+            1 => Ok(Self::Write),
+            2 => Ok(Self::Both),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Xila_network_shutdown_type> for Shutdown_type {
+    fn from(How: Xila_network_shutdown_type) -> Self {
+        match How {
+            Xila_network_shutdown_type::Read => Shutdown_type::Read,
+            Xila_network_shutdown_type::Write => Shutdown_type::Write,
+            Xila_network_shutdown_type::Both => Shutdown_type::Both,
+        }
+    }
+}
+
+/// Option of a socket, the boolean options take `0` or `1` as value and the sizes are in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Xila_network_socket_option_type {
+    Reuse_address,
+    TCP_no_delay,
+    Keep_alive,
+    Broadcast,
+    Send_buffer_size,
+    Receive_buffer_size,
+}
+
+impl TryFrom<u8> for Xila_network_socket_option_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Reuse_address),
+            1 => Ok(Self::TCP_no_delay),
+            2 => Ok(Self::Keep_alive),
+            3 => Ok(Self::Broadcast),
+            4 => Ok(Self::Send_buffer_size),
+            5 => Ok(Self::Receive_buffer_size),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Xila_network_socket_option_type {
+    const fn Into_option(self, Value: usize) -> Socket_option_type {
+        match self {
+            Self::Reuse_address => Socket_option_type::Reuse_address(Value != 0),
+            Self::TCP_no_delay => Socket_option_type::TCP_no_delay(Value != 0),
+            Self::Keep_alive => Socket_option_type::Keep_alive(Value != 0),
+            Self::Broadcast => Socket_option_type::Broadcast(Value != 0),
+            Self::Send_buffer_size => Socket_option_type::Send_buffer_size(Value),
+            Self::Receive_buffer_size => Socket_option_type::Receive_buffer_size(Value),
+        }
+    }
+
+    const fn Into_kind(self) -> Socket_option_kind_type {
+        match self {
+            Self::Reuse_address => Socket_option_kind_type::Reuse_address,
+            Self::TCP_no_delay => Socket_option_kind_type::TCP_no_delay,
+            Self::Keep_alive => Socket_option_kind_type::Keep_alive,
+            Self::Broadcast => Socket_option_kind_type::Broadcast,
+            Self::Send_buffer_size => Socket_option_kind_type::Send_buffer_size,
+            Self::Receive_buffer_size => Socket_option_kind_type::Receive_buffer_size,
+        }
+    }
+}
+
+/// Convert an address given by the caller into a socket address.
+///
+/// # Safety
+///
+/// The address must be null or point to a valid IP address.
+unsafe fn Into_socket_address(
+    Address: *const Xila_network_ip_address_type,
+    Port: u16,
+) -> Result<Socket_address_type, Error_type> {
+    let Address = Address.as_ref().ok_or(Error_type::Invalid_parameter)?;
+
+    Ok(Socket_address_type::From_IP_and_port(
+        IP_type::from(*Address),
+        Port_type::New(Port),
+    ))
+}
+
+/// Write a socket address to the caller, both pointers can be null to ignore the IP address or the port.
+///
+/// # Safety
+///
+/// The pointers must be null or valid for writes.
+unsafe fn Write_socket_address(
+    Socket_address: Socket_address_type,
+    Address: *mut Xila_network_ip_address_type,
+    Port: *mut u16,
+) -> Result<(), Error_type> {
+    let (IP, Socket_port) = Socket_address
+        .Into_IP_and_port()
+        .ok_or(Error_type::Invalid_parameter)?;
+
+    if let Some(Address) = Address.as_mut() {
+        *Address = IP.into();
+    }
+
+    if let Some(Port) = Port.as_mut() {
+        *Port = Socket_port.Into_inner();
+    }
+
+    Ok(())
+}
+
+/// This function is used to create a socket, which can then be bound with [`Xila_file_system_bind`] or connected with [`Xila_file_system_connect`].
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the socket can't be created.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_create_socket(
+    Is_IPv4: bool,
+    Is_TCP: bool,
+    Socket: *mut Xila_unique_file_identifier_type,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        if Socket.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Family = if Is_IPv4 {
+            Address_family_type::IPv4
+        } else {
+            Address_family_type::IPv6
+        };
+
+        let Protocol = if Is_TCP {
+            Protocol_type::TCP
+        } else {
+            Protocol_type::UDP
+        };
+
+        let New_socket = Get_file_system_instance().Create_socket(Task, Family, Protocol)?;
+
+        Socket.write(New_socket.Into_inner());
+
+        Ok(())
+    })
+}
+
+/// This function is used to bind a socket to a local address, the port `0` lets the system choose one (see [`Xila_file_system_get_local_address`]).
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the address is already used.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_bind(
+    Socket: Xila_unique_file_identifier_type,
+    Address: *const Xila_network_ip_address_type,
+    Port: u16,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Address = Into_socket_address(Address, Port)?;
+
+        Get_file_system_instance().Bind_socket(Task, Socket, Address)?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to connect a socket to a remote address.
+///
+/// For a non-blocking socket, the function may fail with the "in progress" error : the socket becomes writable once connected.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the connection fails.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_connect(
+    Socket: Xila_unique_file_identifier_type,
+    Address: *const Xila_network_ip_address_type,
+    Port: u16,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Address = Into_socket_address(Address, Port)?;
+
+        Get_file_system_instance().Connect_socket(Task, Socket, Address)?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to make a bound TCP socket listen for connections, which are then accepted with [`Xila_file_system_accept`].
+///
+/// `Backlog` is the maximum number of pending connections.
+///
+/// # Errors
+///
+/// This function may return an error if the socket isn't bound or isn't a TCP socket.
+#[no_mangle]
+pub extern "C" fn Xila_file_system_listen(
+    Socket: Xila_unique_file_identifier_type,
+    Backlog: usize,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        Get_file_system_instance().Listen(Task, Socket, Backlog)?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to accept a connection on a listening socket.
+///
+/// `Address` and `Port` receive the address of the peer, they can be null.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the socket isn't listening.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_accept(
+    Socket: Xila_unique_file_identifier_type,
+    New_socket: *mut Xila_unique_file_identifier_type,
+    Address: *mut Xila_network_ip_address_type,
+    Port: *mut u16,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        if New_socket.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let (Accepted, Peer) = Get_file_system_instance().Accept(Task, Socket)?;
+
+        New_socket.write(Accepted.Into_inner());
+
+        if let Some((IP, Peer_port)) = Peer {
+            Write_socket_address(
+                Socket_address_type::From_IP_and_port(IP, Peer_port),
+                Address,
+                Port,
+            )?;
+        }
+
+        Ok(())
+    })
+}
+
+/// This function is used to close one or both directions of a connection, the socket still needs to be closed.
+///
+/// `How` is a [`Xila_network_shutdown_type`].
+///
+/// # Errors
+///
+/// This function may return an error if the socket isn't connected or if `How` is invalid.
+#[no_mangle]
+pub extern "C" fn Xila_file_system_shutdown(
+    Socket: Xila_unique_file_identifier_type,
+    How: u8,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let How =
+            Xila_network_shutdown_type::try_from(How).map_err(|_| Error_type::Invalid_parameter)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        Get_file_system_instance().Shutdown(Task, Socket, How.into())?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to get the local address of a socket, `Address` and `Port` can be null.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the socket isn't bound.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_get_local_address(
+    Socket: Xila_unique_file_identifier_type,
+    Address: *mut Xila_network_ip_address_type,
+    Port: *mut u16,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Local_address = Get_file_system_instance().Get_local_address(Task, Socket)?;
+
+        Write_socket_address(Local_address, Address, Port)?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to get the address of the peer of a connected socket, `Address` and `Port` can be null.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the socket isn't connected.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_get_remote_address(
+    Socket: Xila_unique_file_identifier_type,
+    Address: *mut Xila_network_ip_address_type,
+    Port: *mut u16,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Remote_address = Get_file_system_instance().Get_remote_address(Task, Socket)?;
+
+        Write_socket_address(Remote_address, Address, Port)?;
+
+        Ok(())
+    })
+}
 
 /// This function is used to send data through a socket.
 ///
@@ -32,7 +467,7 @@ pub unsafe extern "C" fn Xila_file_system_send(
     })
 }
 
-/// This function is used to receive data through a socket.
+/// This function is used to receive data through a socket, `Received` is set to the number of bytes received.
 ///
 /// # Safety
 ///
@@ -46,6 +481,45 @@ pub unsafe extern "C" fn Xila_file_system_receive(
     Socket: Xila_unique_file_identifier_type,
     Buffer: *mut u8,
     Size: usize,
+    Received: *mut usize,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        if Buffer.is_null() || Received.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Buffer = std::slice::from_raw_parts_mut(Buffer, Size);
+
+        let Size = Get_file_system_instance().Receive(Task, Socket, Buffer)?;
+
+        Received.write(Size);
+
+        Ok(())
+    })
+}
+
+/// This function is used to send a datagram to an address.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the file system fails to send the data.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_send_to(
+    Socket: Xila_unique_file_identifier_type,
+    Buffer: *const u8,
+    Size: usize,
+    Address: *const Xila_network_ip_address_type,
+    Port: u16,
 ) -> Xila_file_system_result_type {
     Into_u32(|| {
         let Task = Get_task_manager_instance()
@@ -58,9 +532,381 @@ pub unsafe extern "C" fn Xila_file_system_receive(
             Err(Error_type::Invalid_parameter)?;
         }
 
+        let Buffer = std::slice::from_raw_parts(Buffer, Size);
+
+        let Address = Into_socket_address(Address, Port)?;
+
+        Get_file_system_instance().Send_to(Task, Socket, Buffer, Address)?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to receive a datagram with the address of its sender, `Address` and `Port` can be null.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the file system fails to receive the data.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_receive_from(
+    Socket: Xila_unique_file_identifier_type,
+    Buffer: *mut u8,
+    Size: usize,
+    Received: *mut usize,
+    Address: *mut Xila_network_ip_address_type,
+    Port: *mut u16,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        if Buffer.is_null() || Received.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
         let Buffer = std::slice::from_raw_parts_mut(Buffer, Size);
 
-        Get_file_system_instance().Receive(Task, Socket, Buffer)?;
+        let (Size, Sender) = Get_file_system_instance().Receive_from(Task, Socket, Buffer)?;
+
+        Received.write(Size);
+
+        Write_socket_address(Sender, Address, Port)?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to set the timeout of the send operations, in microseconds.
+///
+/// # Errors
+///
+/// This function may return an error if the timeout is zero.
+#[no_mangle]
+pub extern "C" fn Xila_file_system_set_send_timeout(
+    Socket: Xila_unique_file_identifier_type,
+    Timeout: u64,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        Get_file_system_instance().Set_send_timeout(
+            Task,
+            Socket,
+            Duration_type::from_micros(Timeout),
+        )?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to set the timeout of the receive operations, in microseconds.
+///
+/// # Errors
+///
+/// This function may return an error if the timeout is zero.
+#[no_mangle]
+pub extern "C" fn Xila_file_system_set_receive_timeout(
+    Socket: Xila_unique_file_identifier_type,
+    Timeout: u64,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        Get_file_system_instance().Set_receive_timeout(
+            Task,
+            Socket,
+            Duration_type::from_micros(Timeout),
+        )?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to get the timeout of the send operations, in microseconds (`0` if there is none).
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_get_send_timeout(
+    Socket: Xila_unique_file_identifier_type,
+    Timeout: *mut u64,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        if Timeout.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Current = Get_file_system_instance().Get_send_timeout(Task, Socket)?;
+
+        Timeout.write(Current.map_or(0, |Current| Current.as_micros() as u64));
+
+        Ok(())
+    })
+}
+
+/// This function is used to get the timeout of the receive operations, in microseconds (`0` if there is none).
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_get_receive_timeout(
+    Socket: Xila_unique_file_identifier_type,
+    Timeout: *mut u64,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        if Timeout.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Current = Get_file_system_instance().Get_receive_timeout(Task, Socket)?;
+
+        Timeout.write(Current.map_or(0, |Current| Current.as_micros() as u64));
+
+        Ok(())
+    })
+}
+
+/// This function is used to set an option of a socket, `Option` is a [`Xila_network_socket_option_type`].
+///
+/// # Errors
+///
+/// This function may return an error if the option is invalid or isn't supported by the socket.
+#[no_mangle]
+pub extern "C" fn Xila_file_system_set_socket_option(
+    Socket: Xila_unique_file_identifier_type,
+    Option: u8,
+    Value: usize,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Option = Xila_network_socket_option_type::try_from(Option)
+            .map_err(|_| Error_type::Invalid_parameter)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        Get_file_system_instance().Set_socket_option(Task, Socket, Option.Into_option(Value))?;
+
+        Ok(())
+    })
+}
+
+/// This function is used to get an option of a socket, `Option` is a [`Xila_network_socket_option_type`].
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the option is invalid or isn't supported by the socket.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_get_socket_option(
+    Socket: Xila_unique_file_identifier_type,
+    Option: u8,
+    Value: *mut usize,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        if Value.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Option = Xila_network_socket_option_type::try_from(Option)
+            .map_err(|_| Error_type::Invalid_parameter)?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Current =
+            match Get_file_system_instance().Get_socket_option(Task, Socket, Option.Into_kind())? {
+                Socket_option_type::Reuse_address(Enabled)
+                | Socket_option_type::TCP_no_delay(Enabled)
+                | Socket_option_type::Keep_alive(Enabled)
+                | Socket_option_type::Broadcast(Enabled) => Enabled as usize,
+                Socket_option_type::Send_buffer_size(Size)
+                | Socket_option_type::Receive_buffer_size(Size) => Size,
+                Socket_option_type::Join_multicast(_) | Socket_option_type::Leave_multicast(_) => {
+                    Err(Error_type::Invalid_parameter)?
+                }
+            };
+
+        Value.write(Current);
+
+        Ok(())
+    })
+}
+
+/// This function is used to join or leave a multicast group (UDP only).
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the address isn't a multicast address.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_set_multicast_membership(
+    Socket: Xila_unique_file_identifier_type,
+    Group: *const Xila_network_ip_address_type,
+    Join: bool,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Group = IP_type::from(*Group.as_ref().ok_or(Error_type::Invalid_parameter)?);
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        let Option = if Join {
+            Socket_option_type::Join_multicast(Group)
+        } else {
+            Socket_option_type::Leave_multicast(Group)
+        };
+
+        Get_file_system_instance().Set_socket_option(Task, Socket, Option)?;
+
+        Ok(())
+    })
+}
+
+/// Configuration of a TLS session, the certificates and keys are in PEM format.
+///
+/// The pointers may be null when the matching size is zero (except `Server_name`).
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Xila_network_tls_configuration_type {
+    pub Is_server: bool,
+    /// Name checked against the certificate of the server (client only), it can only be null if `Verify_peer` is false.
+    pub Server_name: *const c_char,
+    /// Certificate chain, required for a server.
+    pub Certificate: *const u8,
+    pub Certificate_size: usize,
+    pub Private_key: *const u8,
+    pub Private_key_size: usize,
+    /// Trusted in addition to the trust store if used.
+    pub Trusted_certificates: *const u8,
+    pub Trusted_certificates_size: usize,
+    pub Use_trust_store: bool,
+    pub Verify_peer: bool,
+}
+
+unsafe fn Into_slice<'a>(Data: *const u8, Size: usize) -> Result<&'a [u8], Error_type> {
+    match (Data.is_null(), Size) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(Error_type::Invalid_parameter),
+        (false, _) => Ok(std::slice::from_raw_parts(Data, Size)),
+    }
+}
+
+impl Xila_network_tls_configuration_type {
+    unsafe fn Into_configuration(self) -> Result<TLS_configuration_type, Error_type> {
+        let Certificate = Into_slice(self.Certificate, self.Certificate_size)?;
+        let Private_key = Into_slice(self.Private_key, self.Private_key_size)?;
+
+        let Configuration = if self.Is_server {
+            if Certificate.is_empty() || Private_key.is_empty() {
+                return Err(Error_type::Invalid_parameter);
+            }
+
+            TLS_configuration_type::New_server(Certificate.to_vec(), Private_key.to_vec())
+        } else {
+            let Server_name = if self.Server_name.is_null() {
+                if self.Verify_peer {
+                    return Err(Error_type::Invalid_parameter);
+                }
+
+                ""
+            } else {
+                CStr::from_ptr(self.Server_name)
+                    .to_str()
+                    .map_err(|_| Error_type::Invalid_parameter)?
+            };
+
+            let Configuration = TLS_configuration_type::New_client(Server_name);
+
+            if Certificate.is_empty() {
+                Configuration
+            } else {
+                Configuration.Set_certificate(Certificate.to_vec(), Private_key.to_vec())
+            }
+        };
+
+        Ok(Configuration
+            .Add_trusted_certificates(Into_slice(
+                self.Trusted_certificates,
+                self.Trusted_certificates_size,
+            )?)
+            .Set_use_trust_store(self.Use_trust_store)
+            .Set_verify_peer(self.Verify_peer))
+    }
+}
+
+/// This function is used to start a TLS session on a connected (or accepted) TCP socket.
+///
+/// Once started, the data sent and received through the socket (including with the file functions) is encrypted.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the handshake fails or if the certificate of the peer isn't trusted.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_file_system_start_tls(
+    Socket: Xila_unique_file_identifier_type,
+    Configuration: *const Xila_network_tls_configuration_type,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        let Configuration = Configuration
+            .as_ref()
+            .ok_or(Error_type::Invalid_parameter)?
+            .Into_configuration()?;
+
+        let Socket = File_system::Unique_file_identifier_type::From_raw(Socket);
+
+        Get_file_system_instance().Start_TLS(Task, Socket, Configuration)?;
 
         Ok(())
     })
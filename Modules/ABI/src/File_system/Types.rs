@@ -86,6 +86,25 @@ pub static Xila_file_system_status_synchronous_mask: u8 = File_system::Status_ty
 pub static Xila_file_system_status_synchronous_data_only_mask: u8 =
     File_system::Status_type::Synchronous_data_only_bit;
 
+pub type Xila_file_system_events_type = u8;
+
+#[no_mangle]
+pub static Xila_file_system_events_readable_mask: u8 = File_system::Events_type::Readable_bit;
+#[no_mangle]
+pub static Xila_file_system_events_writable_mask: u8 = File_system::Events_type::Writable_bit;
+#[no_mangle]
+pub static Xila_file_system_events_hang_up_mask: u8 = File_system::Events_type::Hang_up_bit;
+#[no_mangle]
+pub static Xila_file_system_events_error_mask: u8 = File_system::Events_type::Error_bit;
+
+/// A file to poll, with the events to wait for and the events that are ready.
+#[repr(C)]
+pub struct Xila_file_system_poll_type {
+    pub File: Xila_unique_file_identifier_type,
+    pub Events: Xila_file_system_events_type,
+    pub Returned_events: Xila_file_system_events_type,
+}
+
 pub type Xila_file_system_inode_type = u64;
 
 type Xila_file_system_identifier_type = u32;
@@ -139,6 +158,44 @@ impl Xila_file_system_statistics_type {
     }
 }
 
+/// Capacity and free space of a file system (equivalent of `statvfs`), block counts are in `Block_size` bytes units.
+#[repr(C)]
+pub struct Xila_file_system_volume_statistics_type {
+    Block_size: u64,
+    Total_blocks: u64,
+    Free_blocks: u64,
+    Available_blocks: u64,
+    Total_inodes: u64,
+    Free_inodes: u64,
+}
+
+impl Xila_file_system_volume_statistics_type {
+    pub fn From_statistics(Statistics: File_system::File_system_statistics_type) -> Self {
+        Self {
+            Block_size: Statistics.Get_block_size(),
+            Total_blocks: Statistics.Get_total_blocks(),
+            Free_blocks: Statistics.Get_free_blocks(),
+            Available_blocks: Statistics.Get_available_blocks(),
+            Total_inodes: Statistics.Get_total_inodes(),
+            Free_inodes: Statistics.Get_free_inodes(),
+        }
+    }
+
+    pub fn From_mutable_pointer(
+        Pointer: *mut Xila_file_system_volume_statistics_type,
+    ) -> Option<*mut Xila_file_system_volume_statistics_type> {
+        if Pointer.is_null() {
+            return None;
+        }
+
+        if Pointer as usize % std::mem::align_of::<Xila_file_system_volume_statistics_type>() != 0 {
+            return None;
+        }
+
+        Some(Pointer)
+    }
+}
+
 pub type Xila_unique_file_identifier_type = usize;
 pub type Xila_file_system_size_type = u64;
 
@@ -0,0 +1,150 @@
+/// This module implements the name resolution C ABI.
+use core::ffi::{c_char, CStr};
+
+use Network::{IP_type, IPv4_type, IPv6_type, Record_kind_type};
+use Task::Get_instance as Get_task_manager_instance;
+use Virtual_file_system::{Error_type, Get_instance as Get_file_system_instance};
+
+use crate::{Into_u32, Xila_file_system_result_type};
+
+/// IP address, the IPv4 address is in host byte order (e.g. `127.0.0.1` is `0x7F000001`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Xila_network_ip_address_type {
+    pub Is_IPv4: bool,
+    pub IPv4: u32,
+    pub IPv6: [u16; 8],
+}
+
+impl From<IP_type> for Xila_network_ip_address_type {
+    fn from(IP: IP_type) -> Self {
+        match IP {
+            IP_type::IPv4(IP) => Self {
+                Is_IPv4: true,
+                IPv4: u32::from_be_bytes(IP.Into_inner()),
+                IPv6: [0; 8],
+            },
+            IP_type::IPv6(IP) => Self {
+                Is_IPv4: false,
+                IPv4: 0,
+                IPv6: IP.Into_inner(),
+            },
+        }
+    }
+}
+
+impl From<Xila_network_ip_address_type> for IP_type {
+    fn from(IP: Xila_network_ip_address_type) -> Self {
+        if IP.Is_IPv4 {
+            IPv4_type::New(IP.IPv4.to_be_bytes()).into()
+        } else {
+            IPv6_type::New(IP.IPv6).into()
+        }
+    }
+}
+
+/// This function is used to parse the textual form of an IP address (e.g. `192.168.1.2` or `fe80::1`).
+///
+/// `Is_IPv4` restricts the kind of the address, it can be null to accept both.
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the address is invalid or of the wrong kind.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_network_parse_ip_address(
+    Text: *const c_char,
+    Is_IPv4: *const bool,
+    Address: *mut Xila_network_ip_address_type,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        if Text.is_null() || Address.is_null() {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Text = CStr::from_ptr(Text)
+            .to_str()
+            .map_err(|_| Error_type::Invalid_parameter)?;
+
+        let IP = IP_type::try_from(Text).map_err(|_| Error_type::Invalid_parameter)?;
+
+        match (Is_IPv4.as_ref(), &IP) {
+            (Some(true), IP_type::IPv6(_)) | (Some(false), IP_type::IPv4(_)) => {
+                Err(Error_type::Invalid_parameter)?
+            }
+            _ => {}
+        }
+
+        Address.write(IP.into());
+
+        Ok(())
+    })
+}
+
+/// This function is used to resolve a host name into its addresses.
+///
+/// The IPv4 addresses come first. `Is_IPv4` restricts the kind of the addresses, it can be null to get both.
+/// `Count` is set to the number of addresses found, which can be greater than `Size` (the extra addresses are not written).
+///
+/// # Safety
+///
+/// This function is unsafe because it dereferences raw pointers.
+///
+/// # Errors
+///
+/// This function may return an error if the name can't be resolved.
+#[no_mangle]
+pub unsafe extern "C" fn Xila_network_resolve(
+    Name: *const c_char,
+    Is_IPv4: *const bool,
+    Addresses: *mut Xila_network_ip_address_type,
+    Size: usize,
+    Count: *mut usize,
+) -> Xila_file_system_result_type {
+    Into_u32(|| {
+        let Task = Get_task_manager_instance()
+            .Get_current_task_identifier()
+            .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+        if Name.is_null() || Count.is_null() || (Addresses.is_null() && Size > 0) {
+            Err(Error_type::Invalid_parameter)?;
+        }
+
+        let Name = CStr::from_ptr(Name)
+            .to_str()
+            .map_err(|_| Error_type::Invalid_parameter)?;
+
+        let Kinds: &[Record_kind_type] = match Is_IPv4.as_ref() {
+            Some(true) => &[Record_kind_type::A],
+            Some(false) => &[Record_kind_type::AAAA],
+            None => &[Record_kind_type::A, Record_kind_type::AAAA],
+        };
+
+        let mut Resolved = Vec::new();
+        let mut First_error = None;
+
+        for Kind in Kinds {
+            match Get_file_system_instance().Resolve(Task, Name, *Kind) {
+                Ok(IPs) => Resolved.extend(IPs),
+                Err(Error) => {
+                    First_error.get_or_insert(Error);
+                }
+            }
+        }
+
+        if Resolved.is_empty() {
+            Err(First_error.unwrap_or(Error_type::Network(Network::Error_type::Not_found)))?;
+        }
+
+        for (Index, IP) in Resolved.iter().take(Size).enumerate() {
+            Addresses.add(Index).write(IP.clone().into());
+        }
+
+        Count.write(Resolved.len());
+
+        Ok(())
+    })
+}
@@ -4,12 +4,14 @@
 
 mod File_system;
 mod Memory;
+mod Network;
 mod Task;
 mod Time;
 mod User;
 
 pub use File_system::*;
 pub use Memory::*;
+pub use Network::*;
 pub use Task::*;
 pub use Time::*;
 pub use User::*;
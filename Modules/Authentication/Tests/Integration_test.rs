@@ -0,0 +1,61 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use File_system::{Create_device, Create_file_system, Memory_file_system_type};
+use Users::Group_identifier_type;
+
+#[test]
+fn Test_create_user_after_freeze() {
+    let Task_instance = Task::Initialize().unwrap();
+
+    unsafe {
+        let _ = Task_instance.Register_task();
+    }
+
+    let _ = Users::Initialize();
+
+    let _ = Time::Initialize(Create_device!(Drivers::Native::Time_driver_type::New()));
+
+    let Task = Task_instance.Get_current_task_identifier().unwrap();
+
+    Virtual_file_system::Initialize(
+        Create_file_system!(Memory_file_system_type::New(1024 * 512)),
+        None,
+    )
+    .unwrap();
+
+    let Virtual_file_system = Virtual_file_system::Get_instance();
+
+    Virtual_file_system::Create_default_hierarchy(Virtual_file_system, Task).unwrap();
+
+    Virtual_file_system
+        .Mount_static_device(
+            Task,
+            &"/Devices/Random",
+            Create_device!(Drivers::Native::Random_device_type),
+        )
+        .unwrap();
+
+    Virtual_file_system::Freeze_system(Virtual_file_system, Task).unwrap();
+
+    let Group_identifier = Group_identifier_type::New(1000);
+
+    Authentication::Create_group(Virtual_file_system, "alix_anneraud", Some(Group_identifier))
+        .unwrap();
+
+    let User_identifier = Authentication::Create_user(
+        Virtual_file_system,
+        "alix_anneraud",
+        "password",
+        Group_identifier,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        Authentication::Authenticate_user(Virtual_file_system, "alix_anneraud", "password")
+            .unwrap(),
+        User_identifier
+    );
+}
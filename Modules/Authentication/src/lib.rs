@@ -11,8 +11,9 @@ pub use Error::*;
 pub use Group::*;
 pub use User::*;
 
-const Users_folder_path: &str = "/System/Users";
-const Group_folder_path: &str = "/System/Groups";
+// - Kept out of `/System`, which is read-only once the system is frozen.
+const Users_folder_path: &str = "/Configuration/Users";
+const Group_folder_path: &str = "/Configuration/Groups";
 const Random_device_path: &str = "/Devices/Random";
 
 pub fn Load_all_users_and_groups() -> Result_type<()> {
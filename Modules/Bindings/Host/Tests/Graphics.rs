@@ -92,6 +92,7 @@ fn Integration_test() {
         .Execute(
             Binary_buffer.to_vec(),
             8 * 1024,
+            &["Graphics"],
             Standard_in,
             Standard_out,
             Standard_error,
@@ -1,17 +1,77 @@
-use std::io::{stderr, stdin, stdout, Read, Write};
+use std::io::{stderr, stdin, stdout, BufRead, Read, Write};
+#[cfg(unix)]
+use std::sync::Mutex;
 
-use File_system::{Create_device, Device_trait, Size_type};
+use File_system::{Create_device, Device_trait, Events_type, Size_type};
 use Task::Task_identifier_type;
 use Virtual_file_system::Virtual_file_system_type;
 
+/// Switch the host terminal to non-canonical mode without echo, the previous mode is restored when dropped.
+#[cfg(unix)]
+struct Raw_mode_type(Option<libc::termios>);
+
+/// Raw mode of the host terminal, shared by all the standard input devices (the terminal is global to the process).
+#[cfg(unix)]
+static Raw_mode: Mutex<Option<Raw_mode_type>> = Mutex::new(None);
+
+#[cfg(unix)]
+impl Raw_mode_type {
+    fn New() -> Self {
+        unsafe {
+            let mut Termios = core::mem::zeroed::<libc::termios>();
+
+            // - Standard input is not a terminal.
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut Termios) != 0 {
+                return Self(None);
+            }
+
+            let Original = Termios;
+
+            Termios.c_lflag &= !(libc::ICANON | libc::ECHO);
+            Termios.c_cc[libc::VMIN] = 1;
+            Termios.c_cc[libc::VTIME] = 0;
+
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &Termios) != 0 {
+                return Self(None);
+            }
+
+            Self(Some(Original))
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Raw_mode_type {
+    fn drop(&mut self) {
+        if let Some(Original) = self.0.take() {
+            unsafe {
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &Original);
+            }
+        }
+    }
+}
+
 pub struct Standard_in_device_type;
 
 impl Device_trait for Standard_in_device_type {
+    /// Read the keys as they are typed (without echo) in raw mode (see [Self::Set_raw_mode]).
     fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<Size_type> {
-        #[allow(clippy::unused_io_amount)]
-        stdin().read(Buffer).unwrap();
+        let Size = stdin().read(Buffer)?;
 
-        Ok(Size_type::New(Buffer.len() as u64))
+        Ok(Size_type::New(Size as u64))
+    }
+
+    /// Read a line edited by the host terminal.
+    fn Read_line(&self, Buffer: &mut String) -> File_system::Result_type<Size_type> {
+        let Current_length = Buffer.len();
+
+        stdin().lock().read_line(Buffer)?;
+
+        if Buffer.ends_with('\n') {
+            Buffer.pop();
+        }
+
+        Ok(Size_type::New((Buffer.len() - Current_length) as u64))
     }
 
     fn Write(&self, _: &[u8]) -> File_system::Result_type<Size_type> {
@@ -33,6 +93,44 @@ impl Device_trait for Standard_in_device_type {
     fn Is_a_terminal(&self) -> bool {
         true
     }
+
+    /// Switch the host terminal to raw mode for the whole line editing, the keys typed between two reads are not echoed.
+    #[cfg(unix)]
+    fn Set_raw_mode(&self, Raw: bool) -> File_system::Result_type<()> {
+        let mut Raw_mode_guard = Raw_mode
+            .lock()
+            .map_err(|_| File_system::Error_type::Poisoned_lock)?;
+
+        if !Raw {
+            // - The previous mode is restored when dropped.
+            *Raw_mode_guard = None;
+        } else if Raw_mode_guard.is_none() {
+            *Raw_mode_guard = Some(Raw_mode_type::New());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the host standard input has data pending, without waiting.
+    #[cfg(unix)]
+    fn Get_events(&self) -> File_system::Result_type<Events_type> {
+        let mut Descriptor = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+
+        if unsafe { libc::poll(&mut Descriptor, 1, 0) } < 0 {
+            return Ok(Events_type::Error);
+        }
+
+        Ok(Events_type::New(
+            Descriptor.revents & libc::POLLIN != 0,
+            false,
+            Descriptor.revents & libc::POLLHUP != 0,
+            Descriptor.revents & (libc::POLLERR | libc::POLLNVAL) != 0,
+        ))
+    }
 }
 
 pub struct Standard_out_device_type;
@@ -1,31 +1,66 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    sync::RwLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use File_system::{Device_trait, Error_type, Result_type, Size_type};
 use Shared::Duration_type;
 
-pub struct Time_driver_type;
+/// Emulated real time clock based on the host clock.
+///
+/// Setting the time doesn't change the host clock : the difference with the host clock is kept instead.
+pub struct Time_driver_type {
+    /// Difference with the host clock in nanoseconds.
+    Offset: RwLock<i128>,
+}
 
 impl Time_driver_type {
     pub fn New() -> Self {
-        Self {}
+        Self {
+            Offset: RwLock::new(0),
+        }
     }
-}
 
-impl Device_trait for Time_driver_type {
-    fn Read(&self, Buffer: &mut [u8]) -> Result_type<Size_type> {
+    fn Get_host_time() -> Result_type<i128> {
         let Duration = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map_err(|_| Error_type::Internal_error)?;
 
-        let Duration = Duration_type::New(Duration.as_secs(), Duration.subsec_nanos());
+        Ok(Duration.as_nanos() as i128)
+    }
+}
+
+impl Device_trait for Time_driver_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<Size_type> {
+        if Buffer.len() != size_of::<Duration_type>() {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        let Offset = *self.Offset.read().map_err(|_| Error_type::Poisoned_lock)?;
+
+        let Time = (Self::Get_host_time()? + Offset).max(0) as u128;
+
+        let Duration =
+            Duration_type::New((Time / 1_000_000_000) as u64, (Time % 1_000_000_000) as u32);
 
         Buffer.copy_from_slice(Duration.as_ref());
 
         Ok(Buffer.len().into())
     }
 
-    fn Write(&self, _: &[u8]) -> Result_type<File_system::Size_type> {
-        Err(Error_type::Unsupported_operation)
+    fn Write(&self, Buffer: &[u8]) -> Result_type<File_system::Size_type> {
+        if Buffer.len() != size_of::<Duration_type>() {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        let mut Duration = Duration_type::default();
+
+        Duration.as_mut().copy_from_slice(Buffer);
+
+        *self.Offset.write().map_err(|_| Error_type::Poisoned_lock)? =
+            Duration.As_nanoseconds() as i128 - Self::Get_host_time()?;
+
+        Ok(Buffer.len().into())
     }
 
     fn Get_size(&self) -> File_system::Result_type<File_system::Size_type> {
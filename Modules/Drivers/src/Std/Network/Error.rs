@@ -2,8 +2,31 @@ use std::io::{self, ErrorKind};
 
 use Network::Error_type;
 
-pub fn Into_socket_error(Error: io::Error) -> Error_type {
-    match Error.kind() {
+/// Convert the raw OS error codes that the standard library doesn't categorize.
+fn From_raw_OS_error(Code: i32) -> Option<Error_type> {
+    let Error = match Code {
+        libc::EINPROGRESS | libc::EALREADY => Error_type::In_progress,
+        libc::EAFNOSUPPORT
+        | libc::EPFNOSUPPORT
+        | libc::EPROTONOSUPPORT
+        | libc::EPROTOTYPE
+        | libc::ESOCKTNOSUPPORT => Error_type::Unsupported_protocol,
+        libc::ENOPROTOOPT | libc::EOPNOTSUPP => Error_type::Unsupported,
+        libc::EBADF | libc::ENOTSOCK => Error_type::Invalid_identifier,
+        libc::EMSGSIZE | libc::EDESTADDRREQ | libc::EISCONN => Error_type::Invalid_input,
+        libc::EPROTO | libc::EBADMSG => Error_type::Invalid_data,
+        libc::ENOBUFS => Error_type::Out_of_memory,
+        libc::EHOSTDOWN => Error_type::Host_unreachable,
+        libc::ENETRESET => Error_type::Connection_reset,
+        libc::ESHUTDOWN => Error_type::Broken_pipe,
+        _ => return None,
+    };
+
+    Some(Error)
+}
+
+fn From_error_kind(Kind: ErrorKind) -> Error_type {
+    match Kind {
         ErrorKind::NotFound => Error_type::Not_found,
         ErrorKind::PermissionDenied => Error_type::Permission_denied,
         ErrorKind::ConnectionRefused => Error_type::Connection_refused,
@@ -18,28 +41,104 @@ pub fn Into_socket_error(Error: io::Error) -> Error_type {
         ErrorKind::BrokenPipe => Error_type::Broken_pipe,
         ErrorKind::AlreadyExists => Error_type::Already_exists,
         ErrorKind::WouldBlock => Error_type::Would_block,
-
-        // ErrorKind::FilesystemLoop => Error_type::Filesystem_loop,
         ErrorKind::InvalidInput => Error_type::Invalid_input,
         ErrorKind::InvalidData => Error_type::Invalid_data,
         ErrorKind::TimedOut => Error_type::Timed_out,
         ErrorKind::WriteZero => Error_type::Write_zero,
         ErrorKind::StorageFull => Error_type::Storage_full,
-
-        // ErrorKind::FilesystemQuotaExceeded => Error_type::Filesystem_quota_exceeded,
         ErrorKind::ResourceBusy => Error_type::Resource_busy,
-
         ErrorKind::Deadlock => Error_type::Deadlock,
-        // ErrorKind::CrossesDevices => todo!(),
-
-        // ErrorKind::InvalidFilename => todo!(),
-        ErrorKind::ArgumentListTooLong => todo!(),
         ErrorKind::Interrupted => Error_type::Interrupted,
         ErrorKind::Unsupported => Error_type::Unsupported,
         ErrorKind::UnexpectedEof => Error_type::Unexpected_end_of_file,
         ErrorKind::OutOfMemory => Error_type::Out_of_memory,
-        // ErrorKind::InProgress => todo!(),
-        ErrorKind::Other => Error_type::Other,
-        _ => todo!(),
+        ErrorKind::ReadOnlyFilesystem => Error_type::Read_only_file_system,
+        ErrorKind::StaleNetworkFileHandle => Error_type::Stale_network_file_handle,
+        ErrorKind::InvalidFilename => Error_type::Invalid_file_name,
+        ErrorKind::ArgumentListTooLong => Error_type::Argument_list_too_long,
+        ErrorKind::NotADirectory => Error_type::Not_a_directory,
+        ErrorKind::IsADirectory => Error_type::Is_a_directory,
+        ErrorKind::DirectoryNotEmpty => Error_type::Directory_not_empty,
+        ErrorKind::NotSeekable => Error_type::Not_seekable,
+        ErrorKind::FileTooLarge => Error_type::File_too_large,
+        ErrorKind::CrossesDevices => Error_type::Crosses_devices,
+        ErrorKind::TooManyLinks => Error_type::Too_many_links,
+        ErrorKind::QuotaExceeded => Error_type::Quota_exceeded,
+        ErrorKind::ExecutableFileBusy => Error_type::Executable_file_busy,
+        // - `Other`, and the kinds added in the future.
+        _ => Error_type::Other,
+    }
+}
+
+/// Convert an error of the host into a socket error, never failing.
+///
+/// The errors without equivalent keep their raw OS error code, if any.
+pub fn Into_socket_error(Error: io::Error) -> Error_type {
+    match (From_error_kind(Error.kind()), Error.raw_os_error()) {
+        (Error_type::Other, Some(Code)) => {
+            From_raw_OS_error(Code).unwrap_or(Error_type::Raw_OS_error(Code))
+        }
+        (Error, _) => Error,
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_into_socket_error() {
+        let Cases = [
+            (libc::ECONNREFUSED, Error_type::Connection_refused),
+            (libc::ECONNRESET, Error_type::Connection_reset),
+            (libc::ETIMEDOUT, Error_type::Timed_out),
+            (libc::EADDRINUSE, Error_type::Address_in_use),
+            (libc::EAGAIN, Error_type::Would_block),
+            (libc::EPIPE, Error_type::Broken_pipe),
+            (libc::EINPROGRESS, Error_type::In_progress),
+            (libc::ENOPROTOOPT, Error_type::Unsupported),
+            (libc::EPROTONOSUPPORT, Error_type::Unsupported_protocol),
+            (libc::ENOTSOCK, Error_type::Invalid_identifier),
+            (libc::EMSGSIZE, Error_type::Invalid_input),
+            (libc::ENOBUFS, Error_type::Out_of_memory),
+            (libc::EXDEV, Error_type::Crosses_devices),
+            (libc::ENAMETOOLONG, Error_type::Invalid_file_name),
+            (libc::E2BIG, Error_type::Argument_list_too_long),
+            (libc::ENOTDIR, Error_type::Not_a_directory),
+            (libc::EROFS, Error_type::Read_only_file_system),
+            (libc::ETXTBSY, Error_type::Executable_file_busy),
+            (libc::ESTALE, Error_type::Stale_network_file_handle),
+            (libc::EDQUOT, Error_type::Quota_exceeded),
+            (libc::ENOSPC, Error_type::Storage_full),
+        ];
+
+        for (Code, Expected) in Cases {
+            assert_eq!(
+                Into_socket_error(io::Error::from_raw_os_error(Code)),
+                Expected,
+                "{}",
+                Code
+            );
+        }
+    }
+
+    #[test]
+    fn Test_into_socket_error_other() {
+        // - Unknown code : the original code is kept.
+        assert_eq!(
+            Into_socket_error(io::Error::from_raw_os_error(4095)),
+            Error_type::Raw_OS_error(4095)
+        );
+
+        // - Errors which don't come from the OS.
+        assert_eq!(
+            Into_socket_error(io::Error::other("Custom")),
+            Error_type::Other
+        );
+
+        assert_eq!(
+            Into_socket_error(io::Error::from(ErrorKind::UnexpectedEof)),
+            Error_type::Unexpected_end_of_file
+        );
     }
 }
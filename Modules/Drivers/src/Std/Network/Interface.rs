@@ -0,0 +1,246 @@
+use std::{collections::BTreeMap, ffi::CStr, fs::read_to_string, ptr::null_mut};
+
+use Network::{
+    Error_type, IP_type, IPv4_type, IPv6_type, Interface_address_type, Interface_status_type,
+    Network_interface_driver_trait, Result_type,
+};
+
+/// Driver reporting the interfaces of the host, read-only : the host configuration isn't modified.
+pub struct Network_interface_driver_type;
+
+impl Network_interface_driver_type {
+    pub fn New() -> Self {
+        Self
+    }
+}
+
+/// Interface as gathered from the addresses of the host.
+#[derive(Default)]
+struct Host_interface_type {
+    Hardware_address: Option<[u8; 6]>,
+    Link_up: bool,
+    Addresses: Vec<Interface_address_type>,
+}
+
+/// Convert a socket address to an IP address, `None` for other families.
+///
+/// # Safety
+///
+/// The address must be null or point to a valid socket address of its family.
+unsafe fn Into_IP(Address: *const libc::sockaddr) -> Option<IP_type> {
+    let Address = Address.as_ref()?;
+
+    match Address.sa_family as libc::c_int {
+        libc::AF_INET => {
+            let Address = &*(Address as *const _ as *const libc::sockaddr_in);
+
+            Some(IPv4_type::New(Address.sin_addr.s_addr.to_ne_bytes()).into())
+        }
+        libc::AF_INET6 => {
+            let Address = &*(Address as *const _ as *const libc::sockaddr_in6);
+
+            let Bytes = Address.sin6_addr.s6_addr;
+            let mut Segments = [0; 8];
+
+            for (Segment, Bytes) in Segments.iter_mut().zip(Bytes.chunks_exact(2)) {
+                *Segment = u16::from_be_bytes([Bytes[0], Bytes[1]]);
+            }
+
+            Some(IPv6_type::New(Segments).into())
+        }
+        _ => None,
+    }
+}
+
+/// Get the prefix length of a netmask (e.g. 24 for `255.255.255.0`).
+fn Get_prefix_length(Netmask: IP_type) -> u8 {
+    match Netmask {
+        IP_type::IPv4(Netmask) => Netmask
+            .Into_inner()
+            .iter()
+            .map(|Byte| Byte.count_ones() as u8)
+            .sum(),
+        IP_type::IPv6(Netmask) => Netmask
+            .Into_inner()
+            .iter()
+            .map(|Segment| Segment.count_ones() as u8)
+            .sum(),
+    }
+}
+
+/// Get the hardware address of a link layer socket address, `None` for other families.
+///
+/// # Safety
+///
+/// The address must be null or point to a valid socket address of its family.
+#[cfg(target_os = "linux")]
+unsafe fn Into_hardware_address(Address: *const libc::sockaddr) -> Option<[u8; 6]> {
+    let Address = Address.as_ref()?;
+
+    if Address.sa_family as libc::c_int != libc::AF_PACKET {
+        return None;
+    }
+
+    let Address = &*(Address as *const _ as *const libc::sockaddr_ll);
+
+    if Address.sll_halen != 6 {
+        return None;
+    }
+
+    let mut Hardware_address = [0; 6];
+    Hardware_address.copy_from_slice(&Address.sll_addr[..6]);
+
+    Some(Hardware_address)
+}
+
+#[cfg(not(target_os = "linux"))]
+unsafe fn Into_hardware_address(_: *const libc::sockaddr) -> Option<[u8; 6]> {
+    None
+}
+
+/// Gather the interfaces of the host with their addresses.
+fn Get_host_interfaces() -> Result_type<BTreeMap<String, Host_interface_type>> {
+    let mut Addresses: *mut libc::ifaddrs = null_mut();
+
+    if unsafe { libc::getifaddrs(&mut Addresses) } != 0 {
+        return Err(Error_type::Other);
+    }
+
+    let mut Interfaces: BTreeMap<String, Host_interface_type> = BTreeMap::new();
+
+    let mut Current = Addresses;
+
+    while let Some(Address) = unsafe { Current.as_ref() } {
+        Current = Address.ifa_next;
+
+        let Name = unsafe { CStr::from_ptr(Address.ifa_name) }
+            .to_string_lossy()
+            .into_owned();
+
+        let Interface = Interfaces.entry(Name).or_default();
+
+        let Running = (libc::IFF_UP | libc::IFF_RUNNING) as libc::c_uint;
+
+        Interface.Link_up = Address.ifa_flags & Running == Running;
+
+        if let Some(Hardware_address) = unsafe { Into_hardware_address(Address.ifa_addr) } {
+            Interface.Hardware_address = Some(Hardware_address);
+        }
+
+        if let Some(IP) = unsafe { Into_IP(Address.ifa_addr) } {
+            let Prefix_length = match unsafe { Into_IP(Address.ifa_netmask) } {
+                Some(Netmask) => Get_prefix_length(Netmask),
+                None => match IP {
+                    IP_type::IPv4(_) => 32,
+                    IP_type::IPv6(_) => 128,
+                },
+            };
+
+            Interface
+                .Addresses
+                .push(Interface_address_type::New(IP, Prefix_length));
+        }
+    }
+
+    unsafe { libc::freeifaddrs(Addresses) };
+
+    Ok(Interfaces)
+}
+
+/// Get the IPv4 default gateway of an interface from the routing table of the host.
+fn Get_gateway(Interface: &str) -> Option<IP_type> {
+    let Table = read_to_string("/proc/net/route").ok()?;
+
+    // - Columns : interface, destination, gateway... in hexadecimal, in network byte order.
+    Table.lines().skip(1).find_map(|Line| {
+        let mut Columns = Line.split_whitespace();
+
+        if Columns.next()? != Interface || Columns.next()? != "00000000" {
+            return None;
+        }
+
+        let Gateway = u32::from_str_radix(Columns.next()?, 16).ok()?;
+
+        Some(IPv4_type::New(Gateway.to_ne_bytes()).into())
+    })
+}
+
+/// Get the DNS servers of the host, shared by all the interfaces.
+fn Get_DNS_servers() -> Vec<IP_type> {
+    let Content = read_to_string("/etc/resolv.conf").unwrap_or_default();
+
+    Content
+        .lines()
+        .filter_map(|Line| {
+            let mut Tokens = Line.split_whitespace();
+
+            if Tokens.next()? != "nameserver" {
+                return None;
+            }
+
+            IP_type::try_from(Tokens.next()?).ok()
+        })
+        .collect()
+}
+
+impl Network_interface_driver_trait for Network_interface_driver_type {
+    fn Get_interfaces(&self) -> Result_type<Vec<String>> {
+        Ok(Get_host_interfaces()?.into_keys().collect())
+    }
+
+    fn Get_status(&self, Interface: &str) -> Result_type<Interface_status_type> {
+        let Host_interface = Get_host_interfaces()?
+            .remove(Interface)
+            .ok_or(Error_type::Not_found)?;
+
+        Ok(Interface_status_type::New(
+            Interface.to_string(),
+            Host_interface.Hardware_address,
+            Host_interface.Link_up,
+            Host_interface.Addresses,
+            Get_gateway(Interface),
+            Get_DNS_servers(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    use Network::Interface_configuration_type;
+
+    #[test]
+    fn Test_loopback() {
+        let Driver = Network_interface_driver_type::New();
+
+        let Interfaces = Driver.Get_interfaces().unwrap();
+
+        let Loopback = Interfaces
+            .iter()
+            .find(|Interface| Interface.starts_with("lo"))
+            .expect("No loopback interface");
+
+        let Status = Driver.Get_status(Loopback).unwrap();
+
+        assert!(Status
+            .Get_addresses()
+            .contains(&Interface_address_type::New(IPv4_type::Localhost.into(), 8)));
+
+        assert_eq!(
+            Driver.Get_status("not an interface"),
+            Err(Error_type::Not_found)
+        );
+    }
+
+    #[test]
+    fn Test_read_only() {
+        let Driver = Network_interface_driver_type::New();
+
+        assert_eq!(
+            Driver.Set_configuration("lo", &Interface_configuration_type::New_DHCP(vec![])),
+            Err(Error_type::Unsupported)
+        );
+        assert_eq!(Driver.Set_link("lo", false), Err(Error_type::Unsupported));
+    }
+}
@@ -1,3 +1,11 @@
+use std::net::{IpAddr, ToSocketAddrs};
+
+use Network::{
+    Error_type, IP_type, IPv4_type, IPv6_type, Network_resolver_trait, Record_kind_type,
+    Result_type,
+};
+
+/// Resolver relying on the name resolution of the host (hosts file, DNS servers, mDNS...).
 pub struct Resolver_type;
 
 impl Resolver_type {
@@ -5,3 +13,142 @@ impl Resolver_type {
         Self
     }
 }
+
+impl Network_resolver_trait for Resolver_type {
+    fn Resolve(&self, Name: &str, Kind: Record_kind_type) -> Result_type<Vec<IP_type>> {
+        // - The host errors are not detailed enough to be converted (e.g. unknown name or no server).
+        let Addresses = (Name, 0)
+            .to_socket_addrs()
+            .map_err(|_| Error_type::Not_found)?;
+
+        let mut Result: Vec<IP_type> = Vec::new();
+
+        for Address in Addresses {
+            let IP = match Address.ip() {
+                IpAddr::V4(IP) => IP_type::IPv4(IPv4_type::New(IP.octets())),
+                IpAddr::V6(IP) => IP_type::IPv6(IPv6_type::New(IP.segments())),
+            };
+
+            if Kind.Matches(&IP) && !Result.contains(&IP) {
+                Result.push(IP);
+            }
+        }
+
+        if Result.is_empty() {
+            return Err(Error_type::Not_found);
+        }
+
+        Ok(Result)
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    use std::{net::UdpSocket, thread};
+
+    use File_system::Create_device;
+    use Network::{Port_type, Stub_resolver_type};
+    use Task::Task_identifier_type;
+    use Time::Duration_type;
+
+    use crate::Std::Network::Network_socket_driver_type;
+
+    #[test]
+    fn Test_resolve_localhost() {
+        let Resolver = Resolver_type::New();
+
+        assert!(Resolver
+            .Resolve("localhost", Record_kind_type::A)
+            .unwrap()
+            .contains(&IPv4_type::Localhost.into()));
+
+        assert_eq!(
+            Resolver.Resolve("invalid.invalid", Record_kind_type::A),
+            Err(Error_type::Not_found)
+        );
+    }
+
+    /// Answer the queries with an A record, until `Count` queries are answered.
+    fn Start_server(Count: usize) -> (Port_type, thread::JoinHandle<()>) {
+        let Server = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        let Port = Port_type::New(Server.local_addr().unwrap().port());
+
+        let Handle = thread::spawn(move || {
+            let mut Buffer = [0; 512];
+
+            for _ in 0..Count {
+                let (Size, Client) = Server.recv_from(&mut Buffer).unwrap();
+
+                let mut Response = Buffer[..Size].to_vec();
+
+                // - Response, with one answer pointing to the name of the question.
+                Response[2..4].copy_from_slice(&[0x81, 0x80]);
+                Response[6..8].copy_from_slice(&[0, 1]);
+                Response.extend_from_slice(&[0xC0, 0x0C, 0, 1, 0, 1, 0, 0, 0, 60, 0, 4]);
+                Response.extend_from_slice(&[10, 0, 0, 1]);
+
+                Server.send_to(&Response, Client).unwrap();
+            }
+        });
+
+        (Port, Handle)
+    }
+
+    #[test]
+    fn Test_stub_resolver() {
+        let _ = Time::Initialize(Create_device!(crate::Native::Time_driver_type::New()));
+
+        let Driver = Network_socket_driver_type::New();
+
+        let (Port, Server) = Start_server(1);
+
+        let Resolver = Stub_resolver_type::New(
+            &Driver,
+            Task_identifier_type::New(1),
+            vec![(IPv4_type::Localhost.into(), Port)],
+            Duration_type::from_secs(1),
+            Create_device!(crate::Native::Random_device_type::New()),
+        );
+
+        let Expected: Vec<IP_type> = vec![IPv4_type::New([10, 0, 0, 1]).into()];
+
+        assert_eq!(
+            Resolver
+                .Resolve("Example.com", Record_kind_type::A)
+                .unwrap(),
+            Expected
+        );
+
+        Server.join().unwrap();
+
+        // - The server is gone : the answer comes from the cache.
+        assert_eq!(
+            Resolver
+                .Resolve("example.com.", Record_kind_type::A)
+                .unwrap(),
+            Expected
+        );
+
+        // - A server that never answers.
+        let Silent_server = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        Resolver
+            .Set_servers(vec![(
+                IPv4_type::Localhost.into(),
+                Port_type::New(Silent_server.local_addr().unwrap().port()),
+            )])
+            .unwrap();
+
+        assert_eq!(
+            Resolver.Resolve("example.com", Record_kind_type::A),
+            Err(Error_type::Timed_out)
+        );
+        assert_eq!(
+            Resolver.Resolve("not a name", Record_kind_type::A),
+            Err(Error_type::Invalid_input)
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    io::{self, Read, Write},
+    mem::ManuallyDrop,
+    net::TcpStream,
+    os::fd::{FromRawFd, RawFd},
+};
+
+use openssl::{
+    pkey::PKey,
+    ssl::{
+        ErrorCode, HandshakeError, Ssl, SslContext, SslContextBuilder, SslMethod, SslStream,
+        SslVerifyMode, SslVersion,
+    },
+    x509::{X509VerifyResult, X509},
+};
+
+use Network::{Error_type, Result_type, TLS_configuration_type, TLS_role_type};
+
+use crate::Std::Network::Error::Into_socket_error;
+
+/// Socket of the driver on which a session is started, it isn't closed with the session.
+struct Socket_stream_type(ManuallyDrop<TcpStream>);
+
+impl Socket_stream_type {
+    fn New(Socket: RawFd) -> Self {
+        Self(ManuallyDrop::new(unsafe { TcpStream::from_raw_fd(Socket) }))
+    }
+}
+
+impl Read for Socket_stream_type {
+    fn read(&mut self, Buffer: &mut [u8]) -> io::Result<usize> {
+        self.0.read(Buffer)
+    }
+}
+
+impl Write for Socket_stream_type {
+    fn write(&mut self, Buffer: &[u8]) -> io::Result<usize> {
+        self.0.write(Buffer)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Read all the certificates of a PEM buffer.
+fn Read_certificates(PEM: &[u8]) -> Result_type<Vec<X509>> {
+    if PEM.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    X509::stack_from_pem(PEM).map_err(|_| Error_type::Invalid_certificate)
+}
+
+/// Set the certificate chain (starting with the certificate itself) and its private key.
+fn Set_certificate(
+    Context: &mut SslContextBuilder,
+    Certificate: &[u8],
+    Private_key: &[u8],
+) -> Result_type<()> {
+    let mut Chain = Read_certificates(Certificate)?.into_iter();
+
+    let Certificate = Chain.next().ok_or(Error_type::Invalid_certificate)?;
+
+    Context
+        .set_certificate(&Certificate)
+        .map_err(|_| Error_type::Invalid_certificate)?;
+
+    for Intermediate in Chain {
+        Context
+            .add_extra_chain_cert(Intermediate)
+            .map_err(|_| Error_type::Invalid_certificate)?;
+    }
+
+    let Private_key =
+        PKey::private_key_from_pem(Private_key).map_err(|_| Error_type::Invalid_certificate)?;
+
+    Context
+        .set_private_key(&Private_key)
+        .and_then(|_| Context.check_private_key())
+        .map_err(|_| Error_type::Invalid_certificate)
+}
+
+/// Create the context from which the session is created, only needed until the session is created.
+fn New_context(Configuration: &TLS_configuration_type) -> Result_type<SslContext> {
+    let Method = match Configuration.Get_role() {
+        TLS_role_type::Client => SslMethod::tls_client(),
+        TLS_role_type::Server => SslMethod::tls_server(),
+    };
+
+    let mut Context = SslContextBuilder::new(Method).map_err(|_| Error_type::Out_of_memory)?;
+
+    Context
+        .set_min_proto_version(Some(SslVersion::TLS1_2))
+        .map_err(|_| Error_type::Unsupported)?;
+
+    for Certificate in Read_certificates(Configuration.Get_trusted_certificates())? {
+        // - Duplicates are reported as errors, they are ignored.
+        let _ = Context.cert_store_mut().add_cert(Certificate);
+    }
+
+    if let (Some(Certificate), Some(Private_key)) = (
+        Configuration.Get_certificate(),
+        Configuration.Get_private_key(),
+    ) {
+        Set_certificate(&mut Context, Certificate, Private_key)?;
+    }
+
+    let Verify = match (Configuration.Get_verify_peer(), Configuration.Get_role()) {
+        (false, _) => SslVerifyMode::NONE,
+        (true, TLS_role_type::Client) => SslVerifyMode::PEER,
+        (true, TLS_role_type::Server) => SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT,
+    };
+
+    Context.set_verify(Verify);
+
+    Ok(Context.build())
+}
+
+/// Convert an error of the socket reported by the TLS implementation, `None` if the connection was closed.
+fn Into_error(Error: Option<&io::Error>) -> Option<Error_type> {
+    let Error = Error?;
+
+    let Error = match Error.raw_os_error() {
+        Some(Code) => io::Error::from_raw_os_error(Code),
+        None => Error.kind().into(),
+    };
+
+    Some(Into_socket_error(Error))
+}
+
+/// TLS session over a connected socket, the socket stays owned by the caller.
+pub struct TLS_session_type(SslStream<Socket_stream_type>);
+
+impl Drop for TLS_session_type {
+    fn drop(&mut self) {
+        // - Notify the peer that the session is closed, without waiting for its answer.
+        let _ = self.0.shutdown();
+    }
+}
+
+impl TLS_session_type {
+    /// Start a session on a connected socket, performing the handshake.
+    ///
+    /// A client verifying the certificate of the server requires its name.
+    pub fn New(Socket: RawFd, Configuration: &TLS_configuration_type) -> Result_type<Self> {
+        let Context = New_context(Configuration)?;
+
+        let mut Session = Ssl::new(&Context).map_err(|_| Error_type::Out_of_memory)?;
+
+        if Configuration.Get_role() == TLS_role_type::Client {
+            let Server_name = Configuration.Get_server_name();
+
+            if Server_name.is_empty() {
+                if Configuration.Get_verify_peer() {
+                    return Err(Error_type::Invalid_input);
+                }
+            } else {
+                Session
+                    .set_hostname(Server_name)
+                    .map_err(|_| Error_type::Invalid_input)?;
+
+                if Configuration.Get_verify_peer() {
+                    Session
+                        .param_mut()
+                        .set_host(Server_name)
+                        .map_err(|_| Error_type::Invalid_input)?;
+                }
+            }
+        }
+
+        let Stream = Socket_stream_type::New(Socket);
+
+        let Result = match Configuration.Get_role() {
+            TLS_role_type::Client => Session.connect(Stream),
+            TLS_role_type::Server => Session.accept(Stream),
+        };
+
+        match Result {
+            Ok(Stream) => Ok(Self(Stream)),
+            Err(HandshakeError::SetupFailure(_)) => Err(Error_type::Handshake_failed),
+            // - Timeout of the socket elapsed.
+            Err(HandshakeError::WouldBlock(_)) => Err(Error_type::Timed_out),
+            Err(HandshakeError::Failure(Stream)) => {
+                if Stream.ssl().verify_result() != X509VerifyResult::OK {
+                    return Err(Error_type::Invalid_certificate);
+                }
+
+                match Stream.error().code() {
+                    ErrorCode::SYSCALL => Err(Into_error(Stream.error().io_error())
+                        .unwrap_or(Error_type::Connection_aborted)),
+                    _ => Err(Error_type::Handshake_failed),
+                }
+            }
+        }
+    }
+
+    /// Convert the error of a failed read or write, `None` if the peer closed the session.
+    fn Get_error(Error: &openssl::ssl::Error) -> Option<Error_type> {
+        match Error.code() {
+            ErrorCode::ZERO_RETURN => None,
+            // - Timeout of the socket elapsed.
+            ErrorCode::WANT_READ | ErrorCode::WANT_WRITE => Some(Error_type::Timed_out),
+            // - Without error, the connection was closed without notifying the end of the session.
+            ErrorCode::SYSCALL => Into_error(Error.io_error()),
+            _ => Some(Error_type::Invalid_data),
+        }
+    }
+
+    /// Receive decrypted data, `0` once the peer closed the session.
+    pub fn Read(&mut self, Buffer: &mut [u8]) -> Result_type<usize> {
+        match self.0.ssl_read(Buffer) {
+            Ok(Size) => Ok(Size),
+            Err(Error) => match Self::Get_error(&Error) {
+                None => Ok(0),
+                Some(Error) => Err(Error),
+            },
+        }
+    }
+
+    pub fn Write_all(&mut self, mut Data: &[u8]) -> Result_type<()> {
+        while !Data.is_empty() {
+            let Size = self
+                .0
+                .ssl_write(Data)
+                .map_err(|Error| Self::Get_error(&Error).unwrap_or(Error_type::Broken_pipe))?;
+
+            Data = &Data[Size..];
+        }
+
+        Ok(())
+    }
+
+    /// Check if decrypted data can be read without waiting for the socket.
+    pub fn Has_pending_data(&self) -> bool {
+        self.0.ssl().pending() > 0
+    }
+}
@@ -0,0 +1,24 @@
+use std::{convert::Infallible, os::fd::RawFd};
+
+use Network::{Error_type, Result_type, TLS_configuration_type};
+
+/// TLS session, which can't be started without the `TLS` feature.
+pub struct TLS_session_type(Infallible);
+
+impl TLS_session_type {
+    pub fn New(_: RawFd, _: &TLS_configuration_type) -> Result_type<Self> {
+        Err(Error_type::Unsupported)
+    }
+
+    pub fn Read(&mut self, _: &mut [u8]) -> Result_type<usize> {
+        match self.0 {}
+    }
+
+    pub fn Write_all(&mut self, _: &[u8]) -> Result_type<()> {
+        match self.0 {}
+    }
+
+    pub fn Has_pending_data(&self) -> bool {
+        match self.0 {}
+    }
+}
@@ -1,7 +1,17 @@
 mod Error;
+mod Interface;
 mod Resolver;
 mod Socket;
+#[cfg(feature = "TLS")]
+mod TLS;
+#[cfg(not(feature = "TLS"))]
+mod Unsupported_TLS;
 
 pub use Error::*;
+pub use Interface::*;
 pub use Resolver::*;
 pub use Socket::*;
+#[cfg(feature = "TLS")]
+pub use TLS::*;
+#[cfg(not(feature = "TLS"))]
+pub use Unsupported_TLS::*;
@@ -0,0 +1,216 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use std::{
+    num::NonZeroUsize,
+    sync::{Mutex, Once},
+};
+
+use Executable::{
+    Arguments_type, Default_runtime_path, Error_type, Mount_static_executables, Read_data_type,
+    Standard_type,
+};
+use File_system::{
+    Create_file_system, Device_trait, Flags_type, Memory_file_system_type, Mode_type, Open_type,
+    Permissions_type, Position_type, Size_type,
+};
+use Task::Task_identifier_type;
+use Virtual_file_system::File_type;
+
+static Initialize_system: Once = Once::new();
+
+/// Arguments received by the runtime and the interpreters, to be checked by the tests.
+static Received_arguments: Mutex<Vec<Arguments_type>> = Mutex::new(Vec::new());
+
+fn Runtime_main(_: Standard_type, Arguments: Arguments_type) -> Result<(), NonZeroUsize> {
+    Received_arguments.lock().unwrap().push(Arguments);
+
+    Ok(())
+}
+
+/// Stand-in for the WASM runtime (mounted at [`Default_runtime_path`]) and the interpreters.
+struct Runtime_executable_type;
+
+impl Device_trait for Runtime_executable_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<Size_type> {
+        let Read_data: &mut Read_data_type = Buffer
+            .try_into()
+            .map_err(|_| File_system::Error_type::Invalid_parameter)?;
+
+        *Read_data = Read_data_type::New(Runtime_main, 1024 * 32);
+
+        Ok(size_of::<Read_data_type>().into())
+    }
+
+    fn Write(&self, _: &[u8]) -> File_system::Result_type<Size_type> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<Size_type> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> File_system::Result_type<Size_type> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+}
+
+fn Initialize() -> Task_identifier_type {
+    let Task_instance = Task::Initialize().unwrap();
+
+    unsafe {
+        let _ = Task_instance.Register_task();
+    }
+
+    let _ = Users::Initialize();
+
+    let _ = Time::Initialize(File_system::Create_device!(
+        Drivers::Native::Time_driver_type::New()
+    ));
+
+    let Task = Task_instance.Get_current_task_identifier().unwrap();
+
+    Initialize_system.call_once(|| {
+        Virtual_file_system::Initialize(
+            Create_file_system!(Memory_file_system_type::New(1024 * 512)),
+            None,
+        )
+        .unwrap();
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        Virtual_file_system
+            .Create_directory(&"/Binaries", Task)
+            .unwrap();
+
+        Mount_static_executables!(
+            Virtual_file_system,
+            Task,
+            &[
+                (&Default_runtime_path, Runtime_executable_type),
+                (&"/Binaries/Interpreter", Runtime_executable_type)
+            ]
+        )
+        .unwrap();
+    });
+
+    Task
+}
+
+/// Create an executable file.
+fn Create_executable(Path: &str, Content: &[u8]) {
+    let Virtual_file_system = Virtual_file_system::Get_instance();
+
+    File_type::Open(
+        Virtual_file_system,
+        Path,
+        Flags_type::New(
+            Mode_type::Write_only,
+            Some(Open_type::Truncate.Set_create(true)),
+            None,
+        ),
+    )
+    .unwrap()
+    .Write(Content)
+    .unwrap();
+
+    Virtual_file_system
+        .Set_permissions(Path, Permissions_type::Executable)
+        .unwrap();
+}
+
+fn New_standard(Task: Task_identifier_type) -> Standard_type {
+    let Virtual_file_system = Virtual_file_system::Get_instance();
+
+    let Open = |Mode| {
+        Virtual_file_system
+            .Open(
+                &"/Standard",
+                Flags_type::New(Mode, Some(Open_type::Create), None),
+                Task,
+            )
+            .unwrap()
+    };
+
+    Standard_type::New(
+        Open(Mode_type::Read_only),
+        Open(Mode_type::Write_only),
+        Open(Mode_type::Write_only),
+        Task,
+        Virtual_file_system,
+    )
+}
+
+/// Execute a file and check that it succeeded.
+fn Execute_and_join(Task: Task_identifier_type, Path: &str, Arguments: &[&str]) {
+    let Result = Executable::Execute(
+        Path,
+        Arguments
+            .iter()
+            .map(|Argument| Argument.to_string())
+            .collect(),
+        New_standard(Task),
+    )
+    .unwrap()
+    .Join()
+    .unwrap();
+
+    assert_eq!(Result, 0);
+}
+
+#[test]
+fn Test_WASM_module() {
+    let Task = Initialize();
+
+    Create_executable("/Module.wasm", b"\0asm\x01\0\0\0");
+
+    Execute_and_join(Task, "/Module.wasm", &["First argument", "Second"]);
+
+    // - The module is given to the runtime, the arguments are kept as is.
+    let Expected = Arguments_type::New(["First argument", "Second"])
+        .Set_program(File_system::Path_owned_type::New("/Module.wasm".to_string()).unwrap());
+
+    assert!(Received_arguments.lock().unwrap().contains(&Expected));
+}
+
+#[test]
+fn Test_unsupported_format() {
+    let Task = Initialize();
+
+    Create_executable("/Text.txt", b"Not an executable");
+
+    assert!(matches!(
+        Executable::Execute("/Text.txt", vec![], New_standard(Task)),
+        Err(Error_type::Unsupported_executable_format)
+    ));
+}
+
+#[test]
+fn Test_script() {
+    let Task = Initialize();
+
+    Create_executable("/Script.sh", b"#!/Binaries/Interpreter -e\necho Hello\n");
+
+    Execute_and_join(Task, "/Script.sh", &["An argument"]);
+
+    // - The script is the program, the argument of the shebang line comes first.
+    let Expected = Arguments_type::New(["-e", "An argument"])
+        .Set_program(File_system::Path_owned_type::New("/Script.sh".to_string()).unwrap());
+
+    assert!(Received_arguments.lock().unwrap().contains(&Expected));
+
+    // - A script interpreted by another script is an argument of the latter.
+    Create_executable("/Nested.sh", b"#!/Script.sh\necho Hello\n");
+
+    Execute_and_join(Task, "/Nested.sh", &["An argument"]);
+
+    let Expected = Arguments_type::New(["-e", "/Nested.sh", "An argument"])
+        .Set_program(File_system::Path_owned_type::New("/Script.sh".to_string()).unwrap());
+
+    assert!(Received_arguments.lock().unwrap().contains(&Expected));
+}
@@ -0,0 +1,39 @@
+use File_system::{Path_owned_type, Path_type};
+
+/// Inputs given to the main function of an executable.
+///
+/// The arguments are kept as separate values, so they can contain spaces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Arguments_type {
+    /// File run by the executable when it is used as a runtime or an interpreter.
+    Program: Option<Path_owned_type>,
+    Arguments: Vec<String>,
+}
+
+impl Arguments_type {
+    pub fn New<S: Into<String>>(Arguments: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            Program: None,
+            Arguments: Arguments.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn Set_program(mut self, Program: Path_owned_type) -> Self {
+        self.Program = Some(Program);
+        self
+    }
+
+    /// Get the WASM module or the script to run, when the executable was started to run it
+    /// (see [`crate::Execute`]).
+    pub fn Get_program(&self) -> Option<&Path_type> {
+        self.Program.as_deref()
+    }
+
+    pub fn Get_arguments(&self) -> &[String] {
+        &self.Arguments
+    }
+
+    pub fn Is_empty(&self) -> bool {
+        self.Program.is_none() && self.Arguments.is_empty()
+    }
+}
@@ -10,6 +10,8 @@ pub enum Error_type {
     Failed_to_get_main_function,
     Invalid_stack_size,
     Permission_denied,
+    Unsupported_executable_format,
+    Invalid_interpreter,
 }
 
 impl Display for Error_type {
@@ -22,6 +24,10 @@ impl Display for Error_type {
             }
             Error_type::Invalid_stack_size => write!(Formatter, "Invalid stack size"),
             Error_type::Permission_denied => write!(Formatter, "Permission denied"),
+            Error_type::Unsupported_executable_format => {
+                write!(Formatter, "Unsupported executable format")
+            }
+            Error_type::Invalid_interpreter => write!(Formatter, "Invalid interpreter"),
         }
     }
 }
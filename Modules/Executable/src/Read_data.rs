@@ -1,8 +1,8 @@
 use std::{mem::transmute, num::NonZeroUsize};
 
-use crate::Standard_type;
+use crate::{Arguments_type, Standard_type};
 
-pub type Main_function_type = fn(Standard_type, String) -> Result<(), NonZeroUsize>;
+pub type Main_function_type = fn(Standard_type, Arguments_type) -> Result<(), NonZeroUsize>;
 
 #[derive(Debug)]
 pub struct Read_data_type {
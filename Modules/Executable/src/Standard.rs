@@ -106,6 +106,20 @@ impl Standard_type {
         self.Print_error("\n");
     }
 
+    /// Read raw data (e.g. key sequences) from the standard input.
+    pub fn Read(&self, Buffer: &mut [u8]) -> Result_type<Size_type> {
+        Ok(self
+            .Virtual_file_system
+            .Read(self.Standard_in, Buffer, self.Task)?)
+    }
+
+    /// Switch the standard input to raw mode (e.g. while editing a line) or back to line mode.
+    pub fn Set_raw_mode(&self, Raw: bool) -> Result_type<()> {
+        Ok(self
+            .Virtual_file_system
+            .Set_raw_mode(self.Standard_in, self.Task, Raw)?)
+    }
+
     pub fn Read_line(&self, Buffer: &mut String) {
         Buffer.clear();
 
@@ -138,6 +152,13 @@ impl Standard_type {
         ))
     }
 
+    /// Replace the standard output (e.g. to redirect it to a file), closing the previous one.
+    pub fn Set_standard_out(&mut self, Standard_out: Unique_file_identifier_type) {
+        let _ = self.Virtual_file_system.Close(self.Standard_out, self.Task);
+
+        self.Standard_out = Standard_out;
+    }
+
     pub fn Split(
         &self,
     ) -> (
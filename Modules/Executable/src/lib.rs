@@ -2,11 +2,13 @@
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
 
+mod Arguments;
 mod Device_trait;
 mod Error;
 mod Read_data;
 mod Standard;
 
+pub use Arguments::*;
 pub use Device_trait::*;
 pub use Error::*;
 pub use Read_data::*;
@@ -16,7 +18,7 @@ use Task::{Join_handle_type, Task_identifier_type};
 use Users::User_identifier_type;
 use Virtual_file_system::File_type;
 
-use File_system::{Path_type, Statistics_type};
+use File_system::{Path_type, Statistics_type, Type_type};
 
 fn Is_execute_allowed(Statistics: &Statistics_type, User: User_identifier_type) -> bool {
     // - Check if the file can executed by anyone
@@ -66,9 +68,187 @@ fn Get_overridden_user(
     Ok(Some(New_user))
 }
 
+/// Executable used to run the WASM modules.
+pub const Default_runtime_path: &str = "/Binaries/WASM";
+
+/// Magic number at the beginning of WASM binaries.
+pub const WASM_magic_number: &[u8] = b"\0asm";
+
+/// Prefix of the first line of the scripts, followed by the interpreter path.
+pub const Shebang: &[u8] = b"#!";
+
+/// Size of the header read to identify the format of an executable file.
+const Header_size: usize = 128;
+
+/// Maximum number of nested interpreters (e.g. a script interpreted by another script).
+const Maximum_interpreter_depth: usize = 4;
+
+/// Main function of an executable with its stack size and inputs.
+struct Loaded_type {
+    Main: Main_function_type,
+    Stack_size: usize,
+    Arguments: Arguments_type,
+    /// The file is a script run by an interpreter.
+    Interpreted: bool,
+}
+
+/// Read the main function and the stack size from an executable device.
+fn Read_main(File: &File_type) -> Result_type<(Main_function_type, usize)> {
+    let mut Read_data = Read_data_type::New_default();
+    File.Read(&mut Read_data)?;
+    let Read_data: Read_data_type = Read_data.try_into().unwrap();
+
+    let Main = Read_data
+        .Get_main()
+        .ok_or(Error_type::Failed_to_get_main_function)?;
+
+    Ok((Main, Read_data.Get_stack_size()))
+}
+
+/// Parse the first line of a script (`#!Interpreter [Argument]`).
+fn Parse_shebang(Header: &[u8]) -> Result_type<(&str, Option<&str>)> {
+    let Line = Header
+        .strip_prefix(Shebang)
+        .ok_or(Error_type::Unsupported_executable_format)?;
+
+    // - The whole line must fit in the header.
+    let End = Line
+        .iter()
+        .position(|Byte| *Byte == b'\n')
+        .ok_or(Error_type::Invalid_interpreter)?;
+
+    let Line = core::str::from_utf8(&Line[..End])
+        .map_err(|_| Error_type::Invalid_interpreter)?
+        .trim();
+
+    let (Interpreter, Argument) = match Line.split_once(char::is_whitespace) {
+        Some((Interpreter, Argument)) => (Interpreter, Some(Argument.trim())),
+        None => (Line, None),
+    };
+
+    if Interpreter.is_empty() {
+        return Err(Error_type::Invalid_interpreter);
+    }
+
+    Ok((
+        Interpreter,
+        Argument.filter(|Argument| !Argument.is_empty()),
+    ))
+}
+
+/// Get the arguments as a list, starting with the program if any.
+fn Flatten_arguments(Arguments: &Arguments_type) -> impl Iterator<Item = String> + '_ {
+    Arguments
+        .Get_program()
+        .map(|Program| Program.As_str().to_string())
+        .into_iter()
+        .chain(Arguments.Get_arguments().iter().cloned())
+}
+
+/// Get the main function of an executable file according to its type.
+///
+/// - Executable devices (mounted with [Mount_static_executables]) provide their main function.
+/// - WASM modules (starting with [WASM_magic_number]) are run by the [Default_runtime_path] executable,
+///   the module being the program of its [Arguments_type].
+/// - Scripts (starting with [Shebang]) are run by their interpreter, the script being the program of its
+///   [Arguments_type] and the argument of the shebang line, if any, being the first argument.
+fn Load(
+    File: &File_type,
+    Statistics: &Statistics_type,
+    Path: &Path_type,
+    Arguments: Arguments_type,
+    User: User_identifier_type,
+    Depth: usize,
+) -> Result_type<Loaded_type> {
+    if let Type_type::Character_device | Type_type::Block_device = Statistics.Get_type() {
+        let (Main, Stack_size) = Read_main(File)?;
+
+        return Ok(Loaded_type {
+            Main,
+            Stack_size,
+            Arguments,
+            Interpreted: false,
+        });
+    }
+
+    if Statistics.Get_type() != Type_type::File {
+        return Err(Error_type::Unsupported_executable_format);
+    }
+
+    let mut Header = [0_u8; Header_size];
+    let Size: usize = File.Read(&mut Header)?.into();
+    let Header = &Header[..Size];
+
+    if Header.starts_with(WASM_magic_number) {
+        let Runtime = File_type::Open(
+            Virtual_file_system::Get_instance(),
+            Default_runtime_path,
+            File_system::Mode_type::Read_only.into(),
+        )?;
+
+        let (Main, Stack_size) = Read_main(&Runtime)?;
+
+        return Ok(Loaded_type {
+            Main,
+            Stack_size,
+            Arguments: Arguments_type::New(Flatten_arguments(&Arguments))
+                .Set_program(Path.to_owned()),
+            Interpreted: false,
+        });
+    }
+
+    let (Interpreter_path, Argument) = Parse_shebang(Header)?;
+
+    if Depth >= Maximum_interpreter_depth {
+        return Err(Error_type::Invalid_interpreter);
+    }
+
+    let Interpreter_path = Path_type::From_str(Interpreter_path);
+
+    if !Interpreter_path.Is_absolute() {
+        return Err(Error_type::Invalid_interpreter);
+    }
+
+    let Interpreter = File_type::Open(
+        Virtual_file_system::Get_instance(),
+        Interpreter_path,
+        File_system::Mode_type::Read_only.into(),
+    )?;
+
+    let Interpreter_statistics = Interpreter.Get_statistics()?;
+
+    if !Is_execute_allowed(&Interpreter_statistics, User) {
+        return Err(Error_type::Permission_denied);
+    }
+
+    // - When the script is itself the interpreter of another script, the latter is an argument.
+    let Arguments = Arguments_type::New(
+        Argument
+            .map(str::to_string)
+            .into_iter()
+            .chain(Flatten_arguments(&Arguments)),
+    )
+    .Set_program(Path.to_owned());
+
+    let Loaded = Load(
+        &Interpreter,
+        &Interpreter_statistics,
+        Interpreter_path,
+        Arguments,
+        User,
+        Depth + 1,
+    )?;
+
+    Ok(Loaded_type {
+        Interpreted: true,
+        ..Loaded
+    })
+}
+
+/// Execute a file with its arguments (see [Load] for the supported formats).
 pub fn Execute(
     Path: impl AsRef<Path_type>,
-    Inputs: String,
+    Arguments: Vec<String>,
     Standard: Standard_type,
 ) -> Result_type<Join_handle_type<isize>> {
     let Task_instance = Task::Get_instance();
@@ -78,33 +258,42 @@ pub fn Execute(
     let File = File_type::Open(
         Virtual_file_system::Get_instance(),
         &Path,
-        File_system::Mode_type::Read_write.into(),
+        File_system::Mode_type::Read_only.into(),
     )?;
 
+    let Statistics = File.Get_statistics()?;
+
+    let User = Task_instance.Get_user(Task)?;
+
     // - Check the executable bit
-    if !Is_execute_allowed(&File.Get_statistics()?, Task_instance.Get_user(Task)?) {
+    if !Is_execute_allowed(&Statistics, User) {
         return Err(Error_type::Permission_denied);
     }
 
     // - Check if the user can override the user identifier
-    let New_user = Get_overridden_user(&File.Get_statistics()?, Task)?;
+    let New_user = Get_overridden_user(&Statistics, Task)?;
 
     let File_name = Path
         .as_ref()
         .Get_file_name()
         .ok_or(File_system::Error_type::Invalid_path)?;
 
-    let mut Read_data = Read_data_type::New_default();
-    File.Read(&mut Read_data)?;
-    let Read_data: Read_data_type = Read_data.try_into().unwrap();
+    let Loaded = Load(
+        &File,
+        &Statistics,
+        Path.as_ref(),
+        Arguments_type::New(Arguments),
+        User,
+        0,
+    )?;
 
-    let Main = Read_data
-        .Get_main()
-        .ok_or(Error_type::Failed_to_get_main_function)?;
-    let Stack_size = Read_data.Get_stack_size();
+    // - The set user identifier bit is ignored for scripts.
+    let New_user = if Loaded.Interpreted { None } else { New_user };
+
+    let (Main, Arguments) = (Loaded.Main, Loaded.Arguments);
 
     let (_, Join_handle) =
-        Task_instance.New_task(Task, File_name, Some(Stack_size), move || {
+        Task_instance.New_task(Task, File_name, Some(Loaded.Stack_size), move || {
             let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
 
             if let Some(New_user) = New_user {
@@ -113,7 +302,7 @@ pub fn Execute(
 
             let Standard = Standard.Transfert(Task).unwrap();
 
-            match Main(Standard, Inputs) {
+            match Main(Standard, Arguments) {
                 Ok(_) => 0_isize,
                 Err(Error) => -(Error.get() as isize),
             }
@@ -128,6 +317,30 @@ mod Tests {
 
     use super::*;
 
+    #[test]
+    fn Test_parse_shebang() {
+        assert_eq!(
+            Parse_shebang(b"#!/Binaries/Command_line_shell\necho").unwrap(),
+            ("/Binaries/Command_line_shell", None)
+        );
+        assert_eq!(
+            Parse_shebang(b"#! /Binaries/WASM  -v \n").unwrap(),
+            ("/Binaries/WASM", Some("-v"))
+        );
+        assert!(matches!(
+            Parse_shebang(b"#!/Binaries/Command_line_shell"),
+            Err(Error_type::Invalid_interpreter)
+        ));
+        assert!(matches!(
+            Parse_shebang(b"#!\n"),
+            Err(Error_type::Invalid_interpreter)
+        ));
+        assert!(matches!(
+            Parse_shebang(b"\x7FELF"),
+            Err(Error_type::Unsupported_executable_format)
+        ));
+    }
+
     #[test]
     fn Is_user_allowed_test() {
         let Statistics = Statistics_type::New(
@@ -0,0 +1,325 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    sync::Once,
+    time::Duration,
+};
+
+use File_system::{
+    Create_device, Create_file_system, Flags_type, Memory_file_system_type, Mode_type, Open_type,
+    Permissions_type,
+};
+use File_transfer::{
+    Decode_header, Encode_credentials, Encode_header, Frame_kind_type, Header_size, Start_session,
+};
+use Network::{IPv4_type, Port_type, Protocol_type};
+use Task::Task_identifier_type;
+use Users::{Group_identifier_type, User_identifier_type};
+use Virtual_file_system::File_type;
+
+static Initialize_system: Once = Once::new();
+
+const Timeout: Duration = Duration::from_secs(10);
+
+fn Initialize() -> Task_identifier_type {
+    let Task_instance = Task::Initialize().unwrap();
+
+    unsafe {
+        let _ = Task_instance.Register_task();
+    }
+
+    let _ = Users::Initialize();
+
+    let _ = Time::Initialize(Create_device!(Drivers::Native::Time_driver_type::New()));
+
+    let Task = Task_instance.Get_current_task_identifier().unwrap();
+
+    Initialize_system.call_once(|| {
+        Virtual_file_system::Initialize(
+            Create_file_system!(Memory_file_system_type::New(1024 * 512)),
+            Some(Box::leak(Box::new(
+                Drivers::Std::Network::Network_socket_driver_type::New(),
+            ))),
+        )
+        .unwrap();
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        Virtual_file_system::Create_default_hierarchy(Virtual_file_system, Task).unwrap();
+
+        let Group_identifier = Group_identifier_type::New(1000);
+
+        Authentication::Create_group(Virtual_file_system, "alix_anneraud", Some(Group_identifier))
+            .unwrap();
+
+        let User = Authentication::Create_user(
+            Virtual_file_system,
+            "alix_anneraud",
+            "password",
+            Group_identifier,
+            None,
+        )
+        .unwrap();
+
+        // - A directory of the user, and one of root that the user can only read.
+        Virtual_file_system
+            .Create_directory(&"/Data/Alix", Task)
+            .unwrap();
+        Virtual_file_system
+            .Set_owner("/Data/Alix", Some(User), Some(Group_identifier))
+            .unwrap();
+        Virtual_file_system
+            .Set_permissions("/Data/Alix", Permissions_type::From_octal(0o755).unwrap())
+            .unwrap();
+
+        Virtual_file_system
+            .Create_directory(&"/Data/Root", Task)
+            .unwrap();
+        Virtual_file_system
+            .Set_owner(
+                "/Data/Root",
+                Some(User_identifier_type::Root),
+                Some(Group_identifier_type::Root),
+            )
+            .unwrap();
+        Virtual_file_system
+            .Set_permissions("/Data/Root", Permissions_type::From_octal(0o755).unwrap())
+            .unwrap();
+
+        File_type::Open(
+            Virtual_file_system,
+            "/Data/Root/Secret",
+            Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+        )
+        .unwrap()
+        .Write(b"Secret")
+        .unwrap();
+        Virtual_file_system
+            .Set_owner(
+                "/Data/Root/Secret",
+                Some(User_identifier_type::Root),
+                Some(Group_identifier_type::Root),
+            )
+            .unwrap();
+        Virtual_file_system
+            .Set_permissions(
+                "/Data/Root/Secret",
+                Permissions_type::From_octal(0o600).unwrap(),
+            )
+            .unwrap();
+    });
+
+    Task
+}
+
+/// Accept a connection and run its session, while the client talks to it.
+fn With_session(Task: Task_identifier_type, Client: impl FnOnce(TcpStream) + Send) {
+    let Virtual_file_system = Virtual_file_system::Get_instance();
+
+    let Socket = Virtual_file_system
+        .Bind(
+            Task,
+            (IPv4_type::Localhost, Port_type::Any).into(),
+            Protocol_type::TCP,
+        )
+        .unwrap();
+
+    Virtual_file_system.Listen(Task, Socket, 1).unwrap();
+
+    let (_, Port) = Virtual_file_system
+        .Get_local_address(Task, Socket)
+        .unwrap()
+        .Into_IP_and_port()
+        .unwrap();
+
+    std::thread::scope(|Scope| {
+        Scope.spawn(move || {
+            let Stream = TcpStream::connect(("127.0.0.1", Port.Into_inner())).unwrap();
+
+            // - Fail instead of waiting forever if the session is stuck.
+            Stream.set_read_timeout(Some(Timeout)).unwrap();
+
+            Client(Stream);
+        });
+
+        let (Connection, _) = Virtual_file_system.Accept(Task, Socket).unwrap();
+
+        Start_session(Virtual_file_system, Task, Connection)
+            .unwrap()
+            .Join()
+            .unwrap();
+    });
+
+    Virtual_file_system.Close(Socket, Task).unwrap();
+}
+
+fn Send_frame(Stream: &mut TcpStream, Kind: Frame_kind_type, Payload: &[u8]) {
+    Stream
+        .write_all(&Encode_header(Kind, Payload.len()).unwrap())
+        .unwrap();
+    Stream.write_all(Payload).unwrap();
+}
+
+fn Receive_frame(Stream: &mut TcpStream) -> (Frame_kind_type, Vec<u8>) {
+    let mut Header = [0; Header_size];
+    Stream.read_exact(&mut Header).unwrap();
+
+    let (Kind, Size) = Decode_header(&Header).unwrap();
+
+    let mut Payload = vec![0; Size];
+    Stream.read_exact(&mut Payload).unwrap();
+
+    (Kind, Payload)
+}
+
+/// Send a request and get the error message if it failed.
+fn Request(Stream: &mut TcpStream, Kind: Frame_kind_type, Path: &str) -> Result<Vec<u8>, String> {
+    Send_frame(Stream, Kind, Path.as_bytes());
+
+    match Receive_frame(Stream) {
+        (Frame_kind_type::Success, Payload) => Ok(Payload),
+        (Frame_kind_type::Failure, Message) => Err(String::from_utf8(Message).unwrap()),
+        (Kind, _) => panic!("Unexpected frame : {:?}", Kind),
+    }
+}
+
+fn Authenticate(Stream: &mut TcpStream) {
+    Send_frame(
+        Stream,
+        Frame_kind_type::Authenticate,
+        &Encode_credentials("alix_anneraud", "password"),
+    );
+
+    assert_eq!(Receive_frame(Stream).0, Frame_kind_type::Success);
+}
+
+fn Put(Stream: &mut TcpStream, Path: &str, Content: &[u8]) -> Result<(), String> {
+    Request(Stream, Frame_kind_type::Put, Path)?;
+
+    for Chunk in Content.chunks(1000) {
+        Send_frame(Stream, Frame_kind_type::Data, Chunk);
+    }
+    Send_frame(Stream, Frame_kind_type::Data, &[]);
+
+    match Receive_frame(Stream) {
+        (Frame_kind_type::Success, _) => Ok(()),
+        (_, Message) => Err(String::from_utf8(Message).unwrap()),
+    }
+}
+
+fn Get(Stream: &mut TcpStream, Path: &str) -> Result<Vec<u8>, String> {
+    Request(Stream, Frame_kind_type::Get, Path)?;
+
+    let mut Content = Vec::new();
+
+    loop {
+        match Receive_frame(Stream) {
+            (Frame_kind_type::Data, Data) if Data.is_empty() => return Ok(Content),
+            (Frame_kind_type::Data, Data) => Content.extend(Data),
+            (_, Message) => return Err(String::from_utf8(Message).unwrap()),
+        }
+    }
+}
+
+#[test]
+fn Test_transfer() {
+    let Task = Initialize();
+
+    With_session(Task, |mut Stream| {
+        Authenticate(&mut Stream);
+
+        let Content: Vec<u8> = (0..10_000).map(|Index| (Index % 251) as u8).collect();
+
+        Put(&mut Stream, "/Data/Alix/File.bin", &Content).unwrap();
+
+        // - Replacing a file truncates it.
+        Put(&mut Stream, "/Data/Alix/Other.txt", b"A longer content").unwrap();
+        Put(&mut Stream, "/Data/Alix/Other.txt", b"Short").unwrap();
+
+        assert_eq!(Get(&mut Stream, "/Data/Alix/File.bin").unwrap(), Content);
+        assert_eq!(Get(&mut Stream, "/Data/Alix/Other.txt").unwrap(), b"Short");
+
+        let Listing = Request(&mut Stream, Frame_kind_type::List, "/Data/Alix").unwrap();
+        let mut Listing: Vec<_> = String::from_utf8(Listing)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        Listing.sort();
+
+        assert_eq!(Listing, ["- 10000 File.bin", "- 5 Other.txt"]);
+
+        Request(&mut Stream, Frame_kind_type::Remove, "/Data/Alix/File.bin").unwrap();
+
+        assert!(Get(&mut Stream, "/Data/Alix/File.bin").is_err());
+        assert_eq!(
+            Request(&mut Stream, Frame_kind_type::Get, "Relative/Path"),
+            Err("Invalid path".to_string())
+        );
+    });
+}
+
+#[test]
+fn Test_permissions() {
+    let Task = Initialize();
+
+    With_session(Task, |mut Stream| {
+        Authenticate(&mut Stream);
+
+        let Denied = "Permission denied";
+
+        assert_eq!(Get(&mut Stream, "/Data/Root/Secret").unwrap_err(), Denied);
+        assert_eq!(
+            Request(&mut Stream, Frame_kind_type::Remove, "/Data/Root/Secret").unwrap_err(),
+            Denied
+        );
+        assert_eq!(
+            Put(&mut Stream, "/Data/Root/Secret", b"Replaced").unwrap_err(),
+            Denied
+        );
+        assert_eq!(
+            Put(&mut Stream, "/Data/Root/New", b"New").unwrap_err(),
+            Denied
+        );
+
+        // - The directory itself is readable.
+        let Listing = Request(&mut Stream, Frame_kind_type::List, "/Data/Root").unwrap();
+        assert_eq!(Listing, b"- 6 Secret\n");
+    });
+}
+
+#[test]
+fn Test_authentication_failure() {
+    let Task = Initialize();
+
+    With_session(Task, |mut Stream| {
+        for _ in 0..3 {
+            Send_frame(
+                &mut Stream,
+                Frame_kind_type::Authenticate,
+                &Encode_credentials("alix_anneraud", "wrong"),
+            );
+
+            assert_eq!(
+                Receive_frame(&mut Stream),
+                (
+                    Frame_kind_type::Failure,
+                    b"Invalid user name or password".to_vec()
+                )
+            );
+        }
+
+        assert_eq!(
+            Receive_frame(&mut Stream),
+            (Frame_kind_type::Failure, b"Authentication failed".to_vec())
+        );
+
+        let mut Rest = Vec::new();
+        Stream.read_to_end(&mut Rest).unwrap();
+        assert!(Rest.is_empty());
+    });
+}
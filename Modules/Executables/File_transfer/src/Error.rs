@@ -0,0 +1,100 @@
+use core::num::NonZeroUsize;
+use core::result::Result;
+use core::{fmt::Display, num::NonZeroU8};
+
+pub type Result_type<T> = Result<T, Error_type>;
+
+#[derive(Debug, Clone)]
+pub enum Error_type {
+    Invalid_arguments,
+    Invalid_port,
+    Network(Virtual_file_system::Error_type),
+    File_system(File_system::Error_type),
+    Failed_to_get_task_identifier(Task::Error_type),
+    Authentication_failed,
+    Connection_closed,
+    Invalid_frame,
+    Frame_too_large,
+}
+
+impl Error_type {
+    pub fn Get_discriminant(&self) -> NonZeroU8 {
+        let Discriminant = match self {
+            Self::Invalid_arguments => 1,
+            Self::Invalid_port => 2,
+            Self::Network(_) => 3,
+            Self::File_system(_) => 4,
+            Self::Failed_to_get_task_identifier(_) => 5,
+            Self::Authentication_failed => 6,
+            Self::Connection_closed => 7,
+            Self::Invalid_frame => 8,
+            Self::Frame_too_large => 9,
+        };
+
+        NonZeroU8::new(Discriminant).unwrap()
+    }
+}
+
+impl From<Virtual_file_system::Error_type> for Error_type {
+    fn from(Error: Virtual_file_system::Error_type) -> Self {
+        Self::Network(Error)
+    }
+}
+
+impl From<File_system::Error_type> for Error_type {
+    fn from(Error: File_system::Error_type) -> Self {
+        Self::File_system(Error)
+    }
+}
+
+impl From<Service::Error_type> for Error_type {
+    fn from(Error: Service::Error_type) -> Self {
+        match Error {
+            Service::Error_type::Invalid_arguments => Self::Invalid_arguments,
+            Service::Error_type::Invalid_port => Self::Invalid_port,
+            Service::Error_type::Network(Error) => Self::Network(Error),
+            Service::Error_type::Authentication_failed => Self::Authentication_failed,
+        }
+    }
+}
+
+impl From<Task::Error_type> for Error_type {
+    fn from(Error: Task::Error_type) -> Self {
+        Self::Failed_to_get_task_identifier(Error)
+    }
+}
+
+impl Display for Error_type {
+    fn fmt(&self, Formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Invalid_arguments => write!(Formatter, "Usage : File_transfer [port]"),
+            Self::Invalid_port => write!(Formatter, "Invalid port"),
+            Self::Network(Error) => write!(Formatter, "Network: {}", Error),
+            Self::File_system(Error) => write!(Formatter, "{}", Error),
+            Self::Failed_to_get_task_identifier(Error) => {
+                write!(Formatter, "Failed to get task identifier: {}", Error)
+            }
+            Self::Authentication_failed => write!(Formatter, "Authentication failed"),
+            Self::Connection_closed => write!(Formatter, "Connection closed"),
+            Self::Invalid_frame => write!(Formatter, "Invalid frame"),
+            Self::Frame_too_large => write!(Formatter, "Frame too large"),
+        }
+    }
+}
+
+impl From<Error_type> for NonZeroUsize {
+    fn from(Error: Error_type) -> Self {
+        Error.Get_discriminant().into()
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_discriminant() {
+        assert_eq!(Error_type::Invalid_arguments.Get_discriminant().get(), 1);
+        assert_eq!(Error_type::Frame_too_large.Get_discriminant().get(), 9);
+    }
+}
@@ -0,0 +1,22 @@
+use core::num::NonZeroUsize;
+
+use Executable::{Arguments_type, Standard_type};
+
+use crate::{Error_type, Session::Start_session};
+
+/// Port listened when none is given.
+pub const Default_port: u16 = 2324;
+
+/// Listen for file transfer connections.
+///
+/// - `File_transfer [port]` : listen on all the interfaces, on port [`Default_port`] by default.
+pub fn Main(Standard: Standard_type, Arguments: Arguments_type) -> Result<(), NonZeroUsize> {
+    if let Err(Error) = Service::Run(&Standard, &Arguments, Default_port, Start_session) {
+        let Error = Error_type::from(Error);
+
+        Standard.Print_error_line(&Error.to_string());
+        return Err(Error.into());
+    }
+
+    Ok(())
+}
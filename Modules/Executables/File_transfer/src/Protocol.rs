@@ -0,0 +1,164 @@
+//! Framing of the file transfer protocol.
+//!
+//! Every message is a frame : one byte for the [`Frame_kind_type`], the size of the payload
+//! on four bytes (big endian), then the payload.
+//!
+//! - The client starts with an [`Frame_kind_type::Authenticate`] frame, answered by
+//!   [`Frame_kind_type::Success`] or [`Frame_kind_type::Failure`].
+//! - Each request carries an absolute path and is answered by [`Frame_kind_type::Success`], or
+//!   [`Frame_kind_type::Failure`] with the error message.
+//! - The content of a file follows a successful [`Frame_kind_type::Get`] (sent by the server)
+//!   or [`Frame_kind_type::Put`] (sent by the client) as [`Frame_kind_type::Data`] frames,
+//!   ended by an empty one. The server answers the end of a put with the final status.
+//! - The payload of a successful [`Frame_kind_type::List`] is one line per entry :
+//!   `<type> <size> <name>`, the type being the first character of `ls -l`.
+
+use File_system::Type_type;
+
+use crate::{Error_type, Result_type};
+
+/// Size of the header of a frame.
+pub const Header_size: usize = 5;
+
+/// Maximum size of the payload of a frame.
+pub const Maximum_payload_size: usize = 64 * 1024;
+
+/// Size of the data frames sent when transferring a file.
+pub const Chunk_size: usize = 4 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Frame_kind_type {
+    /// User name and password, separated by a null byte.
+    Authenticate = 0x01,
+    /// Download a file.
+    Get = 0x02,
+    /// Upload a file, creating it or replacing its content.
+    Put = 0x03,
+    /// List the entries of a directory.
+    List = 0x04,
+    /// Remove a file or an empty directory.
+    Remove = 0x05,
+    /// Part of the content of a file, empty at the end.
+    Data = 0x10,
+    Success = 0x20,
+    /// Error message.
+    Failure = 0x21,
+}
+
+impl TryFrom<u8> for Frame_kind_type {
+    type Error = Error_type;
+
+    fn try_from(Value: u8) -> Result_type<Self> {
+        match Value {
+            0x01 => Ok(Self::Authenticate),
+            0x02 => Ok(Self::Get),
+            0x03 => Ok(Self::Put),
+            0x04 => Ok(Self::List),
+            0x05 => Ok(Self::Remove),
+            0x10 => Ok(Self::Data),
+            0x20 => Ok(Self::Success),
+            0x21 => Ok(Self::Failure),
+            _ => Err(Error_type::Invalid_frame),
+        }
+    }
+}
+
+pub fn Encode_header(Kind: Frame_kind_type, Size: usize) -> Result_type<[u8; Header_size]> {
+    if Size > Maximum_payload_size {
+        return Err(Error_type::Frame_too_large);
+    }
+
+    let mut Header = [0; Header_size];
+
+    Header[0] = Kind as u8;
+    Header[1..].copy_from_slice(&(Size as u32).to_be_bytes());
+
+    Ok(Header)
+}
+
+/// Get the kind and the size of the payload of a frame.
+pub fn Decode_header(Header: &[u8; Header_size]) -> Result_type<(Frame_kind_type, usize)> {
+    let Kind = Frame_kind_type::try_from(Header[0])?;
+
+    let Size = u32::from_be_bytes([Header[1], Header[2], Header[3], Header[4]]) as usize;
+
+    if Size > Maximum_payload_size {
+        return Err(Error_type::Frame_too_large);
+    }
+
+    Ok((Kind, Size))
+}
+
+pub fn Encode_credentials(User_name: &str, Password: &str) -> Vec<u8> {
+    let mut Payload = Vec::with_capacity(User_name.len() + Password.len() + 1);
+
+    Payload.extend_from_slice(User_name.as_bytes());
+    Payload.push(0);
+    Payload.extend_from_slice(Password.as_bytes());
+
+    Payload
+}
+
+pub fn Decode_credentials(Payload: &[u8]) -> Result_type<(&str, &str)> {
+    let Payload = core::str::from_utf8(Payload).map_err(|_| Error_type::Invalid_frame)?;
+
+    Payload.split_once('\0').ok_or(Error_type::Invalid_frame)
+}
+
+/// Get the character representing a type in a listing.
+pub fn Get_type_character(Type: Type_type) -> char {
+    match Type {
+        Type_type::File => '-',
+        Type_type::Directory => 'd',
+        Type_type::Block_device => 'b',
+        Type_type::Character_device => 'c',
+        Type_type::Pipe => 'p',
+        Type_type::Socket => 's',
+        Type_type::Symbolic_link => 'l',
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_header() {
+        let Header = Encode_header(Frame_kind_type::Data, 0x1234).unwrap();
+
+        assert_eq!(Header, [0x10, 0, 0, 0x12, 0x34]);
+        assert_eq!(
+            Decode_header(&Header).unwrap(),
+            (Frame_kind_type::Data, 0x1234)
+        );
+
+        assert!(matches!(
+            Encode_header(Frame_kind_type::Data, Maximum_payload_size + 1),
+            Err(Error_type::Frame_too_large)
+        ));
+        assert!(matches!(
+            Decode_header(&[0x10, 0xFF, 0, 0, 0]),
+            Err(Error_type::Frame_too_large)
+        ));
+        assert!(matches!(
+            Decode_header(&[0x42, 0, 0, 0, 0]),
+            Err(Error_type::Invalid_frame)
+        ));
+    }
+
+    #[test]
+    fn Test_credentials() {
+        let Payload = Encode_credentials("alix_anneraud", "pass word");
+
+        assert_eq!(
+            Decode_credentials(&Payload).unwrap(),
+            ("alix_anneraud", "pass word")
+        );
+
+        assert!(matches!(
+            Decode_credentials(b"alix_anneraud"),
+            Err(Error_type::Invalid_frame)
+        ));
+    }
+}
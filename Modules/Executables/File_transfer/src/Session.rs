@@ -0,0 +1,298 @@
+use File_system::{
+    Error_type as File_system_error_type, Flags_type, Mode_type, Open_type, Path_owned_type,
+    Path_type, Unique_file_identifier_type,
+};
+use Task::{Join_handle_type, Task_identifier_type};
+use Users::User_identifier_type;
+use Virtual_file_system::{Directory_type, File_type, Virtual_file_system_type};
+
+use crate::{
+    Chunk_size, Decode_credentials, Decode_header, Encode_header, Error_type, Frame_kind_type,
+    Get_type_character, Header_size, Result_type,
+};
+
+struct Connection_type {
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+}
+
+impl Connection_type {
+    fn Receive_exact(&self, Buffer: &mut [u8]) -> Result_type<()> {
+        let mut Received = 0;
+
+        while Received < Buffer.len() {
+            let Size = self.Virtual_file_system.Receive(
+                self.Task,
+                self.Socket,
+                &mut Buffer[Received..],
+            )?;
+
+            if Size == 0 {
+                return Err(Error_type::Connection_closed);
+            }
+
+            Received += Size;
+        }
+
+        Ok(())
+    }
+
+    fn Receive_frame(&self) -> Result_type<(Frame_kind_type, Vec<u8>)> {
+        let mut Header = [0; Header_size];
+        self.Receive_exact(&mut Header)?;
+
+        let (Kind, Size) = Decode_header(&Header)?;
+
+        let mut Payload = vec![0; Size];
+        self.Receive_exact(&mut Payload)?;
+
+        Ok((Kind, Payload))
+    }
+
+    fn Send_frame(&self, Kind: Frame_kind_type, Payload: &[u8]) -> Result_type<()> {
+        self.Virtual_file_system.Send(
+            self.Task,
+            self.Socket,
+            &Encode_header(Kind, Payload.len())?,
+        )?;
+
+        if !Payload.is_empty() {
+            self.Virtual_file_system
+                .Send(self.Task, self.Socket, Payload)?;
+        }
+
+        Ok(())
+    }
+
+    fn Send_result(&self, Result: Result_type<()>) -> Result_type<()> {
+        match Result {
+            Ok(()) => self.Send_frame(Frame_kind_type::Success, &[]),
+            // - Errors of the requests are reported to the client, the others end the session.
+            Err(Error_type::File_system(Error)) => {
+                self.Send_frame(Frame_kind_type::Failure, Error.to_string().as_bytes())
+            }
+            Err(Error) => Err(Error),
+        }
+    }
+}
+
+fn Parse_path(Payload: &[u8]) -> Result_type<Path_owned_type> {
+    let Path = String::from_utf8(Payload.to_vec())
+        .ok()
+        .and_then(Path_owned_type::New)
+        .ok_or(File_system_error_type::Invalid_path)?
+        .Canonicalize();
+
+    if !Path.Is_absolute() {
+        return Err(File_system_error_type::Invalid_path.into());
+    }
+
+    Ok(Path)
+}
+
+/// Receive the credentials of the client until they are valid (see [`Service::Authenticate`]).
+fn Authenticate(Connection: &Connection_type) -> Result_type<User_identifier_type> {
+    let (_, User) = Service::Authenticate(
+        Connection.Virtual_file_system,
+        || {
+            let (Kind, Payload) = Connection.Receive_frame()?;
+
+            if Kind != Frame_kind_type::Authenticate {
+                return Err(Error_type::Invalid_frame);
+            }
+
+            let (User_name, Password) = Decode_credentials(&Payload)?;
+
+            Ok((User_name.to_string(), Password.to_string()))
+        },
+        || Connection.Send_frame(Frame_kind_type::Failure, b"Invalid user name or password"),
+    )?;
+
+    Connection.Send_frame(Frame_kind_type::Success, &[])?;
+
+    Ok(User)
+}
+
+fn Get(Connection: &Connection_type, Path: &Path_type) -> Result_type<()> {
+    let File = File_type::Open(
+        Connection.Virtual_file_system,
+        Path,
+        Mode_type::Read_only.into(),
+    )
+    .map_err(Error_type::from);
+
+    let File = match File {
+        Ok(File) => {
+            Connection.Send_result(Ok(()))?;
+            File
+        }
+        Err(Error) => return Connection.Send_result(Err(Error)),
+    };
+
+    let mut Buffer = vec![0; Chunk_size];
+
+    loop {
+        let Size: usize = match File.Read(&mut Buffer) {
+            Ok(Size) => Size.into(),
+            // - A failure replaces the end of the data.
+            Err(Error) => return Connection.Send_result(Err(Error.into())),
+        };
+
+        Connection.Send_frame(Frame_kind_type::Data, &Buffer[..Size])?;
+
+        if Size == 0 {
+            return Ok(());
+        }
+    }
+}
+
+fn Put(Connection: &Connection_type, Path: &Path_type) -> Result_type<()> {
+    // - Replacing a file needs the write permission on it, creating one on its directory.
+    let File = File_type::Open(
+        Connection.Virtual_file_system,
+        Path,
+        Flags_type::New(
+            Mode_type::Write_only,
+            Some(Open_type::Truncate.Set_create(true)),
+            None,
+        ),
+    )
+    .map_err(Error_type::from);
+
+    let File = match File {
+        Ok(File) => {
+            Connection.Send_result(Ok(()))?;
+            File
+        }
+        Err(Error) => return Connection.Send_result(Err(Error)),
+    };
+
+    // - The data are received until the end even if writing fails, to keep the frames in sync.
+    let mut Result = Ok(());
+
+    loop {
+        let (Kind, Payload) = Connection.Receive_frame()?;
+
+        if Kind != Frame_kind_type::Data {
+            return Err(Error_type::Invalid_frame);
+        }
+
+        if Payload.is_empty() {
+            break;
+        }
+
+        if Result.is_ok() {
+            Result = File.Write(&Payload).map(|_| ()).map_err(Error_type::from);
+        }
+    }
+
+    Connection.Send_result(Result)
+}
+
+fn List(Connection: &Connection_type, Path: &Path_type) -> Result_type<()> {
+    let Listing = (|| {
+        let mut Listing = String::new();
+
+        for Entry in Directory_type::Open(Connection.Virtual_file_system, Path)? {
+            if Entry.Get_name() == "." || Entry.Get_name() == ".." {
+                continue;
+            }
+
+            Listing += &format!(
+                "{} {} {}\n",
+                Get_type_character(Entry.Get_type()),
+                Entry.Get_size(),
+                Entry.Get_name()
+            );
+        }
+
+        Ok(Listing)
+    })();
+
+    match Listing {
+        Ok(Listing) => Connection.Send_frame(Frame_kind_type::Success, Listing.as_bytes()),
+        Err(Error) => Connection.Send_result(Err(Error)),
+    }
+}
+
+fn Remove(
+    Connection: &Connection_type,
+    Session_task: Task_identifier_type,
+    Path: &Path_type,
+) -> Result_type<()> {
+    let Result = Connection
+        .Virtual_file_system
+        .Remove(Path, Session_task)
+        .map_err(Error_type::from);
+
+    Connection.Send_result(Result)
+}
+
+fn Run_session(
+    Session_task: Task_identifier_type,
+    Connection: &Connection_type,
+) -> Result_type<()> {
+    let User = Authenticate(Connection)?;
+
+    // - The files are accessed by the session task, with the permissions of the authenticated user.
+    Task::Get_instance().Set_user(Session_task, User)?;
+
+    loop {
+        let (Kind, Payload) = match Connection.Receive_frame() {
+            Ok(Frame) => Frame,
+            Err(Error_type::Connection_closed) => return Ok(()),
+            Err(Error) => return Err(Error),
+        };
+
+        let Path = match Parse_path(&Payload) {
+            Ok(Path) => Path,
+            Err(Error) => {
+                Connection.Send_result(Err(Error))?;
+                continue;
+            }
+        };
+
+        match Kind {
+            Frame_kind_type::Get => Get(Connection, &Path)?,
+            Frame_kind_type::Put => Put(Connection, &Path)?,
+            Frame_kind_type::List => List(Connection, &Path)?,
+            Frame_kind_type::Remove => Remove(Connection, Session_task, &Path)?,
+            _ => return Err(Error_type::Invalid_frame),
+        }
+    }
+}
+
+/// Start a session on an accepted connection, in a child task of the task owning the socket.
+///
+/// The client has to authenticate, then its requests are served with the permissions of its user
+/// until it closes the connection (see [`Frame_kind_type`]).
+/// The socket is closed at the end of the session.
+pub fn Start_session(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+) -> Result_type<Join_handle_type<()>> {
+    let (_, Join_handle) =
+        Task::Get_instance().New_task(Task, "File_transfer_session", None, move || {
+            let Connection = Connection_type {
+                Virtual_file_system,
+                Task,
+                Socket,
+            };
+
+            let Result = Task::Get_instance()
+                .Get_current_task_identifier()
+                .map_err(Error_type::from)
+                .and_then(|Session_task| Run_session(Session_task, &Connection));
+
+            if let Err(Error) = Result {
+                let _ =
+                    Connection.Send_frame(Frame_kind_type::Failure, Error.to_string().as_bytes());
+            }
+
+            let _ = Virtual_file_system.Close(Socket, Task);
+        })?;
+
+    Ok(Join_handle)
+}
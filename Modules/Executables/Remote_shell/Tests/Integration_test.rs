@@ -0,0 +1,218 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    sync::Once,
+    time::{Duration, Instant},
+};
+
+use Executable::Mount_static_executables;
+use File_system::{Create_device, Create_file_system, Memory_file_system_type, Permissions_type};
+use Network::{IPv4_type, Port_type, Protocol_type};
+use Remote_shell::{Shell_path, Start_session};
+use Task::Task_identifier_type;
+use Users::{Group_identifier_type, User_identifier_type};
+
+static Initialize_system: Once = Once::new();
+
+const Timeout: Duration = Duration::from_secs(10);
+
+fn Initialize() -> Task_identifier_type {
+    let Task_instance = Task::Initialize().unwrap();
+
+    unsafe {
+        let _ = Task_instance.Register_task();
+    }
+
+    let _ = Users::Initialize();
+
+    let _ = Time::Initialize(Create_device!(Drivers::Native::Time_driver_type::New()));
+
+    let Task = Task_instance.Get_current_task_identifier().unwrap();
+
+    Initialize_system.call_once(|| {
+        Virtual_file_system::Initialize(
+            Create_file_system!(Memory_file_system_type::New(1024 * 512)),
+            Some(Box::leak(Box::new(
+                Drivers::Std::Network::Network_socket_driver_type::New(),
+            ))),
+        )
+        .unwrap();
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        Virtual_file_system::Create_default_hierarchy(Virtual_file_system, Task).unwrap();
+
+        Virtual_file_system
+            .Mount_static_device(
+                Task,
+                &"/Devices/Random",
+                Create_device!(Drivers::Native::Random_device_type),
+            )
+            .unwrap();
+
+        Mount_static_executables!(
+            Virtual_file_system,
+            Task,
+            &[(&Shell_path, Command_line_shell::Shell_executable_type)]
+        )
+        .unwrap();
+
+        let Group_identifier = Group_identifier_type::New(1000);
+
+        Authentication::Create_group(Virtual_file_system, "alix_anneraud", Some(Group_identifier))
+            .unwrap();
+
+        Authentication::Create_user(
+            Virtual_file_system,
+            "alix_anneraud",
+            "password",
+            Group_identifier,
+            None,
+        )
+        .unwrap();
+    });
+
+    Task_instance
+        .Set_environment_variable(Task, "Paths", "/")
+        .unwrap();
+
+    Task_instance
+        .Set_environment_variable(Task, "Host", "xila")
+        .unwrap();
+
+    Task
+}
+
+/// Accept a connection and run its session, while the client talks to it.
+fn With_session(Task: Task_identifier_type, Client: impl FnOnce(TcpStream) + Send) {
+    let Virtual_file_system = Virtual_file_system::Get_instance();
+
+    let Socket = Virtual_file_system
+        .Bind(
+            Task,
+            (IPv4_type::Localhost, Port_type::Any).into(),
+            Protocol_type::TCP,
+        )
+        .unwrap();
+
+    Virtual_file_system.Listen(Task, Socket, 1).unwrap();
+
+    let (_, Port) = Virtual_file_system
+        .Get_local_address(Task, Socket)
+        .unwrap()
+        .Into_IP_and_port()
+        .unwrap();
+
+    std::thread::scope(|Scope| {
+        Scope.spawn(move || {
+            let Stream = TcpStream::connect(("127.0.0.1", Port.Into_inner())).unwrap();
+
+            // - Fail instead of waiting forever if the session is stuck.
+            Stream.set_read_timeout(Some(Timeout)).unwrap();
+
+            Client(Stream);
+        });
+
+        let (Connection, _) = Virtual_file_system.Accept(Task, Socket).unwrap();
+
+        Start_session(Virtual_file_system, Task, Connection)
+            .unwrap()
+            .Join()
+            .unwrap();
+    });
+
+    Virtual_file_system.Close(Socket, Task).unwrap();
+}
+
+/// Read from the connection until the pattern is received.
+fn Read_until(Stream: &mut TcpStream, Pattern: &str) -> String {
+    let mut Received = Vec::new();
+    let mut Byte = [0; 1];
+
+    while !String::from_utf8_lossy(&Received).contains(Pattern) {
+        assert_eq!(
+            Stream.read(&mut Byte).unwrap_or(0),
+            1,
+            "Connection closed or timed out : {}",
+            String::from_utf8_lossy(&Received)
+        );
+
+        Received.push(Byte[0]);
+    }
+
+    String::from_utf8_lossy(&Received).into_owned()
+}
+
+#[test]
+fn Test_session() {
+    let Task = Initialize();
+
+    With_session(Task, |mut Stream| {
+        Read_until(&mut Stream, "Username: ");
+        Stream.write_all(b"alix_anneraud\n").unwrap();
+
+        Read_until(&mut Stream, "Password: ");
+        Stream.write_all(b"password\r\n").unwrap();
+
+        // - The shell runs as the authenticated user.
+        Read_until(&mut Stream, "alix_anneraud@xila:/$ ");
+
+        Stream.write_all(b"echo hello\n").unwrap();
+        Read_until(&mut Stream, "\nhello");
+
+        // - The device of the session belongs to the user, and only to it.
+        Stream.write_all(b"ls /Devices\n").unwrap();
+        Read_until(&mut Stream, "Remote_shell_");
+
+        let Identifier = Read_until(&mut Stream, "\n");
+
+        let Metadata = Virtual_file_system::Get_instance()
+            .Get_metadata_from_path(
+                &format!("/Devices/Remote_shell_{}", Identifier.trim()).as_str(),
+            )
+            .unwrap();
+
+        assert_eq!(Metadata.Get_user(), User_identifier_type::New(1000));
+        assert_eq!(
+            Metadata.Get_permissions(),
+            Permissions_type::User_read_write
+        );
+
+        Stream.write_all(b"exit\n").unwrap();
+
+        // - The connection is closed at the end of the shell.
+        let mut Rest = Vec::new();
+        Stream.read_to_end(&mut Rest).unwrap();
+    });
+}
+
+#[test]
+fn Test_authentication_failure() {
+    let Task = Initialize();
+
+    With_session(Task, |mut Stream| {
+        let Start = Instant::now();
+
+        for _ in 0..3 {
+            Read_until(&mut Stream, "Username: ");
+            Stream.write_all(b"alix_anneraud\n").unwrap();
+
+            Read_until(&mut Stream, "Password: ");
+            Stream.write_all(b"wrong\n").unwrap();
+
+            Read_until(&mut Stream, "Invalid user name or password\n");
+        }
+
+        let mut Rest = String::new();
+        Stream.read_to_string(&mut Rest).unwrap();
+
+        assert_eq!(Rest, "Authentication failed\n");
+
+        // - Each failure delays the next attempt.
+        assert!(Start.elapsed() >= Duration::from_millis(500 + 1000 + 2000));
+    });
+}
@@ -0,0 +1,109 @@
+use File_system::{Device_trait, Events_type, Unique_file_identifier_type};
+use Task::Task_identifier_type;
+use Virtual_file_system::Virtual_file_system_type;
+
+/// Maximum size of a line read from the connection, in bytes.
+const Maximum_line_size: usize = 4096;
+
+/// Device giving access to a connection, used as the standard input and outputs of a remote shell.
+///
+/// The socket belongs to the task which accepted the connection, and stays open while the device is used.
+pub struct Socket_device_type {
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+}
+
+impl Socket_device_type {
+    pub fn New(
+        Virtual_file_system: &'static Virtual_file_system_type<'static>,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+    ) -> Self {
+        Self {
+            Virtual_file_system,
+            Task,
+            Socket,
+        }
+    }
+}
+
+impl Device_trait for Socket_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<File_system::Size_type> {
+        if !self.Get_events()?.Get_readable() {
+            return Err(File_system::Error_type::Ressource_busy);
+        }
+
+        let Size = self
+            .Virtual_file_system
+            .Read(self.Socket, Buffer, self.Task)?;
+
+        // - A readable socket without data has been closed by the peer.
+        if Size == 0 && !Buffer.is_empty() {
+            return Err(File_system::Error_type::Broken_pipe);
+        }
+
+        Ok(Size)
+    }
+
+    fn Read_line(&self, Buffer: &mut String) -> File_system::Result_type<File_system::Size_type> {
+        let mut Line = Vec::new();
+
+        let mut Byte = [0; 1];
+
+        loop {
+            if self
+                .Virtual_file_system
+                .Read(self.Socket, &mut Byte, self.Task)?
+                == 0
+            {
+                return Err(File_system::Error_type::Broken_pipe);
+            }
+
+            match Byte[0] {
+                b'\n' => break,
+                b'\r' => {}
+                Byte => Line.push(Byte),
+            }
+
+            // - The client can't make the device hold an unlimited amount of data.
+            if Line.len() > Maximum_line_size {
+                return Err(File_system::Error_type::File_too_large);
+            }
+        }
+
+        // - The characters can be encoded on several bytes.
+        let Line =
+            String::from_utf8(Line).map_err(|_| File_system::Error_type::Invalid_parameter)?;
+
+        Buffer.push_str(&Line);
+
+        Ok(Line.len().into())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<File_system::Size_type> {
+        self.Virtual_file_system
+            .Write(self.Socket, Buffer, self.Task)
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<File_system::Size_type> {
+        Ok(0_usize.into())
+    }
+
+    fn Set_position(
+        &self,
+        _: &File_system::Position_type,
+    ) -> File_system::Result_type<File_system::Size_type> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
+
+    fn Get_events(&self) -> File_system::Result_type<Events_type> {
+        self.Virtual_file_system
+            .Get_events(self.Socket, self.Task)
+            .map_err(|_| File_system::Error_type::Internal_error)
+    }
+}
@@ -0,0 +1,108 @@
+use core::num::NonZeroUsize;
+use core::result::Result;
+use core::{fmt::Display, num::NonZeroU8};
+
+pub type Result_type<T> = Result<T, Error_type>;
+
+#[derive(Debug, Clone)]
+pub enum Error_type {
+    Invalid_arguments,
+    Invalid_port,
+    Network(Virtual_file_system::Error_type),
+    Failed_to_mount_device(File_system::Error_type),
+    Failed_to_get_task_identifier(Task::Error_type),
+    Failed_to_execute(Executable::Error_type),
+    Authentication_failed,
+    Connection_closed,
+    Line_too_long,
+}
+
+impl Error_type {
+    pub fn Get_discriminant(&self) -> NonZeroU8 {
+        let Discriminant = match self {
+            Self::Invalid_arguments => 1,
+            Self::Invalid_port => 2,
+            Self::Network(_) => 3,
+            Self::Failed_to_mount_device(_) => 4,
+            Self::Failed_to_get_task_identifier(_) => 5,
+            Self::Failed_to_execute(_) => 6,
+            Self::Authentication_failed => 7,
+            Self::Connection_closed => 8,
+            Self::Line_too_long => 9,
+        };
+
+        NonZeroU8::new(Discriminant).unwrap()
+    }
+}
+
+impl From<Virtual_file_system::Error_type> for Error_type {
+    fn from(Error: Virtual_file_system::Error_type) -> Self {
+        Self::Network(Error)
+    }
+}
+
+impl From<File_system::Error_type> for Error_type {
+    fn from(Error: File_system::Error_type) -> Self {
+        Self::Failed_to_mount_device(Error)
+    }
+}
+
+impl From<Service::Error_type> for Error_type {
+    fn from(Error: Service::Error_type) -> Self {
+        match Error {
+            Service::Error_type::Invalid_arguments => Self::Invalid_arguments,
+            Service::Error_type::Invalid_port => Self::Invalid_port,
+            Service::Error_type::Network(Error) => Self::Network(Error),
+            Service::Error_type::Authentication_failed => Self::Authentication_failed,
+        }
+    }
+}
+
+impl From<Task::Error_type> for Error_type {
+    fn from(Error: Task::Error_type) -> Self {
+        Self::Failed_to_get_task_identifier(Error)
+    }
+}
+
+impl From<Executable::Error_type> for Error_type {
+    fn from(Error: Executable::Error_type) -> Self {
+        Self::Failed_to_execute(Error)
+    }
+}
+
+impl Display for Error_type {
+    fn fmt(&self, Formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Invalid_arguments => write!(Formatter, "Usage : Remote_shell [port]"),
+            Self::Invalid_port => write!(Formatter, "Invalid port"),
+            Self::Network(Error) => write!(Formatter, "Network: {}", Error),
+            Self::Failed_to_mount_device(Error) => {
+                write!(Formatter, "Failed to mount device: {}", Error)
+            }
+            Self::Failed_to_get_task_identifier(Error) => {
+                write!(Formatter, "Failed to get task identifier: {}", Error)
+            }
+            Self::Failed_to_execute(Error) => write!(Formatter, "Failed to execute: {}", Error),
+            Self::Authentication_failed => write!(Formatter, "Authentication failed"),
+            Self::Connection_closed => write!(Formatter, "Connection closed"),
+            Self::Line_too_long => write!(Formatter, "Line too long"),
+        }
+    }
+}
+
+impl From<Error_type> for NonZeroUsize {
+    fn from(Error: Error_type) -> Self {
+        Error.Get_discriminant().into()
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_discriminant() {
+        assert_eq!(Error_type::Invalid_arguments.Get_discriminant().get(), 1);
+        assert_eq!(Error_type::Line_too_long.Get_discriminant().get(), 9);
+    }
+}
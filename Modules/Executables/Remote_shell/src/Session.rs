@@ -0,0 +1,186 @@
+use Executable::Standard_type;
+use File_system::{
+    Device_type, Flags_type, Mode_type, Permissions_type, Unique_file_identifier_type,
+};
+use Task::{Join_handle_type, Task_identifier_type};
+use Users::User_identifier_type;
+use Virtual_file_system::Virtual_file_system_type;
+
+use crate::{Device::Socket_device_type, Error_type, Result_type};
+
+/// Shell started for the authenticated users.
+pub const Shell_path: &str = "/Binaries/Command_line_shell";
+
+/// Maximum size of the user name and the password.
+const Maximum_line_size: usize = 256;
+
+/// Read a line sent by the client, without its line ending.
+fn Read_line(
+    Virtual_file_system: &Virtual_file_system_type,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+) -> Result_type<String> {
+    let mut Line = Vec::new();
+
+    let mut Byte = [0; 1];
+
+    loop {
+        if Virtual_file_system.Receive(Task, Socket, &mut Byte)? == 0 {
+            return Err(Error_type::Connection_closed);
+        }
+
+        match Byte[0] {
+            b'\n' => break,
+            b'\r' => {}
+            Byte => Line.push(Byte),
+        }
+
+        if Line.len() > Maximum_line_size {
+            return Err(Error_type::Line_too_long);
+        }
+    }
+
+    Ok(String::from_utf8_lossy(&Line).into_owned())
+}
+
+fn Write(
+    Virtual_file_system: &Virtual_file_system_type,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+    Text: &str,
+) -> Result_type<()> {
+    Virtual_file_system.Send(Task, Socket, Text.as_bytes())?;
+
+    Ok(())
+}
+
+/// Ask the client for a user name and a password until they are valid (see [`Service::Authenticate`]).
+fn Authenticate(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+) -> Result_type<(String, User_identifier_type)> {
+    Service::Authenticate(
+        Virtual_file_system,
+        || {
+            Write(Virtual_file_system, Task, Socket, "Username: ")?;
+            let User_name = Read_line(Virtual_file_system, Task, Socket)?;
+
+            Write(Virtual_file_system, Task, Socket, "Password: ")?;
+            let Password = Read_line(Virtual_file_system, Task, Socket)?;
+
+            Ok((User_name, Password))
+        },
+        || {
+            Write(
+                Virtual_file_system,
+                Task,
+                Socket,
+                "Invalid user name or password\n",
+            )
+        },
+    )
+}
+
+/// Run the shell of the current task, with the device as standard input and outputs.
+fn Run_shell(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Session_task: Task_identifier_type,
+    Device_path: &str,
+    User_name: &str,
+) -> Result_type<()> {
+    let Standard_in = Virtual_file_system.Open(
+        &Device_path,
+        Flags_type::New(Mode_type::Read_only, None, None),
+        Session_task,
+    )?;
+
+    let Standard_out =
+        Virtual_file_system.Open(&Device_path, Mode_type::Write_only.into(), Session_task)?;
+
+    let Standard_error =
+        Virtual_file_system.Duplicate_file_identifier(Standard_out, Session_task)?;
+
+    let Standard = Standard_type::New(
+        Standard_in,
+        Standard_out,
+        Standard_error,
+        Session_task,
+        Virtual_file_system,
+    );
+
+    // - The shell inherits the user and the environment of the session.
+    Task::Get_instance().Set_environment_variable(Session_task, "User", User_name)?;
+
+    let _ = Executable::Execute(Shell_path, vec![], Standard)?.Join();
+
+    Ok(())
+}
+
+fn Run_session(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Session_task: Task_identifier_type,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+) -> Result_type<()> {
+    let (User_name, User) = Authenticate(Virtual_file_system, Task, Socket)?;
+
+    Task::Get_instance().Set_user(Session_task, User)?;
+
+    let Device_path = format!("/Devices/Remote_shell_{}", Session_task.Into_inner());
+
+    // - The users can't create devices, so the device is created by the task owning the socket.
+    Virtual_file_system.Mount_device(
+        Task,
+        &Device_path.as_str(),
+        Device_type::New(std::sync::Arc::new(Socket_device_type::New(
+            Virtual_file_system,
+            Task,
+            Socket,
+        ))),
+    )?;
+
+    // - Only the authenticated user can read or write the session.
+    let Result = Virtual_file_system
+        .Set_permissions(Device_path.as_str(), Permissions_type::User_read_write)
+        .and_then(|_| {
+            let Group = Users::Get_instance().Get_user_primary_group(User)?;
+
+            Virtual_file_system.Set_owner(Device_path.as_str(), Some(User), Some(Group))
+        })
+        .map_err(Error_type::from)
+        .and_then(|_| Run_shell(Virtual_file_system, Session_task, &Device_path, &User_name));
+
+    let _ = Virtual_file_system.Remove(Device_path.as_str(), Task);
+
+    Result
+}
+
+/// Start a session on an accepted connection, in a child task of the task owning the socket.
+///
+/// The client is asked to authenticate (one line for the user name, then one for the password),
+/// then the data of the connection are the standard input and outputs of a [`Shell_path`] task.
+/// The socket is closed at the end of the session.
+pub fn Start_session(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+) -> Result_type<Join_handle_type<()>> {
+    let (_, Join_handle) =
+        Task::Get_instance().New_task(Task, "Remote_session", None, move || {
+            let Result = Task::Get_instance()
+                .Get_current_task_identifier()
+                .map_err(Error_type::from)
+                .and_then(|Session_task| {
+                    Run_session(Virtual_file_system, Session_task, Task, Socket)
+                });
+
+            if let Err(Error) = Result {
+                let _ = Write(Virtual_file_system, Task, Socket, &format!("{}\n", Error));
+            }
+
+            let _ = Virtual_file_system.Close(Socket, Task);
+        })?;
+
+    Ok(Join_handle)
+}
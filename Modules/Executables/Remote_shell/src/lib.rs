@@ -0,0 +1,14 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+mod Device;
+mod Error;
+mod Executable;
+mod Main;
+mod Session;
+
+pub use Error::*;
+pub use Executable::*;
+pub use Main::Default_port;
+pub use Session::*;
@@ -106,7 +106,7 @@ fn Integration_test() {
         .Set_environment_variable(Task, "Host", "xila")
         .unwrap();
 
-    let Result = Executable::Execute("/Shell", "".to_string(), Standard)
+    let Result = Executable::Execute("/Shell", vec![], Standard)
         .unwrap()
         .Join()
         .unwrap();
@@ -63,7 +63,9 @@ impl Shell_type {
             }
         };
 
-        if let Err(Error) = Virtual_file_system::Get_instance().Remove(&Path) {
+        if let Err(Error) =
+            Virtual_file_system::Get_instance().Remove(&Path, self.Standard.Get_task())
+        {
             self.Standard
                 .Print_error_line(&format!("Failed to remove directory: {}", Error));
         }
@@ -0,0 +1,163 @@
+use File_system::{File_system_statistics_type, Path_owned_type, Path_type};
+
+use crate::Shell_type;
+
+/// Format a size in kibibytes, or with the largest fitting unit if `Human` is set.
+fn Format_size(Size: u64, Human: bool) -> String {
+    if !Human {
+        return format!("{}", Size.div_ceil(1024));
+    }
+
+    const Units: [&str; 5] = ["B", "K", "M", "G", "T"];
+
+    let mut Size = Size as f64;
+    let mut Unit = 0;
+
+    while Size >= 1024.0 && Unit < Units.len() - 1 {
+        Size /= 1024.0;
+        Unit += 1;
+    }
+
+    if Unit == 0 {
+        format!("{}{}", Size, Units[Unit])
+    } else {
+        format!("{:.1}{}", Size, Units[Unit])
+    }
+}
+
+fn Format_line(
+    Source: &str,
+    Statistics: Option<File_system_statistics_type>,
+    Mount_point: &str,
+    Human: bool,
+) -> String {
+    let Statistics = match Statistics {
+        Some(Statistics) => Statistics,
+        None => {
+            return format!(
+                "{:<12} {:>10} {:>10} {:>10} {:>5} {}",
+                Source, "-", "-", "-", "-", Mount_point
+            )
+        }
+    };
+
+    let Total = Statistics.Get_total_size().As_u64();
+    let Used = Statistics.Get_used_size().As_u64();
+
+    let Use = if Total == 0 {
+        "-".to_string()
+    } else {
+        format!("{}%", (Used * 100).div_ceil(Total))
+    };
+
+    format!(
+        "{:<12} {:>10} {:>10} {:>10} {:>5} {}",
+        Source,
+        Format_size(Total, Human),
+        Format_size(Used, Human),
+        Format_size(Statistics.Get_available_size().As_u64(), Human),
+        Use,
+        Mount_point
+    )
+}
+
+impl Shell_type {
+    /// Show the capacity and free space of the mounted file systems (or of the ones containing the given paths).
+    pub fn Disk_free(&mut self, Arguments: &[&str]) {
+        let Human = Arguments.contains(&"-h");
+
+        let Paths: Vec<&str> = Arguments
+            .iter()
+            .filter(|Argument| **Argument != "-h")
+            .copied()
+            .collect();
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        let Size_header = if Human { "Size" } else { "1K-blocks" };
+
+        self.Standard.Print_line(&format!(
+            "{:<12} {:>10} {:>10} {:>10} {:>5} Mounted on",
+            "File system", Size_header, "Used", "Available", "Use%"
+        ));
+
+        if Paths.is_empty() {
+            let Mounts = match Virtual_file_system.Get_mounts() {
+                Ok(Mounts) => Mounts,
+                Err(Error) => {
+                    self.Standard.Print_error_line(&Error.to_string());
+                    return;
+                }
+            };
+
+            for Mount in Mounts {
+                let Statistics = Virtual_file_system
+                    .Get_file_system_statistics(Mount.Get_mount_point())
+                    .ok();
+
+                self.Standard.Print_line(&Format_line(
+                    Mount.Get_source(),
+                    Statistics,
+                    Mount.Get_mount_point().As_str(),
+                    Human,
+                ));
+            }
+
+            return;
+        }
+
+        for Path in Paths {
+            let Path = Path_type::From_str(Path);
+
+            let Path: Path_owned_type = if Path.Is_absolute() {
+                Path.to_owned()
+            } else {
+                match self.Current_directory.clone().Join(Path) {
+                    Some(Path) => Path,
+                    None => {
+                        self.Standard.Print_error_line("Invalid path");
+                        continue;
+                    }
+                }
+            };
+
+            match Virtual_file_system.Get_file_system_statistics(&Path) {
+                Ok(Statistics) => self.Standard.Print_line(&Format_line(
+                    "-",
+                    Some(Statistics),
+                    Path.As_str(),
+                    Human,
+                )),
+                Err(Error) => {
+                    self.Standard
+                        .Print_error_line(&format!("{}: {}", Path.As_str(), Error))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_format_size() {
+        assert_eq!(Format_size(0, false), "0");
+        assert_eq!(Format_size(1025, false), "2");
+        assert_eq!(Format_size(512, true), "512B");
+        assert_eq!(Format_size(1536, true), "1.5K");
+        assert_eq!(Format_size(3 * 1024 * 1024, true), "3.0M");
+    }
+
+    #[test]
+    fn Test_format_line() {
+        let Statistics = File_system_statistics_type::New(512, 8, 2, 2, 0, 0);
+
+        assert_eq!(
+            Format_line("LittleFS", Some(Statistics), "/", false),
+            "LittleFS              4          3          1   75% /"
+        );
+        assert!(Format_line("System", None, "/System", true).ends_with(" - /System"));
+    }
+}
@@ -0,0 +1,60 @@
+use HTTP::{Client_type, Incoming_response_type};
+
+use crate::Shell_type;
+
+impl Shell_type {
+    /// Write the body of the response to the standard output.
+    fn Write_response_body(&mut self, Response: &mut Incoming_response_type) {
+        let mut Buffer = [0_u8; 512];
+
+        loop {
+            let Size = match Response.Read(&mut Buffer) {
+                Ok(0) => break,
+                Ok(Size) => Size,
+                Err(Error) => {
+                    self.Standard
+                        .Print_error_line(&format!("Failed to read the response: {}", Error));
+                    return;
+                }
+            };
+
+            self.Standard.Write(&Buffer[..Size]);
+        }
+    }
+
+    /// Download a resource over HTTP.
+    ///
+    /// - `fetch <url>` : write the body to the standard output (`fetch <url> > <file>` to save it).
+    pub fn Fetch(&mut self, Arguments: &[&str]) {
+        let URL = match Arguments {
+            [URL] => *URL,
+            _ => {
+                self.Standard.Print_error_line("Usage : fetch <url>");
+                return;
+            }
+        };
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        let mut Response =
+            match Client_type::New(Virtual_file_system, self.Standard.Get_task()).Get(URL) {
+                Ok(Response) => Response,
+                Err(Error) => {
+                    self.Standard
+                        .Print_error_line(&format!("{}: {}", URL, Error));
+                    return;
+                }
+            };
+
+        if !Response.Get_status().Is_success() {
+            self.Standard.Print_error_line(&format!(
+                "{}: {}",
+                Response.Get_URL(),
+                Response.Get_status()
+            ));
+            return;
+        }
+
+        self.Write_response_body(&mut Response);
+    }
+}
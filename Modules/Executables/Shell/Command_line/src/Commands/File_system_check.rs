@@ -0,0 +1,81 @@
+use File_system::Path_type;
+
+use crate::Shell_type;
+
+impl Shell_type {
+    /// Check the file system containing a path (the root by default), `-n` only inspects it without repairing.
+    pub fn Check_file_system(&mut self, Arguments: &[&str]) {
+        let Repair = !Arguments.contains(&"-n");
+
+        let Paths: Vec<&str> = Arguments
+            .iter()
+            .filter(|Argument| **Argument != "-n")
+            .copied()
+            .collect();
+
+        let Path = match Paths.as_slice() {
+            [] => Path_type::Root.to_owned(),
+            [Path] => {
+                let Path = Path_type::From_str(Path);
+
+                if Path.Is_absolute() {
+                    Path.to_owned()
+                } else {
+                    match self.Current_directory.clone().Join(Path) {
+                        Some(Path) => Path,
+                        None => {
+                            self.Standard.Print_error_line("Invalid path");
+                            return;
+                        }
+                    }
+                }
+            }
+            _ => {
+                self.Standard
+                    .Print_error_line("Invalid number of arguments");
+                return;
+            }
+        };
+
+        let Report = match Virtual_file_system::Get_instance().Check_file_system(
+            &Path,
+            Repair,
+            self.Standard.Get_task(),
+        ) {
+            Ok(Report) => Report,
+            Err(Error) => {
+                self.Standard.Print_error_line(&Error.to_string());
+                return;
+            }
+        };
+
+        for Issue in Report.Get_issues() {
+            let State = if Issue.Is_repaired() {
+                "repaired"
+            } else {
+                "not repaired"
+            };
+
+            self.Standard.Print_line(&format!(
+                "{}: {} ({})",
+                Issue.Get_path().As_str(),
+                Issue.Get_kind(),
+                State
+            ));
+        }
+
+        let Repaired = Report
+            .Get_issues()
+            .iter()
+            .filter(|Issue| Issue.Is_repaired())
+            .count();
+
+        self.Standard.Print_line(&format!(
+            "{} files, {} directories, {} issues ({} repaired)",
+            Report.Get_files(),
+            Report.Get_directories(),
+            Report.Get_issues().len(),
+            Repaired
+        ));
+    }
+}
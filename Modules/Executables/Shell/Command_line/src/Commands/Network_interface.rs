@@ -0,0 +1,170 @@
+use Network::{Interface_configuration_type, Interface_status_type};
+
+use crate::Shell_type;
+
+/// Format the status of an interface, with its saved configuration if any.
+fn Format_status(
+    Status: &Interface_status_type,
+    Configuration: Option<&Interface_configuration_type>,
+) -> Vec<String> {
+    let State = if Status.Is_link_up() { "up" } else { "down" };
+
+    let mut Lines = vec![format!("{}: {}", Status.Get_name(), State)];
+
+    if let Some(Hardware_address) = Status.Get_hardware_address() {
+        let Hardware_address: Vec<String> = Hardware_address
+            .iter()
+            .map(|Byte| format!("{:02x}", Byte))
+            .collect();
+
+        Lines.push(format!("    hardware {}", Hardware_address.join(":")));
+    }
+
+    for Address in Status.Get_addresses() {
+        Lines.push(format!("    address {}", Address));
+    }
+
+    if let Some(Gateway) = Status.Get_gateway() {
+        Lines.push(format!("    gateway {}", Gateway));
+    }
+
+    for Server in Status.Get_DNS_servers() {
+        Lines.push(format!("    dns {}", Server));
+    }
+
+    if let Some(Configuration) = Configuration {
+        Lines.push(format!("    configuration {}", Configuration));
+    }
+
+    Lines
+}
+
+impl Shell_type {
+    fn Show_network_interfaces(&mut self, Interfaces: &[&str]) {
+        let Manager = Network::Get_instance();
+
+        let Interfaces: Vec<String> = if Interfaces.is_empty() {
+            match Manager.Get_interfaces() {
+                Ok(Interfaces) => Interfaces,
+                Err(Error) => {
+                    self.Standard.Print_error_line(&Error.to_string());
+                    return;
+                }
+            }
+        } else {
+            Interfaces
+                .iter()
+                .map(|Interface| Interface.to_string())
+                .collect()
+        };
+
+        for Interface in Interfaces {
+            let Status = match Manager.Get_status(&Interface) {
+                Ok(Status) => Status,
+                Err(Error) => {
+                    self.Standard
+                        .Print_error_line(&format!("{}: {}", Interface, Error));
+                    continue;
+                }
+            };
+
+            let Configuration = Manager.Get_configuration(&Interface).ok().flatten();
+
+            for Line in Format_status(&Status, Configuration.as_ref()) {
+                self.Standard.Print_line(&Line);
+            }
+        }
+    }
+
+    fn Set_network_interface(&mut self, Interface: &str, Configuration: &[&str]) {
+        let Configuration = match Interface_configuration_type::Parse(&Configuration.join(" ")) {
+            Ok(Configuration) => Configuration,
+            Err(Error) => {
+                self.Standard.Print_error_line(&format!(
+                    "Invalid configuration ({}), expected : dhcp [dns <IP>]... | static address <IP>/<prefix>... [gateway <IP>] [dns <IP>]...",
+                    Error
+                ));
+                return;
+            }
+        };
+
+        if let Err(Error) = Network::Get_instance().Set_configuration(Interface, Configuration) {
+            self.Standard
+                .Print_error_line(&format!("{}: {}", Interface, Error));
+            return;
+        }
+
+        if let Err(Error) =
+            Virtual_file_system::Save_network_configuration(Virtual_file_system::Get_instance())
+        {
+            self.Standard
+                .Print_error_line(&format!("Failed to save the configuration: {}", Error));
+        }
+    }
+
+    /// Show or configure the network interfaces (`ip`/`ifconfig`).
+    ///
+    /// - `ip [show [interface]...]` : show the status of the interfaces.
+    /// - `ip set <interface> <configuration>` : apply and save a configuration (e.g. `dhcp` or `static address 192.168.1.2/24 gateway 192.168.1.1`).
+    /// - `ip up|down <interface>` : enable or disable an interface.
+    pub fn Network_interface(&mut self, Arguments: &[&str]) {
+        if !Network::Is_initialized() {
+            self.Standard.Print_error_line("Network unavailable");
+            return;
+        }
+
+        match Arguments {
+            [] => self.Show_network_interfaces(&[]),
+            ["show", Interfaces @ ..] | ["list", Interfaces @ ..] => {
+                self.Show_network_interfaces(Interfaces)
+            }
+            ["set", Interface, Configuration @ ..] => {
+                self.Set_network_interface(Interface, Configuration)
+            }
+            [State @ ("up" | "down"), Interface] => {
+                if let Err(Error) = Network::Get_instance().Set_link(Interface, *State == "up") {
+                    self.Standard
+                        .Print_error_line(&format!("{}: {}", Interface, Error));
+                }
+            }
+            _ => self.Standard.Print_error_line(
+                "Usage : ip [show [interface]...] | set <interface> <configuration> | up <interface> | down <interface>",
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    use Network::{IPv4_type, Interface_address_type};
+
+    #[test]
+    fn Test_format_status() {
+        let Status = Interface_status_type::New(
+            "eth0".to_string(),
+            Some([0x02, 0, 0, 0, 0, 0x1A]),
+            true,
+            vec![Interface_address_type::New(
+                IPv4_type::New([192, 168, 1, 2]).into(),
+                24,
+            )],
+            Some(IPv4_type::New([192, 168, 1, 1]).into()),
+            vec![],
+        );
+
+        let Configuration = Interface_configuration_type::New_DHCP(vec![]);
+
+        assert_eq!(
+            Format_status(&Status, Some(&Configuration)),
+            [
+                "eth0: up",
+                "    hardware 02:00:00:00:00:1a",
+                "    address 192.168.1.2/24",
+                "    gateway 192.168.1.1",
+                "    configuration dhcp"
+            ]
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::time::Duration;
+
+use File_system::Unique_file_identifier_type;
+use Network::{
+    Address_family_type, Decode_echo_reply, Encode_echo_request, IP_type, Port_type, Protocol_type,
+    Record_kind_type,
+};
+use Task::Task_identifier_type;
+use Virtual_file_system::Socket_address_type;
+
+use crate::Shell_type;
+
+/// Data sent in the echo requests, sent back by the host.
+const Payload: &[u8] = b"Xila ping 0123456789abcdefghijklmnopqrstuvwxyz";
+
+const Default_count: u16 = 4;
+
+/// Time to wait for each reply, and between the requests.
+const Interval: Duration = Duration::from_secs(1);
+
+fn Format_milliseconds(Duration: Duration) -> String {
+    format!("{:.3}", Duration.as_secs_f64() * 1000.0)
+}
+
+/// Format the statistics shown at the end of a `ping`.
+fn Format_summary(Host: &str, Transmitted: u16, Round_trip_times: &[Duration]) -> Vec<String> {
+    let Received = Round_trip_times.len();
+
+    let Loss = if Transmitted == 0 {
+        0
+    } else {
+        (Transmitted as usize - Received) * 100 / Transmitted as usize
+    };
+
+    let mut Lines = vec![
+        format!("--- {} ping statistics ---", Host),
+        format!(
+            "{} packets transmitted, {} received, {}% packet loss",
+            Transmitted, Received, Loss
+        ),
+    ];
+
+    if let (Some(Minimum), Some(Maximum)) =
+        (Round_trip_times.iter().min(), Round_trip_times.iter().max())
+    {
+        let Average = Round_trip_times.iter().sum::<Duration>() / Received as u32;
+
+        Lines.push(format!(
+            "round-trip min/avg/max = {}/{}/{} ms",
+            Format_milliseconds(*Minimum),
+            Format_milliseconds(Average),
+            Format_milliseconds(*Maximum)
+        ));
+    }
+
+    Lines
+}
+
+impl Shell_type {
+    /// Resolve the host to ping, preferring IPv4.
+    fn Resolve_ping_host(&mut self, Task: Task_identifier_type, Host: &str) -> Option<IP_type> {
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        let Result = Virtual_file_system
+            .Resolve(Task, Host, Record_kind_type::A)
+            .or_else(|_| Virtual_file_system.Resolve(Task, Host, Record_kind_type::AAAA));
+
+        match Result {
+            Ok(Addresses) => Addresses.into_iter().next(),
+            Err(Error) => {
+                self.Standard
+                    .Print_error_line(&format!("{}: {}", Host, Error));
+                None
+            }
+        }
+    }
+
+    /// Send an echo request and wait for its reply, return the round-trip time if any.
+    fn Ping_once(
+        &mut self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        IP: &IP_type,
+        Sequence: u16,
+    ) -> Option<Duration> {
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+        let Time_manager = Time::Get_instance();
+
+        let Family = Address_family_type::From_IP(IP);
+
+        let Request = Encode_echo_request(Family, 0, Sequence, Payload);
+
+        let Start = Time_manager.Get_current_time_since_startup().ok()?;
+
+        if let Err(Error) = Virtual_file_system.Send_to(
+            Task,
+            Socket,
+            &Request,
+            Socket_address_type::From_IP_and_port(IP.clone(), Port_type::Any),
+        ) {
+            self.Standard.Print_error_line(&format!(
+                "Failed to send the request {}: {}",
+                Sequence, Error
+            ));
+            return None;
+        }
+
+        let mut Buffer = [0; 128];
+
+        loop {
+            // - Fails once the receive timeout is elapsed.
+            let (Size, Sender) = match Virtual_file_system.Receive_from(Task, Socket, &mut Buffer) {
+                Ok(Received) => Received,
+                Err(_) => {
+                    self.Standard
+                        .Print_line(&format!("Request timeout for sequence {}", Sequence));
+                    return None;
+                }
+            };
+
+            let Now = Time_manager.Get_current_time_since_startup().ok()?;
+
+            let Elapsed = Duration::from_nanos(
+                Now.Get_duration_since_saturating(&Start).As_nanoseconds() as u64,
+            );
+
+            let Reply = match Decode_echo_reply(Family, &Buffer[..Size]) {
+                Some(Reply) if Reply.Get_sequence() == Sequence => Reply,
+                // - Late reply to a previous request or other ICMP message.
+                _ => {
+                    if Elapsed >= Interval {
+                        self.Standard
+                            .Print_line(&format!("Request timeout for sequence {}", Sequence));
+                        return None;
+                    }
+
+                    continue;
+                }
+            };
+
+            let Sender = match Sender.Into_IP_and_port() {
+                Some((IP, _)) => IP.to_string(),
+                None => "?".to_string(),
+            };
+
+            self.Standard.Print_line(&format!(
+                "{} bytes from {}: sequence={} time={} ms",
+                Reply.Get_payload().len() + 8,
+                Sender,
+                Sequence,
+                Format_milliseconds(Elapsed)
+            ));
+
+            return Some(Elapsed);
+        }
+    }
+
+    /// Check the connectivity with a host using ICMP echo requests.
+    ///
+    /// - `ping [-c <count>] <host>` : send `count` requests (4 by default), one per second.
+    pub fn Ping(&mut self, Arguments: &[&str]) {
+        let (Count, Host) = match Arguments {
+            [Host] => (Default_count, *Host),
+            ["-c", Count, Host] => match Count.parse::<u16>() {
+                Ok(Count) if Count > 0 => (Count, *Host),
+                _ => {
+                    self.Standard.Print_error_line("Invalid count");
+                    return;
+                }
+            },
+            _ => {
+                self.Standard
+                    .Print_error_line("Usage : ping [-c <count>] <host>");
+                return;
+            }
+        };
+
+        let Task = self.Standard.Get_task();
+
+        let IP = match self.Resolve_ping_host(Task, Host) {
+            Some(IP) => IP,
+            None => return,
+        };
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        let Socket = match Virtual_file_system.Create_socket(
+            Task,
+            Address_family_type::From_IP(&IP),
+            Protocol_type::ICMP,
+        ) {
+            Ok(Socket) => Socket,
+            Err(Error) => {
+                self.Standard
+                    .Print_error_line(&format!("Failed to open the socket: {}", Error));
+                return;
+            }
+        };
+
+        if let Err(Error) = Virtual_file_system.Set_receive_timeout(Task, Socket, Interval) {
+            self.Standard
+                .Print_error_line(&format!("Failed to set the timeout: {}", Error));
+            let _ = Virtual_file_system.Close(Socket, Task);
+            return;
+        }
+
+        self.Standard.Print_line(&format!(
+            "PING {} ({}): {} data bytes",
+            Host,
+            IP,
+            Payload.len()
+        ));
+
+        let mut Round_trip_times = Vec::with_capacity(Count as usize);
+
+        for Sequence in 1..=Count {
+            if let Some(Round_trip_time) = self.Ping_once(Task, Socket, &IP, Sequence) {
+                Round_trip_times.push(Round_trip_time);
+
+                if Sequence < Count {
+                    Task::Manager_type::Sleep(Interval.saturating_sub(Round_trip_time));
+                }
+            }
+        }
+
+        let _ = Virtual_file_system.Close(Socket, Task);
+
+        for Line in Format_summary(Host, Count, &Round_trip_times) {
+            self.Standard.Print_line(&Line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_format_summary() {
+        let Round_trip_times = [
+            Duration::from_micros(1500),
+            Duration::from_micros(500),
+            Duration::from_micros(1000),
+        ];
+
+        assert_eq!(
+            Format_summary("localhost", 4, &Round_trip_times),
+            [
+                "--- localhost ping statistics ---",
+                "4 packets transmitted, 3 received, 25% packet loss",
+                "round-trip min/avg/max = 0.500/1.000/1.500 ms"
+            ]
+        );
+
+        assert_eq!(
+            Format_summary("10.0.0.1", 2, &[]),
+            [
+                "--- 10.0.0.1 ping statistics ---",
+                "2 packets transmitted, 0 received, 100% packet loss"
+            ]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use File_system::Path_type;
+use Network::{IPv4_type, Port_type};
+use HTTP::{Directory_handler_type, Method_type, Router_type, Server_type};
+
+use crate::Shell_type;
+
+const Default_port: u16 = 8080;
+
+impl Shell_type {
+    /// Serve the content of a directory over HTTP, until the server fails.
+    ///
+    /// - `serve [-a] <directory> [port]` : listen on the loopback, on port 8080 by default.
+    ///   With `-a`, listen on all the interfaces : there is no access control, anyone reaching the device can read the directory.
+    pub fn Serve(&mut self, Arguments: &[&str]) {
+        let (Address, Arguments) = match Arguments {
+            ["-a", Arguments @ ..] => (IPv4_type::New([0; 4]), Arguments),
+            _ => (IPv4_type::Localhost, Arguments),
+        };
+
+        let (Directory, Port) = match Arguments {
+            [Directory] => (*Directory, Default_port),
+            [Directory, Port] => match Port.parse::<u16>() {
+                Ok(Port) => (*Directory, Port),
+                Err(_) => {
+                    self.Standard.Print_error_line("Invalid port");
+                    return;
+                }
+            },
+            _ => {
+                self.Standard
+                    .Print_error_line("Usage : serve [-a] <directory> [port]");
+                return;
+            }
+        };
+
+        let Directory = Path_type::From_str(Directory);
+
+        let Directory = if Directory.Is_absolute() {
+            Directory.to_owned()
+        } else {
+            match self.Current_directory.clone().Join(Directory) {
+                Some(Directory) => Directory,
+                None => {
+                    self.Standard.Print_error_line("Invalid path");
+                    return;
+                }
+            }
+        };
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+        let Task = self.Standard.Get_task();
+
+        let Handler = Directory_handler_type::New(Virtual_file_system, Task, Directory, "/");
+
+        let Router = Router_type::New().Add_prefix(Method_type::Get, "/", move |Request| {
+            Handler.Handle(Request)
+        });
+
+        let Server = match Server_type::Bind(
+            Virtual_file_system,
+            Task,
+            (Address, Port_type::New(Port)).into(),
+            Router,
+        ) {
+            Ok(Server) => Server,
+            Err(Error) => {
+                self.Standard
+                    .Print_error_line(&format!("Failed to listen on port {}: {}", Port, Error));
+                return;
+            }
+        };
+
+        self.Standard
+            .Print_line(&format!("Serving HTTP on {}:{}", Address, Port));
+
+        if let Err(Error) = Server.Run() {
+            self.Standard.Print_error_line(&Error.to_string());
+        }
+    }
+}
@@ -3,8 +3,14 @@ mod Change_directory;
 mod Clear;
 mod Concatenate;
 mod Directory;
+mod Disk_free;
 mod Echo;
 mod Environment_variables;
 mod Exit;
+mod Fetch;
+mod File_system_check;
 mod List;
+mod Network_interface;
+mod Ping;
+mod Serve;
 mod Statistics;
@@ -0,0 +1,89 @@
+use File_system::{Path_type, Type_type};
+use Virtual_file_system::Directory_type;
+
+use crate::{Line_editor::Line_editor_type, Shell_type};
+
+impl Shell_type {
+    pub const Builtins: [&'static str; 18] = [
+        "exit", "cd", "echo", "ls", "clear", "cat", "stat", "mkdir", "export", "unset", "rm", "df",
+        "fsck", "ip", "ifconfig", "ping", "fetch", "serve",
+    ];
+
+    /// Get the entries of a directory whose name starts with `Prefix`.
+    fn Get_directory_entries(Path: &Path_type, Prefix: &str) -> Vec<(String, Type_type)> {
+        let Directory = match Directory_type::Open(Virtual_file_system::Get_instance(), Path) {
+            Ok(Directory) => Directory,
+            Err(_) => return Vec::new(),
+        };
+
+        Directory
+            .filter(|Entry| Entry.Get_name() != "." && Entry.Get_name() != "..")
+            .filter(|Entry| Entry.Get_name().starts_with(Prefix))
+            .map(|Entry| (Entry.Get_name().clone(), Entry.Get_type()))
+            .collect()
+    }
+
+    fn Get_command_candidates(Word: &str, Paths: &[&Path_type]) -> Vec<String> {
+        let Builtins = Self::Builtins
+            .iter()
+            .filter(|Builtin| Builtin.starts_with(Word))
+            .map(|Builtin| Builtin.to_string());
+
+        let Executables = Paths
+            .iter()
+            .flat_map(|Path| Self::Get_directory_entries(Path, Word))
+            .map(|(Name, _)| Name);
+
+        Builtins.chain(Executables).collect()
+    }
+
+    fn Get_path_candidates(&self, Word: &str) -> Vec<String> {
+        let (Directory, Prefix) = match Word.rfind('/') {
+            Some(Index) => Word.split_at(Index + 1),
+            None => ("", Word),
+        };
+
+        let Directory_path = if Directory.starts_with('/') {
+            Path_type::From_str(Directory).to_owned()
+        } else {
+            match self.Current_directory.clone().Append(Directory) {
+                Some(Path) => Path,
+                None => return Vec::new(),
+            }
+        };
+
+        Self::Get_directory_entries(&Directory_path, Prefix)
+            .into_iter()
+            .map(|(Name, Type)| {
+                if Type == Type_type::Directory {
+                    format!("{}{}/", Directory, Name)
+                } else {
+                    format!("{}{}", Directory, Name)
+                }
+            })
+            .collect()
+    }
+
+    /// Get the completion candidates for the word before the cursor.
+    ///
+    /// Commands are completed with the builtins and the executables found in `Paths`, other words
+    /// are completed with the paths of the virtual file system.
+    pub fn Get_completion_candidates(
+        &self,
+        Line_editor: &Line_editor_type,
+        Paths: &[&Path_type],
+    ) -> Vec<String> {
+        let (_, Word) = Line_editor.Get_word_to_complete();
+
+        let mut Candidates = if Line_editor.Is_completing_command() && !Word.contains('/') {
+            Self::Get_command_candidates(&Word, Paths)
+        } else {
+            self.Get_path_candidates(&Word)
+        };
+
+        Candidates.sort();
+        Candidates.dedup();
+
+        Candidates
+    }
+}
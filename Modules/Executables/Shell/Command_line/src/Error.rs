@@ -20,6 +20,9 @@ pub enum Error_type {
     Failed_to_execute_command,
     Failed_to_join_task,
     Invalid_number_of_arguments,
+    Failed_to_read_script,
+    Unsupported_redirection,
+    Failed_to_redirect_output(Executable::Error_type),
 }
 
 impl Error_type {
@@ -65,6 +68,11 @@ impl Display for Error_type {
             Error_type::Invalid_number_of_arguments => {
                 write!(Formatter, "Invalid number of arguments")
             }
+            Error_type::Failed_to_read_script => write!(Formatter, "Failed to read script"),
+            Error_type::Unsupported_redirection => write!(Formatter, "Unsupported redirection"),
+            Error_type::Failed_to_redirect_output(Error) => {
+                write!(Formatter, "Failed to redirect output: {}", Error)
+            }
         }
     }
 }
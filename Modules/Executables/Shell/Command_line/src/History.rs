@@ -0,0 +1,110 @@
+use File_system::{Flags_type, Mode_type, Open_type, Path_owned_type, Path_type, Status_type};
+use Virtual_file_system::File_type;
+
+use crate::Shell_type;
+
+impl Shell_type {
+    pub const History_capacity: usize = 100;
+
+    pub const History_file_name: &'static str = "Shell_history";
+
+    /// Get the path of the history file of the current user (`/Users/<name>/Shell_history`).
+    fn Get_history_path(&self) -> Option<Path_owned_type> {
+        Path_type::Users
+            .Append(&self.User)?
+            .Append(Self::History_file_name)
+    }
+
+    /// Load the history of the current user, an empty history is returned if there is none.
+    pub fn Load_history(&mut self) -> Vec<String> {
+        let Path = match self.Get_history_path() {
+            Some(Path) => Path,
+            None => return Vec::new(),
+        };
+
+        let File = match File_type::Open(
+            Virtual_file_system::Get_instance(),
+            Path,
+            Mode_type::Read_only.into(),
+        ) {
+            Ok(File) => File,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut Buffer = Vec::new();
+
+        if File.Read_to_end(&mut Buffer).is_err() {
+            return Vec::new();
+        }
+
+        let History: Vec<String> = String::from_utf8_lossy(&Buffer)
+            .lines()
+            .filter(|Line| !Line.trim().is_empty())
+            .map(|Line| Line.to_string())
+            .collect();
+
+        self.History_file_length = History.len();
+
+        History
+    }
+
+    /// Append an entry to the history file of the current user.
+    pub fn Save_history_entry(&mut self, Entry: &str, History: &[String]) {
+        let Path = match self.Get_history_path() {
+            Some(Path) => Path,
+            None => return,
+        };
+
+        // - The user directory may not exist yet.
+        if let Some(User_directory) = Path_type::Users.Append(&self.User) {
+            let _ = Virtual_file_system::Get_instance()
+                .Create_directory(&User_directory, self.Standard.Get_task());
+        }
+
+        // - The file is rewritten once it grows too much, to keep only the last entries.
+        let Rewrite = self.History_file_length >= Self::History_capacity * 2;
+
+        let Flags = if Rewrite {
+            Flags_type::New(
+                Mode_type::Write_only,
+                Some(Open_type::Truncate.Set_create(true)),
+                None,
+            )
+        } else {
+            Flags_type::New(
+                Mode_type::Write_only,
+                Some(Open_type::Create),
+                Some(Status_type::None.Set_append(true)),
+            )
+        };
+
+        let File = match File_type::Open(Virtual_file_system::Get_instance(), &Path, Flags) {
+            Ok(File) => File,
+            Err(Error) => {
+                self.Standard
+                    .Print_error_line(&format!("Failed to save history: {}", Error));
+                return;
+            }
+        };
+
+        let Result = if Rewrite {
+            History
+                .iter()
+                .try_for_each(|Entry| File.Write_line(Entry.as_bytes()).map(|_| ()))
+        } else {
+            File.Write_line(Entry.as_bytes()).map(|_| ())
+        };
+
+        if let Err(Error) = Result {
+            self.Standard
+                .Print_error_line(&format!("Failed to save history: {}", Error));
+            return;
+        }
+
+        self.History_file_length = if Rewrite {
+            History.len()
+        } else {
+            self.History_file_length + 1
+        };
+    }
+}
@@ -0,0 +1,650 @@
+//! Line editor for interactive input.
+//!
+//! The editor only relies on the bytes read from the standard input (keys and VT100 escape
+//! sequences) and on the VT100 sequences written to the standard output, so it works on any
+//! terminal device.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action_type {
+    /// Nothing to do (incomplete sequence, ignored key...).
+    None,
+    /// The line must be redrawn.
+    Redraw,
+    /// The line is validated.
+    Submit(String),
+    /// The word before the cursor must be completed.
+    Complete,
+    /// The line is discarded (`Ctrl+C`).
+    Interrupt,
+    /// End of input (`Ctrl+D` on an empty line).
+    End_of_file,
+    /// The screen must be cleared before redrawing the line (`Ctrl+L`).
+    Clear_screen,
+}
+
+/// Incremental reverse history search (`Ctrl+R`).
+struct Search_type {
+    Query: String,
+    /// Index of the matching history entry.
+    Match: Option<usize>,
+}
+
+pub struct Line_editor_type {
+    Line: Vec<char>,
+    Cursor: usize,
+    History: Vec<String>,
+    History_capacity: usize,
+    /// Index of the history entry being displayed (`None` when editing a new line).
+    History_index: Option<usize>,
+    /// Line being edited before navigating through the history.
+    Saved_line: Vec<char>,
+    Search: Option<Search_type>,
+    /// Pending bytes of an escape sequence.
+    Escape: Vec<u8>,
+    /// Pending bytes of an UTF-8 character.
+    UTF_8_buffer: Vec<u8>,
+    /// Ignore the line feed following a carriage return (`\r\n` line endings).
+    Skip_line_feed: bool,
+}
+
+impl Line_editor_type {
+    pub fn New(History_capacity: usize) -> Self {
+        Self {
+            Line: Vec::new(),
+            Cursor: 0,
+            History: Vec::new(),
+            History_capacity,
+            History_index: None,
+            Saved_line: Vec::new(),
+            Search: None,
+            Escape: Vec::with_capacity(8),
+            UTF_8_buffer: Vec::with_capacity(4),
+            Skip_line_feed: false,
+        }
+    }
+
+    pub fn Get_line(&self) -> String {
+        self.Line.iter().collect()
+    }
+
+    pub fn Get_cursor(&self) -> usize {
+        self.Cursor
+    }
+
+    pub fn Get_history(&self) -> &[String] {
+        &self.History
+    }
+
+    /// Replace the history (e.g. when loading it from a file), the oldest entries are dropped if needed.
+    pub fn Set_history(&mut self, History: Vec<String>) {
+        self.History = History;
+
+        if self.History.len() > self.History_capacity {
+            self.History
+                .drain(..self.History.len() - self.History_capacity);
+        }
+    }
+
+    /// Add a line to the history, empty lines and consecutive duplicates are ignored.
+    pub fn Add_history(&mut self, Line: &str) -> bool {
+        let Line = Line.trim();
+
+        if Line.is_empty() || self.History.last().map(String::as_str) == Some(Line) {
+            return false;
+        }
+
+        if self.History.len() == self.History_capacity {
+            self.History.remove(0);
+        }
+
+        self.History.push(Line.to_string());
+
+        true
+    }
+
+    /// Clear the line being edited.
+    pub fn Reset(&mut self) {
+        self.Line.clear();
+        self.Cursor = 0;
+        self.History_index = None;
+        self.Saved_line.clear();
+        self.Search = None;
+        self.Escape.clear();
+        self.UTF_8_buffer.clear();
+    }
+
+    /// Get the start (in characters) and the content of the word before the cursor.
+    pub fn Get_word_to_complete(&self) -> (usize, String) {
+        let Start = self.Line[..self.Cursor]
+            .iter()
+            .rposition(|Character| Character.is_whitespace())
+            .map_or(0, |Index| Index + 1);
+
+        (Start, self.Line[Start..self.Cursor].iter().collect())
+    }
+
+    /// Check if the word before the cursor is the command (first word of the line or after an operator).
+    pub fn Is_completing_command(&self) -> bool {
+        let (Start, _) = self.Get_word_to_complete();
+
+        let Previous: String = self.Line[..Start].iter().collect();
+
+        match Previous.split_whitespace().last() {
+            None => true,
+            Some(Word) => matches!(Word, "|" | "&&" | "||" | ";"),
+        }
+    }
+
+    /// Complete the word before the cursor with the given candidates.
+    ///
+    /// The common prefix of the candidates is inserted. Returns `true` if the completion is
+    /// ambiguous (the candidates should be displayed).
+    pub fn Complete(&mut self, Candidates: &[String]) -> bool {
+        let (Start, Word) = self.Get_word_to_complete();
+
+        let Candidates: Vec<&String> = Candidates
+            .iter()
+            .filter(|Candidate| Candidate.starts_with(&Word))
+            .collect();
+
+        let Common_prefix = match Candidates.split_first() {
+            None => return false,
+            Some((First, Others)) => Others.iter().fold(First.as_str(), |Prefix, Candidate| {
+                let Length = Prefix
+                    .char_indices()
+                    .zip(Candidate.chars())
+                    .take_while(|((_, Left), Right)| Left == Right)
+                    .last()
+                    .map_or(0, |((Index, Character), _)| Index + Character.len_utf8());
+
+                &Prefix[..Length]
+            }),
+        };
+
+        let mut Completion: Vec<char> = Common_prefix.chars().collect();
+
+        // - Unique candidate : add a separator, unless it is a directory.
+        if Candidates.len() == 1 && !Common_prefix.ends_with('/') {
+            Completion.push(' ');
+        }
+
+        self.Line
+            .splice(Start..self.Cursor, Completion.iter().copied());
+        self.Cursor = Start + Completion.len();
+
+        Candidates.len() > 1
+    }
+
+    /// Get the VT100 sequence to redraw the line after the prompt.
+    pub fn Render(&self, Prompt: &str) -> String {
+        let (Prompt, Line, Cursor) = match &self.Search {
+            Some(Search) => {
+                let Match = Search
+                    .Match
+                    .map(|Index| self.History[Index].as_str())
+                    .unwrap_or_default();
+
+                (
+                    format!("(reverse-i-search)`{}': ", Search.Query),
+                    Match.to_string(),
+                    Match.chars().count(),
+                )
+            }
+            None => (Prompt.to_string(), self.Get_line(), self.Cursor),
+        };
+
+        let mut Output = format!("\r{}{}\x1B[K", Prompt, Line);
+
+        let Offset = Line.chars().count() - Cursor;
+
+        if Offset > 0 {
+            Output += &format!("\x1B[{}D", Offset);
+        }
+
+        Output
+    }
+
+    /// Process a byte read from the terminal.
+    pub fn Feed(&mut self, Byte: u8) -> Action_type {
+        if !self.Escape.is_empty() {
+            return self.Feed_escape(Byte);
+        }
+
+        if !self.UTF_8_buffer.is_empty() || Byte >= 0x80 {
+            return self.Feed_UTF_8(Byte);
+        }
+
+        let Skip_line_feed = core::mem::replace(&mut self.Skip_line_feed, false);
+
+        if self.Search.is_some() {
+            if let Some(Action) = self.Feed_search(Byte) {
+                return Action;
+            }
+        }
+
+        match Byte {
+            b'\r' => {
+                self.Skip_line_feed = true;
+                self.Submit()
+            }
+            b'\n' if Skip_line_feed => Action_type::None,
+            b'\n' => self.Submit(),
+            0x1B => {
+                self.Escape.push(Byte);
+                Action_type::None
+            }
+            // - Ctrl+A / Ctrl+E
+            0x01 => self.Move_cursor(0),
+            0x05 => self.Move_cursor(self.Line.len()),
+            // - Ctrl+B / Ctrl+F
+            0x02 => self.Move_cursor(self.Cursor.saturating_sub(1)),
+            0x06 => self.Move_cursor(self.Cursor + 1),
+            0x03 => {
+                self.Reset();
+                Action_type::Interrupt
+            }
+            0x04 if self.Line.is_empty() => Action_type::End_of_file,
+            0x04 => self.Delete(),
+            0x08 | 0x7F => self.Backspace(),
+            b'\t' => Action_type::Complete,
+            // - Ctrl+K / Ctrl+U / Ctrl+W
+            0x0B => {
+                self.Line.truncate(self.Cursor);
+                Action_type::Redraw
+            }
+            0x15 => {
+                self.Line.drain(..self.Cursor);
+                self.Cursor = 0;
+                Action_type::Redraw
+            }
+            0x17 => {
+                let Start = self.Get_previous_word_start();
+                self.Line.drain(Start..self.Cursor);
+                self.Cursor = Start;
+                Action_type::Redraw
+            }
+            0x0C => Action_type::Clear_screen,
+            // - Ctrl+P / Ctrl+N
+            0x10 => self.History_previous(),
+            0x0E => self.History_next(),
+            0x12 => {
+                self.Search = Some(Search_type {
+                    Query: String::new(),
+                    Match: None,
+                });
+                Action_type::Redraw
+            }
+            Byte if Byte < 0x20 => Action_type::None,
+            Byte => self.Insert(Byte as char),
+        }
+    }
+
+    fn Feed_UTF_8(&mut self, Byte: u8) -> Action_type {
+        self.UTF_8_buffer.push(Byte);
+
+        let Expected_length = match self.UTF_8_buffer[0] {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => {
+                self.UTF_8_buffer.clear();
+                return Action_type::None;
+            }
+        };
+
+        if self.UTF_8_buffer.len() < Expected_length {
+            return Action_type::None;
+        }
+
+        let Character = core::str::from_utf8(&self.UTF_8_buffer)
+            .ok()
+            .and_then(|String| String.chars().next());
+
+        self.UTF_8_buffer.clear();
+
+        match Character {
+            Some(Character) => {
+                if let Some(Search) = &mut self.Search {
+                    Search.Query.push(Character);
+                    self.Search_history(self.History.len());
+                    return Action_type::Redraw;
+                }
+
+                self.Insert(Character)
+            }
+            None => Action_type::None,
+        }
+    }
+
+    fn Feed_escape(&mut self, Byte: u8) -> Action_type {
+        self.Escape.push(Byte);
+
+        let Sequence = match self.Escape.as_slice() {
+            // - Incomplete sequences.
+            [0x1B] => return Action_type::None,
+            [0x1B, b'[' | b'O'] => return Action_type::None,
+            [0x1B, b'[', Parameters @ ..] if Parameters.last().is_some_and(u8::is_ascii_digit) => {
+                // - Protect against endless sequences.
+                if Parameters.len() > 4 {
+                    self.Escape.clear();
+                }
+                return Action_type::None;
+            }
+            Sequence => Sequence.to_vec(),
+        };
+
+        self.Escape.clear();
+
+        // - Any sequence ends the search, the match is kept for edition.
+        if self.Search.is_some() {
+            if Sequence == [0x1B, 0x1B] {
+                self.Cancel_search();
+                return Action_type::Redraw;
+            }
+            self.Accept_search();
+        }
+
+        match &Sequence[1..] {
+            b"[A" | b"OA" => self.History_previous(),
+            b"[B" | b"OB" => self.History_next(),
+            b"[C" | b"OC" => self.Move_cursor(self.Cursor + 1),
+            b"[D" | b"OD" => self.Move_cursor(self.Cursor.saturating_sub(1)),
+            b"[H" | b"OH" | b"[1~" | b"[7~" => self.Move_cursor(0),
+            b"[F" | b"OF" | b"[4~" | b"[8~" => self.Move_cursor(self.Line.len()),
+            b"[3~" => self.Delete(),
+            b"b" => self.Move_cursor(self.Get_previous_word_start()),
+            b"f" => self.Move_cursor(self.Get_next_word_end()),
+            _ => Action_type::Redraw,
+        }
+    }
+
+    /// Handle a key in search mode, returns `None` if the key must be processed normally.
+    fn Feed_search(&mut self, Byte: u8) -> Option<Action_type> {
+        let Search = self.Search.as_mut()?;
+
+        match Byte {
+            0x12 => {
+                // - Search an older entry.
+                let Before = Search.Match.unwrap_or(self.History.len());
+                self.Search_history(Before);
+            }
+            0x08 | 0x7F => {
+                Search.Query.pop();
+                self.Search_history(self.History.len());
+            }
+            // - Ctrl+G / Ctrl+C : cancel the search.
+            0x07 | 0x03 => self.Cancel_search(),
+            Byte if Byte >= 0x20 => {
+                Search.Query.push(Byte as char);
+                self.Search_history(self.History.len());
+            }
+            0x1B => return None,
+            _ => {
+                self.Accept_search();
+                return None;
+            }
+        }
+
+        Some(Action_type::Redraw)
+    }
+
+    /// Search the most recent history entry before `Before` containing the query.
+    fn Search_history(&mut self, Before: usize) {
+        let Some(Search) = &mut self.Search else {
+            return;
+        };
+
+        let Found = self.History[..Before.min(self.History.len())]
+            .iter()
+            .rposition(|Entry| Entry.contains(&Search.Query));
+
+        // - Keep the current match if there is no older one.
+        if Found.is_some() || Before == self.History.len() {
+            Search.Match = Found;
+        }
+    }
+
+    fn Accept_search(&mut self) {
+        if let Some(Search) = self.Search.take() {
+            if let Some(Index) = Search.Match {
+                self.Line = self.History[Index].chars().collect();
+                self.Cursor = self.Line.len();
+            }
+        }
+    }
+
+    fn Cancel_search(&mut self) {
+        self.Search = None;
+    }
+
+    fn Submit(&mut self) -> Action_type {
+        self.Accept_search();
+
+        let Line = self.Get_line();
+
+        self.Reset();
+
+        Action_type::Submit(Line)
+    }
+
+    fn Insert(&mut self, Character: char) -> Action_type {
+        self.Line.insert(self.Cursor, Character);
+        self.Cursor += 1;
+
+        Action_type::Redraw
+    }
+
+    fn Backspace(&mut self) -> Action_type {
+        if self.Cursor == 0 {
+            return Action_type::None;
+        }
+
+        self.Cursor -= 1;
+        self.Line.remove(self.Cursor);
+
+        Action_type::Redraw
+    }
+
+    fn Delete(&mut self) -> Action_type {
+        if self.Cursor == self.Line.len() {
+            return Action_type::None;
+        }
+
+        self.Line.remove(self.Cursor);
+
+        Action_type::Redraw
+    }
+
+    fn Move_cursor(&mut self, Position: usize) -> Action_type {
+        self.Cursor = Position.min(self.Line.len());
+
+        Action_type::Redraw
+    }
+
+    fn Get_previous_word_start(&self) -> usize {
+        let Line = &self.Line[..self.Cursor];
+
+        let End = Line
+            .iter()
+            .rposition(|Character| !Character.is_whitespace())
+            .map_or(0, |Index| Index + 1);
+
+        Line[..End]
+            .iter()
+            .rposition(|Character| Character.is_whitespace())
+            .map_or(0, |Index| Index + 1)
+    }
+
+    fn Get_next_word_end(&self) -> usize {
+        let Line = &self.Line[self.Cursor..];
+
+        let Start = Line
+            .iter()
+            .position(|Character| !Character.is_whitespace())
+            .unwrap_or(Line.len());
+
+        self.Cursor
+            + Line[Start..]
+                .iter()
+                .position(|Character| Character.is_whitespace())
+                .map_or(Line.len(), |Index| Start + Index)
+    }
+
+    fn History_previous(&mut self) -> Action_type {
+        let Index = match self.History_index {
+            Some(0) => return Action_type::None,
+            Some(Index) => Index - 1,
+            None if self.History.is_empty() => return Action_type::None,
+            None => {
+                self.Saved_line = core::mem::take(&mut self.Line);
+                self.History.len() - 1
+            }
+        };
+
+        self.History_index = Some(Index);
+        self.Line = self.History[Index].chars().collect();
+        self.Cursor = self.Line.len();
+
+        Action_type::Redraw
+    }
+
+    fn History_next(&mut self) -> Action_type {
+        match self.History_index {
+            None => return Action_type::None,
+            Some(Index) if Index + 1 < self.History.len() => {
+                self.History_index = Some(Index + 1);
+                self.Line = self.History[Index + 1].chars().collect();
+            }
+            Some(_) => {
+                self.History_index = None;
+                self.Line = core::mem::take(&mut self.Saved_line);
+            }
+        }
+
+        self.Cursor = self.Line.len();
+
+        Action_type::Redraw
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    fn Feed_all(Editor: &mut Line_editor_type, Bytes: &[u8]) -> Action_type {
+        let mut Last = Action_type::None;
+
+        for Byte in Bytes {
+            Last = Editor.Feed(*Byte);
+        }
+
+        Last
+    }
+
+    #[test]
+    fn Test_editing() {
+        let mut Editor = Line_editor_type::New(8);
+
+        Feed_all(&mut Editor, b"ecoh\x1B[D\x1B[D\x7Fch");
+        assert_eq!(Editor.Get_line(), "echoh");
+        assert_eq!(Editor.Get_cursor(), 3);
+
+        Feed_all(&mut Editor, b"\x1B[3~\x05 world\x01\x1B[C\x0B");
+        assert_eq!(Editor.Get_line(), "e");
+
+        assert_eq!(
+            Feed_all(&mut Editor, "cho été\r".as_bytes()),
+            Action_type::Submit("echo été".to_string())
+        );
+        assert_eq!(Editor.Get_line(), "");
+        // - The line feed of a `\r\n` line ending is ignored.
+        assert_eq!(Editor.Feed(b'\n'), Action_type::None);
+    }
+
+    #[test]
+    fn Test_words() {
+        let mut Editor = Line_editor_type::New(8);
+
+        Feed_all(&mut Editor, b"ls  /Devices /Binaries\x1Bb\x1Bb\x17");
+        assert_eq!(Editor.Get_line(), "/Devices /Binaries");
+        assert_eq!(Editor.Get_cursor(), 0);
+
+        Feed_all(&mut Editor, b"\x1Bf");
+        assert_eq!(Editor.Get_cursor(), 8);
+    }
+
+    #[test]
+    fn Test_history() {
+        let mut Editor = Line_editor_type::New(2);
+
+        Editor.Set_history(vec!["ls".into(), "cd /".into(), "cat File".into()]);
+        assert_eq!(Editor.Get_history(), ["cd /", "cat File"]);
+
+        assert!(!Editor.Add_history("cat File"));
+        assert!(Editor.Add_history("echo"));
+        assert_eq!(Editor.Get_history(), ["cat File", "echo"]);
+
+        Feed_all(&mut Editor, b"pwd\x1B[A\x1B[A\x1B[A");
+        assert_eq!(Editor.Get_line(), "cat File");
+
+        Feed_all(&mut Editor, b"\x1B[B");
+        assert_eq!(Editor.Get_line(), "echo");
+
+        Feed_all(&mut Editor, b"\x1B[B");
+        assert_eq!(Editor.Get_line(), "pwd");
+    }
+
+    #[test]
+    fn Test_reverse_search() {
+        let mut Editor = Line_editor_type::New(8);
+
+        Editor.Set_history(vec![
+            "echo Foo".into(),
+            "ls /Devices".into(),
+            "echo Bar".into(),
+        ]);
+
+        Feed_all(&mut Editor, b"\x12ech");
+        assert_eq!(
+            Editor.Render("$ "),
+            "\r(reverse-i-search)`ech': echo Bar\x1B[K"
+        );
+
+        Feed_all(&mut Editor, b"\x12\x12");
+        assert_eq!(
+            Editor.Render("$ "),
+            "\r(reverse-i-search)`ech': echo Foo\x1B[K"
+        );
+
+        Feed_all(&mut Editor, b"\x1B[D");
+        assert_eq!(Editor.Get_line(), "echo Foo");
+        assert_eq!(Editor.Render("$ "), "\r$ echo Foo\x1B[K\x1B[1D");
+
+        assert_eq!(
+            Feed_all(&mut Editor, b"\x12ls\r"),
+            Action_type::Submit("ls /Devices".to_string())
+        );
+    }
+
+    #[test]
+    fn Test_completion() {
+        let mut Editor = Line_editor_type::New(8);
+
+        Feed_all(&mut Editor, b"ca");
+        assert!(Editor.Is_completing_command());
+        assert_eq!(Feed_all(&mut Editor, b"\t"), Action_type::Complete);
+
+        assert!(!Editor.Complete(&["cat".into(), "cd".into()]));
+        assert_eq!(Editor.Get_line(), "cat ");
+
+        Feed_all(&mut Editor, b"/Dev");
+        assert!(!Editor.Is_completing_command());
+        assert_eq!(Editor.Get_word_to_complete(), (4, "/Dev".to_string()));
+
+        assert!(Editor.Complete(&[
+            "/Devices/Standard_in".into(),
+            "/Devices/Standard_out".into()
+        ]));
+        assert_eq!(Editor.Get_line(), "cat /Devices/Standard_");
+    }
+}
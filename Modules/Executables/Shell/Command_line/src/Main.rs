@@ -1,13 +1,18 @@
 use std::num::NonZeroUsize;
 
-use Executable::{Execute, Standard_type};
-use File_system::Path_type;
+use Executable::{Arguments_type, Execute, Standard_type};
+use File_system::{Flags_type, Mode_type, Open_type, Path_type, Status_type};
 
 use crate::{
-    Error_type, Parser::Parse, Resolver::Resolve, Result_type, Shell_type, Tokenizer::Tokenize,
+    Error_type,
+    Line_editor::{Action_type, Line_editor_type},
+    Parser::{Command_type, Output_type, Parse},
+    Resolver::Resolve,
+    Result_type, Shell_type,
+    Tokenizer::Tokenize,
 };
 
-pub fn Main(Standard: Standard_type, Arguments: String) -> Result<(), NonZeroUsize> {
+pub fn Main(Standard: Standard_type, Arguments: Arguments_type) -> Result<(), NonZeroUsize> {
     Shell_type::New(Standard).Main(Arguments)
 }
 
@@ -19,15 +24,19 @@ impl Shell_type {
             Running: true,
             User: "".to_string(),
             Host: "".to_string(),
+            History_file_length: 0,
         }
     }
 
     fn Run(&mut self, Path: &Path_type, Arguments: &[&str]) -> Result_type<()> {
         let Standard = self.Standard.Duplicate().unwrap();
 
-        let Input = Arguments.join(" ");
+        let Arguments = Arguments
+            .iter()
+            .map(|Argument| Argument.to_string())
+            .collect();
 
-        let _ = Execute(Path, Input, Standard)
+        let _ = Execute(Path, Arguments, Standard)
             .map_err(|_| Error_type::Failed_to_execute_command)?
             .Join()
             .map_err(|_| Error_type::Failed_to_join_task)?;
@@ -35,80 +44,234 @@ impl Shell_type {
         Ok(())
     }
 
-    fn Parse_input(&mut self, Input: &str, Paths: &[&Path_type]) -> Result_type<()> {
+    /// Get the standard input and error of the shell, with a file as standard output.
+    fn Redirect_output(&self, Output: &Output_type) -> Result_type<Standard_type> {
+        let Path = Path_type::From_str(Output.Get_path());
+
+        let Path = if Path.Is_absolute() {
+            Path.to_owned()
+        } else {
+            self.Current_directory
+                .clone()
+                .Join(Path)
+                .ok_or(Error_type::Invalid_path)?
+        };
+
+        let Open = if Output.Get_append() {
+            Open_type::Create
+        } else {
+            Open_type::Truncate.Set_create(true)
+        };
+
+        let mut Standard = self
+            .Standard
+            .Duplicate()
+            .map_err(Error_type::Failed_to_redirect_output)?;
+
+        let Standard_out = Virtual_file_system::Get_instance()
+            .Open(
+                &Path,
+                Flags_type::New(
+                    Mode_type::Write_only,
+                    Some(Open),
+                    Some(Status_type::None.Set_append(Output.Get_append())),
+                ),
+                self.Standard.Get_task(),
+            )
+            .map_err(|Error| Error_type::Failed_to_redirect_output(Error.into()))?;
+
+        Standard.Set_standard_out(Standard_out);
+
+        Ok(Standard)
+    }
+
+    fn Execute_command(&mut self, Command: &Command_type, Paths: &[&Path_type]) -> Result_type<()> {
+        match Command.Get_command() {
+            "exit" => self.Exit(Command.Get_arguments()),
+            "cd" => self.Change_directory(Command.Get_arguments()),
+            "echo" => self.Echo(Command.Get_arguments()),
+            "ls" => self.List(Command.Get_arguments()),
+            "clear" => self.Clear(Command.Get_arguments()),
+            "cat" => self.Concatenate(Command.Get_arguments()),
+            "stat" => self.Statistics(Command.Get_arguments()),
+            "mkdir" => self.Create_directory(Command.Get_arguments()),
+            "export" => self.Set_environment_variable(Command.Get_arguments()),
+            "unset" => self.Remove_environment_variable(Command.Get_arguments()),
+            "rm" => self.Remove(Command.Get_arguments()),
+            "df" => self.Disk_free(Command.Get_arguments()),
+            "fsck" => self.Check_file_system(Command.Get_arguments()),
+            "ip" | "ifconfig" => self.Network_interface(Command.Get_arguments()),
+            "ping" => self.Ping(Command.Get_arguments()),
+            "fetch" => self.Fetch(Command.Get_arguments()),
+            "serve" => self.Serve(Command.Get_arguments()),
+            _ => {
+                // - Set the current directory for the following commands.
+                if let Err(Error) = Task::Get_instance().Set_environment_variable(
+                    self.Standard.Get_task(),
+                    "Current_directory",
+                    self.Current_directory.As_str(),
+                ) {
+                    self.Standard
+                        .Print_error_line(&format!("Failed to set current directory: {}", Error));
+                }
+
+                let Path = Path_type::From_str(Command.Get_command());
+
+                if Path.Is_valid() {
+                    if Path.Is_absolute() {
+                        self.Run(Path, Command.Get_arguments())?;
+                    } else {
+                        match self.Current_directory.clone().Join(Path) {
+                            Some(Path) => self.Run(&Path, Command.Get_arguments())?,
+                            None => self.Standard.Print_error_line("Invalid command"),
+                        }
+                    }
+                } else {
+                    let Path = Resolve(Command.Get_command(), Paths)?;
+
+                    self.Run(&Path, Command.Get_arguments())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn Parse_input(&mut self, Input: &str, Paths: &[&Path_type]) -> Result_type<()> {
         let Tokens = Input.split_whitespace().collect::<Vec<&str>>();
 
         let Tokens = Tokenize(&Tokens);
         let Commands = Parse(Tokens)?;
 
         for Command in Commands {
-            match Command.Get_command() {
-                "exit" => self.Exit(Command.Get_arguments()),
-                "cd" => self.Change_directory(Command.Get_arguments()),
-                "echo" => self.Echo(Command.Get_arguments()),
-                "ls" => self.List(Command.Get_arguments()),
-                "clear" => self.Clear(Command.Get_arguments()),
-                "cat" => self.Concatenate(Command.Get_arguments()),
-                "stat" => self.Statistics(Command.Get_arguments()),
-                "mkdir" => self.Create_directory(Command.Get_arguments()),
-                "export" => self.Set_environment_variable(Command.Get_arguments()),
-                "unset" => self.Remove_environment_variable(Command.Get_arguments()),
-                "rm" => self.Remove(Command.Get_arguments()),
-                _ => {
-                    // - Set the current directory for the following commands.
-                    if let Err(Error) = Task::Get_instance().Set_environment_variable(
-                        self.Standard.Get_task(),
-                        "Current_directory",
-                        self.Current_directory.As_str(),
-                    ) {
-                        self.Standard.Print_error_line(&format!(
-                            "Failed to set current directory: {}",
-                            Error
-                        ));
-                    }
+            // - The standard output of the shell is replaced while the command runs.
+            let Previous = match Command.Get_output() {
+                Some(Output) => {
+                    let Standard = self.Redirect_output(Output)?;
 
-                    let Path = Path_type::From_str(Command.Get_command());
+                    Some(core::mem::replace(&mut self.Standard, Standard))
+                }
+                None => None,
+            };
 
-                    if Path.Is_valid() {
-                        if Path.Is_absolute() {
-                            self.Run(Path, Command.Get_arguments())?;
-                        } else {
-                            match self.Current_directory.clone().Join(Path) {
-                                Some(Path) => self.Run(&Path, Command.Get_arguments())?,
-                                None => self.Standard.Print_error_line("Invalid command"),
-                            }
-                        }
-                    } else {
-                        let Path = Resolve(Command.Get_command(), Paths)?;
+            let Result = self.Execute_command(&Command, Paths);
 
-                        self.Run(&Path, Command.Get_arguments())?;
-                    }
-                }
+            if let Some(Previous) = Previous {
+                self.Standard = Previous;
             }
+
+            Result?;
         }
 
         Ok(())
     }
 
+    /// Read a line with the line editor, the standard input is in raw mode while the line is edited.
+    ///
+    /// Returns `None` at the end of the input. If the standard input cannot be read byte by byte,
+    /// the line is read directly from the device.
+    fn Read_line_interactive(
+        &mut self,
+        Line_editor: &mut Line_editor_type,
+        Prompt: &str,
+        Paths: &[&Path_type],
+    ) -> Option<String> {
+        // - Not all the terminals have a line mode (e.g. the remote shell sessions).
+        let _ = self.Standard.Set_raw_mode(true);
+
+        let Line = self.Edit_line(Line_editor, Prompt, Paths);
+
+        let _ = self.Standard.Set_raw_mode(false);
+
+        Line
+    }
+
+    fn Edit_line(
+        &mut self,
+        Line_editor: &mut Line_editor_type,
+        Prompt: &str,
+        Paths: &[&Path_type],
+    ) -> Option<String> {
+        let mut Buffer = [0_u8; 16];
+
+        loop {
+            let Size: usize = match self.Standard.Read(&mut Buffer) {
+                Ok(Size) => Size.into(),
+                Err(Executable::Error_type::File_system(
+                    File_system::Error_type::Unsupported_operation,
+                )) => {
+                    let mut Input = String::new();
+
+                    self.Standard.Read_line(&mut Input);
+
+                    return Some(Input);
+                }
+                Err(_) => return None,
+            };
+
+            if Size == 0 {
+                return None;
+            }
+
+            for Byte in &Buffer[..Size] {
+                match Line_editor.Feed(*Byte) {
+                    Action_type::None => continue,
+                    Action_type::Redraw => {}
+                    Action_type::Submit(Line) => {
+                        self.Standard.Print("\r\n");
+
+                        return Some(Line);
+                    }
+                    Action_type::Complete => {
+                        let Candidates = self.Get_completion_candidates(Line_editor, Paths);
+
+                        if Line_editor.Complete(&Candidates) {
+                            self.Standard.Print("\r\n");
+                            self.Standard.Print(&Candidates.join("  "));
+                            self.Standard.Print("\r\n");
+                        }
+                    }
+                    Action_type::Interrupt => {
+                        self.Standard.Print("^C\r\n");
+                    }
+                    Action_type::End_of_file => return None,
+                    Action_type::Clear_screen => {
+                        self.Standard.Print("\x1B[2J\x1B[H");
+                    }
+                }
+
+                self.Standard.Print(&Line_editor.Render(Prompt));
+            }
+
+            self.Standard.Out_flush();
+        }
+    }
+
     fn Main_interactive(&mut self, Paths: &[&Path_type]) -> Result<(), Error_type> {
-        let mut Input = String::new();
+        let mut Line_editor = Line_editor_type::New(Self::History_capacity);
+
+        Line_editor.Set_history(self.Load_history());
 
         while self.Running {
-            self.Standard.Print(&format!(
-                "{}@{}:{}$ ",
-                self.User, self.Host, self.Current_directory
-            ));
+            let Prompt = format!("{}@{}:{}$ ", self.User, self.Host, self.Current_directory);
 
-            self.Standard.Out_flush();
+            self.Standard.Print(&Prompt);
 
-            Input.clear();
+            self.Standard.Out_flush();
 
-            self.Standard.Read_line(&mut Input);
+            let Input = match self.Read_line_interactive(&mut Line_editor, &Prompt, Paths) {
+                Some(Input) => Input,
+                None => break,
+            };
 
-            if Input.is_empty() {
+            if Input.trim().is_empty() {
                 continue;
             }
 
+            if Line_editor.Add_history(&Input) {
+                self.Save_history_entry(Input.trim(), Line_editor.Get_history());
+            }
+
             let Result = self.Parse_input(&Input, Paths);
 
             if let Err(Error) = Result {
@@ -119,7 +282,7 @@ impl Shell_type {
         Ok(())
     }
 
-    pub fn Main(&mut self, Arguments: String) -> Result<(), NonZeroUsize> {
+    pub fn Main(&mut self, Arguments: Arguments_type) -> Result<(), NonZeroUsize> {
         let User =
             match Task::Get_instance().Get_environment_variable(self.Standard.Get_task(), "User") {
                 Ok(User) => User.Get_value().to_string(),
@@ -148,10 +311,14 @@ impl Shell_type {
             .map_err(|_| Error_type::Failed_to_get_path)?;
         self.Host = Host.Get_value().to_string();
 
-        if Arguments.is_empty() {
+        // - A script is only run when the shell is its interpreter (see [Executable::Execute]),
+        //   so that a file named like a command does not replace it.
+        if let Some(Script_path) = Arguments.Get_program() {
+            self.Run_script(Script_path, &Paths)?;
+        } else if Arguments.Is_empty() {
             self.Main_interactive(&Paths)?;
         } else {
-            self.Parse_input(&Arguments, &Paths)?;
+            self.Parse_input(&Arguments.Get_arguments().join(" "), &Paths)?;
         }
 
         Ok(())
@@ -1,12 +1,30 @@
 use crate::{
     Error::{Error_type, Result_type},
-    Tokenizer::Token_type,
+    Tokenizer::{Redirect_type, Redirect_type_type, Token_type},
 };
 
+/// File receiving the standard output of a command (`> file` or `>> file`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output_type<'a> {
+    Path: &'a str,
+    Append: bool,
+}
+
+impl Output_type<'_> {
+    pub fn Get_path(&self) -> &str {
+        self.Path
+    }
+
+    pub fn Get_append(&self) -> bool {
+        self.Append
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Command_type<'a> {
     Command: &'a str,
     Arguments: Vec<&'a str>,
+    Output: Option<Output_type<'a>>,
 }
 
 impl Command_type<'_> {
@@ -17,6 +35,10 @@ impl Command_type<'_> {
     pub fn Get_arguments(&self) -> &[&str] {
         &self.Arguments
     }
+
+    pub fn Get_output(&self) -> Option<&Output_type<'_>> {
+        self.Output.as_ref()
+    }
 }
 
 impl<'a> TryFrom<&[Token_type<'a>]> for Command_type<'a> {
@@ -31,12 +53,42 @@ impl<'a> TryFrom<&[Token_type<'a>]> for Command_type<'a> {
         };
 
         let mut Arguments = Vec::new();
-
-        while let Some(Token_type::String(Argument)) = Iterator.next() {
-            Arguments.push(*Argument);
+        let mut Output = None;
+
+        while let Some(Token) = Iterator.next() {
+            match Token {
+                Token_type::String(Argument) => Arguments.push(*Argument),
+                // - Only the standard output can be redirected.
+                Token_type::Redirect(Redirect_type {
+                    Left: "" | "1",
+                    Redirect_type:
+                        Kind @ (Redirect_type_type::Output | Redirect_type_type::Output_append),
+                    Right,
+                }) => {
+                    // - The file name can be separated from the operator (`> file`).
+                    let Path = if Right.is_empty() {
+                        match Iterator.next() {
+                            Some(Token_type::String(Path)) => *Path,
+                            _ => return Err(Error_type::Missing_file_name_after_redirect_out),
+                        }
+                    } else {
+                        *Right
+                    };
+
+                    Output = Some(Output_type {
+                        Path,
+                        Append: *Kind == Redirect_type_type::Output_append,
+                    });
+                }
+                _ => return Err(Error_type::Unsupported_redirection),
+            }
         }
 
-        Ok(Self { Command, Arguments })
+        Ok(Self {
+            Command,
+            Arguments,
+            Output,
+        })
     }
 }
 
@@ -55,6 +107,8 @@ pub fn Parse(Tokens: Vec<Token_type<'_>>) -> Result_type<Vec<Command_type<'_>>>
 mod Tests {
     use super::*;
 
+    use crate::Tokenizer::Tokenize;
+
     #[test]
     fn Test_parse() {
         let Tokens = vec![
@@ -95,4 +149,51 @@ mod Tests {
         assert_eq!(Commands[1].Command, "grep");
         assert_eq!(Commands[1].Arguments, vec!["main"]);
     }
+
+    #[test]
+    fn Test_parse_redirection() {
+        let Input = "fetch http://host/ > /File"
+            .split_whitespace()
+            .collect::<Vec<&str>>();
+
+        let Commands = Parse(Tokenize(&Input)).unwrap();
+
+        assert_eq!(Commands[0].Arguments, vec!["http://host/"]);
+        assert_eq!(
+            Commands[0].Get_output(),
+            Some(&Output_type {
+                Path: "/File",
+                Append: false
+            })
+        );
+
+        let Input = "echo hello >>/File"
+            .split_whitespace()
+            .collect::<Vec<&str>>();
+
+        let Commands = Parse(Tokenize(&Input)).unwrap();
+
+        assert_eq!(Commands[0].Arguments, vec!["hello"]);
+        assert_eq!(
+            Commands[0].Get_output(),
+            Some(&Output_type {
+                Path: "/File",
+                Append: true
+            })
+        );
+
+        let Input = "echo hello >".split_whitespace().collect::<Vec<&str>>();
+
+        assert!(matches!(
+            Parse(Tokenize(&Input)),
+            Err(Error_type::Missing_file_name_after_redirect_out)
+        ));
+
+        let Input = "echo hello 2>&1".split_whitespace().collect::<Vec<&str>>();
+
+        assert!(matches!(
+            Parse(Tokenize(&Input)),
+            Err(Error_type::Unsupported_redirection)
+        ));
+    }
 }
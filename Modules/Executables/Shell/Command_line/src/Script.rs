@@ -0,0 +1,43 @@
+use File_system::{Mode_type, Path_type};
+use Virtual_file_system::File_type;
+
+use crate::{Error_type, Result_type, Shell_type};
+
+impl Shell_type {
+    /// Run each line of a script, empty lines and comments (starting with `#`) are ignored.
+    ///
+    /// The execution continues after a failing line, until the script calls `exit`.
+    pub fn Run_script(&mut self, Path: &Path_type, Paths: &[&Path_type]) -> Result_type<()> {
+        let File = File_type::Open(
+            Virtual_file_system::Get_instance(),
+            Path,
+            Mode_type::Read_only.into(),
+        )
+        .map_err(|_| Error_type::Failed_to_read_script)?;
+
+        let mut Buffer = Vec::new();
+
+        File.Read_to_end(&mut Buffer)
+            .map_err(|_| Error_type::Failed_to_read_script)?;
+
+        let Script = String::from_utf8(Buffer).map_err(|_| Error_type::Failed_to_read_script)?;
+
+        for Line in Script.lines() {
+            if !self.Running {
+                break;
+            }
+
+            let Line = Line.trim();
+
+            if Line.is_empty() || Line.starts_with('#') {
+                continue;
+            }
+
+            if let Err(Error) = self.Parse_input(Line, Paths) {
+                self.Standard.Print_error_line(&Error.to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -5,11 +5,15 @@
 use Executable::Standard_type;
 
 mod Commands;
+mod Completion;
 mod Device;
 mod Error;
+mod History;
+mod Line_editor;
 mod Main;
 mod Parser;
 mod Resolver;
+mod Script;
 mod Tokenizer;
 
 pub use Device::*;
@@ -22,4 +26,6 @@ pub struct Shell_type {
     Running: bool,
     User: String,
     Host: String,
+    /// Number of entries in the history file, to know when to rewrite it without reading it.
+    History_file_length: usize,
 }
@@ -146,7 +146,7 @@ fn main() {
         .Set_environment_variable(Task, "Host", "xila")
         .unwrap();
 
-    let Result = Executable::Execute("/Binaries/Graphical_shell", "".to_string(), Standard)
+    let Result = Executable::Execute("/Binaries/Graphical_shell", vec![], Standard)
         .unwrap()
         .Join()
         .unwrap();
@@ -300,7 +300,11 @@ impl Desk_type {
 
         Executable::Execute(
             Shortcut.Get_command(),
-            Shortcut.Get_arguments().to_string(),
+            Shortcut
+                .Get_arguments()
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
             Standard_type::New(
                 Standard_in,
                 Standard_out,
@@ -26,10 +26,10 @@ impl Layout_type {
 
     fn Update_clock(&mut self) {
         // - Update the clock
-        let Current_time = Time::Get_instance().Get_current_time();
+        let Current_time = Time::Get_instance().Get_current_local_time();
 
         if let Ok(Current_time) = Current_time {
-            let (_, _, _, Hour, Minute, _) = Unix_to_human_time(Current_time.As_seconds() as i64);
+            let (_, _, _, Hour, Minute, _) = Unix_to_human_time(Current_time);
 
             if let Ok(_Lock) = Graphics::Get_instance().Lock() {
                 self.Clock_string = format!("{:02}:{:02}\0", Hour, Minute);
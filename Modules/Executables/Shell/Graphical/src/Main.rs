@@ -1,7 +1,7 @@
 use core::num::NonZeroUsize;
 use std::time::Duration;
 
-use Executable::Standard_type;
+use Executable::{Arguments_type, Standard_type};
 use File_system::Path_type;
 
 use crate::{
@@ -9,7 +9,7 @@ use crate::{
     Shell_type, Shortcut::Shortcut_type,
 };
 
-pub fn Main(Standard: Standard_type, Arguments: String) -> Result<(), NonZeroUsize> {
+pub fn Main(Standard: Standard_type, Arguments: Arguments_type) -> Result<(), NonZeroUsize> {
     Shell_type::New(Standard).Main(Arguments)
 }
 
@@ -29,15 +29,15 @@ impl Shell_type {
         }
     }
 
-    pub fn Main(&mut self, Arguments: String) -> Result<(), NonZeroUsize> {
-        let Arguments: Vec<&str> = Arguments.split_whitespace().collect();
+    pub fn Main(&mut self, Arguments: Arguments_type) -> Result<(), NonZeroUsize> {
+        if let [Command, Arguments @ ..] = Arguments.Get_arguments() {
+            if Command == "add_shortcut" {
+                let [Path] = Arguments else {
+                    return Err(Error_type::Missing_arguments.into());
+                };
 
-        if Arguments.first() == Some(&"add_shortcut") {
-            if Arguments.len() != 2 {
-                return Err(Error_type::Missing_arguments.into());
+                Shortcut_type::Add(Path_type::From_str(Path))?;
             }
-
-            Shortcut_type::Add(Path_type::From_str(Arguments[1]))?;
         }
 
         while self.Running {
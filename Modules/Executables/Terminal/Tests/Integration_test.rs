@@ -100,7 +100,7 @@ fn main() {
         .Set_environment_variable(Task, "Host", "xila")
         .unwrap();
 
-    let Result = Executable::Execute("/Terminal", "".to_string(), Standard)
+    let Result = Executable::Execute("/Terminal", vec![], Standard)
         .unwrap()
         .Join()
         .unwrap();
@@ -1,10 +1,14 @@
-use File_system::Device_trait;
+use File_system::{Device_trait, Events_type};
 
 use crate::Terminal::Terminal_type;
 
 impl Device_trait for Terminal_type {
-    fn Read(&self, _: &mut [u8]) -> File_system::Result_type<File_system::Size_type> {
-        Err(File_system::Error_type::Unsupported_operation)
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<File_system::Size_type> {
+        let Size = self
+            .Read_raw_input(Buffer)
+            .map_err(|_| File_system::Error_type::Internal_error)?;
+
+        Ok(Size.into())
     }
 
     fn Read_line(&self, Buffer: &mut String) -> File_system::Result_type<File_system::Size_type> {
@@ -16,9 +20,7 @@ impl Device_trait for Terminal_type {
     }
 
     fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<File_system::Size_type> {
-        let String = String::from_utf8_lossy(Buffer);
-
-        self.Print(&String)
+        self.Write(Buffer)
             .map_err(|_| File_system::Error_type::Internal_error)?;
 
         Ok(Buffer.len().into())
@@ -38,4 +40,12 @@ impl Device_trait for Terminal_type {
     fn Flush(&self) -> File_system::Result_type<()> {
         Ok(())
     }
+
+    fn Get_events(&self) -> File_system::Result_type<Events_type> {
+        let Readable = self
+            .Is_input_ready()
+            .map_err(|_| File_system::Error_type::Internal_error)?;
+
+        Ok(Events_type::Writable.Set_readable(Readable))
+    }
 }
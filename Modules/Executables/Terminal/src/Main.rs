@@ -1,7 +1,7 @@
 use core::num::NonZeroUsize;
 use std::{sync::Arc, time::Duration};
 
-use Executable::Standard_type;
+use Executable::{Arguments_type, Standard_type};
 use File_system::{Device_type, Flags_type, Mode_type, Unique_file_identifier_type};
 use Task::Task_identifier_type;
 
@@ -54,7 +54,7 @@ fn Inner_main(Task: Task_identifier_type) -> Result_type<()> {
         Virtual_file_system::Get_instance(),
     );
 
-    Executable::Execute("/Binaries/Command_line_shell", "".to_string(), Standard)?;
+    Executable::Execute("/Binaries/Command_line_shell", vec![], Standard)?;
 
     while Terminal.Event_handler()? {
         Task::Manager_type::Sleep(Duration::from_millis(20));
@@ -63,7 +63,7 @@ fn Inner_main(Task: Task_identifier_type) -> Result_type<()> {
     Ok(())
 }
 
-pub fn Main(Standard: Standard_type, _: String) -> Result<(), NonZeroUsize> {
+pub fn Main(Standard: Standard_type, _: Arguments_type) -> Result<(), NonZeroUsize> {
     if let Err(Error) = Inner_main(Standard.Get_task()) {
         Standard.Print_error(&Error.to_string());
         return Err(Error.into());
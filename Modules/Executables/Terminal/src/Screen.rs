@@ -0,0 +1,1114 @@
+//! VT100 / xterm subset emulator.
+//!
+//! The [Screen_type] parses the byte stream written to the terminal device and maintains a grid of
+//! [Cell_type]. It does not depend on the graphics stack, the rendering is done by the terminal
+//! which only redraws the rows marked as dirty.
+
+use std::collections::VecDeque;
+
+/// Red, green and blue components of a color.
+pub type RGB_type = (u8, u8, u8);
+
+/// Color of a cell as requested by the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Terminal_color_type {
+    Default,
+    Indexed(u8),
+    RGB(u8, u8, u8),
+}
+
+impl Terminal_color_type {
+    /// Standard xterm palette for the 16 first colors.
+    const Palette: [RGB_type; 16] = [
+        (0x00, 0x00, 0x00),
+        (0xCD, 0x00, 0x00),
+        (0x00, 0xCD, 0x00),
+        (0xCD, 0xCD, 0x00),
+        (0x00, 0x00, 0xEE),
+        (0xCD, 0x00, 0xCD),
+        (0x00, 0xCD, 0xCD),
+        (0xE5, 0xE5, 0xE5),
+        (0x7F, 0x7F, 0x7F),
+        (0xFF, 0x00, 0x00),
+        (0x00, 0xFF, 0x00),
+        (0xFF, 0xFF, 0x00),
+        (0x5C, 0x5C, 0xFF),
+        (0xFF, 0x00, 0xFF),
+        (0x00, 0xFF, 0xFF),
+        (0xFF, 0xFF, 0xFF),
+    ];
+
+    /// Get the RGB components of the color, or `None` for the default color.
+    pub fn Get_RGB(&self) -> Option<RGB_type> {
+        match *self {
+            Self::Default => None,
+            Self::RGB(Red, Green, Blue) => Some((Red, Green, Blue)),
+            Self::Indexed(Index) if Index < 16 => Some(Self::Palette[Index as usize]),
+            // - 6x6x6 color cube.
+            Self::Indexed(Index) if Index < 232 => {
+                let Index = Index - 16;
+                let Level = |Value: u8| if Value == 0 { 0 } else { 55 + Value * 40 };
+
+                Some((Level(Index / 36), Level((Index / 6) % 6), Level(Index % 6)))
+            }
+            // - Grayscale ramp.
+            Self::Indexed(Index) => {
+                let Level = 8 + (Index - 232) * 10;
+
+                Some((Level, Level, Level))
+            }
+        }
+    }
+}
+
+/// Graphic rendition attributes (set with the SGR sequence).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attributes_type {
+    pub Foreground: Terminal_color_type,
+    pub Background: Terminal_color_type,
+    pub Bold: bool,
+    pub Underline: bool,
+    pub Inverse: bool,
+}
+
+impl Default for Attributes_type {
+    fn default() -> Self {
+        Self {
+            Foreground: Terminal_color_type::Default,
+            Background: Terminal_color_type::Default,
+            Bold: false,
+            Underline: false,
+            Inverse: false,
+        }
+    }
+}
+
+impl Attributes_type {
+    /// Color of the text when none is requested.
+    pub const Default_foreground: RGB_type = (0xFF, 0xFF, 0xFF);
+    /// Color of the terminal background.
+    pub const Default_background: RGB_type = (0x00, 0x00, 0x00);
+
+    /// Get the displayed foreground and background colors, taking into account the bold and inverse attributes.
+    ///
+    /// The background is `None` when it is the one of the terminal.
+    pub fn Get_rendered_colors(&self) -> (RGB_type, Option<RGB_type>) {
+        let Foreground = match self.Foreground {
+            // - Bold selects the bright variant of the 8 first colors.
+            Terminal_color_type::Indexed(Index) if self.Bold && Index < 8 => {
+                Terminal_color_type::Indexed(Index + 8)
+            }
+            Color => Color,
+        }
+        .Get_RGB()
+        .unwrap_or(Self::Default_foreground);
+
+        let Background = self.Background.Get_RGB();
+
+        if self.Inverse {
+            (
+                Background.unwrap_or(Self::Default_background),
+                Some(Foreground),
+            )
+        } else {
+            (Foreground, Background)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell_type {
+    pub Character: char,
+    pub Attributes: Attributes_type,
+}
+
+impl Default for Cell_type {
+    fn default() -> Self {
+        Self {
+            Character: ' ',
+            Attributes: Attributes_type::default(),
+        }
+    }
+}
+
+type Row_type = Vec<Cell_type>;
+
+/// Consecutive cells of a row displayed with the same rendition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run_type {
+    pub Text: String,
+    pub Foreground: RGB_type,
+    /// `None` for the background of the terminal.
+    pub Background: Option<RGB_type>,
+    pub Underline: bool,
+}
+
+/// Color of the cursor cell.
+pub const Cursor_color: RGB_type = (0x00, 0xC8, 0x00);
+
+/// Split a row into runs of cells with the same rendition, the cursor cell being drawn as a block.
+///
+/// The trailing cells which would be invisible are omitted.
+pub fn Get_row_runs(Cells: &[Cell_type], Cursor: Option<usize>) -> Vec<Run_type> {
+    let mut Runs: Vec<Run_type> = Vec::new();
+
+    let Last = Cells
+        .iter()
+        .rposition(|Cell| {
+            Cell.Character != ' '
+                || Cell.Attributes.Underline
+                || Cell.Attributes.Get_rendered_colors().1.is_some()
+        })
+        .max(Cursor.filter(|Cursor| *Cursor < Cells.len()))
+        .map_or(0, |Last| Last + 1);
+
+    for (Index, Cell) in Cells[..Last].iter().enumerate() {
+        let (Foreground, Background, Underline) = if Cursor == Some(Index) {
+            (
+                Attributes_type::Default_background,
+                Some(Cursor_color),
+                false,
+            )
+        } else {
+            let (Foreground, Background) = Cell.Attributes.Get_rendered_colors();
+
+            (Foreground, Background, Cell.Attributes.Underline)
+        };
+
+        // - Null characters would truncate the label text.
+        let Character = match Cell.Character {
+            '\0' => ' ',
+            Character => Character,
+        };
+
+        match Runs.last_mut() {
+            Some(Run)
+                if Run.Foreground == Foreground
+                    && Run.Background == Background
+                    && Run.Underline == Underline =>
+            {
+                Run.Text.push(Character)
+            }
+            _ => Runs.push(Run_type {
+                Text: Character.to_string(),
+                Foreground,
+                Background,
+                Underline,
+            }),
+        }
+    }
+
+    Runs
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State_type {
+    Ground,
+    Escape,
+    /// Escape sequence with an intermediate byte (e.g. `ESC ( B`), the next byte is ignored.
+    Escape_intermediate,
+    Control_sequence,
+    Operating_system_command,
+    /// `ESC` received inside an operating system command (potential string terminator).
+    Operating_system_command_escape,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Cursor_type {
+    X: usize,
+    Y: usize,
+    Attributes: Attributes_type,
+}
+
+/// Saved state of the primary screen while the alternate screen is active.
+struct Saved_screen_type {
+    Rows: Vec<Row_type>,
+    Cursor: Cursor_type,
+}
+
+pub struct Screen_type {
+    Columns: usize,
+    Rows: Vec<Row_type>,
+    Dirty: Vec<bool>,
+    Cursor: Cursor_type,
+    Saved_cursor: Cursor_type,
+    /// Deferred wrap : the cursor is on the last column and the next printable character wraps.
+    Pending_wrap: bool,
+    Cursor_visible: bool,
+    Auto_wrap: bool,
+    /// Scrolling region (top and bottom rows, inclusive).
+    Scroll_top: usize,
+    Scroll_bottom: usize,
+    Scrollback: VecDeque<Row_type>,
+    Scrollback_capacity: usize,
+    /// Number of rows added to the scrollback since the last call to [Screen_type::Take_scrolled_rows].
+    Scrolled_rows: usize,
+    Alternate: Option<Saved_screen_type>,
+    State: State_type,
+    Parameters: Vec<u16>,
+    Private: bool,
+    /// Bytes of an incomplete UTF-8 character.
+    UTF_8_buffer: Vec<u8>,
+}
+
+impl Screen_type {
+    pub const Maximum_parameters: usize = 16;
+
+    pub fn New(Columns: usize, Rows: usize, Scrollback_capacity: usize) -> Self {
+        let Columns = Columns.max(1);
+        let Rows = Rows.max(1);
+
+        Self {
+            Columns,
+            Rows: vec![vec![Cell_type::default(); Columns]; Rows],
+            Dirty: vec![true; Rows],
+            Cursor: Cursor_type::default(),
+            Saved_cursor: Cursor_type::default(),
+            Pending_wrap: false,
+            Cursor_visible: true,
+            Auto_wrap: true,
+            Scroll_top: 0,
+            Scroll_bottom: Rows - 1,
+            Scrollback: VecDeque::with_capacity(Scrollback_capacity),
+            Scrollback_capacity,
+            Scrolled_rows: 0,
+            Alternate: None,
+            State: State_type::Ground,
+            Parameters: Vec::with_capacity(Self::Maximum_parameters),
+            Private: false,
+            UTF_8_buffer: Vec::with_capacity(4),
+        }
+    }
+
+    pub fn Get_columns(&self) -> usize {
+        self.Columns
+    }
+
+    pub fn Get_rows(&self) -> usize {
+        self.Rows.len()
+    }
+
+    pub fn Get_row(&self, Index: usize) -> &[Cell_type] {
+        &self.Rows[Index]
+    }
+
+    /// Get the text of a row without the trailing spaces.
+    pub fn Get_row_text(&self, Index: usize) -> String {
+        let Text: String = self.Rows[Index].iter().map(|Cell| Cell.Character).collect();
+
+        Text.trim_end().to_string()
+    }
+
+    pub fn Get_cursor_position(&self) -> (usize, usize) {
+        (self.Cursor.X, self.Cursor.Y)
+    }
+
+    pub fn Is_cursor_visible(&self) -> bool {
+        self.Cursor_visible
+    }
+
+    pub fn Is_alternate_screen(&self) -> bool {
+        self.Alternate.is_some()
+    }
+
+    pub fn Get_scrollback(&self) -> &VecDeque<Row_type> {
+        &self.Scrollback
+    }
+
+    /// Get the indexes of the rows modified since the last call and clear the dirty flags.
+    pub fn Take_dirty_rows(&mut self) -> Vec<usize> {
+        let Dirty_rows = self
+            .Dirty
+            .iter()
+            .enumerate()
+            .filter(|(_, Dirty)| **Dirty)
+            .map(|(Index, _)| Index)
+            .collect();
+
+        self.Dirty.iter_mut().for_each(|Dirty| *Dirty = false);
+
+        Dirty_rows
+    }
+
+    /// Get the number of rows pushed to the scrollback since the last call (capped to the scrollback size).
+    pub fn Take_scrolled_rows(&mut self) -> usize {
+        let Scrolled_rows = self.Scrolled_rows.min(self.Scrollback.len());
+
+        self.Scrolled_rows = 0;
+
+        Scrolled_rows
+    }
+
+    /// Process the data written to the terminal.
+    pub fn Feed(&mut self, Data: &[u8]) {
+        for Byte in Data {
+            if !self.UTF_8_buffer.is_empty() || *Byte >= 0x80 {
+                self.Feed_UTF_8(*Byte);
+            } else {
+                self.Feed_character(*Byte as char);
+            }
+        }
+    }
+
+    fn Feed_UTF_8(&mut self, Byte: u8) {
+        // - A new leading byte or an ASCII byte interrupts an invalid sequence.
+        if !self.UTF_8_buffer.is_empty() && (Byte & 0xC0) != 0x80 {
+            self.UTF_8_buffer.clear();
+            self.Feed_character(char::REPLACEMENT_CHARACTER);
+
+            if Byte < 0x80 {
+                self.Feed_character(Byte as char);
+                return;
+            }
+        }
+
+        self.UTF_8_buffer.push(Byte);
+
+        let Expected_length = match self.UTF_8_buffer[0] {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => {
+                self.UTF_8_buffer.clear();
+                self.Feed_character(char::REPLACEMENT_CHARACTER);
+                return;
+            }
+        };
+
+        if self.UTF_8_buffer.len() < Expected_length {
+            return;
+        }
+
+        let Character = core::str::from_utf8(&self.UTF_8_buffer)
+            .ok()
+            .and_then(|String| String.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+        self.UTF_8_buffer.clear();
+        self.Feed_character(Character);
+    }
+
+    fn Feed_character(&mut self, Character: char) {
+        match self.State {
+            State_type::Ground => self.Ground(Character),
+            State_type::Escape => self.Escape(Character),
+            State_type::Escape_intermediate => self.State = State_type::Ground,
+            State_type::Control_sequence => self.Control_sequence(Character),
+            State_type::Operating_system_command => match Character {
+                '\x07' => self.State = State_type::Ground,
+                '\x1B' => self.State = State_type::Operating_system_command_escape,
+                _ => {}
+            },
+            State_type::Operating_system_command_escape => {
+                self.State = if Character == '\\' {
+                    State_type::Ground
+                } else {
+                    State_type::Operating_system_command
+                };
+            }
+        }
+    }
+
+    fn Ground(&mut self, Character: char) {
+        match Character {
+            '\x1B' => self.State = State_type::Escape,
+            '\n' | '\x0B' | '\x0C' => self.Line_feed(),
+            '\r' => self.Set_cursor_x(0),
+            '\x08' => self.Set_cursor_x(self.Cursor.X.saturating_sub(1)),
+            '\t' => self.Set_cursor_x(((self.Cursor.X / 8) + 1) * 8),
+            '\x07' | '\0' => {}
+            Character if Character.is_control() => {}
+            Character => self.Put(Character),
+        }
+    }
+
+    fn Escape(&mut self, Character: char) {
+        self.State = State_type::Ground;
+
+        match Character {
+            '[' => {
+                self.Parameters.clear();
+                self.Private = false;
+                self.State = State_type::Control_sequence;
+            }
+            ']' => self.State = State_type::Operating_system_command,
+            '(' | ')' | '*' | '+' | '#' => self.State = State_type::Escape_intermediate,
+            '7' => self.Save_cursor(),
+            '8' => self.Restore_cursor(),
+            'D' => self.Line_feed(),
+            'E' => {
+                self.Line_feed();
+                self.Set_cursor_x(0);
+            }
+            'M' => self.Reverse_index(),
+            'c' => self.Reset(),
+            _ => {}
+        }
+    }
+
+    fn Control_sequence(&mut self, Character: char) {
+        match Character {
+            '0'..='9' => {
+                if self.Parameters.is_empty() {
+                    self.Parameters.push(0);
+                }
+
+                if let Some(Last) = self.Parameters.last_mut() {
+                    *Last = Last
+                        .saturating_mul(10)
+                        .saturating_add(Character as u16 - '0' as u16);
+                }
+            }
+            ';' | ':' => {
+                if self.Parameters.is_empty() {
+                    self.Parameters.push(0);
+                }
+
+                if self.Parameters.len() < Self::Maximum_parameters {
+                    self.Parameters.push(0);
+                }
+            }
+            '?' | '>' | '=' => self.Private = true,
+            // - Intermediate bytes are ignored.
+            ' '..='/' => {}
+            '@'..='~' => {
+                self.State = State_type::Ground;
+                self.Execute_control_sequence(Character);
+            }
+            // - Invalid character : abort the sequence.
+            _ => self.State = State_type::Ground,
+        }
+    }
+
+    /// Get a parameter, with a default value when missing or zero.
+    fn Get_parameter(&self, Index: usize, Default: u16) -> u16 {
+        match self.Parameters.get(Index) {
+            Some(0) | None => Default,
+            Some(Value) => *Value,
+        }
+    }
+
+    fn Execute_control_sequence(&mut self, Final: char) {
+        if self.Private {
+            match Final {
+                'h' => self.Set_private_modes(true),
+                'l' => self.Set_private_modes(false),
+                _ => {}
+            }
+            return;
+        }
+
+        let First = self.Get_parameter(0, 1) as usize;
+
+        match Final {
+            'A' => self.Set_cursor_y_in_region(self.Cursor.Y.saturating_sub(First), true),
+            'B' | 'e' => self.Set_cursor_y_in_region(self.Cursor.Y + First, false),
+            'C' | 'a' => self.Set_cursor_x(self.Cursor.X + First),
+            'D' => self.Set_cursor_x(self.Cursor.X.saturating_sub(First)),
+            'E' => {
+                self.Set_cursor_y_in_region(self.Cursor.Y + First, false);
+                self.Set_cursor_x(0);
+            }
+            'F' => {
+                self.Set_cursor_y_in_region(self.Cursor.Y.saturating_sub(First), true);
+                self.Set_cursor_x(0);
+            }
+            'G' | '`' => self.Set_cursor_x(First - 1),
+            'd' => self.Set_cursor_position(self.Cursor.X, First - 1),
+            'H' | 'f' => {
+                let Column = self.Get_parameter(1, 1) as usize;
+
+                self.Set_cursor_position(Column - 1, First - 1);
+            }
+            'J' => self.Erase_in_display(self.Parameters.first().copied().unwrap_or(0)),
+            'K' => self.Erase_in_line(self.Parameters.first().copied().unwrap_or(0)),
+            'L' => self.Insert_lines(First),
+            'M' => self.Delete_lines(First),
+            '@' => self.Insert_characters(First),
+            'P' => self.Delete_characters(First),
+            'X' => self.Erase_characters(First),
+            'S' => self.Scroll_up(First),
+            'T' => self.Scroll_down(First),
+            'm' => self.Select_graphic_rendition(),
+            'r' => {
+                let Top = self.Get_parameter(0, 1) as usize - 1;
+                let Bottom = (self.Get_parameter(1, self.Get_rows() as u16) as usize)
+                    .min(self.Get_rows())
+                    - 1;
+
+                if Top < Bottom {
+                    self.Scroll_top = Top;
+                    self.Scroll_bottom = Bottom;
+                    self.Set_cursor_position(0, 0);
+                }
+            }
+            's' => self.Save_cursor(),
+            'u' => self.Restore_cursor(),
+            _ => {}
+        }
+    }
+
+    fn Set_private_modes(&mut self, Enable: bool) {
+        for Index in 0..self.Parameters.len() {
+            match self.Parameters[Index] {
+                7 => self.Auto_wrap = Enable,
+                25 => {
+                    self.Cursor_visible = Enable;
+                    self.Dirty[self.Cursor.Y] = true;
+                }
+                47 | 1047 => self.Set_alternate_screen(Enable),
+                1048 => {
+                    if Enable {
+                        self.Save_cursor();
+                    } else {
+                        self.Restore_cursor();
+                    }
+                }
+                1049 => {
+                    if Enable {
+                        self.Save_cursor();
+                        self.Set_alternate_screen(true);
+                        self.Erase_in_display(2);
+                    } else {
+                        self.Set_alternate_screen(false);
+                        self.Restore_cursor();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn Select_graphic_rendition(&mut self) {
+        if self.Parameters.is_empty() {
+            self.Cursor.Attributes = Attributes_type::default();
+            return;
+        }
+
+        let mut Index = 0;
+
+        while Index < self.Parameters.len() {
+            let Attributes = &mut self.Cursor.Attributes;
+
+            match self.Parameters[Index] {
+                0 => *Attributes = Attributes_type::default(),
+                1 => Attributes.Bold = true,
+                4 => Attributes.Underline = true,
+                7 => Attributes.Inverse = true,
+                21 | 22 => Attributes.Bold = false,
+                24 => Attributes.Underline = false,
+                27 => Attributes.Inverse = false,
+                Value @ 30..=37 => {
+                    Attributes.Foreground = Terminal_color_type::Indexed(Value as u8 - 30)
+                }
+                39 => Attributes.Foreground = Terminal_color_type::Default,
+                Value @ 40..=47 => {
+                    Attributes.Background = Terminal_color_type::Indexed(Value as u8 - 40)
+                }
+                49 => Attributes.Background = Terminal_color_type::Default,
+                Value @ 90..=97 => {
+                    Attributes.Foreground = Terminal_color_type::Indexed(Value as u8 - 90 + 8)
+                }
+                Value @ 100..=107 => {
+                    Attributes.Background = Terminal_color_type::Indexed(Value as u8 - 100 + 8)
+                }
+                Value @ (38 | 48) => {
+                    let (Color, Consumed) = self.Get_extended_color(Index + 1);
+
+                    if let Some(Color) = Color {
+                        let Attributes = &mut self.Cursor.Attributes;
+
+                        if Value == 38 {
+                            Attributes.Foreground = Color;
+                        } else {
+                            Attributes.Background = Color;
+                        }
+                    }
+
+                    Index += Consumed;
+                }
+                _ => {}
+            }
+
+            Index += 1;
+        }
+    }
+
+    /// Parse an extended color (`5;n` or `2;r;g;b`) and return it with the number of parameters consumed.
+    fn Get_extended_color(&self, Index: usize) -> (Option<Terminal_color_type>, usize) {
+        let Parameter = |Offset: usize| {
+            self.Parameters
+                .get(Index + Offset)
+                .map(|Value| (*Value).min(255) as u8)
+        };
+
+        match self.Parameters.get(Index) {
+            Some(5) => (Parameter(1).map(Terminal_color_type::Indexed), 2),
+            Some(2) => match (Parameter(1), Parameter(2), Parameter(3)) {
+                (Some(Red), Some(Green), Some(Blue)) => {
+                    (Some(Terminal_color_type::RGB(Red, Green, Blue)), 4)
+                }
+                _ => (None, self.Parameters.len() - Index),
+            },
+            _ => (None, 0),
+        }
+    }
+
+    fn Get_blank(&self) -> Cell_type {
+        // - Erased cells keep the current background (like xterm).
+        Cell_type {
+            Character: ' ',
+            Attributes: Attributes_type {
+                Background: self.Cursor.Attributes.Background,
+                ..Attributes_type::default()
+            },
+        }
+    }
+
+    fn Put(&mut self, Character: char) {
+        if self.Pending_wrap {
+            self.Pending_wrap = false;
+            self.Line_feed();
+            self.Set_cursor_x(0);
+        }
+
+        let Cell = Cell_type {
+            Character,
+            Attributes: self.Cursor.Attributes,
+        };
+
+        self.Rows[self.Cursor.Y][self.Cursor.X] = Cell;
+        self.Dirty[self.Cursor.Y] = true;
+
+        if self.Cursor.X + 1 < self.Columns {
+            self.Cursor.X += 1;
+        } else if self.Auto_wrap {
+            self.Pending_wrap = true;
+        }
+    }
+
+    fn Set_cursor_x(&mut self, X: usize) {
+        self.Pending_wrap = false;
+        self.Cursor.X = X.min(self.Columns - 1);
+        self.Dirty[self.Cursor.Y] = true;
+    }
+
+    fn Set_cursor_position(&mut self, X: usize, Y: usize) {
+        self.Dirty[self.Cursor.Y] = true;
+        self.Cursor.Y = Y.min(self.Get_rows() - 1);
+        self.Set_cursor_x(X);
+    }
+
+    /// Move the cursor vertically without leaving the scrolling region if the cursor is inside it.
+    fn Set_cursor_y_in_region(&mut self, Y: usize, Up: bool) {
+        let Y = if Up && self.Cursor.Y >= self.Scroll_top {
+            Y.max(self.Scroll_top)
+        } else if !Up && self.Cursor.Y <= self.Scroll_bottom {
+            Y.min(self.Scroll_bottom)
+        } else {
+            Y
+        };
+
+        self.Set_cursor_position(self.Cursor.X, Y);
+    }
+
+    fn Line_feed(&mut self) {
+        self.Pending_wrap = false;
+
+        if self.Cursor.Y == self.Scroll_bottom {
+            self.Scroll_up(1);
+        } else if self.Cursor.Y + 1 < self.Get_rows() {
+            self.Dirty[self.Cursor.Y] = true;
+            self.Cursor.Y += 1;
+            self.Dirty[self.Cursor.Y] = true;
+        }
+    }
+
+    fn Reverse_index(&mut self) {
+        self.Pending_wrap = false;
+
+        if self.Cursor.Y == self.Scroll_top {
+            self.Scroll_down(1);
+        } else {
+            self.Set_cursor_position(self.Cursor.X, self.Cursor.Y.saturating_sub(1));
+        }
+    }
+
+    fn Mark_dirty(&mut self, Top: usize, Bottom: usize) {
+        self.Dirty[Top..=Bottom]
+            .iter_mut()
+            .for_each(|Dirty| *Dirty = true);
+    }
+
+    /// Scroll the scrolling region up, the rows leaving the full screen are moved to the scrollback.
+    fn Scroll_up(&mut self, Count: usize) {
+        let Count = Count.min(self.Scroll_bottom - self.Scroll_top + 1);
+        let Blank = vec![self.Get_blank(); self.Columns];
+
+        for _ in 0..Count {
+            let Row = self.Rows.remove(self.Scroll_top);
+            self.Rows.insert(self.Scroll_bottom, Blank.clone());
+
+            if self.Scroll_top == 0 && self.Alternate.is_none() && self.Scrollback_capacity > 0 {
+                if self.Scrollback.len() == self.Scrollback_capacity {
+                    self.Scrollback.pop_front();
+                }
+
+                self.Scrollback.push_back(Row);
+                self.Scrolled_rows += 1;
+            }
+        }
+
+        self.Mark_dirty(self.Scroll_top, self.Scroll_bottom);
+    }
+
+    fn Scroll_down(&mut self, Count: usize) {
+        let Count = Count.min(self.Scroll_bottom - self.Scroll_top + 1);
+        let Blank = vec![self.Get_blank(); self.Columns];
+
+        for _ in 0..Count {
+            self.Rows.remove(self.Scroll_bottom);
+            self.Rows.insert(self.Scroll_top, Blank.clone());
+        }
+
+        self.Mark_dirty(self.Scroll_top, self.Scroll_bottom);
+    }
+
+    fn Insert_lines(&mut self, Count: usize) {
+        if self.Cursor.Y < self.Scroll_top || self.Cursor.Y > self.Scroll_bottom {
+            return;
+        }
+
+        let Top = self.Scroll_top;
+        self.Scroll_top = self.Cursor.Y;
+        self.Scroll_down(Count);
+        self.Scroll_top = Top;
+        self.Set_cursor_x(0);
+    }
+
+    fn Delete_lines(&mut self, Count: usize) {
+        if self.Cursor.Y < self.Scroll_top || self.Cursor.Y > self.Scroll_bottom {
+            return;
+        }
+
+        let Top = self.Scroll_top;
+        self.Scroll_top = self.Cursor.Y;
+        // - Deleted lines never go to the scrollback.
+        let Capacity = core::mem::replace(&mut self.Scrollback_capacity, 0);
+        self.Scroll_up(Count);
+        self.Scrollback_capacity = Capacity;
+        self.Scroll_top = Top;
+        self.Set_cursor_x(0);
+    }
+
+    fn Insert_characters(&mut self, Count: usize) {
+        let Blank = self.Get_blank();
+        let Row = &mut self.Rows[self.Cursor.Y];
+        let Count = Count.min(self.Columns - self.Cursor.X);
+
+        Row.truncate(self.Columns - Count);
+        Row.splice(
+            self.Cursor.X..self.Cursor.X,
+            core::iter::repeat_n(Blank, Count),
+        );
+
+        self.Dirty[self.Cursor.Y] = true;
+    }
+
+    fn Delete_characters(&mut self, Count: usize) {
+        let Blank = self.Get_blank();
+        let Row = &mut self.Rows[self.Cursor.Y];
+        let Count = Count.min(self.Columns - self.Cursor.X);
+
+        Row.drain(self.Cursor.X..self.Cursor.X + Count);
+        Row.resize(self.Columns, Blank);
+
+        self.Dirty[self.Cursor.Y] = true;
+    }
+
+    fn Erase_characters(&mut self, Count: usize) {
+        let End = (self.Cursor.X + Count).min(self.Columns);
+
+        self.Erase(self.Cursor.Y, self.Cursor.X, End);
+    }
+
+    /// Erase the cells of a row in the range `[Start, End[`.
+    fn Erase(&mut self, Row: usize, Start: usize, End: usize) {
+        let Blank = self.Get_blank();
+
+        self.Rows[Row][Start..End]
+            .iter_mut()
+            .for_each(|Cell| *Cell = Blank);
+
+        self.Dirty[Row] = true;
+    }
+
+    fn Erase_in_line(&mut self, Mode: u16) {
+        let (Start, End) = match Mode {
+            0 => (self.Cursor.X, self.Columns),
+            1 => (0, self.Cursor.X + 1),
+            2 => (0, self.Columns),
+            _ => return,
+        };
+
+        self.Erase(self.Cursor.Y, Start, End);
+    }
+
+    fn Erase_in_display(&mut self, Mode: u16) {
+        let Rows = self.Get_rows();
+
+        match Mode {
+            0 => {
+                self.Erase_in_line(0);
+                (self.Cursor.Y + 1..Rows).for_each(|Row| self.Erase(Row, 0, self.Columns));
+            }
+            1 => {
+                (0..self.Cursor.Y).for_each(|Row| self.Erase(Row, 0, self.Columns));
+                self.Erase_in_line(1);
+            }
+            2 => (0..Rows).for_each(|Row| self.Erase(Row, 0, self.Columns)),
+            3 => {
+                self.Scrollback.clear();
+                self.Scrolled_rows = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn Save_cursor(&mut self) {
+        self.Saved_cursor = self.Cursor;
+    }
+
+    fn Restore_cursor(&mut self) {
+        let Saved = self.Saved_cursor;
+
+        self.Set_cursor_position(Saved.X, Saved.Y);
+        self.Cursor.Attributes = Saved.Attributes;
+    }
+
+    fn Set_alternate_screen(&mut self, Enable: bool) {
+        match (Enable, self.Alternate.take()) {
+            (true, None) => {
+                let Rows = vec![vec![Cell_type::default(); self.Columns]; self.Get_rows()];
+
+                self.Alternate = Some(Saved_screen_type {
+                    Rows: core::mem::replace(&mut self.Rows, Rows),
+                    Cursor: self.Cursor,
+                });
+            }
+            (false, Some(Saved)) => {
+                self.Rows = Saved.Rows;
+                self.Cursor = Saved.Cursor;
+            }
+            (_, Alternate) => {
+                self.Alternate = Alternate;
+                return;
+            }
+        }
+
+        self.Pending_wrap = false;
+        self.Scroll_top = 0;
+        self.Scroll_bottom = self.Get_rows() - 1;
+        self.Mark_dirty(0, self.Get_rows() - 1);
+    }
+
+    /// Full reset (`ESC c`), the scrollback is kept.
+    fn Reset(&mut self) {
+        self.Set_alternate_screen(false);
+        self.Cursor = Cursor_type::default();
+        self.Saved_cursor = Cursor_type::default();
+        self.Cursor_visible = true;
+        self.Auto_wrap = true;
+        self.Scroll_top = 0;
+        self.Scroll_bottom = self.Get_rows() - 1;
+        self.Erase_in_display(2);
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    fn New_screen() -> Screen_type {
+        Screen_type::New(10, 4, 8)
+    }
+
+    #[test]
+    fn Test_print() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"Hello\r\nWorld");
+
+        assert_eq!(Screen.Get_row_text(0), "Hello");
+        assert_eq!(Screen.Get_row_text(1), "World");
+        assert_eq!(Screen.Get_cursor_position(), (5, 1));
+    }
+
+    #[test]
+    fn Test_wrap_and_scroll() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"0123456789ABC\r\n2\r\n3\r\n4");
+
+        assert_eq!(Screen.Get_row_text(0), "ABC");
+        assert_eq!(Screen.Get_row_text(3), "4");
+        assert_eq!(Screen.Get_scrollback().len(), 1);
+        assert_eq!(Screen.Take_scrolled_rows(), 1);
+        assert_eq!(Screen.Take_scrolled_rows(), 0);
+    }
+
+    #[test]
+    fn Test_cursor_addressing() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"\x1B[3;4HX\x1B[2AY\x1B[10CZ\x1B[HW");
+
+        assert_eq!(Screen.Get_row_text(0), "W   Y    Z");
+        assert_eq!(Screen.Get_row_text(2), "   X");
+        assert_eq!(Screen.Get_cursor_position(), (1, 0));
+    }
+
+    #[test]
+    fn Test_erase() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"ABCDEF\r\nGHIJ\x1B[1;3H\x1B[K");
+        assert_eq!(Screen.Get_row_text(0), "AB");
+        assert_eq!(Screen.Get_row_text(1), "GHIJ");
+
+        Screen.Feed(b"\x1B[2J");
+        assert_eq!(Screen.Get_row_text(1), "");
+
+        Screen.Feed(b"abc\x08\x08\x1B[P");
+        assert_eq!(Screen.Get_row_text(0), "  ac");
+    }
+
+    #[test]
+    fn Test_colors() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"\x1B[1;31mA\x1B[38;5;196mB\x1B[38;2;1;2;3;44mC\x1B[0mD");
+
+        let Row = Screen.Get_row(0);
+
+        assert_eq!(
+            Row[0].Attributes.Get_rendered_colors(),
+            ((0xFF, 0x00, 0x00), None)
+        );
+        assert!(Row[0].Attributes.Bold);
+        assert_eq!(
+            Row[1].Attributes.Foreground,
+            Terminal_color_type::Indexed(196)
+        );
+        assert_eq!(
+            Row[2].Attributes.Foreground,
+            Terminal_color_type::RGB(1, 2, 3)
+        );
+        assert_eq!(
+            Row[2].Attributes.Background,
+            Terminal_color_type::Indexed(4)
+        );
+        assert_eq!(Row[3].Attributes, Attributes_type::default());
+
+        assert_eq!(
+            Terminal_color_type::Indexed(196).Get_RGB(),
+            Some((0xFF, 0x00, 0x00))
+        );
+        assert_eq!(Terminal_color_type::Indexed(232).Get_RGB(), Some((8, 8, 8)));
+    }
+
+    #[test]
+    fn Test_alternate_screen() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"Primary");
+        Screen.Feed(b"\x1B[?1049h");
+
+        assert!(Screen.Is_alternate_screen());
+        assert_eq!(Screen.Get_row_text(0), "");
+
+        Screen.Feed(b"\x1B[HAlternate\r\n\r\n\r\n\r\n\r\n");
+        assert_eq!(Screen.Get_scrollback().len(), 0);
+
+        Screen.Feed(b"\x1B[?1049l");
+
+        assert!(!Screen.Is_alternate_screen());
+        assert_eq!(Screen.Get_row_text(0), "Primary");
+        assert_eq!(Screen.Get_cursor_position(), (7, 0));
+    }
+
+    #[test]
+    fn Test_scrolling_region() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"1\r\n2\r\n3\r\n4\x1B[2;3r\x1B[3;1H\n");
+
+        assert_eq!(Screen.Get_row_text(0), "1");
+        assert_eq!(Screen.Get_row_text(1), "3");
+        assert_eq!(Screen.Get_row_text(2), "");
+        assert_eq!(Screen.Get_row_text(3), "4");
+        assert_eq!(Screen.Get_scrollback().len(), 0);
+    }
+
+    #[test]
+    fn Test_dirty_rows_and_split_sequences() {
+        let mut Screen = New_screen();
+
+        Screen.Take_dirty_rows();
+
+        // - Sequences and UTF-8 characters can be split across writes.
+        Screen.Feed(b"\x1B[2");
+        Screen.Feed(b";1H\xC3");
+        Screen.Feed(b"\xA9\x1B]0;Title\x07!");
+
+        assert_eq!(Screen.Get_row_text(1), "é!");
+        assert_eq!(Screen.Take_dirty_rows(), vec![0, 1]);
+        assert!(Screen.Take_dirty_rows().is_empty());
+    }
+
+    #[test]
+    fn Test_row_runs() {
+        let mut Screen = New_screen();
+
+        Screen.Feed(b"a\x1B[44;4mbc\x1B[24;48;2;1;2;3md\x1B[0;7me\x1B[0m f");
+
+        let Run = |Text: &str, Foreground, Background, Underline| Run_type {
+            Text: Text.to_string(),
+            Foreground,
+            Background,
+            Underline,
+        };
+
+        let White = Attributes_type::Default_foreground;
+        let Black = Attributes_type::Default_background;
+
+        assert_eq!(
+            Get_row_runs(Screen.Get_row(0), None),
+            [
+                Run("a", White, None, false),
+                Run("bc", White, Some((0x00, 0x00, 0xEE)), true),
+                Run("d", White, Some((1, 2, 3)), false),
+                // - Inverse of the default colors.
+                Run("e", Black, Some(White), false),
+                Run(" f", White, None, false),
+            ]
+        );
+
+        // - The cursor is drawn as a block, even past the end of the text.
+        assert_eq!(
+            Get_row_runs(Screen.Get_row(1), Some(2)),
+            [
+                Run("  ", White, None, false),
+                Run(" ", Black, Some(Cursor_color), false),
+            ]
+        );
+
+        // - A colored background stays visible on blank cells.
+        Screen.Feed(b"\r\n\x1B[41m  \x1B[0m");
+        assert_eq!(
+            Get_row_runs(Screen.Get_row(1), None),
+            [Run("  ", White, Some((0xCD, 0x00, 0x00)), false)]
+        );
+        assert!(Get_row_runs(Screen.Get_row(2), None).is_empty());
+    }
+}
@@ -1,14 +1,30 @@
-use std::{ffi::CStr, sync::RwLock};
-use Graphics::{Color_type, Event_code_type, Key_type, Window_type, LVGL};
-
-use crate::Error::Result_type;
+use std::{
+    collections::VecDeque,
+    ffi::{CStr, CString},
+    sync::RwLock,
+};
+use Graphics::{Color_RGB888_type, Color_type, Event_code_type, Key_type, Window_type, LVGL};
+
+use crate::{
+    Error::Result_type,
+    Screen::{Get_row_runs, Run_type, Screen_type},
+};
 
 pub(crate) struct Inner_type {
     Window: Window_type,
-    Buffer: String,
-    Display: *mut LVGL::lv_obj_t,
+    Screen: Screen_type,
+    Container: *mut LVGL::lv_obj_t,
+    /// Rows of the grid, holding one label per run of cells.
+    Rows: Vec<*mut LVGL::lv_obj_t>,
+    /// Rows that scrolled out of the grid.
+    Scrollback: VecDeque<*mut LVGL::lv_obj_t>,
+    /// Whether the alternate screen was displayed during the last rendering.
+    Alternate: bool,
     Input: *mut LVGL::lv_obj_t,
     Validated: bool,
+    /// Raw mode : the keys are forwarded to the readers instead of being edited in the input field.
+    Raw: bool,
+    Raw_input: VecDeque<u8>,
 }
 
 pub struct Terminal_type(pub(crate) RwLock<Inner_type>);
@@ -18,8 +34,9 @@ unsafe impl Send for Terminal_type {}
 unsafe impl Sync for Terminal_type {}
 
 impl Terminal_type {
-    const Clear: &'static str = "\x1B[2J";
-    const Home: &'static str = "\x1B[H";
+    const Columns: usize = 80;
+    const Rows: usize = 24;
+    const Scrollback_capacity: usize = 200;
 
     pub fn New() -> Result_type<Self> {
         let _Lock = Graphics::Get_instance().Lock()?;
@@ -40,31 +57,34 @@ impl Terminal_type {
 
             LVGL::lv_obj_set_width(Container, LVGL::lv_pct(100));
             LVGL::lv_obj_set_flex_grow(Container, 1);
-            LVGL::lv_obj_set_scroll_snap_y(Container, LVGL::lv_scroll_snap_t_LV_SCROLL_SNAP_END);
-
-            Container
-        };
-
-        let Buffer = String::with_capacity(80 * 24);
-
-        let Display = unsafe {
-            let Label = LVGL::lv_label_create(Container);
-
-            if Label.is_null() {
-                return Err(crate::Error::Error_type::Failed_to_create_object);
-            }
-
-            LVGL::lv_obj_set_width(Label, LVGL::lv_pct(100));
-            LVGL::lv_label_set_text_static(Label, Buffer.as_ptr() as *const i8);
+            LVGL::lv_obj_set_flex_flow(Container, LVGL::lv_flex_flow_t_LV_FLEX_FLOW_COLUMN);
+            LVGL::lv_obj_set_style_pad_row(Container, 0, LVGL::LV_STATE_DEFAULT);
+            LVGL::lv_obj_set_style_bg_color(
+                Container,
+                LVGL::lv_color_black(),
+                LVGL::LV_STATE_DEFAULT,
+            );
+            LVGL::lv_obj_set_style_text_color(
+                Container,
+                LVGL::lv_color_white(),
+                LVGL::LV_STATE_DEFAULT,
+            );
             LVGL::lv_obj_set_style_text_font(
-                Label,
+                Container,
                 &raw const LVGL::lv_font_unscii_8,
                 LVGL::LV_STATE_DEFAULT,
             );
+            LVGL::lv_obj_set_scroll_snap_y(Container, LVGL::lv_scroll_snap_t_LV_SCROLL_SNAP_END);
 
-            Label
+            Container
         };
 
+        let mut Rows = Vec::with_capacity(Self::Rows);
+
+        for _ in 0..Self::Rows {
+            Rows.push(Self::Create_row(Container)?);
+        }
+
         let Input = unsafe {
             let Input = LVGL::lv_textarea_create(Window.Get_object());
 
@@ -81,87 +101,272 @@ impl Terminal_type {
 
         let Inner = Inner_type {
             Window,
-            Buffer,
-            Display,
+            Screen: Screen_type::New(Self::Columns, Self::Rows, Self::Scrollback_capacity),
+            Container,
+            Rows,
+            Scrollback: VecDeque::with_capacity(Self::Scrollback_capacity),
+            Alternate: false,
             Input,
             Validated: false,
+            Raw: false,
+            Raw_input: VecDeque::with_capacity(32),
         };
 
         Ok(Self(RwLock::new(Inner)))
     }
 
-    pub fn Print(&self, Text: &str) -> Result_type<()> {
-        let mut Inner = self.0.write()?;
+    /// Create an object used to display a row (of the grid or of the scrollback).
+    fn Create_row(Container: *mut LVGL::lv_obj_t) -> Result_type<*mut LVGL::lv_obj_t> {
+        unsafe {
+            let Row = LVGL::lv_obj_create(Container);
+
+            if Row.is_null() {
+                return Err(crate::Error::Error_type::Failed_to_create_object);
+            }
+
+            LVGL::lv_obj_set_width(Row, LVGL::lv_pct(100));
+            LVGL::lv_obj_set_height(Row, LVGL::LV_SIZE_CONTENT);
+            // - Keep the height of a line when the row is empty.
+            LVGL::lv_obj_set_style_min_height(
+                Row,
+                LVGL::lv_font_get_line_height(&raw const LVGL::lv_font_unscii_8),
+                LVGL::LV_STATE_DEFAULT,
+            );
+            LVGL::lv_obj_set_flex_flow(Row, LVGL::lv_flex_flow_t_LV_FLEX_FLOW_ROW);
+            LVGL::lv_obj_set_style_pad_all(Row, 0, LVGL::LV_STATE_DEFAULT);
+            LVGL::lv_obj_set_style_pad_column(Row, 0, LVGL::LV_STATE_DEFAULT);
+            LVGL::lv_obj_set_style_border_width(Row, 0, LVGL::LV_STATE_DEFAULT);
+            LVGL::lv_obj_set_style_radius(Row, 0, LVGL::LV_STATE_DEFAULT);
+            LVGL::lv_obj_set_style_bg_opa(Row, LVGL::LV_OPA_0 as u8, LVGL::LV_STATE_DEFAULT);
+            LVGL::lv_obj_remove_flag(Row, LVGL::lv_obj_flag_t_LV_OBJ_FLAG_SCROLLABLE);
+
+            Ok(Row)
+        }
+    }
+
+    /// Display the runs of a row, one label per run, reusing the labels of the previous rendering.
+    fn Set_row(Row: *mut LVGL::lv_obj_t, Runs: &[Run_type]) -> Result_type<()> {
+        unsafe {
+            let Count = LVGL::lv_obj_get_child_count(Row) as usize;
+
+            for (Index, Run) in Runs.iter().enumerate() {
+                let Label = if Index < Count {
+                    LVGL::lv_obj_get_child(Row, Index as i32)
+                } else {
+                    let Label = LVGL::lv_label_create(Row);
+
+                    if Label.is_null() {
+                        return Err(crate::Error::Error_type::Failed_to_create_object);
+                    }
 
-        Self::Print_internal(&mut Inner, Text)?;
+                    LVGL::lv_label_set_long_mode(
+                        Label,
+                        LVGL::lv_label_long_mode_t_LV_LABEL_LONG_CLIP,
+                    );
+
+                    Label
+                };
+
+                let Text = CString::new(Run.Text.as_str()).unwrap_or_default();
+
+                LVGL::lv_label_set_text(Label, Text.as_ptr());
+
+                let (Red, Green, Blue) = Run.Foreground;
+
+                LVGL::lv_obj_set_style_text_color(
+                    Label,
+                    Color_RGB888_type::New(Red, Green, Blue).into(),
+                    LVGL::LV_STATE_DEFAULT,
+                );
+
+                match Run.Background {
+                    Some((Red, Green, Blue)) => {
+                        LVGL::lv_obj_set_style_bg_color(
+                            Label,
+                            Color_RGB888_type::New(Red, Green, Blue).into(),
+                            LVGL::LV_STATE_DEFAULT,
+                        );
+                        LVGL::lv_obj_set_style_bg_opa(
+                            Label,
+                            LVGL::LV_OPA_COVER as u8,
+                            LVGL::LV_STATE_DEFAULT,
+                        );
+                    }
+                    None => LVGL::lv_obj_set_style_bg_opa(
+                        Label,
+                        LVGL::LV_OPA_0 as u8,
+                        LVGL::LV_STATE_DEFAULT,
+                    ),
+                }
+
+                LVGL::lv_obj_set_style_text_decor(
+                    Label,
+                    if Run.Underline {
+                        LVGL::lv_text_decor_t_LV_TEXT_DECOR_UNDERLINE
+                    } else {
+                        LVGL::lv_text_decor_t_LV_TEXT_DECOR_NONE
+                    } as LVGL::lv_text_decor_t,
+                    LVGL::LV_STATE_DEFAULT,
+                );
+            }
+
+            // - Remove the labels of the runs which no longer exist.
+            for Index in (Runs.len()..Count).rev() {
+                LVGL::lv_obj_delete(LVGL::lv_obj_get_child(Row, Index as i32));
+            }
+        }
 
         Ok(())
     }
 
-    fn Print_internal(Inner: &mut Inner_type, Text: &str) -> Result_type<()> {
-        if !Inner.Buffer.is_empty() {
-            let Last_index = Inner.Buffer.len() - 1;
+    /// Redraw the rows modified since the last rendering.
+    fn Render(Inner: &mut Inner_type) -> Result_type<()> {
+        let Scrolled_rows = Inner.Screen.Take_scrolled_rows();
+        let Dirty_rows = Inner.Screen.Take_dirty_rows();
 
-            Inner.Buffer.remove(Last_index);
+        if Scrolled_rows == 0
+            && Dirty_rows.is_empty()
+            && Inner.Alternate == Inner.Screen.Is_alternate_screen()
+        {
+            return Ok(());
         }
 
-        let Start_index = if let Some(Last_clear) = Text.rfind(Self::Clear) {
-            Inner.Buffer.clear();
-            Last_clear + Self::Clear.len()
-        } else {
-            0
-        };
+        let _Lock = Graphics::Get_instance().Lock()?;
 
-        let Start_index = if let Some(Last_home) = Text.rfind(Self::Home) {
-            Inner.Buffer.clear();
-            Last_home + Self::Home.len()
-        } else {
-            Start_index
-        };
+        // - Move the rows that left the screen to the scrollback.
+        let Scrollback = Inner.Screen.Get_scrollback();
 
-        Inner.Buffer += &Text[Start_index..];
-        Inner.Buffer += "\0";
+        for Row in Scrollback.range(Scrollback.len() - Scrolled_rows..) {
+            let Label = if Inner.Scrollback.len() == Self::Scrollback_capacity {
+                Inner.Scrollback.pop_front().unwrap()
+            } else {
+                Self::Create_row(Inner.Container)?
+            };
 
-        let _Lock = Graphics::Get_instance().Lock().unwrap();
+            Self::Set_row(Label, &Get_row_runs(Row, None))?;
+
+            unsafe {
+                // - The scrollback is placed just before the grid.
+                LVGL::lv_obj_move_to_index(Label, Inner.Scrollback.len() as i32);
+            }
+
+            Inner.Scrollback.push_back(Label);
+        }
+
+        // - The scrollback is hidden while the alternate screen is displayed.
+        if Inner.Alternate != Inner.Screen.Is_alternate_screen() {
+            Inner.Alternate = Inner.Screen.Is_alternate_screen();
+
+            for Label in Inner.Scrollback.iter() {
+                unsafe {
+                    if Inner.Alternate {
+                        LVGL::lv_obj_add_flag(*Label, LVGL::lv_obj_flag_t_LV_OBJ_FLAG_HIDDEN);
+                    } else {
+                        LVGL::lv_obj_remove_flag(*Label, LVGL::lv_obj_flag_t_LV_OBJ_FLAG_HIDDEN);
+                    }
+                }
+            }
+        }
+
+        let (Cursor_x, Cursor_y) = Inner.Screen.Get_cursor_position();
+
+        for Index in Dirty_rows {
+            let Cursor = if Inner.Screen.Is_cursor_visible() && Index == Cursor_y {
+                Some(Cursor_x)
+            } else {
+                None
+            };
+
+            Self::Set_row(
+                Inner.Rows[Index],
+                &Get_row_runs(Inner.Screen.Get_row(Index), Cursor),
+            )?;
+        }
 
         unsafe {
-            LVGL::lv_label_set_text(Inner.Display, Inner.Buffer.as_ptr() as *const i8);
-            LVGL::lv_obj_scroll_to_view(Inner.Display, LVGL::lv_anim_enable_t_LV_ANIM_OFF);
+            LVGL::lv_obj_scroll_to_view(Inner.Rows[Cursor_y], LVGL::lv_anim_enable_t_LV_ANIM_OFF);
         }
 
         Ok(())
     }
 
+    pub fn Print(&self, Text: &str) -> Result_type<()> {
+        self.Write(Text.as_bytes())
+    }
+
+    pub fn Write(&self, Data: &[u8]) -> Result_type<()> {
+        let mut Inner = self.0.write()?;
+
+        Self::Print_internal(&mut Inner, Data)?;
+
+        Ok(())
+    }
+
+    fn Print_internal(Inner: &mut Inner_type, Data: &[u8]) -> Result_type<()> {
+        Inner.Screen.Feed(Data);
+
+        Self::Render(Inner)
+    }
+
     fn Print_line_internal(Inner: &mut Inner_type, Text: &str) -> Result_type<()> {
-        if !Inner.Buffer.is_empty() {
-            let Last_index = Inner.Buffer.len() - 1;
+        Inner.Screen.Feed(Text.trim().as_bytes());
+        Inner.Screen.Feed(b"\r\n");
 
-            Inner.Buffer.remove(Last_index);
-        }
+        Self::Render(Inner)
+    }
 
-        let Start_index = if let Some(Last_clear) = Text.rfind("\x1B[2J") {
-            Inner.Buffer.clear();
-            Last_clear + 4
-        } else {
-            0
-        };
+    /// Get the key sequences typed since the last call (raw mode).
+    ///
+    /// The first call switches the terminal to raw mode : the keys are no longer edited in the
+    /// input field but forwarded as bytes (with VT100 sequences for the special keys).
+    pub fn Read_raw_input(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let mut Inner = self.0.write()?;
 
-        Inner.Buffer += Text[Start_index..].trim();
-        Inner.Buffer += "\n\0";
+        Inner.Raw = true;
 
-        let _Lock = Graphics::Get_instance().Lock().unwrap();
+        let Size = Buffer.len().min(Inner.Raw_input.len());
 
-        unsafe {
-            LVGL::lv_label_set_text(Inner.Display, Inner.Buffer.as_ptr() as *const i8);
-            LVGL::lv_obj_scroll_to_view(Inner.Display, LVGL::lv_anim_enable_t_LV_ANIM_OFF);
+        for (Byte, Input) in Buffer.iter_mut().zip(Inner.Raw_input.drain(..Size)) {
+            *Byte = Input;
         }
 
-        Ok(())
+        Ok(Size)
+    }
+
+    /// Convert a key into the bytes sent by a VT100 terminal.
+    fn Get_key_sequence(Key: Key_type) -> &'static [u8] {
+        match Key {
+            Key_type::Up => b"\x1B[A",
+            Key_type::Down => b"\x1B[B",
+            Key_type::Right => b"\x1B[C",
+            Key_type::Left => b"\x1B[D",
+            Key_type::Home => b"\x1B[H",
+            Key_type::End => b"\x1B[F",
+            Key_type::Delete => b"\x1B[3~",
+            Key_type::Escape => b"\x1B",
+            Key_type::Backspace => b"\x7F",
+            Key_type::Enter => b"\r",
+            Key_type::Next => b"\t",
+            Key_type::Previous | Key_type::Character(_) => b"",
+        }
+    }
+
+    /// Check if an input can be read without waiting (pending keys in raw mode, a validated line otherwise).
+    pub fn Is_input_ready(&self) -> Result_type<bool> {
+        let Inner = self.0.read()?;
+
+        Ok(if Inner.Raw {
+            !Inner.Raw_input.is_empty()
+        } else {
+            Inner.Validated
+        })
     }
 
     pub fn Read_input(&self, String: &mut String) -> Result_type<usize> {
         let mut Inner = self.0.write()?;
 
+        Inner.Raw = false;
+
         if !Inner.Validated {
             return Ok(0);
         }
@@ -191,6 +396,24 @@ impl Terminal_type {
         while let Some(Event) = Inner.Window.Pop_event() {
             match Event.Get_code() {
                 Event_code_type::Delete => return Ok(false),
+                Event_code_type::Key if Inner.Raw => {
+                    match Event.Get_key() {
+                        Some(Key_type::Character(Character)) => {
+                            Inner.Raw_input.push_back(Character)
+                        }
+                        Some(Key) => Inner
+                            .Raw_input
+                            .extend(Self::Get_key_sequence(Key).iter().copied()),
+                        None => continue,
+                    }
+
+                    // - The input field is only used to receive the keys in raw mode.
+                    let _Lock = Graphics::Get_instance().Lock()?;
+
+                    unsafe {
+                        LVGL::lv_textarea_set_text(Inner.Input, c"".as_ptr());
+                    }
+                }
                 Event_code_type::Key => {
                     if let Some(Key_type::Character(Character)) = Event.Get_key() {
                         if Inner.Validated {
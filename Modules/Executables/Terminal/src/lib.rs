@@ -5,6 +5,7 @@
 mod Device;
 mod Executable;
 mod Main;
+mod Screen;
 mod Terminal;
 
 mod Error;
@@ -1,26 +1,26 @@
 use core::num::NonZeroUsize;
 use std::mem::forget;
 
-use Executable::Standard_type;
+use Executable::{Arguments_type, Standard_type, WASM_magic_number};
 use File_system::{Mode_type, Path_type};
 
 use Virtual_file_system::File_type;
 
 use crate::Error_type;
 
-pub fn Inner_main(Standard: &Standard_type, Arguments: String) -> Result<(), Error_type> {
-    let Arguments = Arguments.split_whitespace().collect::<Vec<&str>>();
-
-    if Arguments.len() != 1 {
-        return Err(Error_type::Invalid_number_of_arguments);
-    }
-
-    let Path = Path_type::New(Arguments[0]);
-
-    match Path.Get_extension() {
-        Some("wasm") | Some("WASM") => Ok(()),
-        _ => return Err(Error_type::Not_a_WASM_file),
-    }?;
+pub fn Inner_main(Standard: &Standard_type, Arguments: Arguments_type) -> Result<(), Error_type> {
+    // - The module is the program when run through [Executable::Execute], the first argument otherwise.
+    let (Path, Arguments) = match Arguments.Get_program() {
+        Some(Program) => (Program, Arguments.Get_arguments()),
+        None => {
+            let (Path, Arguments) = Arguments
+                .Get_arguments()
+                .split_first()
+                .ok_or(Error_type::Invalid_number_of_arguments)?;
+
+            (Path_type::New(Path), Arguments)
+        }
+    };
 
     let Path = if Path.Is_absolute() {
         Path.to_owned()
@@ -54,16 +54,33 @@ pub fn Inner_main(Standard: &Standard_type, Arguments: String) -> Result<(), Err
     File.Read_to_end(&mut Buffer)
         .map_err(|_| Error_type::Failed_to_read_file)?;
 
+    if !Buffer.starts_with(WASM_magic_number) {
+        return Err(Error_type::Not_a_WASM_file);
+    }
+
+    // - The module gets its path as first argument.
+    let Arguments: Vec<&str> = [Path.As_str()]
+        .into_iter()
+        .chain(Arguments.iter().map(String::as_str))
+        .collect();
+
     let (Standard_in, Standard_out, Standard_error) = Standard.Split();
 
     Virtual_machine::Get_instance()
-        .Execute(Buffer, 4096, Standard_in, Standard_out, Standard_error)
+        .Execute(
+            Buffer,
+            4096,
+            &Arguments,
+            Standard_in,
+            Standard_out,
+            Standard_error,
+        )
         .map_err(|_| Error_type::Failed_to_execute)?;
 
     Ok(())
 }
 
-pub fn Main(Standard: Standard_type, Arguments: String) -> Result<(), NonZeroUsize> {
+pub fn Main(Standard: Standard_type, Arguments: Arguments_type) -> Result<(), NonZeroUsize> {
     match Inner_main(&Standard, Arguments) {
         Ok(()) => {
             forget(Standard);
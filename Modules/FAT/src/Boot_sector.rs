@@ -0,0 +1,423 @@
+use File_system::{Block_type, Error_type, Result_type};
+
+const Signature_start: usize = 510;
+const Signature: [u8; 2] = [0x55, 0xAA];
+const Jump_instruction: [u8; 3] = [0xEB, 0x58, 0x90];
+const OEM_name: &[u8; 8] = b"XILA    ";
+const exFAT_OEM_name: &[u8; 8] = b"EXFAT   ";
+const Media_descriptor: u8 = 0xF8;
+const Extended_boot_signature: u8 = 0x29;
+
+/// Clusters count bounds defining the FAT type (from the Microsoft specification).
+const Minimum_FAT16_clusters: u32 = 4085;
+const Minimum_FAT32_clusters: u32 = 65525;
+
+pub const Sector_size: u16 = 512;
+pub const Directory_entry_size: u32 = 32;
+
+/// FAT type, determined by the count of clusters of the volume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind_type {
+    FAT16,
+    FAT32,
+}
+
+/// Geometry of a FAT volume, as described by its boot sector (BIOS parameter block).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Boot_sector_type {
+    Bytes_per_sector: u16,
+    Sectors_per_cluster: u8,
+    Reserved_sectors: u16,
+    FAT_count: u8,
+    Root_entry_count: u16,
+    Total_sectors: u32,
+    FAT_size: u32,
+    Root_cluster: u32,
+    File_system_information_sector: u16,
+    Backup_boot_sector: u16,
+    Volume_identifier: u32,
+    Volume_label: [u8; 11],
+}
+
+fn Read_u16(Block: &[u8], Offset: usize) -> u16 {
+    u16::from_le_bytes([Block[Offset], Block[Offset + 1]])
+}
+
+fn Read_u32(Block: &[u8], Offset: usize) -> u32 {
+    u32::from_le_bytes(Block[Offset..Offset + 4].try_into().unwrap())
+}
+
+impl Boot_sector_type {
+    /// Compute the geometry of a new volume of `Total_sectors` sectors of 512 bytes.
+    ///
+    /// FAT32 is used when the volume is large enough to hold 65525 clusters, FAT16 otherwise.
+    pub fn New(
+        Total_sectors: u64,
+        Volume_identifier: u32,
+        Volume_label: &str,
+    ) -> Result_type<Self> {
+        let Total_sectors = Total_sectors.min(u32::MAX as u64) as u32;
+
+        let mut Label = [b' '; 11];
+
+        for (Destination, Source) in Label.iter_mut().zip(Volume_label.bytes()) {
+            *Destination = Source.to_ascii_uppercase();
+        }
+
+        // - FAT32 (cluster size from the Microsoft specification table)
+        let Sectors_per_cluster = match Total_sectors {
+            0..=532_479 => 1,
+            532_480..=16_777_215 => 8,
+            16_777_216..=33_554_431 => 16,
+            33_554_432..=67_108_863 => 32,
+            _ => 64,
+        };
+
+        let Boot_sector = Self {
+            Bytes_per_sector: Sector_size,
+            Sectors_per_cluster,
+            Reserved_sectors: 32,
+            FAT_count: 2,
+            Root_entry_count: 0,
+            Total_sectors,
+            FAT_size: 0,
+            Root_cluster: 2,
+            File_system_information_sector: 1,
+            Backup_boot_sector: 6,
+            Volume_identifier,
+            Volume_label: Label,
+        }
+        .With_FAT_size();
+
+        if Boot_sector.Get_kind() == Some(Kind_type::FAT32) {
+            return Ok(Boot_sector);
+        }
+
+        // - FAT16 : use the smallest cluster size that keeps the clusters count in range.
+        let mut Sectors_per_cluster = 1_u8;
+
+        loop {
+            let Boot_sector = Self {
+                Bytes_per_sector: Sector_size,
+                Sectors_per_cluster,
+                Reserved_sectors: 1,
+                FAT_count: 2,
+                Root_entry_count: 512,
+                Total_sectors,
+                FAT_size: 0,
+                Root_cluster: 0,
+                File_system_information_sector: 0,
+                Backup_boot_sector: 0,
+                Volume_identifier,
+                Volume_label: Label,
+            }
+            .With_FAT_size();
+
+            match Boot_sector.Get_kind() {
+                Some(Kind_type::FAT16) => return Ok(Boot_sector),
+                // - Too many clusters, use larger ones.
+                Some(Kind_type::FAT32) if Sectors_per_cluster < 64 => Sectors_per_cluster *= 2,
+                // - The volume is too small (FAT12 isn't supported).
+                _ => return Err(Error_type::Invalid_parameter),
+            }
+        }
+    }
+
+    /// Compute the size of the FAT (from the Microsoft specification).
+    fn With_FAT_size(mut self) -> Self {
+        let Used = self.Reserved_sectors as u32 + self.Get_root_directory_sectors();
+
+        let Available = self.Total_sectors.saturating_sub(Used);
+
+        let mut Divisor = 256 * self.Sectors_per_cluster as u32 + self.FAT_count as u32;
+
+        if self.Root_entry_count == 0 {
+            Divisor /= 2;
+        }
+
+        self.FAT_size = Available.div_ceil(Divisor);
+
+        self
+    }
+
+    /// Parse a boot sector.
+    ///
+    /// exFAT and FAT12 volumes are not supported.
+    pub fn From_block(Block: &Block_type) -> Result_type<Self> {
+        let Block = &Block.0;
+
+        if Block[Signature_start..Signature_start + 2] != Signature {
+            return Err(Error_type::Corrupted);
+        }
+
+        if &Block[3..11] == exFAT_OEM_name {
+            return Err(Error_type::Unsupported_operation);
+        }
+
+        let Bytes_per_sector = Read_u16(Block, 11);
+        let Sectors_per_cluster = Block[13];
+        let Reserved_sectors = Read_u16(Block, 14);
+        let FAT_count = Block[16];
+        let Root_entry_count = Read_u16(Block, 17);
+        let Total_sectors_16 = Read_u16(Block, 19);
+        let FAT_size_16 = Read_u16(Block, 22);
+        let Total_sectors_32 = Read_u32(Block, 32);
+
+        if !matches!(Bytes_per_sector, 512 | 1024 | 2048 | 4096)
+            || !Sectors_per_cluster.is_power_of_two()
+            || Reserved_sectors == 0
+            || FAT_count == 0
+        {
+            return Err(Error_type::Corrupted);
+        }
+
+        let Total_sectors = if Total_sectors_16 != 0 {
+            Total_sectors_16 as u32
+        } else {
+            Total_sectors_32
+        };
+
+        // - The FAT32 extended BIOS parameter block starts at offset 36, the FAT16 one at 36 too but without the 28 first bytes.
+        let (FAT_size, Root_cluster, File_system_information_sector, Backup_boot_sector, Extended) =
+            if FAT_size_16 != 0 {
+                (FAT_size_16 as u32, 0, 0, 0, 36)
+            } else {
+                (
+                    Read_u32(Block, 36),
+                    Read_u32(Block, 44),
+                    Read_u16(Block, 48),
+                    Read_u16(Block, 50),
+                    64,
+                )
+            };
+
+        let Boot_sector = Self {
+            Bytes_per_sector,
+            Sectors_per_cluster,
+            Reserved_sectors,
+            FAT_count,
+            Root_entry_count,
+            Total_sectors,
+            FAT_size,
+            Root_cluster,
+            File_system_information_sector,
+            Backup_boot_sector,
+            Volume_identifier: Read_u32(Block, Extended + 3),
+            Volume_label: Block[Extended + 7..Extended + 18].try_into().unwrap(),
+        };
+
+        match Boot_sector.Get_kind() {
+            Some(Kind_type::FAT16) if FAT_size_16 != 0 && Root_entry_count != 0 => {}
+            Some(Kind_type::FAT32) if FAT_size_16 == 0 && Root_entry_count == 0 => {
+                if !Boot_sector.Is_valid_cluster(Root_cluster) {
+                    return Err(Error_type::Corrupted);
+                }
+            }
+            Some(_) => return Err(Error_type::Corrupted),
+            None => return Err(Error_type::Unsupported_operation),
+        }
+
+        Ok(Boot_sector)
+    }
+
+    pub fn To_block(&self) -> Block_type {
+        let mut Block = Block_type::default();
+
+        let Data = &mut Block.0;
+
+        Data[0..3].copy_from_slice(&Jump_instruction);
+        Data[3..11].copy_from_slice(OEM_name);
+        Data[11..13].copy_from_slice(&self.Bytes_per_sector.to_le_bytes());
+        Data[13] = self.Sectors_per_cluster;
+        Data[14..16].copy_from_slice(&self.Reserved_sectors.to_le_bytes());
+        Data[16] = self.FAT_count;
+        Data[17..19].copy_from_slice(&self.Root_entry_count.to_le_bytes());
+        Data[21] = Media_descriptor;
+        // - Geometry for INT 13h (unused)
+        Data[24..26].copy_from_slice(&63_u16.to_le_bytes());
+        Data[26..28].copy_from_slice(&255_u16.to_le_bytes());
+
+        if self.Total_sectors < 0x10000 {
+            Data[19..21].copy_from_slice(&(self.Total_sectors as u16).to_le_bytes());
+        } else {
+            Data[32..36].copy_from_slice(&self.Total_sectors.to_le_bytes());
+        }
+
+        let (Extended, File_system_type) = match self.Get_kind() {
+            Some(Kind_type::FAT32) => {
+                Data[36..40].copy_from_slice(&self.FAT_size.to_le_bytes());
+                Data[44..48].copy_from_slice(&self.Root_cluster.to_le_bytes());
+                Data[48..50].copy_from_slice(&self.File_system_information_sector.to_le_bytes());
+                Data[50..52].copy_from_slice(&self.Backup_boot_sector.to_le_bytes());
+
+                (64, b"FAT32   ")
+            }
+            _ => {
+                Data[22..24].copy_from_slice(&(self.FAT_size as u16).to_le_bytes());
+
+                (36, b"FAT16   ")
+            }
+        };
+
+        Data[Extended] = 0x80; // Drive number
+        Data[Extended + 2] = Extended_boot_signature;
+        Data[Extended + 3..Extended + 7].copy_from_slice(&self.Volume_identifier.to_le_bytes());
+        Data[Extended + 7..Extended + 18].copy_from_slice(&self.Volume_label);
+        Data[Extended + 18..Extended + 26].copy_from_slice(File_system_type);
+
+        Data[Signature_start..Signature_start + 2].copy_from_slice(&Signature);
+
+        Block
+    }
+
+    /// Get the FAT type, or `None` for FAT12 volumes.
+    pub fn Get_kind(&self) -> Option<Kind_type> {
+        match self.Get_cluster_count() {
+            0..Minimum_FAT16_clusters => None,
+            Minimum_FAT16_clusters..Minimum_FAT32_clusters => Some(Kind_type::FAT16),
+            _ => Some(Kind_type::FAT32),
+        }
+    }
+
+    pub fn Is_FAT32(&self) -> bool {
+        self.Get_kind() == Some(Kind_type::FAT32)
+    }
+
+    pub fn Get_bytes_per_sector(&self) -> u16 {
+        self.Bytes_per_sector
+    }
+
+    pub fn Get_FAT_count(&self) -> u8 {
+        self.FAT_count
+    }
+
+    pub fn Get_root_cluster(&self) -> u32 {
+        self.Root_cluster
+    }
+
+    pub fn Get_backup_boot_sector(&self) -> u16 {
+        self.Backup_boot_sector
+    }
+
+    /// Get the offset of the FSInfo sector (FAT32 only).
+    pub fn Get_file_system_information_offset(&self) -> Option<u64> {
+        if !self.Is_FAT32() || self.File_system_information_sector == 0 {
+            return None;
+        }
+
+        Some(self.File_system_information_sector as u64 * self.Bytes_per_sector as u64)
+    }
+
+    /// Get the size of a cluster in bytes.
+    pub fn Get_cluster_size(&self) -> usize {
+        self.Sectors_per_cluster as usize * self.Bytes_per_sector as usize
+    }
+
+    fn Get_root_directory_sectors(&self) -> u32 {
+        (self.Root_entry_count as u32 * Directory_entry_size).div_ceil(self.Bytes_per_sector as u32)
+    }
+
+    fn Get_first_data_sector(&self) -> u32 {
+        self.Reserved_sectors as u32
+            + self.FAT_count as u32 * self.FAT_size
+            + self.Get_root_directory_sectors()
+    }
+
+    /// Get the count of data clusters of the volume.
+    pub fn Get_cluster_count(&self) -> u32 {
+        self.Total_sectors
+            .saturating_sub(self.Get_first_data_sector())
+            .checked_div(self.Sectors_per_cluster as u32)
+            .unwrap_or(0)
+    }
+
+    /// Check if a cluster number refers to a data cluster.
+    pub fn Is_valid_cluster(&self, Cluster: u32) -> bool {
+        (2..self.Get_cluster_count() + 2).contains(&Cluster)
+    }
+
+    /// Get the offset of a FAT copy in bytes.
+    pub fn Get_FAT_offset(&self, Index: u8) -> u64 {
+        (self.Reserved_sectors as u64 + Index as u64 * self.FAT_size as u64)
+            * self.Bytes_per_sector as u64
+    }
+
+    /// Get the offset and the size in bytes of the fixed root directory (FAT16 only).
+    pub fn Get_root_directory_area(&self) -> (u64, u64) {
+        let Offset = self.Get_FAT_offset(self.FAT_count);
+
+        (
+            Offset,
+            self.Root_entry_count as u64 * Directory_entry_size as u64,
+        )
+    }
+
+    /// Get the offset of a data cluster in bytes.
+    pub fn Get_cluster_offset(&self, Cluster: u32) -> u64 {
+        (self.Get_first_data_sector() as u64
+            + (Cluster as u64 - 2) * self.Sectors_per_cluster as u64)
+            * self.Bytes_per_sector as u64
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_new_FAT16() {
+        let Boot_sector = Boot_sector_type::New(8192, 0x1234, "Xila").unwrap();
+
+        assert_eq!(Boot_sector.Get_kind(), Some(Kind_type::FAT16));
+        assert!(Boot_sector.Get_cluster_count() >= Minimum_FAT16_clusters);
+
+        // - The FAT must be large enough for all the clusters.
+        assert!(
+            (Boot_sector.Get_FAT_offset(1) - Boot_sector.Get_FAT_offset(0))
+                >= (Boot_sector.Get_cluster_count() as u64 + 2) * 2
+        );
+
+        assert_eq!(
+            Boot_sector_type::From_block(&Boot_sector.To_block()).unwrap(),
+            Boot_sector
+        );
+    }
+
+    #[test]
+    fn Test_new_FAT32() {
+        let Boot_sector = Boot_sector_type::New(80_000, 0x1234, "Xila").unwrap();
+
+        assert_eq!(Boot_sector.Get_kind(), Some(Kind_type::FAT32));
+        assert!(
+            (Boot_sector.Get_FAT_offset(1) - Boot_sector.Get_FAT_offset(0))
+                >= (Boot_sector.Get_cluster_count() as u64 + 2) * 4
+        );
+
+        assert_eq!(
+            Boot_sector_type::From_block(&Boot_sector.To_block()).unwrap(),
+            Boot_sector
+        );
+    }
+
+    #[test]
+    fn Test_unsupported() {
+        // - Too small for FAT16
+        assert_eq!(
+            Boot_sector_type::New(1024, 0, ""),
+            Err(Error_type::Invalid_parameter)
+        );
+
+        let mut Block = Boot_sector_type::New(8192, 0, "").unwrap().To_block();
+        Block.0[3..11].copy_from_slice(exFAT_OEM_name);
+
+        assert_eq!(
+            Boot_sector_type::From_block(&Block),
+            Err(Error_type::Unsupported_operation)
+        );
+
+        assert_eq!(
+            Boot_sector_type::From_block(&Block_type::default()),
+            Err(Error_type::Corrupted)
+        );
+    }
+}
@@ -0,0 +1,571 @@
+use File_system::{
+    Entry_type, Error_type, Inode_type, Path_type, Result_type, Size_type, Time_type, Type_type,
+};
+
+use crate::{
+    Directory_attribute, End_marker, Entry_size, Free_marker, Get_long_name_entries,
+    Get_short_name, Get_short_name_alias, Is_valid_name, Long_name_attribute, Long_name_type,
+    Maximum_long_name_length, Short_entry_type, Volume_label_attribute, Volume_type,
+};
+
+/// Maximum count of entries of a directory (from the Microsoft specification).
+const Maximum_entries: usize = 65536;
+
+/// Location of the entries of a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location_type {
+    /// Fixed size root directory of FAT16 volumes.
+    Root,
+    /// Directory stored in a chain of clusters, identified by its first cluster.
+    Cluster(u32),
+}
+
+impl Location_type {
+    /// Get the location of the root directory.
+    pub fn Get_root(Volume: &Volume_type) -> Self {
+        let Boot_sector = Volume.Get_boot_sector();
+
+        if Boot_sector.Is_FAT32() {
+            Self::Cluster(Boot_sector.Get_root_cluster())
+        } else {
+            Self::Root
+        }
+    }
+
+    /// Get the location of a directory from its first cluster (0 refers to the root directory).
+    pub fn New(Volume: &Volume_type, Cluster: u32) -> Self {
+        if Cluster == 0 {
+            Self::Get_root(Volume)
+        } else {
+            Self::Cluster(Cluster)
+        }
+    }
+
+    /// Get the cluster referring to this directory in the entries of its children (0 for the root directory).
+    pub fn Get_cluster(&self, Volume: &Volume_type) -> u32 {
+        match self {
+            Self::Cluster(Cluster) if *self != Self::Get_root(Volume) => *Cluster,
+            _ => 0,
+        }
+    }
+
+    /// Get the offset of an entry of the directory.
+    fn Get_slot_offset(&self, Volume: &Volume_type, Index: usize) -> Result_type<u64> {
+        let Offset = (Index * Entry_size) as u64;
+
+        match self {
+            Self::Root => {
+                let (Start, Size) = Volume.Get_boot_sector().Get_root_directory_area();
+
+                if Offset >= Size {
+                    return Err(Error_type::Corrupted);
+                }
+
+                Ok(Start + Offset)
+            }
+            Self::Cluster(First) => {
+                let Cluster_size = Volume.Get_cluster_size() as u64;
+
+                let Chain = Volume.Get_chain(*First)?;
+
+                let Cluster = Chain
+                    .get((Offset / Cluster_size) as usize)
+                    .ok_or(Error_type::Corrupted)?;
+
+                Ok(Volume.Get_boot_sector().Get_cluster_offset(*Cluster) + Offset % Cluster_size)
+            }
+        }
+    }
+
+    /// Read the short entry at `Index`.
+    pub fn Read_entry(&self, Volume: &Volume_type, Index: usize) -> Result_type<Short_entry_type> {
+        let mut Bytes = [0; Entry_size];
+
+        Volume.Read(self.Get_slot_offset(Volume, Index)?, &mut Bytes)?;
+
+        Ok(Short_entry_type::From_bytes(&Bytes))
+    }
+
+    /// Write the short entry at `Index`.
+    pub fn Write_entry(
+        &self,
+        Volume: &Volume_type,
+        Index: usize,
+        Entry: &Short_entry_type,
+    ) -> Result_type<()> {
+        Volume.Write(self.Get_slot_offset(Volume, Index)?, &Entry.To_bytes())
+    }
+}
+
+/// Entry found in a directory.
+#[derive(Debug, Clone)]
+pub struct Found_entry_type {
+    /// Index of the short entry in the directory.
+    Index: usize,
+    /// Count of slots used by the entry (long name entries included).
+    Slot_count: usize,
+    Name: String,
+    Entry: Short_entry_type,
+}
+
+impl Found_entry_type {
+    pub fn Get_index(&self) -> usize {
+        self.Index
+    }
+
+    pub fn Get_entry(&self) -> &Short_entry_type {
+        &self.Entry
+    }
+
+    /// Get the location of the entries of the directory (if the entry is a directory).
+    pub fn Get_location(&self, Volume: &Volume_type) -> Result_type<Location_type> {
+        if !self.Entry.Is_directory() {
+            return Err(Error_type::Not_directory);
+        }
+
+        Ok(Location_type::New(Volume, self.Entry.Get_first_cluster()))
+    }
+
+    pub fn Get_type(&self) -> Type_type {
+        if self.Entry.Is_directory() {
+            Type_type::Directory
+        } else {
+            Type_type::File
+        }
+    }
+}
+
+/// Entries of a directory loaded in memory.
+pub struct Directory_type {
+    Location: Location_type,
+    Clusters: Vec<u32>,
+    Data: Vec<u8>,
+}
+
+impl Directory_type {
+    pub fn Read(Volume: &Volume_type, Location: Location_type) -> Result_type<Self> {
+        let (Clusters, Data) = match Location {
+            Location_type::Root => {
+                let (Offset, Size) = Volume.Get_boot_sector().Get_root_directory_area();
+
+                let mut Data = vec![0; Size as usize];
+
+                Volume.Read(Offset, &mut Data)?;
+
+                (Vec::new(), Data)
+            }
+            Location_type::Cluster(First) => {
+                let Clusters = Volume.Get_chain(First)?;
+
+                let Cluster_size = Volume.Get_cluster_size();
+
+                let mut Data = vec![0; Clusters.len() * Cluster_size];
+
+                for (Cluster, Buffer) in Clusters.iter().zip(Data.chunks_mut(Cluster_size)) {
+                    Volume.Read(
+                        Volume.Get_boot_sector().Get_cluster_offset(*Cluster),
+                        Buffer,
+                    )?;
+                }
+
+                (Clusters, Data)
+            }
+        };
+
+        Ok(Self {
+            Location,
+            Clusters,
+            Data,
+        })
+    }
+
+    fn Get_slot_count(&self) -> usize {
+        self.Data.len() / Entry_size
+    }
+
+    fn Get_slot(&self, Index: usize) -> &[u8] {
+        &self.Data[Index * Entry_size..(Index + 1) * Entry_size]
+    }
+
+    fn Write_slot(&mut self, Volume: &Volume_type, Index: usize, Bytes: &[u8]) -> Result_type<()> {
+        let Offset = match self.Location {
+            Location_type::Root => {
+                Volume.Get_boot_sector().Get_root_directory_area().0 + (Index * Entry_size) as u64
+            }
+            Location_type::Cluster(_) => {
+                let Cluster_size = Volume.Get_cluster_size();
+                let Offset = Index * Entry_size;
+
+                Volume
+                    .Get_boot_sector()
+                    .Get_cluster_offset(self.Clusters[Offset / Cluster_size])
+                    + (Offset % Cluster_size) as u64
+            }
+        };
+
+        Volume.Write(Offset, Bytes)?;
+
+        self.Data[Index * Entry_size..(Index + 1) * Entry_size].copy_from_slice(Bytes);
+
+        Ok(())
+    }
+
+    /// Get the entries of the directory, "." and ".." excluded.
+    pub fn Get_entries(&self) -> Vec<Found_entry_type> {
+        let mut Entries = Vec::new();
+
+        let mut Long_name = Long_name_type::default();
+
+        for Index in 0..self.Get_slot_count() {
+            let Bytes = self.Get_slot(Index);
+
+            match Bytes[0] {
+                End_marker => break,
+                Free_marker => {
+                    Long_name.Clear();
+                    continue;
+                }
+                _ => {}
+            }
+
+            if Bytes[11] & 0x3F == Long_name_attribute {
+                Long_name.Push(Bytes);
+                continue;
+            }
+
+            let Entry = Short_entry_type::From_bytes(Bytes);
+
+            if Entry.Is_dot()
+                || (Entry.Get_attributes() & (Volume_label_attribute | Directory_attribute)
+                    == Volume_label_attribute)
+            {
+                Long_name.Clear();
+                continue;
+            }
+
+            let (Name, Slot_count) = match Long_name.Get_name(&Entry) {
+                Some(Name) => (Name, Long_name.Get_count() + 1),
+                None => (Entry.Get_name(), 1),
+            };
+
+            Entries.push(Found_entry_type {
+                Index,
+                Slot_count,
+                Name,
+                Entry,
+            });
+
+            Long_name.Clear();
+        }
+
+        Entries
+    }
+
+    /// Find an entry by name (case insensitive, like FAT).
+    pub fn Find(&self, Name: &str) -> Option<Found_entry_type> {
+        let Name = Name.to_lowercase();
+
+        self.Get_entries()
+            .into_iter()
+            .find(|Entry| Entry.Name.to_lowercase() == Name)
+    }
+
+    fn Has_short_name(&self, Short_name: &[u8; 11]) -> bool {
+        (0..self.Get_slot_count())
+            .map(|Index| self.Get_slot(Index))
+            .take_while(|Bytes| Bytes[0] != End_marker)
+            .any(|Bytes| {
+                Bytes[0] != Free_marker
+                    && Bytes[11] != Long_name_attribute
+                    && Bytes[..11] == Short_name[..]
+            })
+    }
+
+    /// Check if the directory only contains "." and "..".
+    pub fn Is_empty(&self) -> bool {
+        self.Get_entries().is_empty()
+    }
+
+    /// Find a run of `Count` free slots, extending the directory if needed.
+    fn Get_free_slots(&mut self, Volume: &mut Volume_type, Count: usize) -> Result_type<usize> {
+        loop {
+            let mut Run = 0;
+
+            for Index in 0..self.Get_slot_count() {
+                if matches!(self.Get_slot(Index)[0], Free_marker | End_marker) {
+                    Run += 1;
+
+                    if Run == Count {
+                        return Ok(Index + 1 - Count);
+                    }
+                } else {
+                    Run = 0;
+                }
+            }
+
+            // - The fixed root directory can't be extended.
+            if self.Location == Location_type::Root
+                || self.Get_slot_count() + Volume.Get_cluster_size() / Entry_size > Maximum_entries
+            {
+                return Err(Error_type::File_system_full);
+            }
+
+            let Cluster = Volume.Allocate_cluster(self.Clusters.last().copied(), true)?;
+
+            self.Clusters.push(Cluster);
+            self.Data
+                .resize(self.Data.len() + Volume.Get_cluster_size(), 0);
+        }
+    }
+
+    /// Add an entry, with long name entries if the name can't be stored as a short name.
+    pub fn Insert(
+        &mut self,
+        Volume: &mut Volume_type,
+        Name: &str,
+        mut Entry: Short_entry_type,
+    ) -> Result_type<Found_entry_type> {
+        if Name.encode_utf16().count() > Maximum_long_name_length {
+            return Err(Error_type::Name_too_long);
+        }
+
+        if !Is_valid_name(Name) {
+            return Err(Error_type::Invalid_path);
+        }
+
+        if self.Find(Name).is_some() {
+            return Err(Error_type::Already_exists);
+        }
+
+        let Long_name_entries = match Get_short_name(Name) {
+            Some((Short_name, Case)) if !self.Has_short_name(&Short_name) => {
+                Entry.Set_short_name(Short_name, Case);
+
+                Vec::new()
+            }
+            _ => {
+                let Short_name =
+                    Get_short_name_alias(Name, |Short_name| self.Has_short_name(Short_name))?;
+
+                Entry.Set_short_name(Short_name, 0);
+
+                Get_long_name_entries(Name, Entry.Get_checksum())
+            }
+        };
+
+        let Slot_count = Long_name_entries.len() + 1;
+
+        let First = self.Get_free_slots(Volume, Slot_count)?;
+
+        for (Index, Bytes) in Long_name_entries.iter().enumerate() {
+            self.Write_slot(Volume, First + Index, Bytes)?;
+        }
+
+        let Index = First + Slot_count - 1;
+
+        self.Write_slot(Volume, Index, &Entry.To_bytes())?;
+
+        Ok(Found_entry_type {
+            Index,
+            Slot_count,
+            Name: Name.to_string(),
+            Entry,
+        })
+    }
+
+    /// Remove an entry and its long name entries (the clusters of the entry are not freed).
+    pub fn Remove(&mut self, Volume: &Volume_type, Entry: &Found_entry_type) -> Result_type<()> {
+        for Index in Entry.Index + 1 - Entry.Slot_count..=Entry.Index {
+            let mut Bytes: [u8; Entry_size] = self.Get_slot(Index).try_into().unwrap();
+
+            Bytes[0] = Free_marker;
+
+            self.Write_slot(Volume, Index, &Bytes)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a sub-directory.
+    pub fn Create_directory(
+        &mut self,
+        Volume: &mut Volume_type,
+        Name: &str,
+        Time: Time_type,
+    ) -> Result_type<Found_entry_type> {
+        if self.Find(Name).is_some() {
+            return Err(Error_type::Already_exists);
+        }
+
+        let Cluster = Volume.Allocate_cluster(None, true)?;
+
+        let Location = Location_type::Cluster(Cluster);
+
+        let Result = Location
+            .Write_entry(Volume, 0, &Short_entry_type::New_dot(".", Cluster, Time))
+            .and_then(|_| {
+                Location.Write_entry(
+                    Volume,
+                    1,
+                    &Short_entry_type::New_dot("..", self.Location.Get_cluster(Volume), Time),
+                )
+            })
+            .and_then(|_| {
+                self.Insert(
+                    Volume,
+                    Name,
+                    Short_entry_type::New(Directory_attribute, Cluster, Time),
+                )
+            });
+
+        if Result.is_err() {
+            Volume.Free_chain(Cluster)?;
+        }
+
+        Result
+    }
+}
+
+/// Result of the resolution of a path.
+pub enum Resolved_type {
+    Root,
+    Entry(Location_type, Found_entry_type),
+}
+
+fn Get_components(Path: &Path_type) -> Result_type<Vec<&str>> {
+    let Components: Vec<&str> = Path
+        .As_str()
+        .split('/')
+        .filter(|Component| !Component.is_empty() && *Component != ".")
+        .collect();
+
+    if Components.contains(&"..") {
+        return Err(Error_type::Invalid_path);
+    }
+
+    Ok(Components)
+}
+
+/// Find the entry of a path.
+pub fn Resolve(Volume: &Volume_type, Path: &Path_type) -> Result_type<Resolved_type> {
+    let Components = Get_components(Path)?;
+
+    let mut Location = Location_type::Get_root(Volume);
+
+    let mut Resolved = Resolved_type::Root;
+
+    for Component in Components {
+        if let Resolved_type::Entry(_, Entry) = &Resolved {
+            Location = Entry.Get_location(Volume)?;
+        }
+
+        let Entry = Directory_type::Read(Volume, Location)?
+            .Find(Component)
+            .ok_or(Error_type::Not_found)?;
+
+        Resolved = Resolved_type::Entry(Location, Entry);
+    }
+
+    Ok(Resolved)
+}
+
+/// Get the parent directory of a path and the name of the entry.
+pub fn Resolve_parent<'a>(
+    Volume: &Volume_type,
+    Path: &'a Path_type,
+) -> Result_type<(Location_type, &'a str)> {
+    let Components = Get_components(Path)?;
+
+    let (Name, _) = Components.split_last().ok_or(Error_type::Invalid_path)?;
+
+    let Parent = Path.Go_parent().ok_or(Error_type::Invalid_path)?;
+
+    let Location = match Resolve(Volume, Parent)? {
+        Resolved_type::Root => Location_type::Get_root(Volume),
+        Resolved_type::Entry(_, Entry) => Entry.Get_location(Volume)?,
+    };
+
+    Ok((Location, Name))
+}
+
+/// Snapshot of the entries of a directory, used by the open directories.
+#[derive(Debug, Clone)]
+pub struct Open_directory_type {
+    Path: String,
+    Entries: Vec<Entry_type>,
+    Position: usize,
+}
+
+impl Open_directory_type {
+    pub fn Open(Volume: &Volume_type, Path: &Path_type) -> Result_type<Self> {
+        let (Location, Parent) = match Resolve(Volume, Path)? {
+            Resolved_type::Root => (
+                Location_type::Get_root(Volume),
+                Location_type::Get_root(Volume),
+            ),
+            Resolved_type::Entry(Parent, Entry) => (Entry.Get_location(Volume)?, Parent),
+        };
+
+        let Get_inode = |Location: Location_type| match Location {
+            Location_type::Root => Inode_type::New(0),
+            Location_type::Cluster(Cluster) => Inode_type::New(Cluster as u64),
+        };
+
+        let mut Entries = vec![
+            Entry_type::New(
+                Get_inode(Location),
+                ".".to_string(),
+                Type_type::Directory,
+                Size_type::New(0),
+            ),
+            Entry_type::New(
+                Get_inode(Parent),
+                "..".to_string(),
+                Type_type::Directory,
+                Size_type::New(0),
+            ),
+        ];
+
+        Entries.extend(
+            Directory_type::Read(Volume, Location)?
+                .Get_entries()
+                .into_iter()
+                .map(|Entry| {
+                    Entry_type::New(
+                        Inode_type::New(Entry.Entry.Get_first_cluster() as u64),
+                        Entry.Name.clone(),
+                        Entry.Get_type(),
+                        Size_type::New(Entry.Entry.Get_size() as u64),
+                    )
+                }),
+        );
+
+        Ok(Self {
+            Path: Path.As_str().to_string(),
+            Entries,
+            Position: 0,
+        })
+    }
+
+    pub fn Get_path(&self) -> &Path_type {
+        Path_type::From_str(&self.Path)
+    }
+
+    pub fn Read(&mut self) -> Option<Entry_type> {
+        let Entry = self.Entries.get(self.Position).cloned();
+
+        if Entry.is_some() {
+            self.Position += 1;
+        }
+
+        Entry
+    }
+
+    pub fn Get_position(&self) -> usize {
+        self.Position
+    }
+
+    pub fn Set_position(&mut self, Position: usize) {
+        self.Position = Position.min(self.Entries.len());
+    }
+}
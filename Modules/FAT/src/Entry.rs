@@ -0,0 +1,568 @@
+use File_system::{Error_type, Result_type, Time_type};
+use Shared::{Human_to_unix_time, Unix_to_human_time};
+
+pub const Entry_size: usize = 32;
+
+pub const Read_only_attribute: u8 = 0x01;
+pub const Hidden_attribute: u8 = 0x02;
+pub const System_attribute: u8 = 0x04;
+pub const Volume_label_attribute: u8 = 0x08;
+pub const Directory_attribute: u8 = 0x10;
+pub const Archive_attribute: u8 = 0x20;
+pub const Long_name_attribute: u8 =
+    Read_only_attribute | Hidden_attribute | System_attribute | Volume_label_attribute;
+
+/// First name byte of a deleted entry.
+pub const Free_marker: u8 = 0xE5;
+/// First name byte of the entry following the last used one.
+pub const End_marker: u8 = 0x00;
+/// Replacement of a leading 0xE5 byte in a short name.
+const Kanji_marker: u8 = 0x05;
+
+/// Case flags (in the reserved byte used by Windows NT) of short names stored in lowercase.
+const Lowercase_base: u8 = 0x08;
+const Lowercase_extension: u8 = 0x10;
+
+const Last_long_name_entry: u8 = 0x40;
+const Long_name_characters_per_entry: usize = 13;
+/// Offsets of the UTF-16 characters in a long name entry.
+const Long_name_character_offsets: [usize; Long_name_characters_per_entry] =
+    [1, 3, 5, 7, 9, 14, 16, 18, 20, 22, 24, 28, 30];
+pub const Maximum_long_name_length: usize = 255;
+
+const Short_name_special_characters: &str = "!#$%&'()-@^_`{}~";
+const Long_name_invalid_characters: &str = "\"*/:<>?\\|";
+
+/// Unix time of the FAT epoch (1980-01-01 00:00:00), FAT can't store earlier times.
+const FAT_epoch: u64 = 315_532_800;
+/// Unix time of the latest time FAT can store (2107-12-31 23:59:58).
+const FAT_end: u64 = 4_354_819_198;
+
+/// Short (8.3) directory entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Short_entry_type {
+    Name: [u8; 11],
+    Attributes: u8,
+    Case: u8,
+    Creation_time: Time_type,
+    Access_time: Time_type,
+    Modification_time: Time_type,
+    First_cluster: u32,
+    Size: u32,
+}
+
+impl Short_entry_type {
+    pub fn New(Attributes: u8, First_cluster: u32, Time: Time_type) -> Self {
+        Self {
+            Name: [b' '; 11],
+            Attributes,
+            Case: 0,
+            Creation_time: Time,
+            Access_time: Time,
+            Modification_time: Time,
+            First_cluster,
+            Size: 0,
+        }
+    }
+
+    /// Create a "." or ".." entry.
+    pub fn New_dot(Name: &str, First_cluster: u32, Time: Time_type) -> Self {
+        let mut Entry = Self::New(Directory_attribute, First_cluster, Time);
+
+        Entry.Name[..Name.len()].copy_from_slice(Name.as_bytes());
+
+        Entry
+    }
+
+    pub fn From_bytes(Bytes: &[u8]) -> Self {
+        let Read_u16 = |Offset: usize| u16::from_le_bytes([Bytes[Offset], Bytes[Offset + 1]]);
+
+        let mut Name: [u8; 11] = Bytes[0..11].try_into().unwrap();
+
+        if Name[0] == Kanji_marker {
+            Name[0] = Free_marker;
+        }
+
+        Self {
+            Name,
+            Attributes: Bytes[11],
+            Case: Bytes[12],
+            Creation_time: From_FAT_time(Read_u16(16), Read_u16(14), Bytes[13]),
+            Access_time: From_FAT_time(Read_u16(18), 0, 0),
+            Modification_time: From_FAT_time(Read_u16(24), Read_u16(22), 0),
+            First_cluster: (Read_u16(20) as u32) << 16 | Read_u16(26) as u32,
+            Size: u32::from_le_bytes(Bytes[28..32].try_into().unwrap()),
+        }
+    }
+
+    pub fn To_bytes(&self) -> [u8; Entry_size] {
+        let mut Bytes = [0; Entry_size];
+
+        Bytes[0..11].copy_from_slice(&self.Name);
+
+        if Bytes[0] == Free_marker {
+            Bytes[0] = Kanji_marker;
+        }
+
+        Bytes[11] = self.Attributes;
+        Bytes[12] = self.Case;
+
+        let (Date, Time, Hundredths) = To_FAT_time(self.Creation_time);
+        Bytes[13] = Hundredths;
+        Bytes[14..16].copy_from_slice(&Time.to_le_bytes());
+        Bytes[16..18].copy_from_slice(&Date.to_le_bytes());
+
+        let (Date, _, _) = To_FAT_time(self.Access_time);
+        Bytes[18..20].copy_from_slice(&Date.to_le_bytes());
+
+        Bytes[20..22].copy_from_slice(&((self.First_cluster >> 16) as u16).to_le_bytes());
+
+        let (Date, Time, _) = To_FAT_time(self.Modification_time);
+        Bytes[22..24].copy_from_slice(&Time.to_le_bytes());
+        Bytes[24..26].copy_from_slice(&Date.to_le_bytes());
+
+        Bytes[26..28].copy_from_slice(&(self.First_cluster as u16).to_le_bytes());
+        Bytes[28..32].copy_from_slice(&self.Size.to_le_bytes());
+
+        Bytes
+    }
+
+    pub fn Set_short_name(&mut self, Name: [u8; 11], Case: u8) {
+        self.Name = Name;
+        self.Case = Case;
+    }
+
+    /// Get the name of the entry in the 8.3 format, using the case flags.
+    pub fn Get_name(&self) -> String {
+        let Convert = |Bytes: &[u8], Lowercase: bool| {
+            Bytes
+                .iter()
+                .map(|Byte| {
+                    if Lowercase {
+                        Byte.to_ascii_lowercase() as char
+                    } else {
+                        *Byte as char
+                    }
+                })
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        };
+
+        let Base = Convert(&self.Name[..8], self.Case & Lowercase_base != 0);
+        let Extension = Convert(&self.Name[8..], self.Case & Lowercase_extension != 0);
+
+        if Extension.is_empty() {
+            Base
+        } else {
+            format!("{}.{}", Base, Extension)
+        }
+    }
+
+    /// Get the checksum of the short name, stored in the associated long name entries.
+    pub fn Get_checksum(&self) -> u8 {
+        self.Name
+            .iter()
+            .fold(0_u8, |Sum, Byte| Sum.rotate_right(1).wrapping_add(*Byte))
+    }
+
+    pub fn Get_attributes(&self) -> u8 {
+        self.Attributes
+    }
+
+    pub fn Set_attributes(&mut self, Attributes: u8) {
+        self.Attributes = Attributes;
+    }
+
+    pub fn Is_directory(&self) -> bool {
+        self.Attributes & Directory_attribute != 0
+    }
+
+    pub fn Is_read_only(&self) -> bool {
+        self.Attributes & Read_only_attribute != 0
+    }
+
+    /// Check if the entry is "." or "..".
+    pub fn Is_dot(&self) -> bool {
+        self.Name[0] == b'.'
+    }
+
+    pub fn Get_first_cluster(&self) -> u32 {
+        self.First_cluster
+    }
+
+    pub fn Set_first_cluster(&mut self, First_cluster: u32) {
+        self.First_cluster = First_cluster;
+    }
+
+    pub fn Get_size(&self) -> u32 {
+        self.Size
+    }
+
+    pub fn Set_size(&mut self, Size: u32) {
+        self.Size = Size;
+    }
+
+    pub fn Get_creation_time(&self) -> Time_type {
+        self.Creation_time
+    }
+
+    pub fn Get_access_time(&self) -> Time_type {
+        self.Access_time
+    }
+
+    pub fn Get_modification_time(&self) -> Time_type {
+        self.Modification_time
+    }
+
+    pub fn Set_creation_time(&mut self, Time: Time_type) {
+        self.Creation_time = Time;
+    }
+
+    pub fn Set_access_time(&mut self, Time: Time_type) {
+        self.Access_time = Time;
+    }
+
+    pub fn Set_modification_time(&mut self, Time: Time_type) {
+        self.Modification_time = Time;
+    }
+}
+
+/// Convert a FAT date and time (local time, considered as UTC) to a Unix time.
+///
+/// A null date is converted to the FAT epoch.
+pub fn From_FAT_time(Date: u16, Time: u16, Hundredths: u8) -> Time_type {
+    if Date == 0 {
+        return Time_type::New(FAT_epoch);
+    }
+
+    let Year = 1980 + (Date >> 9);
+    let Month = ((Date >> 5) & 0x0F).clamp(1, 12) as u8;
+    let Day = (Date & 0x1F).max(1) as u8;
+    let Hour = (Time >> 11) as u8;
+    let Minute = ((Time >> 5) & 0x3F) as u8;
+    let Second = ((Time & 0x1F) * 2) as u8 + Hundredths.min(199) / 100;
+
+    Time_type::New(Human_to_unix_time(Year, Month, Day, Hour, Minute, Second).max(0) as u64)
+}
+
+/// Convert a Unix time to a FAT date, time and hundredths of seconds.
+///
+/// The time is clamped to the range representable by FAT (1980 to 2107).
+pub fn To_FAT_time(Time: Time_type) -> (u16, u16, u8) {
+    let Time = Time.As_u64().clamp(FAT_epoch, FAT_end);
+
+    let (Year, Month, Day, Hour, Minute, Second) = Unix_to_human_time(Time as i64);
+
+    let Date = ((Year - 1980) << 9) | (Month as u16) << 5 | Day as u16;
+    let Time = (Hour as u16) << 11 | (Minute as u16) << 5 | (Second as u16 / 2);
+
+    (Date, Time, (Second % 2) * 100)
+}
+
+fn Is_short_name_character(Character: char) -> bool {
+    Character.is_ascii_alphanumeric() || Short_name_special_characters.contains(Character)
+}
+
+/// Check if a name can be stored in long name entries.
+pub fn Is_valid_name(Name: &str) -> bool {
+    !Name.is_empty()
+        && Name != "."
+        && Name != ".."
+        && Name.encode_utf16().count() <= Maximum_long_name_length
+        && !Name
+            .chars()
+            .any(|Character| Character < ' ' || Long_name_invalid_characters.contains(Character))
+}
+
+/// Get the short name and case flags of `Name` if it can be stored without long name entries.
+pub fn Get_short_name(Name: &str) -> Option<([u8; 11], u8)> {
+    let (Base, Extension) = match Name.rsplit_once('.') {
+        Some((Base, Extension)) => (Base, Extension),
+        None => (Name, ""),
+    };
+
+    if Base.is_empty()
+        || Base.len() > 8
+        || Extension.len() > 3
+        || (Name.contains('.') && Extension.is_empty())
+        || !Base
+            .chars()
+            .chain(Extension.chars())
+            .all(Is_short_name_character)
+    {
+        return None;
+    }
+
+    // - Each part must have a single case to be represented with the case flags.
+    let Get_case = |Part: &str| -> Option<u8> {
+        let Lower = Part.chars().any(|Character| Character.is_ascii_lowercase());
+        let Upper = Part.chars().any(|Character| Character.is_ascii_uppercase());
+
+        match (Lower, Upper) {
+            (true, true) => None,
+            (true, false) => Some(1),
+            _ => Some(0),
+        }
+    };
+
+    let Case = (Get_case(Base)? * Lowercase_base) | (Get_case(Extension)? * Lowercase_extension);
+
+    let mut Short_name = [b' '; 11];
+
+    for (i, Byte) in Base.bytes().enumerate() {
+        Short_name[i] = Byte.to_ascii_uppercase();
+    }
+
+    for (i, Byte) in Extension.bytes().enumerate() {
+        Short_name[8 + i] = Byte.to_ascii_uppercase();
+    }
+
+    Some((Short_name, Case))
+}
+
+/// Generate a unique short name alias (`BASIS~N.EXT`) for a long name.
+pub fn Get_short_name_alias(
+    Name: &str,
+    Exists: impl Fn(&[u8; 11]) -> bool,
+) -> Result_type<[u8; 11]> {
+    let Convert = |Part: &str| -> Vec<u8> {
+        Part.chars()
+            .filter(|Character| *Character != ' ' && *Character != '.')
+            .map(|Character| {
+                if Is_short_name_character(Character) {
+                    Character.to_ascii_uppercase() as u8
+                } else {
+                    b'_'
+                }
+            })
+            .collect()
+    };
+
+    let Name = Name.trim_start_matches('.');
+
+    let (Base, Extension) = match Name.rsplit_once('.') {
+        Some((Base, Extension)) => (Convert(Base), Convert(Extension)),
+        None => (Convert(Name), Vec::new()),
+    };
+
+    let Base = if Base.is_empty() { vec![b'_'] } else { Base };
+
+    let mut Short_name = [b' '; 11];
+
+    for (i, Byte) in Extension.iter().take(3).enumerate() {
+        Short_name[8 + i] = *Byte;
+    }
+
+    for Number in 1..1_000_000_u32 {
+        let Tail = format!("~{}", Number);
+
+        let Base_length = Base.len().min(8 - Tail.len());
+
+        Short_name[..8].fill(b' ');
+        Short_name[..Base_length].copy_from_slice(&Base[..Base_length]);
+        Short_name[Base_length..Base_length + Tail.len()].copy_from_slice(Tail.as_bytes());
+
+        if !Exists(&Short_name) {
+            return Ok(Short_name);
+        }
+    }
+
+    Err(Error_type::Already_exists)
+}
+
+/// Get the long name entries of a name, in their on-disk order (last part first).
+pub fn Get_long_name_entries(Name: &str, Checksum: u8) -> Vec<[u8; Entry_size]> {
+    let mut Characters: Vec<u16> = Name.encode_utf16().collect();
+
+    // - The name is null terminated (if it doesn't fill the last entry) and padded with 0xFFFF.
+    let Count = Characters.len().div_ceil(Long_name_characters_per_entry);
+
+    if Characters.len() < Count * Long_name_characters_per_entry {
+        Characters.push(0);
+    }
+
+    Characters.resize(Count * Long_name_characters_per_entry, 0xFFFF);
+
+    (0..Count)
+        .rev()
+        .map(|Index| {
+            let mut Bytes = [0; Entry_size];
+
+            Bytes[0] = (Index + 1) as u8;
+
+            if Index + 1 == Count {
+                Bytes[0] |= Last_long_name_entry;
+            }
+
+            Bytes[11] = Long_name_attribute;
+            Bytes[13] = Checksum;
+
+            let Part = &Characters[Index * Long_name_characters_per_entry..];
+
+            for (Offset, Character) in Long_name_character_offsets.iter().zip(Part) {
+                Bytes[*Offset..*Offset + 2].copy_from_slice(&Character.to_le_bytes());
+            }
+
+            Bytes
+        })
+        .collect()
+}
+
+/// Accumulator of the long name entries preceding a short entry.
+#[derive(Default)]
+pub struct Long_name_type {
+    Characters: Vec<u16>,
+    Checksum: u8,
+    Expected: u8,
+    Count: usize,
+}
+
+impl Long_name_type {
+    /// Add a long name entry, long name entries are stored in reverse order.
+    pub fn Push(&mut self, Bytes: &[u8]) {
+        let Ordinal = Bytes[0] & !Last_long_name_entry;
+
+        if Bytes[0] & Last_long_name_entry != 0 {
+            *self = Self {
+                Characters: vec![0xFFFF; Ordinal as usize * Long_name_characters_per_entry],
+                Checksum: Bytes[13],
+                Expected: Ordinal,
+                Count: 0,
+            };
+        } else if Ordinal == 0 || Ordinal != self.Expected || Bytes[13] != self.Checksum {
+            self.Clear();
+            return;
+        }
+
+        if Ordinal == 0 {
+            self.Clear();
+            return;
+        }
+
+        let Start = (Ordinal as usize - 1) * Long_name_characters_per_entry;
+
+        for (i, Offset) in Long_name_character_offsets.iter().enumerate() {
+            self.Characters[Start + i] = u16::from_le_bytes([Bytes[*Offset], Bytes[Offset + 1]]);
+        }
+
+        self.Expected = Ordinal - 1;
+        self.Count += 1;
+    }
+
+    pub fn Clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Get the count of long name entries accumulated.
+    pub fn Get_count(&self) -> usize {
+        self.Count
+    }
+
+    /// Get the long name if it is complete and belongs to the short entry.
+    pub fn Get_name(&self, Entry: &Short_entry_type) -> Option<String> {
+        if self.Count == 0 || self.Expected != 0 || self.Checksum != Entry.Get_checksum() {
+            return None;
+        }
+
+        let Length = self
+            .Characters
+            .iter()
+            .position(|Character| *Character == 0 || *Character == 0xFFFF)
+            .unwrap_or(self.Characters.len());
+
+        String::from_utf16(&self.Characters[..Length]).ok()
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_short_name() {
+        assert_eq!(Get_short_name("README.TXT"), Some((*b"README  TXT", 0)));
+        assert_eq!(
+            Get_short_name("readme.txt"),
+            Some((*b"README  TXT", Lowercase_base | Lowercase_extension))
+        );
+        assert_eq!(Get_short_name("Test0"), None);
+        assert_eq!(Get_short_name("too_long_name"), None);
+        assert_eq!(Get_short_name("a.b.c"), None);
+        assert_eq!(Get_short_name("space name"), None);
+
+        let mut Entry = Short_entry_type::New(0, 0, Time_type::New(0));
+        let (Name, Case) = Get_short_name("data.bin").unwrap();
+        Entry.Set_short_name(Name, Case);
+        assert_eq!(Entry.Get_name(), "data.bin");
+    }
+
+    #[test]
+    fn Test_short_name_alias() {
+        assert_eq!(
+            Get_short_name_alias("Long file name.text", |_| false).unwrap(),
+            *b"LONGFI~1TEX"
+        );
+
+        assert_eq!(
+            Get_short_name_alias("Long file name.text", |Name| Name == b"LONGFI~1TEX").unwrap(),
+            *b"LONGFI~2TEX"
+        );
+    }
+
+    #[test]
+    fn Test_long_name() {
+        let Name = "A long file name with ünicode.txt";
+
+        let Entry = Short_entry_type::New(0, 0, Time_type::New(0));
+
+        let Entries = Get_long_name_entries(Name, Entry.Get_checksum());
+
+        assert_eq!(Entries.len(), 3);
+
+        let mut Long_name = Long_name_type::default();
+
+        for Bytes in &Entries {
+            Long_name.Push(Bytes);
+        }
+
+        assert_eq!(Long_name.Get_count(), 3);
+        assert_eq!(Long_name.Get_name(&Entry).unwrap(), Name);
+
+        assert!(Is_valid_name(Name));
+        assert!(!Is_valid_name("a:b"));
+        assert!(!Is_valid_name(".."));
+    }
+
+    #[test]
+    fn Test_time() {
+        // - 2024-02-29 13:37:42
+        let Time = Time_type::New(1_709_213_862);
+
+        let (Date, Hour, Hundredths) = To_FAT_time(Time);
+
+        assert_eq!(From_FAT_time(Date, Hour, Hundredths), Time);
+
+        // - Out of range times are clamped.
+        let (Date, Hour, Hundredths) = To_FAT_time(Time_type::New(123));
+
+        assert_eq!(
+            From_FAT_time(Date, Hour, Hundredths),
+            Time_type::New(FAT_epoch)
+        );
+    }
+
+    #[test]
+    fn Test_entry_round_trip() {
+        let mut Entry = Short_entry_type::New(
+            Archive_attribute,
+            0x12345,
+            Time_type::New(FAT_epoch + 86_400),
+        );
+        Entry.Set_short_name(*b"\xE5BC     TXT", 0);
+        Entry.Set_size(42);
+
+        assert_eq!(Entry.To_bytes()[0], Kanji_marker);
+        assert_eq!(Short_entry_type::From_bytes(&Entry.To_bytes()), Entry);
+    }
+}
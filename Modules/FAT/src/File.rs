@@ -0,0 +1,290 @@
+use File_system::{
+    Error_type, Flags_type, Mode_type, Path_owned_type, Path_type, Position_type, Result_type,
+    Time_type,
+};
+
+use crate::{
+    Archive_attribute, Directory_type, Location_type, Resolve, Resolve_parent, Resolved_type,
+    Short_entry_type, Volume_type,
+};
+
+/// Open file, referring to its directory entry.
+///
+/// The size and the clusters of the file are read from the entry at each access, so that the
+/// duplicated file identifiers stay consistent.
+#[derive(Debug, Clone)]
+pub struct File_type {
+    Parent: Location_type,
+    Index: usize,
+    Path: Path_owned_type,
+    Flags: Flags_type,
+    Position: u64,
+}
+
+impl File_type {
+    /// Open a file, creating it if asked.
+    pub fn Open(
+        Volume: &mut Volume_type,
+        Path: &Path_type,
+        Flags: Flags_type,
+        Time: Time_type,
+        Read_only: bool,
+    ) -> Result_type<Self> {
+        let Open = Flags.Get_open();
+
+        let Writable = Flags.Get_mode().Get_write() || Open.Get_truncate();
+
+        let (Parent, Index, Created) = match Resolve(Volume, Path) {
+            Ok(Resolved_type::Root) => return Err(Error_type::Is_directory),
+            Ok(Resolved_type::Entry(Parent, Found)) => {
+                if Open.Get_exclusive() {
+                    return Err(Error_type::Already_exists);
+                }
+
+                if Found.Get_entry().Is_directory() {
+                    return Err(Error_type::Is_directory);
+                }
+
+                if Writable && (Read_only || Found.Get_entry().Is_read_only()) {
+                    return Err(Error_type::Permission_denied);
+                }
+
+                (Parent, Found.Get_index(), false)
+            }
+            Err(Error_type::Not_found) if Open.Get_create() => {
+                if Read_only {
+                    return Err(Error_type::Permission_denied);
+                }
+
+                let (Parent, Name) = Resolve_parent(Volume, Path)?;
+
+                let Found = Directory_type::Read(Volume, Parent)?.Insert(
+                    Volume,
+                    Name,
+                    Short_entry_type::New(Archive_attribute, 0, Time),
+                )?;
+
+                (Parent, Found.Get_index(), true)
+            }
+            Err(Error) => return Err(Error),
+        };
+
+        let File = Self {
+            Parent,
+            Index,
+            Path: Path.to_owned(),
+            Flags,
+            Position: 0,
+        };
+
+        if Open.Get_truncate() && !Created {
+            let mut Entry = File.Get_entry(Volume)?;
+
+            Volume.Free_chain(Entry.Get_first_cluster())?;
+
+            Entry.Set_first_cluster(0);
+            Entry.Set_size(0);
+            Entry.Set_modification_time(Time);
+
+            File.Set_entry(Volume, &Entry)?;
+        }
+
+        Ok(File)
+    }
+
+    pub fn Get_entry(&self, Volume: &Volume_type) -> Result_type<Short_entry_type> {
+        self.Parent.Read_entry(Volume, self.Index)
+    }
+
+    fn Set_entry(&self, Volume: &Volume_type, Entry: &Short_entry_type) -> Result_type<()> {
+        self.Parent.Write_entry(Volume, self.Index, Entry)
+    }
+
+    /// Check if the file refers to an entry.
+    pub fn Is_entry(&self, Parent: Location_type, Index: usize) -> bool {
+        self.Parent == Parent && self.Index == Index
+    }
+
+    /// Update the entry and the path of the file after a rename.
+    pub fn Set_entry_location(&mut self, Parent: Location_type, Index: usize) {
+        self.Parent = Parent;
+        self.Index = Index;
+    }
+
+    pub fn Get_path(&self) -> &Path_type {
+        &self.Path
+    }
+
+    pub fn Set_path(&mut self, Path: Path_owned_type) {
+        self.Path = Path;
+    }
+
+    pub fn Get_mode(&self) -> Mode_type {
+        self.Flags.Get_mode()
+    }
+
+    pub fn Read(&mut self, Volume: &Volume_type, Buffer: &mut [u8]) -> Result_type<usize> {
+        if !self.Flags.Get_mode().Get_read() {
+            return Err(Error_type::Invalid_mode);
+        }
+
+        let Entry = self.Get_entry(Volume)?;
+
+        let Size = Entry.Get_size() as u64;
+
+        if self.Position >= Size {
+            return Ok(0);
+        }
+
+        let Length = (Buffer.len() as u64).min(Size - self.Position) as usize;
+
+        let Chain = Volume.Get_chain(Entry.Get_first_cluster())?;
+
+        let Cluster_size = Volume.Get_cluster_size() as u64;
+
+        let mut Read = 0;
+
+        while Read < Length {
+            let Position = self.Position + Read as u64;
+
+            let Cluster = Chain
+                .get((Position / Cluster_size) as usize)
+                .ok_or(Error_type::Corrupted)?;
+
+            let Offset = Position % Cluster_size;
+
+            let Chunk = ((Cluster_size - Offset) as usize).min(Length - Read);
+
+            Volume.Read(
+                Volume.Get_boot_sector().Get_cluster_offset(*Cluster) + Offset,
+                &mut Buffer[Read..Read + Chunk],
+            )?;
+
+            Read += Chunk;
+        }
+
+        self.Position += Read as u64;
+
+        Ok(Read)
+    }
+
+    /// Write data at an offset of the file, allocating the missing clusters.
+    fn Write_at(
+        Volume: &mut Volume_type,
+        Entry: &mut Short_entry_type,
+        Offset: u64,
+        Data: &[u8],
+    ) -> Result_type<()> {
+        let Cluster_size = Volume.Get_cluster_size() as u64;
+
+        let End = Offset + Data.len() as u64;
+
+        let mut Chain = Volume.Get_chain(Entry.Get_first_cluster())?;
+
+        while (Chain.len() as u64) < End.div_ceil(Cluster_size) {
+            let Cluster = Volume.Allocate_cluster(Chain.last().copied(), false)?;
+
+            if Chain.is_empty() {
+                Entry.Set_first_cluster(Cluster);
+            }
+
+            Chain.push(Cluster);
+        }
+
+        let mut Written = 0;
+
+        while Written < Data.len() {
+            let Position = Offset + Written as u64;
+
+            let Cluster = Chain[(Position / Cluster_size) as usize];
+
+            let Cluster_offset = Position % Cluster_size;
+
+            let Chunk = ((Cluster_size - Cluster_offset) as usize).min(Data.len() - Written);
+
+            Volume.Write(
+                Volume.Get_boot_sector().Get_cluster_offset(Cluster) + Cluster_offset,
+                &Data[Written..Written + Chunk],
+            )?;
+
+            Written += Chunk;
+        }
+
+        if End > Entry.Get_size() as u64 {
+            Entry.Set_size(End as u32);
+        }
+
+        Ok(())
+    }
+
+    pub fn Write(
+        &mut self,
+        Volume: &mut Volume_type,
+        Buffer: &[u8],
+        Time: Time_type,
+    ) -> Result_type<usize> {
+        if !self.Flags.Get_mode().Get_write() {
+            return Err(Error_type::Invalid_mode);
+        }
+
+        let mut Entry = self.Get_entry(Volume)?;
+
+        if self.Flags.Get_status().Get_append() {
+            self.Position = Entry.Get_size() as u64;
+        }
+
+        if self.Position + Buffer.len() as u64 > u32::MAX as u64 {
+            return Err(Error_type::File_too_large);
+        }
+
+        // - Fill the gap between the end of the file and the position with zeroes.
+        let mut Result = Ok(());
+
+        while Result.is_ok() && (Entry.Get_size() as u64) < self.Position {
+            let Size = Entry.Get_size() as u64;
+
+            let Zeroes =
+                vec![0; (self.Position - Size).min(Volume.Get_cluster_size() as u64) as usize];
+
+            Result = Self::Write_at(Volume, &mut Entry, Size, &Zeroes);
+        }
+
+        if Result.is_ok() {
+            Result = Self::Write_at(Volume, &mut Entry, self.Position, Buffer);
+        }
+
+        // - The entry is updated even on failure, to keep track of the allocated clusters.
+        Entry.Set_attributes(Entry.Get_attributes() | Archive_attribute);
+        Entry.Set_modification_time(Time);
+
+        self.Set_entry(Volume, &Entry)?;
+
+        Result?;
+
+        self.Position += Buffer.len() as u64;
+
+        Ok(Buffer.len())
+    }
+
+    pub fn Set_position(
+        &mut self,
+        Volume: &Volume_type,
+        Position: &Position_type,
+    ) -> Result_type<u64> {
+        let New_position = match Position {
+            Position_type::Start(Position) => *Position as i128,
+            Position_type::Current(Offset) => self.Position as i128 + *Offset as i128,
+            Position_type::End(Offset) => {
+                self.Get_entry(Volume)?.Get_size() as i128 + *Offset as i128
+            }
+        };
+
+        if New_position < 0 || New_position > u32::MAX as i128 {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        self.Position = New_position as u64;
+
+        Ok(self.Position)
+    }
+}
@@ -0,0 +1,1061 @@
+use std::{collections::BTreeMap, sync::RwLock};
+
+use File_system::{
+    Device_type, Entry_type, Error_type, File_identifier_inner_type, File_identifier_type,
+    File_system_identifier_type, File_system_statistics_type, File_system_traits, Flags_type,
+    Get_new_file_identifier, Inode_type, Local_file_identifier_type, Metadata_type, Mode_type,
+    Path_owned_type, Path_type, Position_type, Result_type, Size_type, Statistics_type, Time_type,
+    Type_type,
+};
+use Users::{Group_identifier_type, User_identifier_type};
+
+use crate::{
+    Boot_sector_type, Directory_type, File_type, Mount_options_type, Open_directory_type,
+    Read_only_attribute, Resolve, Resolve_parent, Resolved_type, Volume_type,
+};
+
+const Volume_label: &str = "XILA";
+
+struct Inner_type {
+    Volume: Volume_type,
+    Open_files: BTreeMap<Local_file_identifier_type, File_type>,
+    Open_directories: BTreeMap<Local_file_identifier_type, Open_directory_type>,
+}
+
+/// FAT16 / FAT32 file system.
+///
+/// exFAT volumes are refused with [`Error_type::Unsupported_operation`] : exFAT has its own layout (allocation bitmap,
+/// checksummed entry sets, up-case table) and would be a separate backend, it is not implemented yet.
+pub struct File_system_type {
+    Inner: RwLock<Inner_type>,
+    Options: Mount_options_type,
+}
+
+/// Get the lowercase form of a path, to compare them (FAT names are case insensitive).
+fn Get_lowercase_path(Path: &Path_type) -> String {
+    Path.As_str().to_lowercase()
+}
+
+impl File_system_type {
+    pub fn New(Device: Device_type, Options: Mount_options_type) -> Result_type<Self> {
+        let Boot_sector = Volume_type::Read_boot_sector(&Device)?;
+
+        let Volume = Volume_type::New(Device, Boot_sector);
+
+        // - The free clusters count isn't maintained, invalidate it for the other systems.
+        if !Options.Is_read_only() {
+            Volume.Write_file_system_information()?;
+        }
+
+        Ok(Self {
+            Inner: RwLock::new(Inner_type {
+                Volume,
+                Open_files: BTreeMap::new(),
+                Open_directories: BTreeMap::new(),
+            }),
+            Options,
+        })
+    }
+
+    /// Format a device, FAT32 is used for devices of more than ~32 MB, FAT16 otherwise.
+    pub fn Format(Device: Device_type) -> Result_type<()> {
+        let Size = u64::from(Device.Get_size()?);
+
+        // - The volume identifier is usually derived from the format time.
+        let Volume_identifier = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|Duration| Duration.as_secs() as u32 ^ Duration.subsec_nanos())
+            .unwrap_or(0);
+
+        let Boot_sector = Boot_sector_type::New(
+            Size / crate::Sector_size as u64,
+            Volume_identifier,
+            Volume_label,
+        )?;
+
+        Volume_type::Format(&Device, &Boot_sector)
+    }
+
+    pub fn Get_options(&self) -> &Mount_options_type {
+        &self.Options
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    const Directory_flag: File_identifier_inner_type = 1 << 31;
+    #[cfg(target_pointer_width = "32")]
+    const Directory_flag: File_identifier_inner_type = 1 << 15;
+
+    const Directory_minimum: File_identifier_type = File_identifier_type::New(Self::Directory_flag);
+
+    pub fn Is_file(File: Local_file_identifier_type) -> bool {
+        File.Split().1 < Self::Directory_minimum
+    }
+
+    fn Check_writable(&self) -> Result_type<()> {
+        if self.Options.Is_read_only() {
+            return Err(Error_type::Permission_denied);
+        }
+
+        Ok(())
+    }
+
+    /// Get the metadata of a path from its FAT entry, the owner and the permissions being emulated (see [`Mount_options_type`]).
+    ///
+    /// The modification times have a 2 seconds precision, the access times only keep the date.
+    fn Get_path_metadata(
+        &self,
+        Inner: &Inner_type,
+        Path: &Path_type,
+    ) -> Result_type<Metadata_type> {
+        let Metadata = match Resolve(&Inner.Volume, Path)? {
+            Resolved_type::Root => Metadata_type::Get_default(
+                Type_type::Directory,
+                crate::From_FAT_time(0, 0, 0),
+                self.Options.Get_user(),
+                self.Options.Get_group(),
+            )
+            .ok_or(Error_type::Internal_error)?,
+            Resolved_type::Entry(_, Found) => {
+                let Entry = Found.Get_entry();
+
+                let mut Metadata = Metadata_type::Get_default(
+                    Found.Get_type(),
+                    Entry.Get_creation_time(),
+                    self.Options.Get_user(),
+                    self.Options.Get_group(),
+                )
+                .ok_or(Error_type::Internal_error)?;
+
+                Metadata.Set_modification_time(Entry.Get_modification_time());
+                Metadata.Set_access_time(Entry.Get_access_time());
+                Metadata.Set_permissions(
+                    self.Options
+                        .Get_permissions(Found.Get_type(), Entry.Is_read_only()),
+                );
+
+                Metadata
+            }
+        };
+
+        Ok(Metadata)
+    }
+
+    fn Get_path_statistics(
+        &self,
+        Inner: &Inner_type,
+        Path: &Path_type,
+    ) -> Result_type<Statistics_type> {
+        let Metadata = self.Get_path_metadata(Inner, Path)?;
+
+        let (Inode, Size) = match Resolve(&Inner.Volume, Path)? {
+            Resolved_type::Root => (Inner.Volume.Get_boot_sector().Get_root_cluster(), 0),
+            Resolved_type::Entry(_, Found) => (
+                Found.Get_entry().Get_first_cluster(),
+                Found.Get_entry().Get_size(),
+            ),
+        };
+
+        Ok(Statistics_type::New(
+            File_system_identifier_type::New(0),
+            Metadata
+                .Get_inode()
+                .unwrap_or(Inode_type::New(Inode as u64)),
+            1,
+            Size_type::New(Size as u64),
+            Metadata.Get_access_time(),
+            Metadata.Get_modification_time(),
+            Metadata.Get_modification_time(),
+            Metadata.Get_type(),
+            Metadata.Get_permissions(),
+            Metadata.Get_user(),
+            Metadata.Get_group(),
+        ))
+    }
+}
+
+impl File_system_traits for File_system_type {
+    fn Open(
+        &self,
+        Task: Task::Task_identifier_type,
+        Path: &Path_type,
+        Flags: Flags_type,
+        Time: Time_type,
+        _: User_identifier_type,
+        _: Group_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let File = File_type::Open(
+            &mut Inner.Volume,
+            Path,
+            Flags,
+            Time,
+            self.Options.Is_read_only(),
+        )?;
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(File_identifier_type::Minimum),
+            Some(Self::Directory_minimum),
+            &Inner.Open_files,
+        )?;
+
+        if Inner.Open_files.insert(File_identifier, File).is_some() {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Close(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        Inner
+            .Open_files
+            .remove(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Inner.Volume.Flush()
+    }
+
+    fn Close_all(&self, Task: Task::Task_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        Inner.Open_files.retain(|Key, _| Key.Split().0 != Task);
+        Inner
+            .Open_directories
+            .retain(|Key, _| Key.Split().0 != Task);
+
+        Inner.Volume.Flush()
+    }
+
+    fn Duplicate(
+        &self,
+        File: Local_file_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let (Task, _) = File.Split();
+
+        let mut Inner = self.Inner.write()?;
+
+        let File = Inner
+            .Open_files
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?
+            .clone();
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(File_identifier_type::Minimum),
+            Some(Self::Directory_minimum),
+            &Inner.Open_files,
+        )?;
+
+        if Inner.Open_files.insert(File_identifier, File).is_some() {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Transfert(
+        &self,
+        New_task: Task::Task_identifier_type,
+        File_identifier: Local_file_identifier_type,
+        New_file: Option<File_identifier_type>,
+    ) -> Result_type<Local_file_identifier_type> {
+        let mut Inner = self.Inner.write()?;
+
+        if Self::Is_file(File_identifier) {
+            let File = Inner
+                .Open_files
+                .remove(&File_identifier)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            let New_identifier = match New_file {
+                Some(New_file) => Local_file_identifier_type::New(New_task, New_file),
+                None => Get_new_file_identifier(
+                    New_task,
+                    Some(File_identifier_type::Minimum),
+                    Some(Self::Directory_minimum),
+                    &Inner.Open_files,
+                )?,
+            };
+
+            if Inner.Open_files.contains_key(&New_identifier) {
+                Inner.Open_files.insert(File_identifier, File);
+
+                return Err(Error_type::Invalid_identifier);
+            }
+
+            Inner.Open_files.insert(New_identifier, File);
+
+            Ok(New_identifier)
+        } else {
+            let Directory = Inner
+                .Open_directories
+                .remove(&File_identifier)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            let New_identifier = match New_file {
+                Some(New_file) => Local_file_identifier_type::New(New_task, New_file),
+                None => Get_new_file_identifier(
+                    New_task,
+                    Some(Self::Directory_minimum),
+                    Some(File_identifier_type::Maximum),
+                    &Inner.Open_directories,
+                )?,
+            };
+
+            if Inner.Open_directories.contains_key(&New_identifier) {
+                Inner.Open_directories.insert(File_identifier, Directory);
+
+                return Err(Error_type::Invalid_identifier);
+            }
+
+            Inner.Open_directories.insert(New_identifier, Directory);
+
+            Ok(New_identifier)
+        }
+    }
+
+    fn Remove(&self, Path: &Path_type) -> Result_type<()> {
+        self.Check_writable()?;
+
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let (Parent, Found) = match Resolve(&Inner.Volume, Path)? {
+            Resolved_type::Root => return Err(Error_type::Permission_denied),
+            Resolved_type::Entry(Parent, Found) => (Parent, Found),
+        };
+
+        if Inner
+            .Open_files
+            .values()
+            .any(|File| File.Is_entry(Parent, Found.Get_index()))
+        {
+            return Err(Error_type::Ressource_busy);
+        }
+
+        if Found.Get_entry().Is_directory()
+            && !Directory_type::Read(&Inner.Volume, Found.Get_location(&Inner.Volume)?)?.Is_empty()
+        {
+            return Err(Error_type::Directory_not_empty);
+        }
+
+        Directory_type::Read(&Inner.Volume, Parent)?.Remove(&Inner.Volume, &Found)?;
+
+        Inner
+            .Volume
+            .Free_chain(Found.Get_entry().Get_first_cluster())?;
+
+        Inner.Volume.Flush()
+    }
+
+    fn Read(
+        &self,
+        File: Local_file_identifier_type,
+        Buffer: &mut [u8],
+        _: Time_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(File.Read(&Inner.Volume, Buffer)?.into())
+    }
+
+    fn Write(
+        &self,
+        File: Local_file_identifier_type,
+        Buffer: &[u8],
+        Time: Time_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(File.Write(&mut Inner.Volume, Buffer, Time)?.into())
+    }
+
+    fn Rename(&self, Source: &Path_type, Destination: &Path_type) -> Result_type<()> {
+        self.Check_writable()?;
+
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let (Source_parent, Found) = match Resolve(&Inner.Volume, Source)? {
+            Resolved_type::Root => return Err(Error_type::Permission_denied),
+            Resolved_type::Entry(Parent, Found) => (Parent, Found),
+        };
+
+        // - A directory can't be moved into itself.
+        if Get_lowercase_path(Destination).starts_with(&format!("{}/", Get_lowercase_path(Source)))
+        {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        let (Destination_parent, Name) = Resolve_parent(&Inner.Volume, Destination)?;
+
+        let mut Destination_directory = Directory_type::Read(&Inner.Volume, Destination_parent)?;
+
+        let mut Source_removed = false;
+
+        // - Replace the existing destination (if it isn't the source itself, e.g. when changing the case of a name).
+        if let Some(Existing) = Destination_directory.Find(Name) {
+            let Is_source =
+                Destination_parent == Source_parent && Existing.Get_index() == Found.Get_index();
+
+            if !Is_source {
+                match (
+                    Found.Get_entry().Is_directory(),
+                    Existing.Get_entry().Is_directory(),
+                ) {
+                    (false, true) => return Err(Error_type::Is_directory),
+                    (true, false) => return Err(Error_type::Not_directory),
+                    (true, true) => {
+                        if !Directory_type::Read(
+                            &Inner.Volume,
+                            Existing.Get_location(&Inner.Volume)?,
+                        )?
+                        .Is_empty()
+                        {
+                            return Err(Error_type::Directory_not_empty);
+                        }
+                    }
+                    (false, false) => {}
+                }
+
+                if Inner
+                    .Open_files
+                    .values()
+                    .any(|File| File.Is_entry(Destination_parent, Existing.Get_index()))
+                {
+                    return Err(Error_type::Ressource_busy);
+                }
+            }
+
+            Destination_directory.Remove(&Inner.Volume, &Existing)?;
+
+            if Is_source {
+                Source_removed = true;
+            } else {
+                Inner
+                    .Volume
+                    .Free_chain(Existing.Get_entry().Get_first_cluster())?;
+            }
+        }
+
+        // - Insert the new entry before removing the old one, to not lose the file on failure.
+        let Inserted =
+            Destination_directory.Insert(&mut Inner.Volume, Name, Found.Get_entry().clone())?;
+
+        if Destination_parent == Source_parent {
+            if !Source_removed {
+                Destination_directory.Remove(&Inner.Volume, &Found)?;
+            }
+        } else {
+            Directory_type::Read(&Inner.Volume, Source_parent)?.Remove(&Inner.Volume, &Found)?;
+
+            // - Update the parent reference of a moved directory.
+            if Found.Get_entry().Is_directory() {
+                let Location = Found.Get_location(&Inner.Volume)?;
+
+                let mut Parent_entry = Location.Read_entry(&Inner.Volume, 1)?;
+
+                Parent_entry.Set_first_cluster(Destination_parent.Get_cluster(&Inner.Volume));
+
+                Location.Write_entry(&Inner.Volume, 1, &Parent_entry)?;
+            }
+        }
+
+        // - Update the open files.
+        let Source_lowercase = Get_lowercase_path(Source);
+
+        for File in Inner.Open_files.values_mut() {
+            if File.Is_entry(Source_parent, Found.Get_index()) {
+                File.Set_entry_location(Destination_parent, Inserted.Get_index());
+                File.Set_path(Destination.to_owned());
+            } else if Get_lowercase_path(File.Get_path())
+                .starts_with(&format!("{}/", Source_lowercase))
+            {
+                let Path = format!(
+                    "{}{}",
+                    Destination.As_str(),
+                    &File.Get_path().As_str()[Source.As_str().len()..]
+                );
+
+                if let Some(Path) = Path_owned_type::New(Path) {
+                    File.Set_path(Path);
+                }
+            }
+        }
+
+        Inner.Volume.Flush()
+    }
+
+    fn Set_position(
+        &self,
+        File: Local_file_identifier_type,
+        Position: &Position_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(File.Set_position(&Inner.Volume, Position)?.into())
+    }
+
+    fn Flush(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        let Inner = self.Inner.read()?;
+
+        if !Inner.Open_files.contains_key(&File) {
+            return Err(Error_type::Invalid_identifier);
+        }
+
+        Inner.Volume.Flush()
+    }
+
+    fn Get_statistics(&self, File: Local_file_identifier_type) -> Result_type<Statistics_type> {
+        let Inner = self.Inner.read()?;
+
+        let Path = if let Some(File) = Inner.Open_files.get(&File) {
+            File.Get_path()
+        } else if let Some(Directory) = Inner.Open_directories.get(&File) {
+            Directory.Get_path()
+        } else {
+            return Err(Error_type::Invalid_identifier);
+        };
+
+        self.Get_path_statistics(&Inner, Path)
+    }
+
+    fn Get_mode(&self, File: Local_file_identifier_type) -> Result_type<Mode_type> {
+        let Inner = self.Inner.read()?;
+
+        let Result = if Self::Is_file(File) {
+            Inner
+                .Open_files
+                .get(&File)
+                .ok_or(Error_type::Invalid_identifier)?
+                .Get_mode()
+        } else {
+            Inner
+                .Open_directories
+                .get(&File)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            Mode_type::Read_only
+        };
+
+        Ok(Result)
+    }
+
+    fn Get_type_name(&self) -> &'static str {
+        "FAT"
+    }
+
+    fn Get_file_system_statistics(&self) -> Result_type<File_system_statistics_type> {
+        let Inner = self.Inner.read()?;
+
+        let Free_clusters = Inner.Volume.Get_free_cluster_count()? as u64;
+
+        // - FAT has no inode table, the directory entries are allocated in clusters too.
+        Ok(File_system_statistics_type::New(
+            Inner.Volume.Get_cluster_size() as u64,
+            Inner.Volume.Get_boot_sector().Get_cluster_count() as u64,
+            Free_clusters,
+            Free_clusters,
+            0,
+            0,
+        ))
+    }
+
+    fn Open_directory(
+        &self,
+        Path: &Path_type,
+        Task: Task::Task_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Directory = Open_directory_type::Open(&Inner.Volume, Path)?;
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(Self::Directory_minimum),
+            Some(File_identifier_type::Maximum),
+            &Inner.Open_directories,
+        )?;
+
+        if Inner
+            .Open_directories
+            .insert(File_identifier, Directory)
+            .is_some()
+        {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Read_directory(&self, File: Local_file_identifier_type) -> Result_type<Option<Entry_type>> {
+        let mut Inner = self.Inner.write()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(Directory.Read())
+    }
+
+    fn Set_position_directory(
+        &self,
+        File: Local_file_identifier_type,
+        Position: Size_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Directory.Set_position(Position.into());
+
+        Ok(())
+    }
+
+    fn Rewind_directory(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        self.Set_position_directory(File, Size_type::New(0))
+    }
+
+    fn Close_directory(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        Inner
+            .Open_directories
+            .remove(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(())
+    }
+
+    fn Create_directory(
+        &self,
+        Path: &Path_type,
+        Time: Time_type,
+        _: User_identifier_type,
+        _: Group_identifier_type,
+    ) -> Result_type<()> {
+        self.Check_writable()?;
+
+        let mut Inner = self.Inner.write()?;
+
+        let (Parent, Name) = Resolve_parent(&Inner.Volume, Path)?;
+
+        Directory_type::Read(&Inner.Volume, Parent)?.Create_directory(
+            &mut Inner.Volume,
+            Name,
+            Time,
+        )?;
+
+        Inner.Volume.Flush()
+    }
+
+    fn Get_position_directory(&self, File: Local_file_identifier_type) -> Result_type<Size_type> {
+        let Inner = self.Inner.read()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(Directory.Get_position().into())
+    }
+
+    /// Set the times of an entry and its read-only attribute, with the precision of FAT (see [`Self::Get_path_metadata`]).
+    ///
+    /// The owner can't be changed, nor the permissions except removing or restoring the write permissions of a file
+    /// (see [`Mount_options_type::Get_permissions`]) : these changes are [`Error_type::Unsupported_operation`].
+    fn Set_metadata_from_path(
+        &self,
+        Path: &Path_type,
+        Metadata: &Metadata_type,
+    ) -> Result_type<()> {
+        self.Check_writable()?;
+
+        if Metadata.Get_user() != self.Options.Get_user()
+            || Metadata.Get_group() != self.Options.Get_group()
+        {
+            return Err(Error_type::Unsupported_operation);
+        }
+
+        let Type = Metadata.Get_type();
+
+        let Read_only = if Metadata.Get_permissions() == self.Options.Get_permissions(Type, false) {
+            false
+        } else if Type != Type_type::Directory
+            && Metadata.Get_permissions() == self.Options.Get_permissions(Type, true)
+        {
+            true
+        } else {
+            // - Directories can't be read-only on FAT (the attribute has another meaning).
+            return Err(Error_type::Unsupported_operation);
+        };
+
+        let Inner = self.Inner.write()?;
+
+        let (Parent, Found) = match Resolve(&Inner.Volume, Path)? {
+            // - The root directory has no entry to store the times in.
+            Resolved_type::Root => return Err(Error_type::Unsupported_operation),
+            Resolved_type::Entry(Parent, Found) => (Parent, Found),
+        };
+
+        let mut Entry = Found.Get_entry().clone();
+
+        Entry.Set_creation_time(Metadata.Get_creation_time());
+        Entry.Set_modification_time(Metadata.Get_modification_time());
+        Entry.Set_access_time(Metadata.Get_access_time());
+
+        if !Entry.Is_directory() {
+            Entry.Set_attributes(if Read_only {
+                Entry.Get_attributes() | Read_only_attribute
+            } else {
+                Entry.Get_attributes() & !Read_only_attribute
+            });
+        }
+
+        Parent.Write_entry(&Inner.Volume, Found.Get_index(), &Entry)
+    }
+
+    fn Get_metadata_from_path(&self, Path: &Path_type) -> Result_type<Metadata_type> {
+        let Inner = self.Inner.read()?;
+
+        self.Get_path_metadata(&Inner, Path)
+    }
+
+    fn Get_metadata(&self, File: Local_file_identifier_type) -> Result_type<Metadata_type> {
+        let Inner = self.Inner.read()?;
+
+        let File = Inner
+            .Open_files
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        self.Get_path_metadata(&Inner, File.Get_path())
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+
+    use std::sync::Arc;
+
+    use File_system::{Create_device, Memory_device_type, Open_type, Permissions_type};
+
+    use super::*;
+
+    fn Initialize_with_size(Size: usize) -> File_system_type {
+        let _ = Users::Initialize();
+
+        Task::Initialize().unwrap();
+
+        unsafe {
+            let _ = Task::Get_instance().Register_task();
+        }
+
+        let Device = Device_type::New(Arc::new(Memory_device_type::<512>::New(Size)));
+
+        File_system_type::Format(Device.clone()).unwrap();
+
+        File_system_type::New(Device, Mount_options_type::default()).unwrap()
+    }
+
+    fn Initialize() -> File_system_type {
+        Initialize_with_size(8192 * 512)
+    }
+
+    #[test]
+    fn Test_open_close_delete() {
+        File_system::Tests::Test_open_close_delete(Initialize());
+    }
+
+    #[test]
+    fn Test_read_write() {
+        File_system::Tests::Test_read_write(Initialize());
+    }
+
+    #[test]
+    fn Test_move() {
+        File_system::Tests::Test_move(Initialize());
+    }
+
+    #[test]
+    fn Test_set_position() {
+        File_system::Tests::Test_set_position(Initialize());
+    }
+
+    #[test]
+    fn Test_flush() {
+        File_system::Tests::Test_flush(Initialize());
+    }
+
+    #[test]
+    fn Test_set_get_metadata() {
+        // - The access times only keep the date.
+        File_system::Tests::Test_set_get_metadata_with_time_precision(Initialize(), 24 * 60 * 60);
+    }
+
+    #[test]
+    fn Test_read_directory() {
+        File_system::Tests::Test_read_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_set_position_directory() {
+        File_system::Tests::Test_set_position_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_rewind_directory() {
+        File_system::Tests::Test_rewind_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_create_remove_directory() {
+        File_system::Tests::Test_create_remove_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_loader() {
+        File_system::Tests::Test_loader(Initialize());
+    }
+
+    #[test]
+    fn Test_file_system_statistics() {
+        let File_system = Initialize();
+
+        let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
+
+        let Before = File_system.Get_file_system_statistics().unwrap();
+
+        assert_eq!(
+            Before.Get_block_size() as usize,
+            File_system.Inner.read().unwrap().Volume.Get_cluster_size()
+        );
+        // - The root directory of a FAT16 volume is outside of the data clusters.
+        assert_eq!(Before.Get_free_blocks(), Before.Get_total_blocks());
+
+        let File = File_system
+            .Open(
+                Task,
+                Path_type::From_str("/File"),
+                Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+                Time_type::New(0),
+                User_identifier_type::Root,
+                Group_identifier_type::Root,
+            )
+            .unwrap();
+
+        let Data = vec![1; Before.Get_block_size() as usize * 3];
+
+        File_system.Write(File, &Data, Time_type::New(0)).unwrap();
+        File_system.Close(File).unwrap();
+
+        let After = File_system.Get_file_system_statistics().unwrap();
+
+        assert_eq!(After.Get_free_blocks(), Before.Get_free_blocks() - 3);
+    }
+
+    #[test]
+    fn Test_owner_and_permissions() {
+        let _ = Users::Initialize();
+
+        Task::Initialize().unwrap();
+
+        unsafe {
+            let _ = Task::Get_instance().Register_task();
+        }
+
+        let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
+
+        let Device = Create_device!(Memory_device_type::<512>::New(8192 * 512));
+
+        File_system_type::Format(Device.clone()).unwrap();
+
+        let Path = Path_type::From_str("/File");
+
+        let Read_only = Permissions_type::From_octal(0o444).unwrap();
+
+        {
+            let File_system =
+                File_system_type::New(Device.clone(), Mount_options_type::default()).unwrap();
+
+            let File = File_system
+                .Open(
+                    Task,
+                    Path,
+                    Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+                    Time_type::New(0),
+                    User_identifier_type::New(1000),
+                    Group_identifier_type::New(1000),
+                )
+                .unwrap();
+            File_system.Close(File).unwrap();
+
+            // - The files belong to the user of the volume.
+            let mut Metadata = File_system.Get_metadata_from_path(Path).unwrap();
+
+            assert_eq!(Metadata.Get_user(), User_identifier_type::Root);
+
+            // - The changes that can't be stored are refused.
+            let mut Other_owner = Metadata.clone();
+            Other_owner.Set_owner(User_identifier_type::New(1000));
+
+            assert_eq!(
+                File_system.Set_metadata_from_path(Path, &Other_owner),
+                Err(Error_type::Unsupported_operation)
+            );
+
+            let mut Other_permissions = Metadata.clone();
+            Other_permissions.Set_permissions(Permissions_type::From_octal(0o600).unwrap());
+
+            assert_eq!(
+                File_system.Set_metadata_from_path(Path, &Other_permissions),
+                Err(Error_type::Unsupported_operation)
+            );
+
+            // - Removing the write permissions sets the read-only attribute.
+            Metadata.Set_permissions(Read_only);
+
+            File_system.Set_metadata_from_path(Path, &Metadata).unwrap();
+        }
+
+        let File_system = File_system_type::New(Device, Mount_options_type::default()).unwrap();
+
+        assert_eq!(
+            File_system
+                .Get_metadata_from_path(Path)
+                .unwrap()
+                .Get_permissions(),
+            Read_only
+        );
+    }
+
+    #[test]
+    fn Test_FAT32_persistence() {
+        let _ = Users::Initialize();
+
+        Task::Initialize().unwrap();
+
+        unsafe {
+            let _ = Task::Get_instance().Register_task();
+        }
+
+        let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
+
+        let Device = Create_device!(Memory_device_type::<512>::New(80_000 * 512));
+
+        File_system_type::Format(Device.clone()).unwrap();
+
+        let Data: Vec<u8> = (0..10_000).map(|i| (i % 251) as u8).collect();
+
+        let Path = Path_type::From_str("/Directory with a long name/A file with a long name.bin");
+
+        {
+            let File_system =
+                File_system_type::New(Device.clone(), Mount_options_type::default()).unwrap();
+
+            assert!(Volume_type::Read_boot_sector(&Device).unwrap().Is_FAT32());
+
+            File_system
+                .Create_directory(
+                    Path_type::From_str("/Directory with a long name"),
+                    Time_type::New(0),
+                    User_identifier_type::Root,
+                    Group_identifier_type::Root,
+                )
+                .unwrap();
+
+            let File = File_system
+                .Open(
+                    Task,
+                    Path,
+                    Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+                    Time_type::New(0),
+                    User_identifier_type::Root,
+                    Group_identifier_type::Root,
+                )
+                .unwrap();
+
+            // - Write after the end to check the gap filling.
+            File_system
+                .Set_position(File, &Position_type::Start(100))
+                .unwrap();
+            File_system
+                .Write(File, &Data, Time_type::New(1_700_000_001))
+                .unwrap();
+
+            File_system.Close(File).unwrap();
+        }
+
+        // - Mount the volume again (read-only) and check the content.
+        let File_system =
+            File_system_type::New(Device, Mount_options_type::default().Set_read_only(true))
+                .unwrap();
+
+        let File = File_system
+            .Open(
+                Task,
+                Path_type::From_str("/DIRECTORY WITH A LONG NAME/a file with a long name.BIN"),
+                Mode_type::Read_only.into(),
+                Time_type::New(0),
+                User_identifier_type::Root,
+                Group_identifier_type::Root,
+            )
+            .unwrap();
+
+        let mut Buffer = vec![0xFF; Data.len() + 200];
+
+        let Read: usize = File_system
+            .Read(File, &mut Buffer, Time_type::New(0))
+            .unwrap()
+            .into();
+
+        assert_eq!(Read, Data.len() + 100);
+        assert!(Buffer[..100].iter().all(|Byte| *Byte == 0));
+        assert_eq!(&Buffer[100..Read], &Data[..]);
+
+        let Metadata = File_system.Get_metadata(File).unwrap();
+
+        assert!(!Metadata.Get_permissions().Get_user().Get_write());
+
+        // - The modification time is kept, with a 2 seconds precision.
+        assert_eq!(
+            Metadata.Get_modification_time(),
+            Time_type::New(1_700_000_000)
+        );
+
+        assert_eq!(File_system.Remove(Path), Err(Error_type::Permission_denied));
+
+        File_system.Close(File).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+use File_system::{Permissions_type, Type_type};
+use Users::{Group_identifier_type, User_identifier_type};
+
+/// Mount options of a FAT volume.
+///
+/// FAT doesn't store owners nor permissions : they are emulated from these options, like the `uid`, `gid`, `fmask` and `dmask` options of Unix systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mount_options_type {
+    User: User_identifier_type,
+    Group: Group_identifier_type,
+    File_permissions: Permissions_type,
+    Directory_permissions: Permissions_type,
+    Read_only: bool,
+}
+
+impl Default for Mount_options_type {
+    fn default() -> Self {
+        Self {
+            User: User_identifier_type::Root,
+            Group: Group_identifier_type::Root,
+            File_permissions: Permissions_type::New_default(Type_type::File),
+            Directory_permissions: Permissions_type::New_default(Type_type::Directory),
+            Read_only: false,
+        }
+    }
+}
+
+impl Mount_options_type {
+    pub const fn Set_user(mut self, User: User_identifier_type) -> Self {
+        self.User = User;
+        self
+    }
+
+    pub const fn Set_group(mut self, Group: Group_identifier_type) -> Self {
+        self.Group = Group;
+        self
+    }
+
+    pub const fn Set_file_permissions(mut self, Permissions: Permissions_type) -> Self {
+        self.File_permissions = Permissions;
+        self
+    }
+
+    pub const fn Set_directory_permissions(mut self, Permissions: Permissions_type) -> Self {
+        self.Directory_permissions = Permissions;
+        self
+    }
+
+    pub const fn Set_read_only(mut self, Read_only: bool) -> Self {
+        self.Read_only = Read_only;
+        self
+    }
+
+    pub const fn Get_user(&self) -> User_identifier_type {
+        self.User
+    }
+
+    pub const fn Get_group(&self) -> Group_identifier_type {
+        self.Group
+    }
+
+    pub const fn Is_read_only(&self) -> bool {
+        self.Read_only
+    }
+
+    /// Get the emulated permissions of an entry, the write permissions are removed for read-only entries or volumes.
+    pub fn Get_permissions(&self, Type: Type_type, Read_only: bool) -> Permissions_type {
+        let Permissions = match Type {
+            Type_type::Directory => self.Directory_permissions,
+            _ => self.File_permissions,
+        };
+
+        if Read_only || self.Read_only {
+            Permissions_type::From_octal(Permissions.As_u16() & !0o222).unwrap_or(Permissions)
+        } else {
+            Permissions
+        }
+    }
+}
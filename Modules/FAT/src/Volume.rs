@@ -0,0 +1,346 @@
+use File_system::{Block_type, Device_type, Error_type, Position_type, Result_type};
+
+use crate::Boot_sector_type;
+
+const File_system_information_lead_signature: u32 = 0x41615252;
+const File_system_information_structure_signature: u32 = 0x61417272;
+const File_system_information_trail_signature: u32 = 0xAA550000;
+/// Value of the FSInfo fields when they are unknown.
+const Unknown: u32 = 0xFFFFFFFF;
+
+const FAT32_mask: u32 = 0x0FFFFFFF;
+const FAT16_end_of_chain: u32 = 0xFFFF;
+const FAT32_end_of_chain: u32 = 0x0FFFFFFF;
+/// Values above this one mark a bad cluster (0x?FF7) or the end of a chain.
+const FAT16_bad_cluster: u32 = 0xFFF7;
+const FAT32_bad_cluster: u32 = 0x0FFFFFF7;
+
+/// A mounted FAT volume : the underlying device, its geometry and the cluster allocation.
+pub struct Volume_type {
+    Device: Device_type,
+    Boot_sector: Boot_sector_type,
+    /// Hint for the next cluster allocation.
+    Next_free_cluster: u32,
+}
+
+impl Volume_type {
+    /// Read the boot sector of a device.
+    pub fn Read_boot_sector(Device: &Device_type) -> Result_type<Boot_sector_type> {
+        let mut Block = Block_type::default();
+
+        Read_at(Device, 0, &mut Block.0)?;
+
+        Boot_sector_type::From_block(&Block)
+    }
+
+    pub fn New(Device: Device_type, Boot_sector: Boot_sector_type) -> Self {
+        Self {
+            Device,
+            Boot_sector,
+            Next_free_cluster: 2,
+        }
+    }
+
+    /// Write a new empty file system to a device.
+    pub fn Format(Device: &Device_type, Boot_sector: &Boot_sector_type) -> Result_type<()> {
+        let Block = Boot_sector.To_block();
+
+        Write_at(Device, 0, &Block.0)?;
+
+        // - Clear the file allocation tables and the fixed root directory
+        let Zeroes = vec![0; Boot_sector.Get_cluster_size().max(4096)];
+
+        let (Root_offset, Root_size) = Boot_sector.Get_root_directory_area();
+
+        Fill(
+            Device,
+            Boot_sector.Get_FAT_offset(0),
+            Root_offset + Root_size - Boot_sector.Get_FAT_offset(0),
+            &Zeroes,
+        )?;
+
+        let mut Volume = Self::New(Device.clone(), Boot_sector.clone());
+
+        // - Reserved entries : media descriptor and end of chain
+        Volume.Set_FAT_entry(0, 0x0FFFFF00 | Block.0[21] as u32)?;
+        Volume.Set_FAT_entry(1, Volume.Get_end_of_chain())?;
+
+        if Boot_sector.Is_FAT32() {
+            Write_at(
+                Device,
+                Boot_sector.Get_backup_boot_sector() as u64
+                    * Boot_sector.Get_bytes_per_sector() as u64,
+                &Block.0,
+            )?;
+
+            let Root_cluster = Boot_sector.Get_root_cluster();
+
+            Volume.Set_FAT_entry(Root_cluster, Volume.Get_end_of_chain())?;
+            Volume.Clear_cluster(Root_cluster)?;
+
+            Volume.Write_file_system_information()?;
+        }
+
+        Device.Flush()
+    }
+
+    pub fn Get_boot_sector(&self) -> &Boot_sector_type {
+        &self.Boot_sector
+    }
+
+    pub fn Get_cluster_size(&self) -> usize {
+        self.Boot_sector.Get_cluster_size()
+    }
+
+    pub fn Read(&self, Offset: u64, Buffer: &mut [u8]) -> Result_type<()> {
+        Read_at(&self.Device, Offset, Buffer)
+    }
+
+    pub fn Write(&self, Offset: u64, Buffer: &[u8]) -> Result_type<()> {
+        Write_at(&self.Device, Offset, Buffer)
+    }
+
+    pub fn Flush(&self) -> Result_type<()> {
+        self.Device.Flush()
+    }
+
+    /// Write the FSInfo sector, the free clusters count is left unknown to be recomputed by the readers.
+    pub fn Write_file_system_information(&self) -> Result_type<()> {
+        let Offset = match self.Boot_sector.Get_file_system_information_offset() {
+            Some(Offset) => Offset,
+            None => return Ok(()),
+        };
+
+        let mut Block = Block_type::default();
+
+        Block.0[0..4].copy_from_slice(&File_system_information_lead_signature.to_le_bytes());
+        Block.0[484..488]
+            .copy_from_slice(&File_system_information_structure_signature.to_le_bytes());
+        Block.0[488..492].copy_from_slice(&Unknown.to_le_bytes());
+        Block.0[492..496].copy_from_slice(&self.Next_free_cluster.to_le_bytes());
+        Block.0[508..512].copy_from_slice(&File_system_information_trail_signature.to_le_bytes());
+
+        self.Write(Offset, &Block.0)
+    }
+
+    fn Get_end_of_chain(&self) -> u32 {
+        if self.Boot_sector.Is_FAT32() {
+            FAT32_end_of_chain
+        } else {
+            FAT16_end_of_chain
+        }
+    }
+
+    fn Get_FAT_entry_location(&self, Cluster: u32) -> (u64, usize) {
+        if self.Boot_sector.Is_FAT32() {
+            (Cluster as u64 * 4, 4)
+        } else {
+            (Cluster as u64 * 2, 2)
+        }
+    }
+
+    fn Get_FAT_entry(&self, Cluster: u32) -> Result_type<u32> {
+        let (Offset, Size) = self.Get_FAT_entry_location(Cluster);
+
+        let mut Buffer = [0; 4];
+
+        self.Read(
+            self.Boot_sector.Get_FAT_offset(0) + Offset,
+            &mut Buffer[..Size],
+        )?;
+
+        Ok(u32::from_le_bytes(Buffer) & FAT32_mask)
+    }
+
+    /// Set an entry in all the copies of the file allocation table.
+    fn Set_FAT_entry(&mut self, Cluster: u32, Value: u32) -> Result_type<()> {
+        let (Offset, Size) = self.Get_FAT_entry_location(Cluster);
+
+        let mut Value = Value;
+
+        // - The 4 high bits of FAT32 entries are reserved and must be preserved.
+        if self.Boot_sector.Is_FAT32() {
+            let mut Buffer = [0; 4];
+
+            self.Read(self.Boot_sector.Get_FAT_offset(0) + Offset, &mut Buffer)?;
+
+            Value = (u32::from_le_bytes(Buffer) & !FAT32_mask) | (Value & FAT32_mask);
+        }
+
+        for Index in 0..self.Boot_sector.Get_FAT_count() {
+            self.Write(
+                self.Boot_sector.Get_FAT_offset(Index) + Offset,
+                &Value.to_le_bytes()[..Size],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the cluster following `Cluster` in its chain, or `None` at the end of the chain.
+    pub fn Get_next_cluster(&self, Cluster: u32) -> Result_type<Option<u32>> {
+        let Next = self.Get_FAT_entry(Cluster)?;
+
+        let Bad_cluster = if self.Boot_sector.Is_FAT32() {
+            FAT32_bad_cluster
+        } else {
+            FAT16_bad_cluster
+        };
+
+        if Next > Bad_cluster {
+            return Ok(None);
+        }
+
+        if !self.Boot_sector.Is_valid_cluster(Next) {
+            return Err(Error_type::Corrupted);
+        }
+
+        Ok(Some(Next))
+    }
+
+    /// Get all the clusters of a chain (empty when `First` is 0).
+    pub fn Get_chain(&self, First: u32) -> Result_type<Vec<u32>> {
+        let mut Chain = Vec::new();
+
+        if First == 0 {
+            return Ok(Chain);
+        }
+
+        if !self.Boot_sector.Is_valid_cluster(First) {
+            return Err(Error_type::Corrupted);
+        }
+
+        let mut Current = Some(First);
+
+        while let Some(Cluster) = Current {
+            // - Prevent infinite loops on corrupted chains.
+            if Chain.len() > self.Boot_sector.Get_cluster_count() as usize {
+                return Err(Error_type::Corrupted);
+            }
+
+            Chain.push(Cluster);
+
+            Current = self.Get_next_cluster(Cluster)?;
+        }
+
+        Ok(Chain)
+    }
+
+    /// Fill a cluster with zeroes.
+    pub fn Clear_cluster(&self, Cluster: u32) -> Result_type<()> {
+        let Zeroes = vec![0; self.Get_cluster_size()];
+
+        self.Write(self.Boot_sector.Get_cluster_offset(Cluster), &Zeroes)
+    }
+
+    /// Count the free clusters by scanning the file allocation table.
+    pub fn Get_free_cluster_count(&self) -> Result_type<u32> {
+        let mut Free = 0;
+
+        for Cluster in 2..self.Boot_sector.Get_cluster_count() + 2 {
+            if self.Get_FAT_entry(Cluster)? == 0 {
+                Free += 1;
+            }
+        }
+
+        Ok(Free)
+    }
+
+    /// Allocate a cluster and append it to the chain ending with `Previous` (if any).
+    pub fn Allocate_cluster(&mut self, Previous: Option<u32>, Clear: bool) -> Result_type<u32> {
+        let Count = self.Boot_sector.Get_cluster_count();
+
+        let Start = if self.Boot_sector.Is_valid_cluster(self.Next_free_cluster) {
+            self.Next_free_cluster
+        } else {
+            2
+        };
+
+        let mut Found = None;
+
+        for Index in 0..Count {
+            let Cluster = 2 + (Start - 2 + Index) % Count;
+
+            if self.Get_FAT_entry(Cluster)? == 0 {
+                Found = Some(Cluster);
+                break;
+            }
+        }
+
+        let Cluster = Found.ok_or(Error_type::File_system_full)?;
+
+        self.Set_FAT_entry(Cluster, self.Get_end_of_chain())?;
+
+        if let Some(Previous) = Previous {
+            self.Set_FAT_entry(Previous, Cluster)?;
+        }
+
+        if Clear {
+            self.Clear_cluster(Cluster)?;
+        }
+
+        self.Next_free_cluster = Cluster + 1;
+
+        Ok(Cluster)
+    }
+
+    /// Free all the clusters of a chain.
+    pub fn Free_chain(&mut self, First: u32) -> Result_type<()> {
+        for Cluster in self.Get_chain(First)? {
+            self.Set_FAT_entry(Cluster, 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn Read_at(Device: &Device_type, Offset: u64, Buffer: &mut [u8]) -> Result_type<()> {
+    Device.Set_position(&Position_type::Start(Offset))?;
+
+    let mut Read = 0;
+
+    while Read < Buffer.len() {
+        let Size: usize = Device.Read(&mut Buffer[Read..])?.into();
+
+        if Size == 0 {
+            return Err(Error_type::Input_output);
+        }
+
+        Read += Size;
+    }
+
+    Ok(())
+}
+
+fn Write_at(Device: &Device_type, Offset: u64, Buffer: &[u8]) -> Result_type<()> {
+    Device.Set_position(&Position_type::Start(Offset))?;
+
+    let mut Written = 0;
+
+    while Written < Buffer.len() {
+        let Size: usize = Device.Write(&Buffer[Written..])?.into();
+
+        if Size == 0 {
+            return Err(Error_type::Input_output);
+        }
+
+        Written += Size;
+    }
+
+    Ok(())
+}
+
+/// Fill an area of a device with zeroes.
+fn Fill(Device: &Device_type, Offset: u64, Size: u64, Zeroes: &[u8]) -> Result_type<()> {
+    let mut Done = 0;
+
+    while Done < Size {
+        let Chunk = (Size - Done).min(Zeroes.len() as u64) as usize;
+
+        Write_at(Device, Offset + Done, &Zeroes[..Chunk])?;
+
+        Done += Chunk as u64;
+    }
+
+    Ok(())
+}
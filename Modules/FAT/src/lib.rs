@@ -0,0 +1,19 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+mod Boot_sector;
+mod Directory;
+mod Entry;
+mod File;
+mod File_system;
+mod Mount_options;
+mod Volume;
+
+use Boot_sector::*;
+use Directory::*;
+use Entry::*;
+use File::*;
+pub use File_system::*;
+pub use Mount_options::*;
+use Volume::*;
@@ -4,7 +4,7 @@ use std::{
     sync::Arc,
 };
 
-use crate::{Error_type, Position_type, Result_type, Size_type};
+use crate::{Error_type, Events_type, Position_type, Result_type, Size_type};
 
 #[macro_export]
 macro_rules! Create_device {
@@ -73,6 +73,18 @@ pub trait Device_trait: Send + Sync {
         false
     }
 
+    /// Switch a terminal to raw mode (the keys are read as they are typed, without echo) or back to line mode.
+    fn Set_raw_mode(&self, _: bool) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    /// Get the events that are currently ready on the device (used for polling).
+    ///
+    /// Devices whose [`Device_trait::Read`] or [`Device_trait::Write`] may return [`Error_type::Ressource_busy`] should override this method.
+    fn Get_events(&self) -> Result_type<Events_type> {
+        Ok(Events_type::Read_write)
+    }
+
     fn Dump_device(&self) -> Result_type<Vec<u8>> {
         let Size = self.Get_size()?;
 
@@ -99,6 +111,11 @@ impl Device_type {
         Device_type(Device)
     }
 
+    /// Get an identifier of the device, shared by its clones.
+    pub fn Get_identifier(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+
     pub fn Read(&self, Buffer: &mut [u8]) -> Result_type<Size_type> {
         self.0.Read(Buffer)
     }
@@ -139,6 +156,14 @@ impl Device_type {
         self.0.Is_a_block_device()
     }
 
+    pub fn Set_raw_mode(&self, Raw: bool) -> Result_type<()> {
+        self.0.Set_raw_mode(Raw)
+    }
+
+    pub fn Get_events(&self) -> Result_type<Events_type> {
+        self.0.Get_events()
+    }
+
     pub fn Dump_device(&self) -> Result_type<Vec<u8>> {
         self.0.Dump_device()
     }
@@ -45,6 +45,8 @@ pub enum Error_type {
     No_space_left,
     Time_error,
     Invalid_inode,
+    Broken_pipe,
+    Read_only_file_system,
     Other,
 }
 
@@ -148,6 +150,8 @@ impl Display for Error_type {
             Error_type::No_space_left => "No space left",
             Error_type::Time_error => "Time error",
             Error_type::Invalid_inode => "Invalid inode",
+            Error_type::Broken_pipe => "Broken pipe",
+            Error_type::Read_only_file_system => "Read-only file system",
             Error_type::Other => "Other",
         };
 
@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
 
 use crate::{
-    Entry_type, File_identifier_type, Inode_type, Local_file_identifier_type, Metadata_type,
-    Mode_type, Statistics_type, Time_type,
+    Check_report_type, Entry_type, File_identifier_type, File_system_statistics_type, Inode_type,
+    Local_file_identifier_type, Metadata_type, Mode_type, Statistics_type, Time_type,
 };
 
 use super::{Error_type, Flags_type, Path_type, Position_type, Result_type, Size_type};
@@ -192,6 +192,44 @@ pub trait File_system_traits: Send + Sync {
     fn Get_statistics(&self, File: Local_file_identifier_type) -> Result_type<Statistics_type>;
 
     fn Get_mode(&self, File: Local_file_identifier_type) -> Result_type<Mode_type>;
+
+    /// Get the name of the file system type (e.g. `FAT`), shown in the mount table.
+    fn Get_type_name(&self) -> &'static str {
+        "Unknown"
+    }
+
+    /// Get the capacity and free space of the file system.
+    fn Get_file_system_statistics(&self) -> Result_type<File_system_statistics_type> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    /// Check the consistency of the file system, and try to repair the issues found if `Repair` is set.
+    ///
+    /// Without `Repair`, the file system is only inspected (nothing is written).
+    fn Check(&self, _Repair: bool) -> Result_type<Check_report_type> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    // - Extended attributes
+
+    /// Get the value of an extended attribute of a file, or [`Error_type::No_attribute`] if it is not set.
+    fn Get_attribute(&self, _Path: &Path_type, _Name: &str) -> Result_type<Vec<u8>> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    /// Set an extended attribute of a file, replacing the previous value if any.
+    fn Set_attribute(&self, _Path: &Path_type, _Name: &str, _Value: &[u8]) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Remove_attribute(&self, _Path: &Path_type, _Name: &str) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    /// Get the names of the extended attributes of a file.
+    fn List_attributes(&self, _Path: &Path_type) -> Result_type<Vec<String>> {
+        Err(Error_type::Unsupported_operation)
+    }
 }
 
 pub fn Get_new_file_identifier<T>(
@@ -450,6 +488,15 @@ pub mod Tests {
     }
 
     pub fn Test_set_get_metadata(File_system: impl File_system_traits) {
+        Test_set_get_metadata_with_time_precision(File_system, 0);
+    }
+
+    /// Same as [`Test_set_get_metadata`] for the file systems storing the times with a lower precision (e.g. FAT) :
+    /// the times read can be up to `Time_precision` seconds earlier than the ones set.
+    pub fn Test_set_get_metadata_with_time_precision(
+        File_system: impl File_system_traits,
+        Time_precision: u64,
+    ) {
         let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
 
         let Path = Get_test_path().Append("Test_set_owner").unwrap();
@@ -467,7 +514,8 @@ pub mod Tests {
             )
             .unwrap();
 
-        let Time = Time_type::New(123);
+        // - Within the range of all the file systems (FAT starts in 1980).
+        let Time = Time_type::New(1_700_000_000);
 
         let Metadata = Metadata_type::Get_default(
             Type_type::File,
@@ -483,6 +531,26 @@ pub mod Tests {
 
         let Metadata_read = File_system.Get_metadata_from_path(&Path).unwrap();
 
+        let mut Metadata = Metadata;
+
+        for (Set, Read) in [
+            (
+                Metadata.Get_creation_time(),
+                Metadata_read.Get_creation_time(),
+            ),
+            (
+                Metadata.Get_modification_time(),
+                Metadata_read.Get_modification_time(),
+            ),
+            (Metadata.Get_access_time(), Metadata_read.Get_access_time()),
+        ] {
+            assert!(Read <= Set && Set.As_u64() - Read.As_u64() <= Time_precision);
+        }
+
+        Metadata.Set_creation_time(Metadata_read.Get_creation_time());
+        Metadata.Set_modification_time(Metadata_read.Get_modification_time());
+        Metadata.Set_access_time(Metadata_read.Get_access_time());
+
         assert_eq!(Metadata, Metadata_read);
 
         File_system.Close(File).unwrap();
@@ -685,6 +753,83 @@ pub mod Tests {
         File_system.Remove(&Path).unwrap();
     }
 
+    pub fn Test_attributes(File_system: impl File_system_traits) {
+        let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
+
+        let Path = Get_test_path().Append("Test_attributes").unwrap();
+
+        let File = File_system
+            .Open(
+                Task,
+                &Path,
+                Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+                Time_type::New(0),
+                User_identifier_type::Root,
+                Group_identifier_type::Root,
+            )
+            .unwrap();
+
+        File_system.Close(File).unwrap();
+
+        assert_eq!(
+            File_system.List_attributes(&Path).unwrap(),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            File_system.Get_attribute(&Path, crate::Mime_type_attribute),
+            Err(Error_type::No_attribute)
+        );
+
+        File_system
+            .Set_attribute(&Path, crate::Mime_type_attribute, b"text/plain")
+            .unwrap();
+        File_system
+            .Set_attribute(&Path, crate::Icon_attribute, b"/Icons/Text.png")
+            .unwrap();
+
+        assert_eq!(
+            File_system
+                .Get_attribute(&Path, crate::Mime_type_attribute)
+                .unwrap(),
+            b"text/plain"
+        );
+        assert_eq!(
+            File_system.List_attributes(&Path).unwrap(),
+            vec![
+                crate::Icon_attribute.to_string(),
+                crate::Mime_type_attribute.to_string()
+            ]
+        );
+
+        // - The metadata is kept apart from the attributes.
+        assert_eq!(
+            File_system
+                .Get_metadata_from_path(&Path)
+                .unwrap()
+                .Get_type(),
+            Type_type::File
+        );
+
+        File_system
+            .Remove_attribute(&Path, crate::Icon_attribute)
+            .unwrap();
+
+        assert_eq!(
+            File_system.Remove_attribute(&Path, crate::Icon_attribute),
+            Err(Error_type::No_attribute)
+        );
+        assert_eq!(
+            File_system.Set_attribute(&Path, "Large", &[0; crate::Attributes_type::Maximum_size]),
+            Err(Error_type::No_space_left)
+        );
+        assert_eq!(
+            File_system.Get_attribute(Path_type::New("/Not_found"), crate::Icon_attribute),
+            Err(Error_type::Not_found)
+        );
+
+        File_system.Remove(&Path).unwrap();
+    }
+
     pub fn Test_loader(mut File_system: impl File_system_traits) {
         // - Load the file in the file system
         let Source_path = "Cargo.toml";
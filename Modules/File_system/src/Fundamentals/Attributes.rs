@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+
+use crate::{Error_type, Result_type};
+
+/// Attribute holding the MIME type of a file (e.g. `text/plain`).
+pub const Mime_type_attribute: &str = "Xila.Mime_type";
+/// Attribute holding the path of the icon of a file.
+pub const Icon_attribute: &str = "Xila.Icon";
+/// Attribute holding the name of the application owning a file.
+pub const Application_attribute: &str = "Xila.Application";
+
+/// Extended attributes of a file : arbitrary named values attached to it.
+///
+/// All the attributes of a file are encoded together, so file systems with a single attribute slot
+/// (like LittleFS custom attributes) can store them. The encoded size is capped by [`Attributes_type::Maximum_size`].
+///
+/// # Encoding
+///
+/// For each attribute, by name order : the name length (1 byte), the name, the value length (2 bytes, little endian) and the value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attributes_type(BTreeMap<String, Vec<u8>>);
+
+impl Attributes_type {
+    /// Identifier of the attributes for file systems storing them alongside the metadata (see [`crate::Metadata_type::Identifier`]).
+    pub const Identifier: u8 = 0x02;
+
+    /// Maximum encoded size of the attributes of a file (the default maximum attribute size of LittleFS).
+    pub const Maximum_size: usize = 1022;
+
+    /// Maximum length of an attribute name.
+    pub const Maximum_name_size: usize = u8::MAX as usize;
+
+    pub fn New() -> Self {
+        Self::default()
+    }
+
+    pub fn Get(&self, Name: &str) -> Result_type<&[u8]> {
+        self.0
+            .get(Name)
+            .map(|Value| Value.as_slice())
+            .ok_or(Error_type::No_attribute)
+    }
+
+    /// Set an attribute, replacing the previous value if any.
+    pub fn Set(&mut self, Name: &str, Value: &[u8]) -> Result_type<()> {
+        if Name.is_empty() || Name.contains('\0') {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        if Name.len() > Self::Maximum_name_size {
+            return Err(Error_type::Name_too_long);
+        }
+
+        let Previous_size = self.Get_entry_size(Name);
+
+        let Size = self.Get_encoded_size() - Previous_size + 1 + Name.len() + 2 + Value.len();
+
+        if Size > Self::Maximum_size {
+            return Err(Error_type::No_space_left);
+        }
+
+        self.0.insert(Name.to_string(), Value.to_vec());
+
+        Ok(())
+    }
+
+    pub fn Remove(&mut self, Name: &str) -> Result_type<()> {
+        self.0.remove(Name).ok_or(Error_type::No_attribute)?;
+
+        Ok(())
+    }
+
+    pub fn Get_names(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    pub fn Is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn Get_entry_size(&self, Name: &str) -> usize {
+        match self.0.get(Name) {
+            Some(Value) => 1 + Name.len() + 2 + Value.len(),
+            None => 0,
+        }
+    }
+
+    pub fn Get_encoded_size(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(Name, Value)| 1 + Name.len() + 2 + Value.len())
+            .sum()
+    }
+
+    pub fn To_bytes(&self) -> Vec<u8> {
+        let mut Bytes = Vec::with_capacity(self.Get_encoded_size());
+
+        for (Name, Value) in &self.0 {
+            Bytes.push(Name.len() as u8);
+            Bytes.extend_from_slice(Name.as_bytes());
+            Bytes.extend_from_slice(&(Value.len() as u16).to_le_bytes());
+            Bytes.extend_from_slice(Value);
+        }
+
+        Bytes
+    }
+
+    /// Decode attributes, return [`Error_type::Corrupted`] if the data is malformed.
+    pub fn From_bytes(Bytes: &[u8]) -> Result_type<Self> {
+        let mut Attributes = BTreeMap::new();
+
+        let mut Remaining = Bytes;
+
+        while let Some((&Name_length, Rest)) = Remaining.split_first() {
+            let Name_length = Name_length as usize;
+
+            if Rest.len() < Name_length + 2 {
+                return Err(Error_type::Corrupted);
+            }
+
+            let (Name, Rest) = Rest.split_at(Name_length);
+            let (Value_length, Rest) = Rest.split_at(2);
+
+            let Value_length = u16::from_le_bytes([Value_length[0], Value_length[1]]) as usize;
+
+            if Rest.len() < Value_length {
+                return Err(Error_type::Corrupted);
+            }
+
+            let (Value, Rest) = Rest.split_at(Value_length);
+
+            let Name = core::str::from_utf8(Name).map_err(|_| Error_type::Corrupted)?;
+
+            Attributes.insert(Name.to_string(), Value.to_vec());
+
+            Remaining = Rest;
+        }
+
+        Ok(Self(Attributes))
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_set_get_remove() {
+        let mut Attributes = Attributes_type::New();
+
+        Attributes.Set(Mime_type_attribute, b"text/plain").unwrap();
+        Attributes.Set(Icon_attribute, b"/Icons/Text.png").unwrap();
+        Attributes
+            .Set(Mime_type_attribute, b"text/markdown")
+            .unwrap();
+
+        assert_eq!(
+            Attributes.Get(Mime_type_attribute).unwrap(),
+            b"text/markdown"
+        );
+        assert_eq!(
+            Attributes.Get_names(),
+            vec![Icon_attribute.to_string(), Mime_type_attribute.to_string()]
+        );
+
+        Attributes.Remove(Icon_attribute).unwrap();
+
+        assert_eq!(
+            Attributes.Get(Icon_attribute),
+            Err(Error_type::No_attribute)
+        );
+        assert_eq!(
+            Attributes.Remove(Icon_attribute),
+            Err(Error_type::No_attribute)
+        );
+        assert_eq!(Attributes.Set("", b""), Err(Error_type::Invalid_parameter));
+    }
+
+    #[test]
+    fn Test_encoding() {
+        let mut Attributes = Attributes_type::New();
+
+        assert_eq!(Attributes_type::From_bytes(&[]).unwrap(), Attributes);
+
+        Attributes.Set(Application_attribute, b"Terminal").unwrap();
+        Attributes.Set("Empty", b"").unwrap();
+
+        let Bytes = Attributes.To_bytes();
+
+        assert_eq!(Bytes.len(), Attributes.Get_encoded_size());
+        assert_eq!(Attributes_type::From_bytes(&Bytes).unwrap(), Attributes);
+        assert_eq!(
+            Attributes_type::From_bytes(&Bytes[..Bytes.len() - 1]),
+            Err(Error_type::Corrupted)
+        );
+    }
+
+    #[test]
+    fn Test_maximum_size() {
+        let mut Attributes = Attributes_type::New();
+
+        let Value = vec![0; Attributes_type::Maximum_size - 3 - 4];
+
+        Attributes.Set("Name", &Value).unwrap();
+
+        assert_eq!(Attributes.Get_encoded_size(), Attributes_type::Maximum_size);
+        assert_eq!(Attributes.Set("Other", b""), Err(Error_type::No_space_left));
+
+        // - Replacing a value only counts the difference.
+        Attributes.Set("Name", &Value[4..]).unwrap();
+        Attributes.Set("A", b"").unwrap();
+    }
+}
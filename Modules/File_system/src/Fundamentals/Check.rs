@@ -0,0 +1,131 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::Path_owned_type;
+
+/// Kind of problem found by a file system check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Check_issue_kind_type {
+    /// The content of a file can't be read back.
+    Corrupted_content,
+    /// A directory can't be listed.
+    Corrupted_directory,
+    /// The blocks of the file system can't be walked.
+    Corrupted_structure,
+    /// The metadata of a file (type, owner, permissions, times) is missing.
+    Missing_metadata,
+    /// The metadata of a file is malformed or doesn't match the entry.
+    Corrupted_metadata,
+    /// The extended attributes of a file are malformed.
+    Corrupted_attributes,
+    /// Data left behind by an interrupted operation (removed but still allocated, half moved).
+    Orphan,
+    /// The space accounting doesn't match the content.
+    Inconsistent_size,
+}
+
+impl Display for Check_issue_kind_type {
+    fn fmt(&self, Formatter: &mut Formatter) -> fmt::Result {
+        let String = match self {
+            Check_issue_kind_type::Corrupted_content => "Corrupted content",
+            Check_issue_kind_type::Corrupted_directory => "Corrupted directory",
+            Check_issue_kind_type::Corrupted_structure => "Corrupted structure",
+            Check_issue_kind_type::Missing_metadata => "Missing metadata",
+            Check_issue_kind_type::Corrupted_metadata => "Corrupted metadata",
+            Check_issue_kind_type::Corrupted_attributes => "Corrupted attributes",
+            Check_issue_kind_type::Orphan => "Orphan",
+            Check_issue_kind_type::Inconsistent_size => "Inconsistent size",
+        };
+
+        write!(Formatter, "{}", String)
+    }
+}
+
+/// Problem found by a file system check, on a path relative to the file system root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check_issue_type {
+    Path: Path_owned_type,
+    Kind: Check_issue_kind_type,
+    Repaired: bool,
+}
+
+impl Check_issue_type {
+    pub fn New(Path: Path_owned_type, Kind: Check_issue_kind_type, Repaired: bool) -> Self {
+        Self {
+            Path,
+            Kind,
+            Repaired,
+        }
+    }
+
+    pub fn Get_path(&self) -> &Path_owned_type {
+        &self.Path
+    }
+
+    pub fn Get_kind(&self) -> Check_issue_kind_type {
+        self.Kind
+    }
+
+    pub fn Is_repaired(&self) -> bool {
+        self.Repaired
+    }
+}
+
+/// Result of a file system check (see [`crate::File_system_traits::Check`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Check_report_type {
+    Files: usize,
+    Directories: usize,
+    Issues: Vec<Check_issue_type>,
+    /// The file system couldn't be repaired and has been formatted.
+    Formatted: bool,
+}
+
+impl Check_report_type {
+    pub fn New() -> Self {
+        Self::default()
+    }
+
+    pub fn Add_file(&mut self) {
+        self.Files += 1;
+    }
+
+    pub fn Add_directory(&mut self) {
+        self.Directories += 1;
+    }
+
+    pub fn Add_issue(
+        &mut self,
+        Path: Path_owned_type,
+        Kind: Check_issue_kind_type,
+        Repaired: bool,
+    ) {
+        self.Issues
+            .push(Check_issue_type::New(Path, Kind, Repaired));
+    }
+
+    pub fn Set_formatted(&mut self) {
+        self.Formatted = true;
+    }
+
+    pub fn Get_files(&self) -> usize {
+        self.Files
+    }
+
+    pub fn Get_directories(&self) -> usize {
+        self.Directories
+    }
+
+    pub fn Get_issues(&self) -> &[Check_issue_type] {
+        &self.Issues
+    }
+
+    pub fn Is_formatted(&self) -> bool {
+        self.Formatted
+    }
+
+    /// Check if there is no issue left (all the issues found have been repaired).
+    pub fn Is_clean(&self) -> bool {
+        self.Issues.iter().all(|Issue| Issue.Is_repaired())
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::Debug;
+
+/// Readiness events of a file, used for polling.
+///
+/// The events are stored in a 8-bit integer, with the following layout:
+///
+/// | Readable | Writable | Hang up | Error |
+/// |----------|----------|---------|-------|
+/// | 0        | 1        | 2       | 3     |
+///
+/// # Example
+///
+/// ```rust
+/// use File_system::Events_type;
+///
+/// let Events = Events_type::New(true, false, false, false);
+///
+/// assert!(Events.Get_readable());
+/// assert!(!Events.Get_writable());
+///
+/// let Ready = Events.Intersection(Events_type::Read_write);
+///
+/// assert_eq!(Ready, Events_type::Readable);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct Events_type(u8);
+
+impl Events_type {
+    pub const Readable_bit: u8 = 1 << 0;
+    pub const Writable_bit: u8 = 1 << 1;
+    pub const Hang_up_bit: u8 = 1 << 2;
+    pub const Error_bit: u8 = 1 << 3;
+
+    pub const Size: u8 = 4;
+
+    pub const None: Self = Self::New(false, false, false, false);
+    pub const Readable: Self = Self::New(true, false, false, false);
+    pub const Writable: Self = Self::New(false, true, false, false);
+    pub const Read_write: Self = Self::New(true, true, false, false);
+    pub const Hang_up: Self = Self::New(false, false, true, false);
+    pub const Error: Self = Self::New(false, false, false, true);
+
+    pub const fn New(Readable: bool, Writable: bool, Hang_up: bool, Error: bool) -> Self {
+        Self(0)
+            .Set_readable(Readable)
+            .Set_writable(Writable)
+            .Set_hang_up(Hang_up)
+            .Set_error(Error)
+    }
+
+    const fn Set_bit(mut self, Mask: u8, Value: bool) -> Self {
+        if Value {
+            self.0 |= Mask;
+        } else {
+            self.0 &= !Mask;
+        }
+        self
+    }
+
+    const fn Get_bit(&self, Mask: u8) -> bool {
+        self.0 & Mask != 0
+    }
+
+    pub const fn Set_readable(self, Value: bool) -> Self {
+        self.Set_bit(Self::Readable_bit, Value)
+    }
+
+    pub const fn Get_readable(&self) -> bool {
+        self.Get_bit(Self::Readable_bit)
+    }
+
+    pub const fn Set_writable(self, Value: bool) -> Self {
+        self.Set_bit(Self::Writable_bit, Value)
+    }
+
+    pub const fn Get_writable(&self) -> bool {
+        self.Get_bit(Self::Writable_bit)
+    }
+
+    pub const fn Set_hang_up(self, Value: bool) -> Self {
+        self.Set_bit(Self::Hang_up_bit, Value)
+    }
+
+    pub const fn Get_hang_up(&self) -> bool {
+        self.Get_bit(Self::Hang_up_bit)
+    }
+
+    pub const fn Set_error(self, Value: bool) -> Self {
+        self.Set_bit(Self::Error_bit, Value)
+    }
+
+    pub const fn Get_error(&self) -> bool {
+        self.Get_bit(Self::Error_bit)
+    }
+
+    pub const fn Is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Get the events that are present in both `self` and `Other`.
+    pub const fn Intersection(&self, Other: Self) -> Self {
+        Self(self.0 & Other.0)
+    }
+
+    /// Get the events that are present in either `self` or `Other`.
+    pub const fn Union(&self, Other: Self) -> Self {
+        Self(self.0 | Other.0)
+    }
+
+    pub const fn From_u8(Value: u8) -> Self {
+        Self(Value)
+    }
+
+    pub const fn As_u8(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Debug for Events_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Formatter
+            .debug_struct("Events_type")
+            .field("Readable", &self.Get_readable())
+            .field("Writable", &self.Get_writable())
+            .field("Hang_up", &self.Get_hang_up())
+            .field("Error", &self.Get_error())
+            .finish()
+    }
+}
+
+impl Default for Events_type {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_events_set_get() {
+        let Events = Events_type::None;
+        assert!(Events.Is_empty());
+
+        let Events = Events.Set_readable(true).Set_hang_up(true);
+        assert!(Events.Get_readable());
+        assert!(!Events.Get_writable());
+        assert!(Events.Get_hang_up());
+        assert!(!Events.Get_error());
+
+        let Events = Events.Set_readable(false);
+        assert_eq!(Events, Events_type::Hang_up);
+    }
+
+    #[test]
+    fn Test_events_intersection_union() {
+        let Events = Events_type::Read_write.Union(Events_type::Error);
+
+        assert_eq!(
+            Events.Intersection(Events_type::Writable),
+            Events_type::Writable
+        );
+        assert!(Events.Intersection(Events_type::Hang_up).Is_empty());
+        assert_eq!(Events_type::From_u8(Events.As_u8()), Events);
+    }
+}
@@ -0,0 +1,104 @@
+use super::Size_type;
+
+/// Statistics of a file system (capacity and free space).
+///
+/// This type is the equivalent of `statvfs` : block counts are expressed in units of `Block_size` bytes.
+///
+/// # Fields
+///
+/// * `Block_size`: The size of a block in bytes.
+/// * `Total_blocks`: The total number of blocks of the file system.
+/// * `Free_blocks`: The number of free blocks.
+/// * `Available_blocks`: The number of free blocks available to unprivileged users.
+/// * `Total_inodes`: The total number of inodes (0 if the file system has no inode limit).
+/// * `Free_inodes`: The number of free inodes (0 if the file system has no inode limit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct File_system_statistics_type {
+    Block_size: u64,
+    Total_blocks: u64,
+    Free_blocks: u64,
+    Available_blocks: u64,
+    Total_inodes: u64,
+    Free_inodes: u64,
+}
+
+impl File_system_statistics_type {
+    pub const fn New(
+        Block_size: u64,
+        Total_blocks: u64,
+        Free_blocks: u64,
+        Available_blocks: u64,
+        Total_inodes: u64,
+        Free_inodes: u64,
+    ) -> Self {
+        Self {
+            Block_size,
+            Total_blocks,
+            Free_blocks,
+            Available_blocks,
+            Total_inodes,
+            Free_inodes,
+        }
+    }
+
+    pub const fn Get_block_size(&self) -> u64 {
+        self.Block_size
+    }
+
+    pub const fn Get_total_blocks(&self) -> u64 {
+        self.Total_blocks
+    }
+
+    pub const fn Get_free_blocks(&self) -> u64 {
+        self.Free_blocks
+    }
+
+    pub const fn Get_available_blocks(&self) -> u64 {
+        self.Available_blocks
+    }
+
+    pub const fn Get_used_blocks(&self) -> u64 {
+        self.Total_blocks.saturating_sub(self.Free_blocks)
+    }
+
+    pub const fn Get_total_inodes(&self) -> u64 {
+        self.Total_inodes
+    }
+
+    pub const fn Get_free_inodes(&self) -> u64 {
+        self.Free_inodes
+    }
+
+    pub const fn Get_total_size(&self) -> Size_type {
+        Size_type::New(self.Total_blocks * self.Block_size)
+    }
+
+    pub const fn Get_free_size(&self) -> Size_type {
+        Size_type::New(self.Free_blocks * self.Block_size)
+    }
+
+    pub const fn Get_available_size(&self) -> Size_type {
+        Size_type::New(self.Available_blocks * self.Block_size)
+    }
+
+    pub const fn Get_used_size(&self) -> Size_type {
+        Size_type::New(self.Get_used_blocks() * self.Block_size)
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_sizes() {
+        let Statistics = File_system_statistics_type::New(512, 100, 40, 30, 0, 0);
+
+        assert_eq!(Statistics.Get_used_blocks(), 60);
+        assert_eq!(Statistics.Get_total_size(), 51200);
+        assert_eq!(Statistics.Get_free_size(), 20480);
+        assert_eq!(Statistics.Get_available_size(), 15360);
+        assert_eq!(Statistics.Get_used_size(), 30720);
+    }
+}
@@ -295,6 +295,10 @@ impl Status_type {
     pub const fn From_u8(Value: u8) -> Self {
         Self(Value)
     }
+
+    pub const fn As_u8(&self) -> u8 {
+        self.0
+    }
 }
 
 impl Debug for Status_type {
@@ -13,8 +13,7 @@ impl Path_type {
     pub const Root: &'static Path_type = Self::From_str("/");
     pub const Empty: &'static Path_type = Self::From_str("");
 
-    /// Contains the OS core, including the kernel, init system, and critical drivers.
-    /// Prevents modification by regular users.
+    /// Live state of the kernel (tasks, file systems, devices, memory...), generated on read.
     pub const System: &'static Path_type = Self::From_str("/System");
 
     /// Stores system-wide settings in a structured format (e.g., JSON, TOML).
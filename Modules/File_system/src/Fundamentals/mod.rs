@@ -1,4 +1,8 @@
+mod Attributes;
+mod Check;
 mod Entry;
+mod Events;
+mod File_system_statistics;
 mod Flags;
 mod Identifiers;
 mod Metadata;
@@ -9,7 +13,11 @@ mod Size;
 mod Statistics;
 mod Type;
 
+pub use Attributes::*;
+pub use Check::*;
 pub use Entry::*;
+pub use Events::*;
+pub use File_system_statistics::*;
 pub use Flags::*;
 pub use Identifiers::*;
 pub use Metadata::*;
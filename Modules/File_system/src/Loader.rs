@@ -79,7 +79,11 @@ impl Loader_type {
             let Destination_file = File_system.Open(
                 Task_identifier_type::New(0),
                 Destination_path,
-                Flags_type::New(Mode_type::Read_only, Some(Open_type::Create), None),
+                Flags_type::New(
+                    Mode_type::Write_only,
+                    Some(Open_type::Create.Set_truncate(true)),
+                    None,
+                ),
                 Time_type::New(0),
                 User_identifier_type::Root,
                 Group_identifier_type::Root,
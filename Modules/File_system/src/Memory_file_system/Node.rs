@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use crate::{Attributes_type, Inode_type, Metadata_type};
+
+/// Content of a node.
+pub enum Content_type {
+    File(Vec<u8>),
+    /// Entries of a directory, by name.
+    Directory(BTreeMap<String, Inode_type>),
+}
+
+/// File or directory stored in memory.
+pub struct Node_type {
+    Metadata: Metadata_type,
+    Attributes: Attributes_type,
+    Content: Content_type,
+    Parent: Inode_type,
+    /// Count of directory entries referring to the node (0 once removed while still open).
+    Links: u64,
+}
+
+impl Node_type {
+    pub fn New(Metadata: Metadata_type, Content: Content_type, Parent: Inode_type) -> Self {
+        Self {
+            Metadata,
+            Attributes: Attributes_type::New(),
+            Content,
+            Parent,
+            Links: 1,
+        }
+    }
+
+    pub fn Get_metadata(&self) -> &Metadata_type {
+        &self.Metadata
+    }
+
+    pub fn Get_metadata_mutable(&mut self) -> &mut Metadata_type {
+        &mut self.Metadata
+    }
+
+    pub fn Set_metadata(&mut self, Metadata: Metadata_type) {
+        self.Metadata = Metadata;
+    }
+
+    pub fn Get_attributes(&self) -> &Attributes_type {
+        &self.Attributes
+    }
+
+    pub fn Get_attributes_mutable(&mut self) -> &mut Attributes_type {
+        &mut self.Attributes
+    }
+
+    pub fn Get_parent(&self) -> Inode_type {
+        self.Parent
+    }
+
+    pub fn Set_parent(&mut self, Parent: Inode_type) {
+        self.Parent = Parent;
+    }
+
+    pub fn Get_links(&self) -> u64 {
+        self.Links
+    }
+
+    pub fn Set_links(&mut self, Links: u64) {
+        self.Links = Links;
+    }
+
+    pub fn Is_directory(&self) -> bool {
+        matches!(self.Content, Content_type::Directory(_))
+    }
+
+    /// Get the size of the content (the count of entries for directories).
+    pub fn Get_size(&self) -> usize {
+        match &self.Content {
+            Content_type::File(Data) => Data.len(),
+            Content_type::Directory(Entries) => Entries.len(),
+        }
+    }
+
+    pub fn Get_entries(&self) -> Option<&BTreeMap<String, Inode_type>> {
+        match &self.Content {
+            Content_type::Directory(Entries) => Some(Entries),
+            Content_type::File(_) => None,
+        }
+    }
+
+    pub fn Get_entries_mutable(&mut self) -> Option<&mut BTreeMap<String, Inode_type>> {
+        match &mut self.Content {
+            Content_type::Directory(Entries) => Some(Entries),
+            Content_type::File(_) => None,
+        }
+    }
+
+    pub fn Get_data(&self) -> Option<&Vec<u8>> {
+        match &self.Content {
+            Content_type::File(Data) => Some(Data),
+            Content_type::Directory(_) => None,
+        }
+    }
+
+    pub fn Get_data_mutable(&mut self) -> Option<&mut Vec<u8>> {
+        match &mut self.Content {
+            Content_type::File(Data) => Some(Data),
+            Content_type::Directory(_) => None,
+        }
+    }
+}
@@ -0,0 +1,1306 @@
+mod Node;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::RwLock,
+};
+
+use Task::Task_identifier_type;
+use Users::{Group_identifier_type, User_identifier_type};
+
+use crate::{
+    Check_issue_kind_type, Check_report_type, Component_type, Entry_type, Error_type,
+    File_identifier_inner_type, File_identifier_type, File_system_identifier_type,
+    File_system_statistics_type, File_system_traits, Flags_type, Get_new_file_identifier,
+    Get_new_inode, Inode_type, Local_file_identifier_type, Metadata_type, Mode_type,
+    Path_owned_type, Path_type, Permission_type, Position_type, Result_type, Size_type,
+    Statistics_type, Time_type, Type_type,
+};
+
+use Node::{Content_type, Node_type};
+
+const Root_inode: Inode_type = Inode_type::New(0);
+
+#[derive(Debug, Clone)]
+struct File_type {
+    Inode: Inode_type,
+    Flags: Flags_type,
+    Position: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Directory_type {
+    Inode: Inode_type,
+    Position: usize,
+}
+
+struct Inner_type {
+    Nodes: BTreeMap<Inode_type, Node_type>,
+    Open_files: BTreeMap<Local_file_identifier_type, File_type>,
+    Open_directories: BTreeMap<Local_file_identifier_type, Directory_type>,
+    /// Total size of the files content.
+    Used_size: usize,
+}
+
+/// File system keeping all its files in memory, with a cap on the size of their content.
+///
+/// It is used for the temporary files (mounted on `/Temporary`), to spare the flash memory, and
+/// for the tests.
+pub struct Memory_file_system_type {
+    Inner: RwLock<Inner_type>,
+    Maximum_size: usize,
+}
+
+impl Memory_file_system_type {
+    pub fn New(Maximum_size: usize) -> Self {
+        let Metadata = Metadata_type::Get_default(
+            Type_type::Directory,
+            Time_type::New(0),
+            User_identifier_type::Root,
+            Group_identifier_type::Root,
+        )
+        .expect("Failed to create root metadata");
+
+        let mut Nodes = BTreeMap::new();
+
+        Nodes.insert(
+            Root_inode,
+            Node_type::New(
+                Metadata,
+                Content_type::Directory(BTreeMap::new()),
+                Root_inode,
+            ),
+        );
+
+        Self {
+            Inner: RwLock::new(Inner_type {
+                Nodes,
+                Open_files: BTreeMap::new(),
+                Open_directories: BTreeMap::new(),
+                Used_size: 0,
+            }),
+            Maximum_size,
+        }
+    }
+
+    pub fn Get_maximum_size(&self) -> usize {
+        self.Maximum_size
+    }
+
+    pub fn Get_used_size(&self) -> Result_type<usize> {
+        Ok(self.Inner.read()?.Used_size)
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    const Directory_flag: File_identifier_inner_type = 1 << 31;
+    #[cfg(target_pointer_width = "32")]
+    const Directory_flag: File_identifier_inner_type = 1 << 15;
+
+    const Directory_minimum: File_identifier_type = File_identifier_type::New(Self::Directory_flag);
+
+    pub fn Is_file(File: Local_file_identifier_type) -> bool {
+        File.Split().1 < Self::Directory_minimum
+    }
+}
+
+/// Check if a user is allowed to access a node with the given permission.
+fn Is_permission_granted(
+    Metadata: &Metadata_type,
+    User: User_identifier_type,
+    Group: Group_identifier_type,
+    Permission: Permission_type,
+) -> bool {
+    if User == User_identifier_type::Root {
+        return true;
+    }
+
+    let Permissions = Metadata.Get_permissions();
+
+    let Granted = if User == Metadata.Get_user() {
+        Permissions.Get_user()
+    } else if Group == Metadata.Get_group() {
+        Permissions.Get_group()
+    } else {
+        Permissions.Get_others()
+    };
+
+    Granted.Include(Permission)
+}
+
+impl Inner_type {
+    fn Get_node(&self, Inode: Inode_type) -> Result_type<&Node_type> {
+        self.Nodes.get(&Inode).ok_or(Error_type::Not_found)
+    }
+
+    fn Get_node_mutable(&mut self, Inode: Inode_type) -> Result_type<&mut Node_type> {
+        self.Nodes.get_mut(&Inode).ok_or(Error_type::Not_found)
+    }
+
+    /// Get the inode of a path.
+    fn Resolve(&self, Path: &Path_type) -> Result_type<Inode_type> {
+        let mut Current = Root_inode;
+
+        for Component in Path.Get_components() {
+            match Component {
+                Component_type::Root | Component_type::Current => {}
+                Component_type::Parent => Current = self.Get_node(Current)?.Get_parent(),
+                Component_type::Normal(Name) => {
+                    Current = *self
+                        .Get_node(Current)?
+                        .Get_entries()
+                        .ok_or(Error_type::Not_directory)?
+                        .get(Name)
+                        .ok_or(Error_type::Not_found)?;
+                }
+            }
+        }
+
+        Ok(Current)
+    }
+
+    /// Get the inode of the parent directory of a path and the name of the last component.
+    fn Resolve_parent<'a>(&self, Path: &'a Path_type) -> Result_type<(Inode_type, &'a str)> {
+        let Name = Path.Get_file_name().ok_or(Error_type::Invalid_path)?;
+
+        if Name.is_empty() || Name == "." || Name == ".." {
+            return Err(Error_type::Invalid_path);
+        }
+
+        let Parent = self.Resolve(Path.Go_parent().ok_or(Error_type::Invalid_path)?)?;
+
+        if !self.Get_node(Parent)?.Is_directory() {
+            return Err(Error_type::Not_directory);
+        }
+
+        Ok((Parent, Name))
+    }
+
+    /// Create a node in a directory.
+    fn Insert(
+        &mut self,
+        Parent: Inode_type,
+        Name: &str,
+        Metadata: Metadata_type,
+        Content: Content_type,
+    ) -> Result_type<Inode_type> {
+        let Inode = Get_new_inode(&self.Nodes)?;
+
+        self.Nodes
+            .insert(Inode, Node_type::New(Metadata, Content, Parent));
+
+        self.Get_node_mutable(Parent)?
+            .Get_entries_mutable()
+            .ok_or(Error_type::Not_directory)?
+            .insert(Name.to_string(), Inode);
+
+        Ok(Inode)
+    }
+
+    fn Is_open(&self, Inode: Inode_type) -> bool {
+        self.Open_files.values().any(|File| File.Inode == Inode)
+            || self
+                .Open_directories
+                .values()
+                .any(|Directory| Directory.Inode == Inode)
+    }
+
+    /// Release a node that has no more links and isn't open anymore.
+    fn Release(&mut self, Inode: Inode_type) {
+        let Releasable = match self.Nodes.get(&Inode) {
+            Some(Node) => Node.Get_links() == 0 && !self.Is_open(Inode),
+            None => false,
+        };
+
+        if Releasable {
+            if let Some(Node) = self.Nodes.remove(&Inode) {
+                if let Some(Data) = Node.Get_data() {
+                    self.Used_size -= Data.len();
+                }
+            }
+        }
+    }
+
+    /// Remove the entry of a node from its parent directory.
+    fn Unlink(&mut self, Parent: Inode_type, Name: &str) -> Result_type<()> {
+        let Inode = self
+            .Get_node_mutable(Parent)?
+            .Get_entries_mutable()
+            .ok_or(Error_type::Not_directory)?
+            .remove(Name)
+            .ok_or(Error_type::Not_found)?;
+
+        let Node = self.Get_node_mutable(Inode)?;
+
+        Node.Set_links(Node.Get_links().saturating_sub(1));
+
+        self.Release(Inode);
+
+        Ok(())
+    }
+
+    /// Check the node tree : dangling entries, wrong parents, unreachable nodes and the size accounting.
+    fn Check(&mut self, Repair: bool) -> Result_type<Check_report_type> {
+        let mut Report = Check_report_type::New();
+
+        let mut Reachable = BTreeSet::from([Root_inode]);
+        let mut Stack = vec![(Root_inode, Path_owned_type::Root())];
+
+        while let Some((Inode, Path)) = Stack.pop() {
+            let Entries: Vec<(String, Inode_type)> = match self.Get_node(Inode)?.Get_entries() {
+                Some(Entries) => Entries
+                    .iter()
+                    .map(|(Name, Inode)| (Name.clone(), *Inode))
+                    .collect(),
+                None => continue,
+            };
+
+            for (Name, Child) in Entries {
+                let Child_path = Path.clone().Append(&Name).ok_or(Error_type::Invalid_path)?;
+
+                let Node = match self.Nodes.get_mut(&Child) {
+                    Some(Node) => Node,
+                    None => {
+                        Report.Add_issue(
+                            Child_path,
+                            Check_issue_kind_type::Corrupted_directory,
+                            Repair,
+                        );
+
+                        if Repair {
+                            if let Some(Entries) =
+                                self.Get_node_mutable(Inode)?.Get_entries_mutable()
+                            {
+                                Entries.remove(&Name);
+                            }
+                        }
+
+                        continue;
+                    }
+                };
+
+                if !Node.Is_directory() {
+                    Report.Add_file();
+                    Reachable.insert(Child);
+                    continue;
+                }
+
+                Report.Add_directory();
+
+                if Node.Get_parent() != Inode {
+                    Report.Add_issue(
+                        Child_path.clone(),
+                        Check_issue_kind_type::Corrupted_directory,
+                        Repair,
+                    );
+
+                    if Repair {
+                        Node.Set_parent(Inode);
+                    }
+                }
+
+                if Reachable.insert(Child) {
+                    Stack.push((Child, Child_path));
+                }
+            }
+        }
+
+        // - Removed files are only kept while they are open.
+        let Orphans: Vec<Inode_type> = self
+            .Nodes
+            .keys()
+            .filter(|Inode| !Reachable.contains(Inode) && !self.Is_open(**Inode))
+            .copied()
+            .collect();
+
+        for Inode in Orphans {
+            Report.Add_issue(
+                Path_owned_type::Root(),
+                Check_issue_kind_type::Orphan,
+                Repair,
+            );
+
+            if Repair {
+                self.Nodes.remove(&Inode);
+            }
+        }
+
+        let Used_size = self
+            .Nodes
+            .values()
+            .filter_map(|Node| Node.Get_data())
+            .map(|Data| Data.len())
+            .sum();
+
+        if Used_size != self.Used_size {
+            Report.Add_issue(
+                Path_owned_type::Root(),
+                Check_issue_kind_type::Inconsistent_size,
+                Repair,
+            );
+
+            if Repair {
+                self.Used_size = Used_size;
+            }
+        }
+
+        Ok(Report)
+    }
+
+    /// Check if `Inode` is `Ancestor` or one of its descendants.
+    fn Is_descendant(&self, Inode: Inode_type, Ancestor: Inode_type) -> Result_type<bool> {
+        let mut Current = Inode;
+
+        loop {
+            if Current == Ancestor {
+                return Ok(true);
+            }
+
+            if Current == Root_inode {
+                return Ok(false);
+            }
+
+            Current = self.Get_node(Current)?.Get_parent();
+        }
+    }
+
+    fn Get_statistics(&self, Inode: Inode_type) -> Result_type<Statistics_type> {
+        let Node = self.Get_node(Inode)?;
+
+        let Metadata = Node.Get_metadata();
+
+        Ok(Statistics_type::New(
+            File_system_identifier_type::New(0),
+            Metadata.Get_inode().unwrap_or(Inode),
+            Node.Get_links(),
+            Size_type::New(Node.Get_size() as u64),
+            Metadata.Get_access_time(),
+            Metadata.Get_modification_time(),
+            Metadata.Get_modification_time(),
+            Metadata.Get_type(),
+            Metadata.Get_permissions(),
+            Metadata.Get_user(),
+            Metadata.Get_group(),
+        ))
+    }
+}
+
+impl File_system_traits for Memory_file_system_type {
+    fn Open(
+        &self,
+        Task: Task_identifier_type,
+        Path: &Path_type,
+        Flags: Flags_type,
+        Time: Time_type,
+        User: User_identifier_type,
+        Group: Group_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Open = Flags.Get_open();
+
+        let Permission = Permission_type::New(
+            Flags.Get_mode().Get_read(),
+            Flags.Get_mode().Get_write() || Open.Get_truncate(),
+            false,
+        );
+
+        let Inode = match Inner.Resolve(Path) {
+            Ok(Inode) => {
+                if Open.Get_exclusive() {
+                    return Err(Error_type::Already_exists);
+                }
+
+                let Node = Inner.Get_node_mutable(Inode)?;
+
+                if Node.Is_directory() {
+                    return Err(Error_type::Is_directory);
+                }
+
+                if !Is_permission_granted(Node.Get_metadata(), User, Group, Permission) {
+                    return Err(Error_type::Permission_denied);
+                }
+
+                if Open.Get_truncate() {
+                    let Data = Node.Get_data_mutable().ok_or(Error_type::Is_directory)?;
+
+                    let Size = Data.len();
+
+                    Data.clear();
+                    Data.shrink_to_fit();
+
+                    Node.Get_metadata_mutable().Set_modification_time(Time);
+
+                    Inner.Used_size -= Size;
+                }
+
+                Inode
+            }
+            Err(Error_type::Not_found) if Open.Get_create() => {
+                let (Parent, Name) = Inner.Resolve_parent(Path)?;
+
+                if !Is_permission_granted(
+                    Inner.Get_node(Parent)?.Get_metadata(),
+                    User,
+                    Group,
+                    Permission_type::New(false, true, false),
+                ) {
+                    return Err(Error_type::Permission_denied);
+                }
+
+                let Metadata = Metadata_type::Get_default(Type_type::File, Time, User, Group)
+                    .ok_or(Error_type::Invalid_parameter)?;
+
+                Inner.Insert(Parent, Name, Metadata, Content_type::File(Vec::new()))?
+            }
+            Err(Error) => return Err(Error),
+        };
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(File_identifier_type::Minimum),
+            Some(Self::Directory_minimum),
+            &Inner.Open_files,
+        )?;
+
+        let File = File_type {
+            Inode,
+            Flags,
+            Position: 0,
+        };
+
+        if Inner.Open_files.insert(File_identifier, File).is_some() {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Close(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let File = Inner
+            .Open_files
+            .remove(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Inner.Release(File.Inode);
+
+        Ok(())
+    }
+
+    fn Close_all(&self, Task: Task_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inodes = Inner
+            .Open_files
+            .iter()
+            .filter(|(Key, _)| Key.Split().0 == Task)
+            .map(|(_, File)| File.Inode)
+            .collect::<Vec<_>>();
+
+        Inner.Open_files.retain(|Key, _| Key.Split().0 != Task);
+        Inner
+            .Open_directories
+            .retain(|Key, _| Key.Split().0 != Task);
+
+        for Inode in Inodes {
+            Inner.Release(Inode);
+        }
+
+        Ok(())
+    }
+
+    fn Duplicate(
+        &self,
+        File: Local_file_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let (Task, _) = File.Split();
+
+        let mut Inner = self.Inner.write()?;
+
+        let File = Inner
+            .Open_files
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?
+            .clone();
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(File_identifier_type::Minimum),
+            Some(Self::Directory_minimum),
+            &Inner.Open_files,
+        )?;
+
+        if Inner.Open_files.insert(File_identifier, File).is_some() {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Transfert(
+        &self,
+        New_task: Task_identifier_type,
+        File_identifier: Local_file_identifier_type,
+        New_file: Option<File_identifier_type>,
+    ) -> Result_type<Local_file_identifier_type> {
+        let mut Inner = self.Inner.write()?;
+
+        if Self::Is_file(File_identifier) {
+            let File = Inner
+                .Open_files
+                .remove(&File_identifier)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            let New_identifier = match New_file {
+                Some(New_file) => Local_file_identifier_type::New(New_task, New_file),
+                None => Get_new_file_identifier(
+                    New_task,
+                    Some(File_identifier_type::Minimum),
+                    Some(Self::Directory_minimum),
+                    &Inner.Open_files,
+                )?,
+            };
+
+            if Inner.Open_files.contains_key(&New_identifier) {
+                Inner.Open_files.insert(File_identifier, File);
+
+                return Err(Error_type::Invalid_identifier);
+            }
+
+            Inner.Open_files.insert(New_identifier, File);
+
+            Ok(New_identifier)
+        } else {
+            let Directory = Inner
+                .Open_directories
+                .remove(&File_identifier)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            let New_identifier = match New_file {
+                Some(New_file) => Local_file_identifier_type::New(New_task, New_file),
+                None => Get_new_file_identifier(
+                    New_task,
+                    Some(Self::Directory_minimum),
+                    Some(File_identifier_type::Maximum),
+                    &Inner.Open_directories,
+                )?,
+            };
+
+            if Inner.Open_directories.contains_key(&New_identifier) {
+                Inner.Open_directories.insert(File_identifier, Directory);
+
+                return Err(Error_type::Invalid_identifier);
+            }
+
+            Inner.Open_directories.insert(New_identifier, Directory);
+
+            Ok(New_identifier)
+        }
+    }
+
+    fn Remove(&self, Path: &Path_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let (Parent, Name) = Inner.Resolve_parent(Path)?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        if let Some(Entries) = Inner.Get_node(Inode)?.Get_entries() {
+            if !Entries.is_empty() {
+                return Err(Error_type::Directory_not_empty);
+            }
+        }
+
+        // - Open files stay readable until they are closed.
+        Inner.Unlink(Parent, Name)
+    }
+
+    fn Read(
+        &self,
+        File: Local_file_identifier_type,
+        Buffer: &mut [u8],
+        Time: Time_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        if !File.Flags.Get_mode().Get_read() {
+            return Err(Error_type::Invalid_mode);
+        }
+
+        let Node = Inner
+            .Nodes
+            .get_mut(&File.Inode)
+            .ok_or(Error_type::Not_found)?;
+
+        let Data = Node.Get_data().ok_or(Error_type::Is_directory)?;
+
+        let Size = Buffer.len().min(Data.len().saturating_sub(File.Position));
+
+        Buffer[..Size].copy_from_slice(&Data[File.Position..File.Position + Size]);
+
+        File.Position += Size;
+
+        Node.Get_metadata_mutable().Set_access_time(Time);
+
+        Ok(Size.into())
+    }
+
+    fn Write(
+        &self,
+        File: Local_file_identifier_type,
+        Buffer: &[u8],
+        Time: Time_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        if !File.Flags.Get_mode().Get_write() {
+            return Err(Error_type::Invalid_mode);
+        }
+
+        let Node = Inner
+            .Nodes
+            .get_mut(&File.Inode)
+            .ok_or(Error_type::Not_found)?;
+
+        let Data = Node.Get_data_mutable().ok_or(Error_type::Is_directory)?;
+
+        if File.Flags.Get_status().Get_append() {
+            File.Position = Data.len();
+        }
+
+        let End = File.Position + Buffer.len();
+
+        let Growth = End.saturating_sub(Data.len());
+
+        if Inner.Used_size + Growth > self.Maximum_size {
+            return Err(Error_type::No_space_left);
+        }
+
+        // - Writing after the end of the file fills the gap with zeroes.
+        if Growth > 0 {
+            Data.resize(End, 0);
+        }
+
+        Data[File.Position..End].copy_from_slice(Buffer);
+
+        File.Position = End;
+
+        Inner.Used_size += Growth;
+
+        Node.Get_metadata_mutable().Set_modification_time(Time);
+
+        Ok(Buffer.len().into())
+    }
+
+    fn Rename(&self, Source: &Path_type, Destination: &Path_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let (Source_parent, Source_name) = Inner.Resolve_parent(Source)?;
+
+        let Inode = Inner.Resolve(Source)?;
+
+        let (Destination_parent, Destination_name) = Inner.Resolve_parent(Destination)?;
+
+        // - A directory can't be moved into itself.
+        if Inner.Is_descendant(Destination_parent, Inode)? {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        let Existing = Inner
+            .Get_node(Destination_parent)?
+            .Get_entries()
+            .ok_or(Error_type::Not_directory)?
+            .get(Destination_name)
+            .copied();
+
+        if let Some(Existing) = Existing {
+            if Existing == Inode {
+                return Ok(());
+            }
+
+            let Existing_node = Inner.Get_node(Existing)?;
+
+            match (
+                Inner.Get_node(Inode)?.Is_directory(),
+                Existing_node.Get_entries(),
+            ) {
+                (false, Some(_)) => return Err(Error_type::Is_directory),
+                (true, None) => return Err(Error_type::Not_directory),
+                (true, Some(Entries)) if !Entries.is_empty() => {
+                    return Err(Error_type::Directory_not_empty)
+                }
+                _ => {}
+            }
+
+            Inner.Unlink(Destination_parent, Destination_name)?;
+        }
+
+        Inner
+            .Get_node_mutable(Source_parent)?
+            .Get_entries_mutable()
+            .ok_or(Error_type::Not_directory)?
+            .remove(Source_name);
+
+        Inner
+            .Get_node_mutable(Destination_parent)?
+            .Get_entries_mutable()
+            .ok_or(Error_type::Not_directory)?
+            .insert(Destination_name.to_string(), Inode);
+
+        Inner
+            .Get_node_mutable(Inode)?
+            .Set_parent(Destination_parent);
+
+        Ok(())
+    }
+
+    fn Set_position(
+        &self,
+        File: Local_file_identifier_type,
+        Position: &Position_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        let Size = Inner
+            .Nodes
+            .get(&File.Inode)
+            .ok_or(Error_type::Not_found)?
+            .Get_size();
+
+        let New_position = match Position {
+            Position_type::Start(Position) => *Position as i128,
+            Position_type::Current(Offset) => File.Position as i128 + *Offset as i128,
+            Position_type::End(Offset) => Size as i128 + *Offset as i128,
+        };
+
+        if New_position < 0 || New_position > usize::MAX as i128 {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        File.Position = New_position as usize;
+
+        Ok(Size_type::New(File.Position as u64))
+    }
+
+    fn Flush(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        let Inner = self.Inner.read()?;
+
+        if !Inner.Open_files.contains_key(&File) {
+            return Err(Error_type::Invalid_identifier);
+        }
+
+        Ok(())
+    }
+
+    fn Create_directory(
+        &self,
+        Path: &Path_type,
+        Time: Time_type,
+        User: User_identifier_type,
+        Group: Group_identifier_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        if Inner.Resolve(Path).is_ok() {
+            return Err(Error_type::Already_exists);
+        }
+
+        let (Parent, Name) = Inner.Resolve_parent(Path)?;
+
+        if !Is_permission_granted(
+            Inner.Get_node(Parent)?.Get_metadata(),
+            User,
+            Group,
+            Permission_type::New(false, true, false),
+        ) {
+            return Err(Error_type::Permission_denied);
+        }
+
+        let Metadata = Metadata_type::Get_default(Type_type::Directory, Time, User, Group)
+            .ok_or(Error_type::Invalid_parameter)?;
+
+        Inner.Insert(
+            Parent,
+            Name,
+            Metadata,
+            Content_type::Directory(BTreeMap::new()),
+        )?;
+
+        Ok(())
+    }
+
+    fn Open_directory(
+        &self,
+        Path: &Path_type,
+        Task: Task_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        if !Inner.Get_node(Inode)?.Is_directory() {
+            return Err(Error_type::Not_directory);
+        }
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(Self::Directory_minimum),
+            Some(File_identifier_type::Maximum),
+            &Inner.Open_directories,
+        )?;
+
+        let Directory = Directory_type { Inode, Position: 0 };
+
+        if Inner
+            .Open_directories
+            .insert(File_identifier, Directory)
+            .is_some()
+        {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Read_directory(&self, File: Local_file_identifier_type) -> Result_type<Option<Entry_type>> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inner = &mut *Inner;
+
+        let Directory = Inner
+            .Open_directories
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        let Node = Inner
+            .Nodes
+            .get(&Directory.Inode)
+            .ok_or(Error_type::Not_found)?;
+
+        // - The "." and ".." entries come first.
+        let (Name, Inode) = match Directory.Position {
+            0 => (".", Directory.Inode),
+            1 => ("..", Node.Get_parent()),
+            Position => match Node
+                .Get_entries()
+                .ok_or(Error_type::Not_directory)?
+                .iter()
+                .nth(Position - 2)
+            {
+                Some((Name, Inode)) => (Name.as_str(), *Inode),
+                None => return Ok(None),
+            },
+        };
+
+        let Entry_node = Inner.Nodes.get(&Inode).ok_or(Error_type::Not_found)?;
+
+        Directory.Position += 1;
+
+        Ok(Some(Entry_type::New(
+            Inode,
+            Name.to_string(),
+            Entry_node.Get_metadata().Get_type(),
+            Size_type::New(Entry_node.Get_size() as u64),
+        )))
+    }
+
+    fn Set_position_directory(
+        &self,
+        File: Local_file_identifier_type,
+        Position: Size_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Directory.Position = Position.into();
+
+        Ok(())
+    }
+
+    fn Get_position_directory(&self, File: Local_file_identifier_type) -> Result_type<Size_type> {
+        let Inner = self.Inner.read()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(Directory.Position.into())
+    }
+
+    fn Rewind_directory(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        self.Set_position_directory(File, Size_type::New(0))
+    }
+
+    fn Close_directory(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Directory = Inner
+            .Open_directories
+            .remove(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Inner.Release(Directory.Inode);
+
+        Ok(())
+    }
+
+    fn Get_metadata(&self, File: Local_file_identifier_type) -> Result_type<Metadata_type> {
+        let Inner = self.Inner.read()?;
+
+        let Inode = if let Some(File) = Inner.Open_files.get(&File) {
+            File.Inode
+        } else if let Some(Directory) = Inner.Open_directories.get(&File) {
+            Directory.Inode
+        } else {
+            return Err(Error_type::Invalid_identifier);
+        };
+
+        Ok(Inner.Get_node(Inode)?.Get_metadata().clone())
+    }
+
+    fn Set_metadata_from_path(
+        &self,
+        Path: &Path_type,
+        Metadata: &Metadata_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        Inner
+            .Get_node_mutable(Inode)?
+            .Set_metadata(Metadata.clone());
+
+        Ok(())
+    }
+
+    fn Get_metadata_from_path(&self, Path: &Path_type) -> Result_type<Metadata_type> {
+        let Inner = self.Inner.read()?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        Ok(Inner.Get_node(Inode)?.Get_metadata().clone())
+    }
+
+    fn Get_statistics(&self, File: Local_file_identifier_type) -> Result_type<Statistics_type> {
+        let Inner = self.Inner.read()?;
+
+        let Inode = if let Some(File) = Inner.Open_files.get(&File) {
+            File.Inode
+        } else if let Some(Directory) = Inner.Open_directories.get(&File) {
+            Directory.Inode
+        } else {
+            return Err(Error_type::Invalid_identifier);
+        };
+
+        Inner.Get_statistics(Inode)
+    }
+
+    fn Get_mode(&self, File: Local_file_identifier_type) -> Result_type<Mode_type> {
+        let Inner = self.Inner.read()?;
+
+        let Result = if Self::Is_file(File) {
+            Inner
+                .Open_files
+                .get(&File)
+                .ok_or(Error_type::Invalid_identifier)?
+                .Flags
+                .Get_mode()
+        } else {
+            Inner
+                .Open_directories
+                .get(&File)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            Mode_type::Read_only
+        };
+
+        Ok(Result)
+    }
+
+    fn Get_type_name(&self) -> &'static str {
+        "Memory"
+    }
+
+    fn Check(&self, Repair: bool) -> Result_type<Check_report_type> {
+        self.Inner.write()?.Check(Repair)
+    }
+
+    fn Get_attribute(&self, Path: &Path_type, Name: &str) -> Result_type<Vec<u8>> {
+        let Inner = self.Inner.read()?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        Ok(Inner.Get_node(Inode)?.Get_attributes().Get(Name)?.to_vec())
+    }
+
+    fn Set_attribute(&self, Path: &Path_type, Name: &str, Value: &[u8]) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        Inner
+            .Get_node_mutable(Inode)?
+            .Get_attributes_mutable()
+            .Set(Name, Value)
+    }
+
+    fn Remove_attribute(&self, Path: &Path_type, Name: &str) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        Inner
+            .Get_node_mutable(Inode)?
+            .Get_attributes_mutable()
+            .Remove(Name)
+    }
+
+    fn List_attributes(&self, Path: &Path_type) -> Result_type<Vec<String>> {
+        let Inner = self.Inner.read()?;
+
+        let Inode = Inner.Resolve(Path)?;
+
+        Ok(Inner.Get_node(Inode)?.Get_attributes().Get_names())
+    }
+
+    fn Get_file_system_statistics(&self) -> Result_type<File_system_statistics_type> {
+        let Free = self
+            .Maximum_size
+            .saturating_sub(self.Inner.read()?.Used_size) as u64;
+
+        // - The size limit is byte-granular and there is no inode limit.
+        Ok(File_system_statistics_type::New(
+            1,
+            self.Maximum_size as u64,
+            Free,
+            Free,
+            0,
+            0,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use crate::{Open_type, Permissions_type};
+
+    use super::*;
+
+    fn Initialize() -> Memory_file_system_type {
+        let _ = Users::Initialize();
+
+        let _ = Task::Initialize();
+
+        unsafe {
+            let _ = Task::Get_instance().Register_task();
+        }
+
+        Memory_file_system_type::New(1024 * 1024)
+    }
+
+    #[test]
+    fn Test_open_close_delete() {
+        crate::Tests::Test_open_close_delete(Initialize());
+    }
+
+    #[test]
+    fn Test_read_write() {
+        crate::Tests::Test_read_write(Initialize());
+    }
+
+    #[test]
+    fn Test_move() {
+        crate::Tests::Test_move(Initialize());
+    }
+
+    #[test]
+    fn Test_set_position() {
+        crate::Tests::Test_set_position(Initialize());
+    }
+
+    #[test]
+    fn Test_flush() {
+        crate::Tests::Test_flush(Initialize());
+    }
+
+    #[test]
+    fn Test_set_get_metadata() {
+        crate::Tests::Test_set_get_metadata(Initialize());
+    }
+
+    #[test]
+    fn Test_read_directory() {
+        crate::Tests::Test_read_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_set_position_directory() {
+        crate::Tests::Test_set_position_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_rewind_directory() {
+        crate::Tests::Test_rewind_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_create_remove_directory() {
+        crate::Tests::Test_create_remove_directory(Initialize());
+    }
+
+    #[test]
+    fn Test_loader() {
+        crate::Tests::Test_loader(Initialize());
+    }
+
+    #[test]
+    fn Test_attributes() {
+        crate::Tests::Test_attributes(Initialize());
+    }
+
+    #[test]
+    fn Test_check() {
+        let File_system = Initialize();
+
+        let Metadata = Metadata_type::Get_default(
+            Type_type::File,
+            Time_type::New(0),
+            User_identifier_type::Root,
+            Group_identifier_type::Root,
+        )
+        .unwrap();
+
+        File_system
+            .Create_directory(
+                Path_type::From_str("/Directory"),
+                Time_type::New(0),
+                User_identifier_type::Root,
+                Group_identifier_type::Root,
+            )
+            .unwrap();
+
+        assert!(File_system.Check(false).unwrap().Is_clean());
+
+        // - Corrupt the file system : an unreachable node, a dangling entry and a wrong size.
+        {
+            let mut Inner = File_system.Inner.write().unwrap();
+
+            Inner.Nodes.insert(
+                Inode_type::New(100),
+                Node_type::New(Metadata, Content_type::File(vec![0; 4]), Root_inode),
+            );
+
+            Inner
+                .Get_node_mutable(Root_inode)
+                .unwrap()
+                .Get_entries_mutable()
+                .unwrap()
+                .insert("Dangling".to_string(), Inode_type::New(200));
+        }
+
+        let Report = File_system.Check(false).unwrap();
+
+        assert_eq!(Report.Get_directories(), 1);
+        assert_eq!(Report.Get_issues().len(), 3);
+        assert!(!Report.Is_clean());
+        assert_eq!(
+            Report.Get_issues()[0].Get_path(),
+            &Path_owned_type::Root().Append("Dangling").unwrap()
+        );
+
+        // - The inspection doesn't change anything.
+        assert_eq!(File_system.Check(false).unwrap(), Report);
+
+        // - Releasing the orphan node also fixes the size.
+        let Report = File_system.Check(true).unwrap();
+
+        assert_eq!(Report.Get_issues().len(), 2);
+        assert!(Report.Is_clean());
+        assert!(File_system.Check(false).unwrap().Get_issues().is_empty());
+        assert_eq!(File_system.Get_used_size().unwrap(), 0);
+    }
+
+    #[test]
+    fn Test_size_limit_and_permissions() {
+        let _ = Initialize();
+
+        let File_system = Memory_file_system_type::New(8);
+
+        let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
+
+        let User = User_identifier_type::New(1000);
+        let Group = Group_identifier_type::New(1000);
+
+        let Path = Path_type::From_str("/File");
+
+        let Flags = Flags_type::New(Mode_type::Read_write, Some(Open_type::Create_only), None);
+
+        // - The root directory is only writable by root by default.
+        assert_eq!(
+            File_system.Open(Task, Path, Flags, Time_type::New(0), User, Group),
+            Err(Error_type::Permission_denied)
+        );
+
+        let mut Metadata = File_system.Get_metadata_from_path(Path_type::Root).unwrap();
+
+        Metadata.Set_permissions(Permissions_type::From_octal(0o777).unwrap());
+
+        File_system
+            .Set_metadata_from_path(Path_type::Root, &Metadata)
+            .unwrap();
+
+        let File = File_system
+            .Open(Task, Path, Flags, Time_type::New(0), User, Group)
+            .unwrap();
+
+        File_system.Write(File, &[1; 6], Time_type::New(0)).unwrap();
+
+        let Statistics = File_system.Get_file_system_statistics().unwrap();
+
+        assert_eq!(Statistics.Get_total_size(), 8);
+        assert_eq!(Statistics.Get_used_size(), 6);
+        assert_eq!(Statistics.Get_available_size(), 2);
+
+        assert_eq!(
+            File_system.Write(File, &[1; 6], Time_type::New(0)),
+            Err(Error_type::No_space_left)
+        );
+
+        // - Removed open files are kept until they are closed.
+        File_system.Remove(Path).unwrap();
+
+        assert_eq!(File_system.Get_used_size().unwrap(), 6);
+
+        File_system.Close(File).unwrap();
+
+        assert_eq!(File_system.Get_used_size().unwrap(), 0);
+    }
+}
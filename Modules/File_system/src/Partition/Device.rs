@@ -0,0 +1,186 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::{Device_trait, Device_type, Error_type, Position_type, Result_type, Size_type};
+
+use super::{Get_device_lock, Partition_entry_type, Sector_size};
+
+/// Device restricted to a partition of another device.
+///
+/// The positions are relative to the start of the partition and the accesses are bounded to its size.
+/// The accesses hold the lock of the underlying device (see [`Get_device_lock`]), since they move its position.
+pub struct Partition_device_type {
+    Device: Device_type,
+    Start: u64,
+    Size: u64,
+    Index: usize,
+    Position: RwLock<u64>,
+    Lock: Arc<Mutex<()>>,
+}
+
+impl Partition_device_type {
+    pub fn New(Device: Device_type, Partition: &Partition_entry_type) -> Result_type<Self> {
+        Ok(Self {
+            Lock: Get_device_lock(&Device)?,
+            Device,
+            Start: Partition.Get_start_offset(),
+            Size: Partition.Get_size(),
+            Index: Partition.Get_index(),
+            Position: RwLock::new(0),
+        })
+    }
+
+    pub fn Get_start(&self) -> u64 {
+        self.Start
+    }
+
+    /// Get the index of the partition in the partition table (see [`Partition_entry_type::Get_index`]).
+    pub fn Get_index(&self) -> usize {
+        self.Index
+    }
+
+    /// Move the underlying device to the current position and get the remaining size of the partition.
+    fn Seek(&self, Position: u64) -> Result_type<u64> {
+        self.Device
+            .Set_position(&Position_type::Start(self.Start + Position))?;
+
+        Ok(self.Size.saturating_sub(Position))
+    }
+}
+
+impl Device_trait for Partition_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<Size_type> {
+        let _Lock = self.Lock.lock()?;
+        let mut Position = self.Position.write()?;
+
+        let Remaining = self.Seek(*Position)?;
+        let Size = (Buffer.len() as u64).min(Remaining) as usize;
+
+        let Read: usize = self.Device.Read(&mut Buffer[..Size])?.into();
+
+        *Position += Read as u64;
+
+        Ok(Read.into())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<Size_type> {
+        let _Lock = self.Lock.lock()?;
+        let mut Position = self.Position.write()?;
+
+        let Remaining = self.Seek(*Position)?;
+
+        if Remaining == 0 && !Buffer.is_empty() {
+            return Err(Error_type::No_space_left);
+        }
+
+        let Size = (Buffer.len() as u64).min(Remaining) as usize;
+
+        let Written: usize = self.Device.Write(&Buffer[..Size])?.into();
+
+        *Position += Written as u64;
+
+        Ok(Written.into())
+    }
+
+    fn Get_size(&self) -> Result_type<Size_type> {
+        Ok(self.Size.into())
+    }
+
+    fn Set_position(&self, Position: &Position_type) -> Result_type<Size_type> {
+        let mut Current = self.Position.write()?;
+
+        let New_position = match Position {
+            Position_type::Start(Position) => *Position as i128,
+            Position_type::Current(Offset) => *Current as i128 + *Offset as i128,
+            Position_type::End(Offset) => self.Size as i128 + *Offset as i128,
+        };
+
+        if New_position < 0 || New_position > self.Size as i128 {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        *Current = New_position as u64;
+
+        Ok(Size_type::New(*Current))
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        self.Device.Flush()
+    }
+
+    fn Erase(&self) -> Result_type<()> {
+        let _Lock = self.Lock.lock()?;
+        let Position = self.Position.read()?;
+
+        if self.Seek(*Position)? == 0 {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        self.Device.Erase()
+    }
+
+    fn Get_block_size(&self) -> Result_type<usize> {
+        match self.Device.Get_block_size() {
+            Err(Error_type::Unsupported_operation) => Ok(Sector_size),
+            Result => Result,
+        }
+    }
+
+    fn Is_a_block_device(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    use crate::{Create_device, Master_boot_record_type, Memory_device_type, Partition_kind_type};
+
+    #[test]
+    fn Test_bounded_access() {
+        let Device = Create_device!(Memory_device_type::<512>::New(512 * 16));
+
+        let Partition = Partition_entry_type::New(
+            4,
+            2,
+            Partition_kind_type::Master_boot_record(Master_boot_record_type::Linux_partition),
+            false,
+            "",
+        );
+
+        let Partition_device = Partition_device_type::New(Device.clone(), &Partition).unwrap();
+
+        assert_eq!(Partition_device.Get_size().unwrap(), 1024_usize);
+
+        // - Writes are truncated at the end of the partition.
+        Partition_device
+            .Set_position(&Position_type::Start(1020))
+            .unwrap();
+        assert_eq!(Partition_device.Write(&[0xAA; 8]).unwrap(), 4_usize);
+        assert_eq!(
+            Partition_device.Write(&[0xAA; 8]),
+            Err(Error_type::No_space_left)
+        );
+
+        // - The offset is translated to the underlying device.
+        Partition_device
+            .Set_position(&Position_type::Start(0))
+            .unwrap();
+        Partition_device.Write(&[1, 2, 3]).unwrap();
+
+        let Data = Device.Dump_device().unwrap();
+        assert_eq!(&Data[2048..2051], &[1, 2, 3]);
+        assert_eq!(&Data[3068..3072], &[0xAA; 4]);
+        assert_eq!(Data[3072], 0);
+
+        let mut Buffer = [0_u8; 8];
+        Partition_device
+            .Set_position(&Position_type::End(-2))
+            .unwrap();
+        assert_eq!(Partition_device.Read(&mut Buffer).unwrap(), 2_usize);
+
+        assert!(Partition_device
+            .Set_position(&Position_type::Start(1025))
+            .is_err());
+    }
+}
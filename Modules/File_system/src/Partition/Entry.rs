@@ -0,0 +1,92 @@
+use super::Sector_size;
+
+/// Type of a partition, as stored in the partition table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Partition_kind_type {
+    /// Partition type byte of a master boot record entry.
+    Master_boot_record(u8),
+    /// Partition type GUID of a GUID partition table entry (in its on-disk mixed-endian layout).
+    GUID_partition_table([u8; 16]),
+}
+
+/// Partition described by a partition table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partition_entry_type {
+    Start_sector: u64,
+    Sector_count: u64,
+    Kind: Partition_kind_type,
+    Bootable: bool,
+    Name: String,
+    /// Index of the entry in the partition table, the empty entries included.
+    Index: usize,
+}
+
+impl Partition_entry_type {
+    pub fn New(
+        Start_sector: u64,
+        Sector_count: u64,
+        Kind: Partition_kind_type,
+        Bootable: bool,
+        Name: &str,
+    ) -> Self {
+        Self {
+            Start_sector,
+            Sector_count,
+            Kind,
+            Bootable,
+            Name: Name.to_string(),
+            Index: 0,
+        }
+    }
+
+    pub fn Set_index(mut self, Index: usize) -> Self {
+        self.Index = Index;
+        self
+    }
+
+    /// Get the index of the entry in the partition table (e.g. `1` for the second primary partition).
+    pub fn Get_index(&self) -> usize {
+        self.Index
+    }
+
+    pub fn Get_start_sector(&self) -> u64 {
+        self.Start_sector
+    }
+
+    pub fn Get_sector_count(&self) -> u64 {
+        self.Sector_count
+    }
+
+    /// Get the index of the last sector of the partition (inclusive).
+    pub fn Get_end_sector(&self) -> u64 {
+        (self.Start_sector + self.Sector_count).saturating_sub(1)
+    }
+
+    /// Get the offset of the partition in bytes.
+    pub fn Get_start_offset(&self) -> u64 {
+        self.Start_sector * Sector_size as u64
+    }
+
+    /// Get the size of the partition in bytes.
+    pub fn Get_size(&self) -> u64 {
+        self.Sector_count * Sector_size as u64
+    }
+
+    pub fn Get_kind(&self) -> Partition_kind_type {
+        self.Kind
+    }
+
+    pub fn Is_bootable(&self) -> bool {
+        self.Bootable
+    }
+
+    pub fn Get_name(&self) -> &str {
+        &self.Name
+    }
+
+    /// Check if two partitions share at least one sector.
+    pub fn Is_overlapping(&self, Other: &Self) -> bool {
+        self.Start_sector < Other.Start_sector + Other.Sector_count
+            && Other.Start_sector < self.Start_sector + self.Sector_count
+    }
+}
@@ -0,0 +1,471 @@
+use crate::{Block_type, Device_type, Error_type, Result_type};
+
+use super::{
+    Master_boot_record_type, Partition_entry_type, Partition_kind_type, Read_sector, Sector_size,
+    Write_sector,
+};
+
+pub type GUID_type = [u8; 16];
+
+const Header_signature: &[u8; 8] = b"EFI PART";
+const Header_revision: u32 = 0x0001_0000;
+const Header_size: u32 = 92;
+const Entry_count: usize = 128;
+const Entry_size: usize = 128;
+const Entry_name_length: usize = 36;
+/// Number of sectors used by the partition entries array.
+const Entries_sector_count: u64 = ((Entry_count * Entry_size) / Sector_size) as u64;
+/// Attribute bit of the partitions bootable by legacy BIOS.
+const Legacy_bootable_attribute: u64 = 1 << 2;
+
+/// Create a GUID in its on-disk layout (the first three fields are little endian).
+pub const fn New_GUID(First: u32, Second: u16, Third: u16, Fourth: [u8; 8]) -> GUID_type {
+    let First = First.to_le_bytes();
+    let Second = Second.to_le_bytes();
+    let Third = Third.to_le_bytes();
+
+    [
+        First[0], First[1], First[2], First[3], Second[0], Second[1], Third[0], Third[1],
+        Fourth[0], Fourth[1], Fourth[2], Fourth[3], Fourth[4], Fourth[5], Fourth[6], Fourth[7],
+    ]
+}
+
+/// CRC-32 (IEEE 802.3) used by the GUID partition table headers and entries.
+fn Get_CRC32(Data: &[u8]) -> u32 {
+    let mut CRC = !0_u32;
+
+    for Byte in Data {
+        CRC ^= *Byte as u32;
+
+        for _ in 0..8 {
+            let Mask = (CRC & 1).wrapping_neg();
+            CRC = (CRC >> 1) ^ (0xEDB8_8320 & Mask);
+        }
+    }
+
+    !CRC
+}
+
+fn Read_u32(Buffer: &[u8], Offset: usize) -> u32 {
+    u32::from_le_bytes(Buffer[Offset..Offset + 4].try_into().unwrap())
+}
+
+fn Read_u64(Buffer: &[u8], Offset: usize) -> u64 {
+    u64::from_le_bytes(Buffer[Offset..Offset + 8].try_into().unwrap())
+}
+
+/// GUID partition table (GPT).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GUID_partition_table_type {
+    Disk_identifier: GUID_type,
+    First_usable_sector: u64,
+    Last_usable_sector: u64,
+    Partitions: Vec<Partition_entry_type>,
+    Partition_identifiers: Vec<GUID_type>,
+}
+
+impl GUID_partition_table_type {
+    pub const EFI_system_partition: GUID_type = New_GUID(
+        0xC12A7328,
+        0xF81F,
+        0x11D2,
+        [0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B],
+    );
+    pub const Basic_data_partition: GUID_type = New_GUID(
+        0xEBD0A0A2,
+        0xB9E5,
+        0x4433,
+        [0x87, 0xC0, 0x68, 0xB6, 0xB7, 0x26, 0x99, 0xC7],
+    );
+    pub const Linux_file_system_partition: GUID_type = New_GUID(
+        0x0FC63DAF,
+        0x8483,
+        0x4772,
+        [0x8E, 0x79, 0x3D, 0x69, 0xD8, 0x47, 0x7D, 0xE4],
+    );
+
+    /// Create an empty table for a device of `Sector_count` sectors.
+    pub fn New(Disk_identifier: GUID_type, Sector_count: u64) -> Result_type<Self> {
+        // - Protective MBR, primary and backup headers and entries.
+        if Sector_count < 3 + 2 * Entries_sector_count {
+            return Err(Error_type::No_space_left);
+        }
+
+        Ok(Self {
+            Disk_identifier,
+            First_usable_sector: 2 + Entries_sector_count,
+            Last_usable_sector: Sector_count - 2 - Entries_sector_count,
+            Partitions: Vec::new(),
+            Partition_identifiers: Vec::new(),
+        })
+    }
+
+    pub fn Get_disk_identifier(&self) -> GUID_type {
+        self.Disk_identifier
+    }
+
+    pub fn Get_first_usable_sector(&self) -> u64 {
+        self.First_usable_sector
+    }
+
+    pub fn Get_last_usable_sector(&self) -> u64 {
+        self.Last_usable_sector
+    }
+
+    pub fn Get_partitions(&self) -> &[Partition_entry_type] {
+        &self.Partitions
+    }
+
+    pub fn Get_partition_identifier(&self, Index: usize) -> Option<GUID_type> {
+        self.Partition_identifiers.get(Index).copied()
+    }
+
+    /// Add a partition with its unique identifier.
+    pub fn Add_partition(
+        &mut self,
+        Partition: Partition_entry_type,
+        Identifier: GUID_type,
+    ) -> Result_type<()> {
+        if self.Partitions.len() >= Entry_count {
+            return Err(Error_type::No_space_left);
+        }
+
+        match Partition.Get_kind() {
+            Partition_kind_type::GUID_partition_table(Type) if Type != [0; 16] => {}
+            _ => return Err(Error_type::Invalid_parameter),
+        }
+
+        if Partition.Get_sector_count() == 0
+            || Partition.Get_start_sector() < self.First_usable_sector
+            || Partition.Get_end_sector() > self.Last_usable_sector
+            || Partition.Get_name().encode_utf16().count() > Entry_name_length
+        {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        if self
+            .Partitions
+            .iter()
+            .any(|Existing| Existing.Is_overlapping(&Partition))
+        {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        // - The partitions are written in the order they are added.
+        self.Partitions
+            .push(Partition.Set_index(self.Partitions.len()));
+        self.Partition_identifiers.push(Identifier);
+
+        Ok(())
+    }
+
+    fn Parse_entry(Entry: &[u8]) -> Option<(Partition_entry_type, GUID_type)> {
+        let Type: GUID_type = Entry[0..16].try_into().unwrap();
+
+        if Type == [0; 16] {
+            return None;
+        }
+
+        let Identifier: GUID_type = Entry[16..32].try_into().unwrap();
+        let First_sector = Read_u64(Entry, 32);
+        let Last_sector = Read_u64(Entry, 40);
+        let Attributes = Read_u64(Entry, 48);
+
+        let Name = Entry[56..56 + Entry_name_length * 2]
+            .chunks_exact(2)
+            .map(|Unit| u16::from_le_bytes([Unit[0], Unit[1]]))
+            .take_while(|Unit| *Unit != 0)
+            .collect::<Vec<_>>();
+
+        let Partition = Partition_entry_type::New(
+            First_sector,
+            (Last_sector + 1).saturating_sub(First_sector),
+            Partition_kind_type::GUID_partition_table(Type),
+            Attributes & Legacy_bootable_attribute != 0,
+            &String::from_utf16_lossy(&Name),
+        );
+
+        Some((Partition, Identifier))
+    }
+
+    /// Parse a table from its header sector and its partition entries.
+    ///
+    /// The checksums of the header and of the entries are verified.
+    pub fn From_blocks(Header: &Block_type, Entries: &[u8]) -> Result_type<Self> {
+        let Header = &Header.0;
+
+        if &Header[0..8] != Header_signature {
+            return Err(Error_type::Corrupted);
+        }
+
+        let Size = Read_u32(Header, 12) as usize;
+
+        if !(Header_size as usize..=Sector_size).contains(&Size) {
+            return Err(Error_type::Corrupted);
+        }
+
+        let mut Copy = Header[..Size].to_vec();
+        Copy[16..20].fill(0);
+
+        if Get_CRC32(&Copy) != Read_u32(Header, 16) {
+            return Err(Error_type::Corrupted);
+        }
+
+        let Count = Read_u32(Header, 80) as usize;
+        let Size = Read_u32(Header, 84) as usize;
+
+        if Size < Entry_size || Entries.len() < Count * Size {
+            return Err(Error_type::Corrupted);
+        }
+
+        if Get_CRC32(&Entries[..Count * Size]) != Read_u32(Header, 88) {
+            return Err(Error_type::Corrupted);
+        }
+
+        let (Partitions, Partition_identifiers) = Entries[..Count * Size]
+            .chunks_exact(Size)
+            .enumerate()
+            .filter_map(|(Index, Entry)| {
+                let (Partition, Identifier) = Self::Parse_entry(Entry)?;
+
+                Some((Partition.Set_index(Index), Identifier))
+            })
+            .unzip();
+
+        Ok(Self {
+            Disk_identifier: Header[56..72].try_into().unwrap(),
+            First_usable_sector: Read_u64(Header, 40),
+            Last_usable_sector: Read_u64(Header, 48),
+            Partitions,
+            Partition_identifiers,
+        })
+    }
+
+    /// Get the partition entries array.
+    pub fn To_entries(&self) -> Vec<u8> {
+        let mut Entries = vec![0_u8; Entry_count * Entry_size];
+
+        for ((Partition, Identifier), Entry) in self
+            .Partitions
+            .iter()
+            .zip(&self.Partition_identifiers)
+            .zip(Entries.chunks_exact_mut(Entry_size))
+        {
+            if let Partition_kind_type::GUID_partition_table(Type) = Partition.Get_kind() {
+                Entry[0..16].copy_from_slice(&Type);
+            }
+            Entry[16..32].copy_from_slice(Identifier);
+            Entry[32..40].copy_from_slice(&Partition.Get_start_sector().to_le_bytes());
+            Entry[40..48].copy_from_slice(&Partition.Get_end_sector().to_le_bytes());
+
+            let Attributes = if Partition.Is_bootable() {
+                Legacy_bootable_attribute
+            } else {
+                0
+            };
+            Entry[48..56].copy_from_slice(&Attributes.to_le_bytes());
+
+            for (Unit, Bytes) in Partition
+                .Get_name()
+                .encode_utf16()
+                .zip(Entry[56..56 + Entry_name_length * 2].chunks_exact_mut(2))
+            {
+                Bytes.copy_from_slice(&Unit.to_le_bytes());
+            }
+        }
+
+        Entries
+    }
+
+    /// Get the header sector, for the primary (`Backup` = false) or the backup table.
+    pub fn To_header_block(&self, Entries: &[u8], Backup: bool) -> Block_type {
+        let Last_sector = self.Last_usable_sector + 1 + Entries_sector_count;
+
+        let (Current_sector, Alternate_sector, Entries_sector) = if Backup {
+            (Last_sector, 1, self.Last_usable_sector + 1)
+        } else {
+            (1, Last_sector, 2)
+        };
+
+        let mut Block = Block_type::default();
+        let Header = &mut Block.0;
+
+        Header[0..8].copy_from_slice(Header_signature);
+        Header[8..12].copy_from_slice(&Header_revision.to_le_bytes());
+        Header[12..16].copy_from_slice(&Header_size.to_le_bytes());
+        Header[24..32].copy_from_slice(&Current_sector.to_le_bytes());
+        Header[32..40].copy_from_slice(&Alternate_sector.to_le_bytes());
+        Header[40..48].copy_from_slice(&self.First_usable_sector.to_le_bytes());
+        Header[48..56].copy_from_slice(&self.Last_usable_sector.to_le_bytes());
+        Header[56..72].copy_from_slice(&self.Disk_identifier);
+        Header[72..80].copy_from_slice(&Entries_sector.to_le_bytes());
+        Header[80..84].copy_from_slice(&(Entry_count as u32).to_le_bytes());
+        Header[84..88].copy_from_slice(&(Entry_size as u32).to_le_bytes());
+        Header[88..92].copy_from_slice(&Get_CRC32(Entries).to_le_bytes());
+
+        let CRC = Get_CRC32(&Header[..Header_size as usize]);
+        Header[16..20].copy_from_slice(&CRC.to_le_bytes());
+
+        Block
+    }
+
+    fn Read_at(Device: &Device_type, Header_sector: u64) -> Result_type<Self> {
+        let mut Header = Block_type::default();
+
+        Read_sector(Device, Header_sector, &mut Header)?;
+
+        if &Header.0[0..8] != Header_signature {
+            return Err(Error_type::Corrupted);
+        }
+
+        let Entries_sector = Read_u64(&Header.0, 72);
+        let Entries_size = Read_u32(&Header.0, 80) as usize * Read_u32(&Header.0, 84) as usize;
+
+        if Entries_size > Entry_count * Entry_size * 8 {
+            return Err(Error_type::Corrupted);
+        }
+
+        let mut Entries = vec![0_u8; Entries_size.div_ceil(Sector_size) * Sector_size];
+        let mut Block = Block_type::default();
+
+        for (Index, Chunk) in Entries.chunks_exact_mut(Sector_size).enumerate() {
+            Read_sector(Device, Entries_sector + Index as u64, &mut Block)?;
+            Chunk.copy_from_slice(&Block.0);
+        }
+
+        Self::From_blocks(&Header, &Entries)
+    }
+
+    /// Read the table of a device, the backup table is used if the primary one is corrupted.
+    pub fn Read(Device: &Device_type) -> Result_type<Self> {
+        match Self::Read_at(Device, 1) {
+            Ok(Table) => Ok(Table),
+            Err(Error) => {
+                let Sector_count = u64::from(Device.Get_size()?) / Sector_size as u64;
+
+                if Sector_count < 2 {
+                    return Err(Error);
+                }
+
+                Self::Read_at(Device, Sector_count - 1)
+            }
+        }
+    }
+
+    /// Write the protective master boot record, the primary and the backup tables to a device.
+    pub fn Write(&self, Device: &Device_type) -> Result_type<()> {
+        let Last_sector = self.Last_usable_sector + 1 + Entries_sector_count;
+
+        Master_boot_record_type::New_protective(Last_sector + 1).Write(Device)?;
+
+        let Entries = self.To_entries();
+
+        for (Entries_sector, Header_sector, Backup) in [
+            (2, 1, false),
+            (self.Last_usable_sector + 1, Last_sector, true),
+        ] {
+            for (Index, Chunk) in Entries.chunks_exact(Sector_size).enumerate() {
+                let mut Block = Block_type::default();
+                Block.0.copy_from_slice(Chunk);
+
+                Write_sector(Device, Entries_sector + Index as u64, &Block)?;
+            }
+
+            Write_sector(
+                Device,
+                Header_sector,
+                &self.To_header_block(&Entries, Backup),
+            )?;
+        }
+
+        Device.Flush()
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_CRC32() {
+        assert_eq!(Get_CRC32(b"123456789"), 0xCBF43926);
+        assert_eq!(Get_CRC32(b""), 0);
+    }
+
+    #[test]
+    fn Test_round_trip() {
+        let mut Table = GUID_partition_table_type::New([1; 16], 4096).unwrap();
+
+        assert_eq!(Table.Get_first_usable_sector(), 34);
+        assert_eq!(Table.Get_last_usable_sector(), 4062);
+
+        Table
+            .Add_partition(
+                Partition_entry_type::New(
+                    34,
+                    1000,
+                    Partition_kind_type::GUID_partition_table(
+                        GUID_partition_table_type::EFI_system_partition,
+                    ),
+                    true,
+                    "Boot",
+                ),
+                [2; 16],
+            )
+            .unwrap();
+        Table
+            .Add_partition(
+                Partition_entry_type::New(
+                    1034,
+                    3029,
+                    Partition_kind_type::GUID_partition_table(
+                        GUID_partition_table_type::Linux_file_system_partition,
+                    ),
+                    false,
+                    "Data",
+                ),
+                [3; 16],
+            )
+            .unwrap();
+
+        // - Beyond the last usable sector
+        assert_eq!(
+            Table.Add_partition(
+                Partition_entry_type::New(
+                    4063,
+                    1,
+                    Partition_kind_type::GUID_partition_table(
+                        GUID_partition_table_type::Basic_data_partition,
+                    ),
+                    false,
+                    "",
+                ),
+                [4; 16],
+            ),
+            Err(Error_type::Invalid_parameter)
+        );
+
+        let Entries = Table.To_entries();
+
+        for Backup in [false, true] {
+            let Header = Table.To_header_block(&Entries, Backup);
+
+            assert_eq!(
+                GUID_partition_table_type::From_blocks(&Header, &Entries).unwrap(),
+                Table
+            );
+        }
+
+        // - Corrupted entries
+        let mut Corrupted = Entries.clone();
+        Corrupted[100] ^= 0xFF;
+
+        assert_eq!(
+            GUID_partition_table_type::From_blocks(
+                &Table.To_header_block(&Entries, false),
+                &Corrupted
+            ),
+            Err(Error_type::Corrupted)
+        );
+    }
+}
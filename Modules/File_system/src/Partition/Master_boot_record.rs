@@ -0,0 +1,350 @@
+use crate::{Block_type, Device_type, Error_type, Result_type};
+
+use super::{Partition_entry_type, Partition_kind_type, Read_sector, Write_sector};
+
+const Disk_signature_start: usize = 440;
+const Partition_table_start: usize = 446;
+const Partition_entry_size: usize = 16;
+const Partition_entry_count: usize = 4;
+const Footer_start: usize = 510;
+const Footer_value: u16 = 0xAA55; // MBR magic number
+const Partition_entry_status_index: usize = 0;
+const Partition_entry_first_chs_index: usize = 1;
+const Partition_entry_type_index: usize = 4;
+const Partition_entry_last_chs_index: usize = 5;
+const Partition_entry_lba_start_index: usize = 8;
+const Partition_entry_block_count_index: usize = 12;
+const Bootable_status: u8 = 0x80;
+/// CHS address used when the partition is only addressed with LBA.
+const Unused_chs_address: [u8; 3] = [0xFE, 0xFF, 0xFF];
+
+/// Master boot record (MBR) with its four primary partitions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Master_boot_record_type {
+    Disk_signature: u32,
+    Partitions: Vec<Partition_entry_type>,
+}
+
+impl Master_boot_record_type {
+    pub const Empty_partition: u8 = 0x00;
+    pub const FAT32_partition: u8 = 0x0C;
+    pub const Linux_partition: u8 = 0x83;
+    pub const exFAT_partition: u8 = 0x07;
+    /// Partition covering the whole disk when it uses a GUID partition table.
+    pub const Protective_partition: u8 = 0xEE;
+
+    pub fn New(Disk_signature: u32) -> Self {
+        Self {
+            Disk_signature,
+            Partitions: Vec::new(),
+        }
+    }
+
+    /// Create a protective master boot record, used in front of a GUID partition table.
+    pub fn New_protective(Sector_count: u64) -> Self {
+        let Sector_count = Sector_count.saturating_sub(1).min(u32::MAX as u64);
+
+        Self {
+            Disk_signature: 0,
+            Partitions: vec![Partition_entry_type::New(
+                1,
+                Sector_count,
+                Partition_kind_type::Master_boot_record(Self::Protective_partition),
+                false,
+                "",
+            )],
+        }
+    }
+
+    pub fn Get_disk_signature(&self) -> u32 {
+        self.Disk_signature
+    }
+
+    pub fn Get_partitions(&self) -> &[Partition_entry_type] {
+        &self.Partitions
+    }
+
+    pub fn Get_partition_count(&self) -> usize {
+        self.Partitions.len()
+    }
+
+    pub fn Get_partition(&self, Index: usize) -> Option<&Partition_entry_type> {
+        self.Partitions.get(Index)
+    }
+
+    /// Check if the master boot record only protects a GUID partition table.
+    pub fn Is_protective(&self) -> bool {
+        self.Partitions.iter().any(|Partition| {
+            Partition.Get_kind()
+                == Partition_kind_type::Master_boot_record(Self::Protective_partition)
+        })
+    }
+
+    /// Add a primary partition.
+    pub fn Add_partition(&mut self, Partition: Partition_entry_type) -> Result_type<()> {
+        if self.Partitions.len() >= Partition_entry_count {
+            return Err(Error_type::No_space_left);
+        }
+
+        match Partition.Get_kind() {
+            Partition_kind_type::Master_boot_record(Self::Empty_partition)
+            | Partition_kind_type::GUID_partition_table(_) => {
+                return Err(Error_type::Invalid_parameter)
+            }
+            Partition_kind_type::Master_boot_record(_) => {}
+        }
+
+        // - The start and the size of the partition are stored on 32 bits.
+        if Partition.Get_start_sector() == 0
+            || Partition.Get_start_sector() > u32::MAX as u64
+            || Partition.Get_sector_count() == 0
+            || Partition.Get_sector_count() > u32::MAX as u64
+        {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        if self
+            .Partitions
+            .iter()
+            .any(|Existing| Existing.Is_overlapping(&Partition))
+        {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        // - The partitions are written in the order they are added.
+        self.Partitions
+            .push(Partition.Set_index(self.Partitions.len()));
+
+        Ok(())
+    }
+
+    pub fn From_block(Block: &Block_type) -> Result_type<Self> {
+        let Block = &Block.0;
+
+        // - Check the MBR signature
+        if u16::from_le_bytes([Block[Footer_start], Block[Footer_start + 1]]) != Footer_value {
+            return Err(Error_type::Corrupted);
+        }
+
+        let Disk_signature = u32::from_le_bytes(
+            Block[Disk_signature_start..Disk_signature_start + 4]
+                .try_into()
+                .unwrap(),
+        );
+
+        // - Read the partition table
+        let mut Partitions = Vec::new();
+
+        for i in 0..Partition_entry_count {
+            let Partition_start = Partition_table_start + (i * Partition_entry_size);
+
+            let Partition_informations =
+                &Block[Partition_start..(Partition_start + Partition_entry_size)];
+
+            // Only 0x80 (8th bit) and 0x00 are valid (bootable, and non-bootable)
+            if (Partition_informations[Partition_entry_status_index] & 0x7F) != 0x00 {
+                continue;
+            }
+
+            let Partition_type = Partition_informations[Partition_entry_type_index];
+
+            if Partition_type == Self::Empty_partition {
+                continue;
+            }
+
+            let Start_block_index = u32::from_le_bytes(
+                Partition_informations
+                    [Partition_entry_lba_start_index..(Partition_entry_lba_start_index + 4)]
+                    .try_into()
+                    .unwrap(),
+            );
+
+            let Block_count = u32::from_le_bytes(
+                Partition_informations
+                    [Partition_entry_block_count_index..(Partition_entry_block_count_index + 4)]
+                    .try_into()
+                    .unwrap(),
+            );
+
+            Partitions.push(
+                Partition_entry_type::New(
+                    Start_block_index as u64,
+                    Block_count as u64,
+                    Partition_kind_type::Master_boot_record(Partition_type),
+                    Partition_informations[Partition_entry_status_index] == Bootable_status,
+                    "",
+                )
+                .Set_index(i),
+            );
+        }
+
+        Ok(Self {
+            Disk_signature,
+            Partitions,
+        })
+    }
+
+    pub fn To_block(&self) -> Block_type {
+        let mut Block = Block_type::default();
+
+        Block.0[Disk_signature_start..Disk_signature_start + 4]
+            .copy_from_slice(&self.Disk_signature.to_le_bytes());
+
+        for (i, Partition) in self.Partitions.iter().enumerate() {
+            let Partition_start = Partition_table_start + (i * Partition_entry_size);
+
+            let Entry = &mut Block.0[Partition_start..(Partition_start + Partition_entry_size)];
+
+            let Partition_type = match Partition.Get_kind() {
+                Partition_kind_type::Master_boot_record(Partition_type) => Partition_type,
+                Partition_kind_type::GUID_partition_table(_) => Self::Protective_partition,
+            };
+
+            Entry[Partition_entry_status_index] = if Partition.Is_bootable() {
+                Bootable_status
+            } else {
+                0x00
+            };
+            Entry[Partition_entry_first_chs_index..Partition_entry_first_chs_index + 3]
+                .copy_from_slice(&Unused_chs_address);
+            Entry[Partition_entry_type_index] = Partition_type;
+            Entry[Partition_entry_last_chs_index..Partition_entry_last_chs_index + 3]
+                .copy_from_slice(&Unused_chs_address);
+            Entry[Partition_entry_lba_start_index..Partition_entry_lba_start_index + 4]
+                .copy_from_slice(&(Partition.Get_start_sector() as u32).to_le_bytes());
+            Entry[Partition_entry_block_count_index..Partition_entry_block_count_index + 4]
+                .copy_from_slice(&(Partition.Get_sector_count() as u32).to_le_bytes());
+        }
+
+        Block.0[Footer_start..Footer_start + 2].copy_from_slice(&Footer_value.to_le_bytes());
+
+        Block
+    }
+
+    /// Read the master boot record from the first sector of a device.
+    pub fn Read(Device: &Device_type) -> Result_type<Self> {
+        let mut Block = Block_type::default();
+
+        Read_sector(Device, 0, &mut Block)?;
+
+        Self::From_block(&Block)
+    }
+
+    /// Write the master boot record to the first sector of a device.
+    ///
+    /// The boot code area of the sector is preserved.
+    pub fn Write(&self, Device: &Device_type) -> Result_type<()> {
+        let mut Existing = Block_type::default();
+
+        let mut Block = self.To_block();
+
+        if Read_sector(Device, 0, &mut Existing).is_ok() {
+            Block.0[..Disk_signature_start].copy_from_slice(&Existing.0[..Disk_signature_start]);
+        }
+
+        Write_sector(Device, 0, &Block)?;
+
+        Device.Flush()
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    fn Generate_MBR_block(Signature: u16, Partitions: &[(u8, u32, u32)]) -> Block_type {
+        let mut Block = Block_type::default();
+
+        for (i, (Partition_type, Start, Count)) in Partitions.iter().enumerate() {
+            let Partition_entry_start = Partition_table_start + (i * Partition_entry_size);
+
+            let Entry =
+                &mut Block.0[Partition_entry_start..Partition_entry_start + Partition_entry_size];
+
+            Entry[Partition_entry_type_index] = *Partition_type;
+            Entry[Partition_entry_lba_start_index..Partition_entry_lba_start_index + 4]
+                .copy_from_slice(&Start.to_le_bytes());
+            Entry[Partition_entry_block_count_index..Partition_entry_block_count_index + 4]
+                .copy_from_slice(&Count.to_le_bytes());
+        }
+
+        Block.0[Footer_start..Footer_start + 2].copy_from_slice(&Signature.to_le_bytes());
+
+        Block
+    }
+
+    #[test]
+    fn Test_from_block() {
+        for i in 1..5 {
+            let Partitions = (0..i)
+                .map(|j| (0x83, (j * 100 + 1) as u32, 100))
+                .collect::<Vec<_>>();
+
+            let Master_boot_record =
+                Master_boot_record_type::From_block(&Generate_MBR_block(Footer_value, &Partitions))
+                    .unwrap();
+
+            assert_eq!(Master_boot_record.Get_partition_count(), i);
+
+            for (j, Partition) in Master_boot_record.Get_partitions().iter().enumerate() {
+                assert_eq!(
+                    Partition.Get_kind(),
+                    Partition_kind_type::Master_boot_record(0x83)
+                );
+                assert_eq!(Partition.Get_start_sector(), (j * 100 + 1) as u64);
+                assert_eq!(Partition.Get_sector_count(), 100);
+            }
+        }
+    }
+
+    #[test]
+    fn Test_from_block_invalid_footer() {
+        for Signature in [0x0000, 0x55AA, 0x55AB, 0x55BA, 0x5A55] {
+            assert_eq!(
+                Master_boot_record_type::From_block(&Generate_MBR_block(Signature, &[])),
+                Err(Error_type::Corrupted)
+            );
+        }
+    }
+
+    #[test]
+    fn Test_round_trip() {
+        let mut Master_boot_record = Master_boot_record_type::New(0x12345678);
+
+        Master_boot_record
+            .Add_partition(Partition_entry_type::New(
+                2048,
+                8192,
+                Partition_kind_type::Master_boot_record(Master_boot_record_type::FAT32_partition),
+                true,
+                "",
+            ))
+            .unwrap();
+        Master_boot_record
+            .Add_partition(Partition_entry_type::New(
+                10240,
+                4096,
+                Partition_kind_type::Master_boot_record(Master_boot_record_type::Linux_partition),
+                false,
+                "",
+            ))
+            .unwrap();
+
+        // - Overlapping partition
+        assert_eq!(
+            Master_boot_record.Add_partition(Partition_entry_type::New(
+                12000,
+                10,
+                Partition_kind_type::Master_boot_record(Master_boot_record_type::Linux_partition),
+                false,
+                "",
+            )),
+            Err(Error_type::Invalid_parameter)
+        );
+
+        let Read = Master_boot_record_type::From_block(&Master_boot_record.To_block()).unwrap();
+
+        assert_eq!(Read, Master_boot_record);
+        assert!(!Read.Is_protective());
+    }
+}
@@ -0,0 +1,193 @@
+//! Partition tables (MBR and GPT) and partition devices.
+
+mod Device;
+mod Entry;
+mod GUID_partition_table;
+mod Master_boot_record;
+
+pub use Device::*;
+pub use Entry::*;
+pub use GUID_partition_table::*;
+pub use Master_boot_record::*;
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, Weak},
+};
+
+use crate::{Block_type, Device_type, Error_type, Position_type, Result_type};
+
+/// Size of a logical sector, partition tables are always addressed in 512 bytes sectors.
+pub const Sector_size: usize = 512;
+
+/// Locks of the devices accessed through their partitions or their partition table.
+static Device_locks: Mutex<BTreeMap<usize, Weak<Mutex<()>>>> = Mutex::new(BTreeMap::new());
+
+/// Get the lock to hold while moving the position of a device then reading or writing it.
+///
+/// The lock is shared by the partitions of the device and the accesses to its partition table.
+pub fn Get_device_lock(Device: &Device_type) -> Result_type<Arc<Mutex<()>>> {
+    let mut Locks = Device_locks.lock()?;
+
+    Locks.retain(|_, Lock| Lock.strong_count() > 0);
+
+    if let Some(Lock) = Locks.get(&Device.Get_identifier()).and_then(Weak::upgrade) {
+        return Ok(Lock);
+    }
+
+    let Lock = Arc::new(Mutex::new(()));
+
+    Locks.insert(Device.Get_identifier(), Arc::downgrade(&Lock));
+
+    Ok(Lock)
+}
+
+/// Read a sector of a device.
+pub(crate) fn Read_sector(
+    Device: &Device_type,
+    Index: u64,
+    Block: &mut Block_type,
+) -> Result_type<()> {
+    let Lock = Get_device_lock(Device)?;
+    let _Lock = Lock.lock()?;
+
+    Device.Set_position(&Position_type::Start(Index * Sector_size as u64))?;
+
+    let mut Read = 0;
+
+    while Read < Sector_size {
+        let Size: usize = Device.Read(&mut Block.0[Read..])?.into();
+
+        if Size == 0 {
+            return Err(Error_type::Input_output);
+        }
+
+        Read += Size;
+    }
+
+    Ok(())
+}
+
+/// Write a sector of a device.
+pub(crate) fn Write_sector(
+    Device: &Device_type,
+    Index: u64,
+    Block: &Block_type,
+) -> Result_type<()> {
+    let Lock = Get_device_lock(Device)?;
+    let _Lock = Lock.lock()?;
+
+    Device.Set_position(&Position_type::Start(Index * Sector_size as u64))?;
+
+    let mut Written = 0;
+
+    while Written < Sector_size {
+        let Size: usize = Device.Write(&Block.0[Written..])?.into();
+
+        if Size == 0 {
+            return Err(Error_type::Input_output);
+        }
+
+        Written += Size;
+    }
+
+    Ok(())
+}
+
+/// Read the partition table of a device.
+///
+/// A GUID partition table is read when the master boot record is protective, otherwise the primary partitions of the master boot record are returned.
+pub fn Read_partition_table(Device: &Device_type) -> Result_type<Vec<Partition_entry_type>> {
+    let Master_boot_record = Master_boot_record_type::Read(Device)?;
+
+    if Master_boot_record.Is_protective() {
+        return Ok(GUID_partition_table_type::Read(Device)?
+            .Get_partitions()
+            .to_vec());
+    }
+
+    Ok(Master_boot_record.Get_partitions().to_vec())
+}
+
+/// Get a device for each partition of a device.
+pub fn Get_partition_devices(Device: &Device_type) -> Result_type<Vec<Partition_device_type>> {
+    Read_partition_table(Device)?
+        .iter()
+        .map(|Partition| Partition_device_type::New(Device.clone(), Partition))
+        .collect()
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    use crate::{Create_device, Device_trait, Memory_device_type};
+
+    #[test]
+    fn Test_read_partition_table() {
+        let Device = Create_device!(Memory_device_type::<512>::New(512 * 4096));
+
+        // - Master boot record
+        let mut Master_boot_record = Master_boot_record_type::New(1);
+
+        Master_boot_record
+            .Add_partition(Partition_entry_type::New(
+                1,
+                100,
+                Partition_kind_type::Master_boot_record(Master_boot_record_type::FAT32_partition),
+                true,
+                "",
+            ))
+            .unwrap();
+
+        Master_boot_record.Write(&Device).unwrap();
+
+        assert_eq!(
+            Read_partition_table(&Device).unwrap(),
+            Master_boot_record.Get_partitions()
+        );
+
+        // - GUID partition table
+        let mut Table = GUID_partition_table_type::New([1; 16], 4096).unwrap();
+
+        Table
+            .Add_partition(
+                Partition_entry_type::New(
+                    2048,
+                    1024,
+                    Partition_kind_type::GUID_partition_table(
+                        GUID_partition_table_type::Basic_data_partition,
+                    ),
+                    false,
+                    "Data",
+                ),
+                [2; 16],
+            )
+            .unwrap();
+
+        Table.Write(&Device).unwrap();
+
+        assert_eq!(
+            Read_partition_table(&Device).unwrap(),
+            Table.Get_partitions()
+        );
+
+        // - The backup table is used when the primary one is corrupted.
+        Write_sector(&Device, 1, &Block_type::default()).unwrap();
+
+        assert_eq!(
+            Read_partition_table(&Device).unwrap(),
+            Table.Get_partitions()
+        );
+
+        let Partitions = Get_partition_devices(&Device).unwrap();
+
+        assert_eq!(Partitions.len(), 1);
+        assert_eq!(Partitions[0].Get_start(), 2048 * 512);
+        assert_eq!(Partitions[0].Get_size().unwrap(), 1024_usize * 512);
+        assert_eq!(Partitions[0].Get_index(), 0);
+
+        // - The partitions and the partition table share the lock of the device.
+        assert_eq!(Arc::strong_count(&Get_device_lock(&Device).unwrap()), 2);
+    }
+}
@@ -8,6 +8,8 @@ mod File_system;
 mod Fundamentals;
 pub mod Loader;
 mod Memory_device;
+mod Memory_file_system;
+mod Partition;
 mod Time;
 
 pub use Device::{Device_trait, Device_type};
@@ -16,4 +18,6 @@ pub use Error::*;
 pub use File_system::*;
 pub use Fundamentals::*;
 pub use Memory_device::*;
+pub use Memory_file_system::*;
+pub use Partition::*;
 pub use Time::*;
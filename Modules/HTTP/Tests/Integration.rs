@@ -0,0 +1,471 @@
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+
+use std::{
+    net::TcpStream,
+    sync::mpsc::channel,
+    time::{Duration, Instant},
+};
+
+use File_system::{
+    Create_device, Create_file_system, Flags_type, Memory_file_system_type, Mode_type, Open_type,
+    Path_owned_type,
+};
+use Network::{IPv4_type, Port_type, Protocol_type};
+use Task::Task_identifier_type;
+use Virtual_file_system::{Socket_address_type, Virtual_file_system_type};
+use HTTP::{
+    Client_type, Directory_handler_type, Encode_chunk, Error_type, Headers_type, Method_type,
+    Response_type, Router_type, Server_type, Status_code_type, URL_type,
+};
+
+fn Initialize<'a>() -> (Task_identifier_type, Virtual_file_system_type<'a>) {
+    let Task_instance = Task::Initialize().expect("Failed to initialize task manager");
+
+    unsafe {
+        let _ = Task_instance.Register_task();
+    }
+
+    let _ = Users::Initialize();
+
+    let _ = Time::Initialize(Create_device!(Drivers::Native::Time_driver_type::New()));
+
+    let Task = Task_instance
+        .Get_current_task_identifier()
+        .expect("Failed to get current task identifier");
+
+    let Network_socket_driver = Box::leak(Box::new(
+        Drivers::Std::Network::Network_socket_driver_type::New(),
+    ));
+
+    let Virtual_file_system = Virtual_file_system_type::New(
+        Task_instance,
+        Users::Get_instance(),
+        Time::Get_instance(),
+        Create_file_system!(Memory_file_system_type::New(1024 * 512)),
+        Some(Network_socket_driver),
+    )
+    .unwrap();
+
+    (Task, Virtual_file_system)
+}
+
+fn Get_port(Address: Socket_address_type) -> u16 {
+    Address.Into_IP_and_port().unwrap().1.Into_inner()
+}
+
+/// Run a server handling a number of connections in its own task, while the client gets the port of the server.
+fn With_server<'a>(
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+    Router: impl FnOnce(Task_identifier_type) -> Router_type<'a> + Send,
+    Connections: usize,
+    Client: impl FnOnce(u16),
+) {
+    std::thread::scope(|Scope| {
+        let (Sender, Receiver) = channel();
+
+        Scope.spawn(move || {
+            let Task_instance = Task::Get_instance();
+
+            unsafe {
+                Task_instance.Register_task().unwrap();
+            }
+
+            let Task = Task_instance.Get_current_task_identifier().unwrap();
+
+            let Server = Server_type::Bind(
+                Virtual_file_system,
+                Task,
+                (IPv4_type::Localhost, Port_type::Any).into(),
+                Router(Task),
+            )
+            .unwrap();
+
+            Sender
+                .send(Get_port(Server.Get_local_address().unwrap()))
+                .unwrap();
+
+            for _ in 0..Connections {
+                Server.Handle_connection().unwrap();
+            }
+        });
+
+        Client(Receiver.recv().unwrap());
+    });
+}
+
+#[test]
+fn Test_get_post() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Router = |_| {
+        Router_type::New()
+            .Add(Method_type::Get, "/hello", |Request| {
+                Response_type::New_text(
+                    Status_code_type::OK,
+                    &format!("Hello {}", Request.Get_query().unwrap_or("world")),
+                )
+            })
+            .Add(Method_type::Post, "/echo", |Request| {
+                Response_type::New(Status_code_type::Created)
+                    .Set_header(
+                        "Content-Type",
+                        Request.Get_headers().Get("Content-Type").unwrap_or(""),
+                    )
+                    .Set_body(Request.Get_body().to_vec())
+            })
+    };
+
+    With_server(&Virtual_file_system, Router, 5, |Port| {
+        let Client = Client_type::New(&Virtual_file_system, Task);
+
+        let mut Response = Client
+            .Get(&format!("http://127.0.0.1:{}/hello", Port))
+            .unwrap();
+
+        assert_eq!(Response.Get_status(), Status_code_type::OK);
+        assert_eq!(
+            Response.Get_headers().Get("Content-Type"),
+            Some("text/plain; charset=utf-8")
+        );
+        assert_eq!(Response.Read_to_end(1024).unwrap(), b"Hello world");
+
+        // - The body is larger than the maximum.
+        let mut Response = Client
+            .Get(&format!("http://127.0.0.1:{}/hello?Xila", Port))
+            .unwrap();
+
+        assert_eq!(Response.Read_to_end(4), Err(Error_type::Body_too_large));
+
+        let URL = URL_type::Parse(&format!("http://127.0.0.1:{}/echo", Port)).unwrap();
+
+        let mut Headers = Headers_type::New();
+        Headers.Set("Content-Type", "application/json");
+
+        let Response = Client
+            .Send(Method_type::Post, &URL, &Headers, b"{\"Key\": 1}")
+            .unwrap()
+            .Into_response(1024)
+            .unwrap();
+
+        assert_eq!(Response.Get_status(), Status_code_type::Created);
+        assert_eq!(
+            Response.Get_headers().Get("Content-Type"),
+            Some("application/json")
+        );
+        assert_eq!(Response.Get_body(), b"{\"Key\": 1}");
+
+        // - No body is sent for the `HEAD` requests.
+        let mut Response = Client
+            .Send(
+                Method_type::Head,
+                &URL.Join("/hello").unwrap(),
+                &Headers_type::New(),
+                &[],
+            )
+            .unwrap();
+
+        assert_eq!(Response.Get_headers().Get_content_length(), Ok(Some(11)));
+        assert_eq!(Response.Read_to_end(1024).unwrap(), b"");
+
+        let Response = Client.Get(URL.to_string().as_str()).unwrap();
+
+        assert_eq!(Response.Get_status(), Status_code_type::Method_not_allowed);
+        assert_eq!(Response.Get_headers().Get("Allow"), Some("POST"));
+    });
+}
+
+#[test]
+fn Test_redirections() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Router = |_| {
+        Router_type::New()
+            .Add(Method_type::Get, "/target", |_| {
+                Response_type::New_text(Status_code_type::OK, "Target")
+            })
+            .Add(Method_type::Get, "/old/page", |_| {
+                Response_type::New(Status_code_type::Found).Set_header("Location", "../target")
+            })
+            .Add(Method_type::Post, "/form", |_| {
+                Response_type::New(Status_code_type::See_other).Set_header("Location", "/target")
+            })
+            .Add(Method_type::Get, "/loop", |_| {
+                Response_type::New(Status_code_type::Temporary_redirect)
+                    .Set_header("Location", "/loop")
+            })
+    };
+
+    With_server(&Virtual_file_system, Router, 8, |Port| {
+        let Client = Client_type::New(&Virtual_file_system, Task).Set_maximum_redirections(2);
+
+        let Base = format!("http://127.0.0.1:{}", Port);
+
+        // - `..` is kept as is, then the server redirects again.
+        let Response = Client.Get(&format!("{}/old/page", Base)).unwrap();
+
+        assert_eq!(Response.Get_status(), Status_code_type::Not_found);
+        assert_eq!(Response.Get_URL().Get_path(), "/old/../target");
+
+        // - The `POST` becomes a `GET`.
+        let mut Response = Client
+            .Send(
+                Method_type::Post,
+                &URL_type::Parse(&format!("{}/form", Base)).unwrap(),
+                &Headers_type::New(),
+                b"Data",
+            )
+            .unwrap();
+
+        assert_eq!(Response.Get_status(), Status_code_type::OK);
+        assert_eq!(Response.Get_URL().Get_path(), "/target");
+        assert_eq!(Response.Read_to_end(1024).unwrap(), b"Target");
+
+        assert_eq!(
+            Client.Get(&format!("{}/loop", Base)).err(),
+            Some(Error_type::Too_many_redirections)
+        );
+
+        // - The redirections can be returned as is.
+        let Response = Client_type::New(&Virtual_file_system, Task)
+            .Set_maximum_redirections(0)
+            .Get(&format!("{}/loop", Base))
+            .unwrap();
+
+        assert_eq!(Response.Get_status(), Status_code_type::Temporary_redirect);
+        assert_eq!(Response.Get_headers().Get("Location"), Some("/loop"));
+    });
+}
+
+#[test]
+fn Test_chunked_response() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Listener = Virtual_file_system
+        .Bind(
+            Task,
+            (IPv4_type::Localhost, Port_type::Any).into(),
+            Protocol_type::TCP,
+        )
+        .unwrap();
+
+    Virtual_file_system.Listen(Task, Listener, 1).unwrap();
+
+    let Port = Get_port(
+        Virtual_file_system
+            .Get_local_address(Task, Listener)
+            .unwrap(),
+    );
+
+    std::thread::scope(|Scope| {
+        Scope.spawn(|| {
+            let (Socket, _) = Virtual_file_system.Accept(Task, Listener).unwrap();
+
+            // - Wait for the end of the request.
+            let mut Request = Vec::new();
+
+            while !Request.ends_with(b"\r\n\r\n") {
+                let mut Buffer = [0; 256];
+                let Size = Virtual_file_system
+                    .Receive(Task, Socket, &mut Buffer)
+                    .unwrap();
+
+                assert_ne!(Size, 0);
+
+                Request.extend_from_slice(&Buffer[..Size]);
+            }
+
+            let Request = String::from_utf8(Request).unwrap();
+
+            assert!(Request.starts_with("GET /chunks HTTP/1.1\r\n"));
+            assert!(Request.contains(&format!("Host: 127.0.0.1:{}\r\n", Port)));
+            assert!(Request.contains("Connection: close\r\n"));
+
+            let mut Response =
+                b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n"
+                    .to_vec();
+
+            Response.extend(Encode_chunk(b"Hello, "));
+            Response.extend(b"6;extension=1\r\nworld!\r\n");
+            Response.extend(b"0\r\nTrailer: ignored\r\n\r\n");
+
+            // - Sent in small parts to split the chunks between the receptions.
+            for Part in Response.chunks(5) {
+                Virtual_file_system.Send(Task, Socket, Part).unwrap();
+            }
+
+            Virtual_file_system.Close(Socket, Task).unwrap();
+        });
+
+        let mut Response = Client_type::New(&Virtual_file_system, Task)
+            .Get(&format!("http://127.0.0.1:{}/chunks", Port))
+            .unwrap();
+
+        assert_eq!(Response.Get_status(), Status_code_type::OK);
+
+        let mut Body = Vec::new();
+        let mut Buffer = [0; 4];
+
+        loop {
+            let Size = Response.Read(&mut Buffer).unwrap();
+
+            if Size == 0 {
+                break;
+            }
+
+            Body.extend_from_slice(&Buffer[..Size]);
+        }
+
+        assert_eq!(Body, b"Hello, world!");
+    });
+
+    Virtual_file_system.Close(Listener, Task).unwrap();
+}
+
+#[test]
+fn Test_serve_directory() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    Virtual_file_system
+        .Create_directory(&"/Site", Task)
+        .unwrap();
+    Virtual_file_system
+        .Create_directory(&"/Site/Documents", Task)
+        .unwrap();
+
+    // - Larger than the chunks in which the files are sent.
+    let Large = (0..10_000).map(|Index| Index as u8).collect::<Vec<_>>();
+
+    for (Path, Content) in [
+        ("/Site/index.html", b"<h1>Xila</h1>".as_slice()),
+        ("/Site/Documents/My file.txt", b"Text"),
+        ("/Site/Documents/<i>&.txt", b""),
+        ("/Site/Large.bin", &Large),
+        ("/Secret", b"Secret"),
+    ] {
+        let File = Virtual_file_system
+            .Open(
+                &Path,
+                Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+                Task,
+            )
+            .unwrap();
+
+        Virtual_file_system.Write(File, Content, Task).unwrap();
+        Virtual_file_system.Close(File, Task).unwrap();
+    }
+
+    let Virtual_file_system = &Virtual_file_system;
+
+    let Router = move |Server_task| {
+        let Handler = Directory_handler_type::New(
+            Virtual_file_system,
+            Server_task,
+            Path_owned_type::New("/Site".to_string()).unwrap(),
+            "/",
+        );
+
+        Router_type::New().Add_prefix(Method_type::Get, "/", move |Request| {
+            Handler.Handle(Request)
+        })
+    };
+
+    With_server(Virtual_file_system, Router, 7, |Port| {
+        let Client = Client_type::New(Virtual_file_system, Task);
+
+        let Get = |Path: &str| {
+            Client
+                .Get(&format!("http://127.0.0.1:{}{}", Port, Path))
+                .unwrap()
+                .Into_response(16 * 1024)
+                .unwrap()
+        };
+
+        let Response = Get("/");
+
+        assert_eq!(Response.Get_body(), b"<h1>Xila</h1>");
+        assert_eq!(
+            Response.Get_headers().Get("Content-Type"),
+            Some("text/html; charset=utf-8")
+        );
+
+        let Response = Get("/Documents/My%20file.txt");
+
+        assert_eq!(Response.Get_status(), Status_code_type::OK);
+        assert_eq!(Response.Get_body(), b"Text");
+
+        // - Redirected to the directory, then listed.
+        let Response = Get("/Documents");
+
+        assert_eq!(Response.Get_status(), Status_code_type::OK);
+        assert!(String::from_utf8_lossy(Response.Get_body())
+            .contains("<a href=\"My%20file.txt\">My file.txt</a>"));
+        assert!(String::from_utf8_lossy(Response.Get_body())
+            .contains("<a href=\"%3Ci%3E%26.txt\">&lt;i&gt;&amp;.txt</a>"));
+
+        let Response = Get("/Large.bin");
+
+        assert_eq!(Response.Get_status(), Status_code_type::OK);
+        assert_eq!(Response.Get_body(), Large);
+
+        assert_eq!(Get("/Missing").Get_status(), Status_code_type::Not_found);
+        assert_eq!(Get("/../Secret").Get_status(), Status_code_type::Not_found);
+    });
+}
+
+#[test]
+fn Test_run_concurrent_connections() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Virtual_file_system: &'static Virtual_file_system_type =
+        Box::leak(Box::new(Virtual_file_system));
+
+    let (Sender, Receiver) = channel();
+
+    // - The server never stops, its thread is left running.
+    std::thread::spawn(move || {
+        let Task_instance = Task::Get_instance();
+
+        unsafe {
+            Task_instance.Register_task().unwrap();
+        }
+
+        let Task = Task_instance.Get_current_task_identifier().unwrap();
+
+        let Router = Router_type::New().Add(Method_type::Get, "/hello", |_| {
+            Response_type::New_text(Status_code_type::OK, "Hello")
+        });
+
+        let Server = Server_type::Bind(
+            Virtual_file_system,
+            Task,
+            (IPv4_type::Localhost, Port_type::Any).into(),
+            Router,
+        )
+        .unwrap()
+        .Set_timeout(Duration::from_secs(60));
+
+        Sender
+            .send(Get_port(Server.Get_local_address().unwrap()))
+            .unwrap();
+
+        Server.Run().unwrap();
+    });
+
+    let Port = Receiver.recv().unwrap();
+
+    // - A client which doesn't send its request doesn't block the others.
+    let _Slow_client = TcpStream::connect(("127.0.0.1", Port)).unwrap();
+
+    std::thread::sleep(Duration::from_millis(100));
+
+    let Start = Instant::now();
+
+    let mut Response = Client_type::New(Virtual_file_system, Task)
+        .Get(&format!("http://127.0.0.1:{}/hello", Port))
+        .unwrap();
+
+    assert_eq!(Response.Read_to_end(1024).unwrap(), b"Hello");
+    assert!(Start.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,203 @@
+use crate::{Connection_type, Error_type, Headers_type, Result_type};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State_type {
+    /// Size of the body left to read.
+    Length(usize),
+    /// Size of the current chunk left to read, the size of the next chunk is read at `0`.
+    Chunked(usize),
+    /// The body ends when the peer closes the connection.
+    Until_close,
+    Done,
+}
+
+/// Decoder of a message body, according to its headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Body_type(State_type);
+
+/// Parse the size line of a chunk, ignoring its extensions.
+fn Parse_chunk_size(Line: &str) -> Result_type<usize> {
+    let Size = Line.split_once(';').map_or(Line, |(Size, _)| Size).trim();
+
+    if Size.is_empty() {
+        return Err(Error_type::Invalid_chunk);
+    }
+
+    usize::from_str_radix(Size, 16).map_err(|_| Error_type::Invalid_chunk)
+}
+
+impl Body_type {
+    /// Body of a request, which is empty if neither its length nor chunks are announced.
+    pub fn For_request(Headers: &Headers_type) -> Result_type<Self> {
+        Self::New(Headers, State_type::Done)
+    }
+
+    /// Body of a response, which lasts until the connection is closed if neither its length nor chunks are announced.
+    ///
+    /// `Has_body` is false for the responses to the `HEAD` requests and the statuses without body.
+    pub fn For_response(Headers: &Headers_type, Has_body: bool) -> Result_type<Self> {
+        if !Has_body {
+            return Ok(Self(State_type::Done));
+        }
+
+        Self::New(Headers, State_type::Until_close)
+    }
+
+    fn New(Headers: &Headers_type, Default: State_type) -> Result_type<Self> {
+        // - The chunks take precedence over the length.
+        if Headers.Is_chunked() {
+            return Ok(Self(State_type::Chunked(0)));
+        }
+
+        Ok(Self(match Headers.Get_content_length()? {
+            Some(0) => State_type::Done,
+            Some(Length) => State_type::Length(Length),
+            None => Default,
+        }))
+    }
+
+    pub fn Is_done(&self) -> bool {
+        self.0 == State_type::Done
+    }
+
+    /// Read the next part of the body, `0` once it is completely read.
+    pub fn Read(
+        &mut self,
+        Connection: &mut Connection_type,
+        Buffer: &mut [u8],
+    ) -> Result_type<usize> {
+        if Buffer.is_empty() {
+            return Ok(0);
+        }
+
+        let Buffer_size = Buffer.len();
+
+        loop {
+            match self.0 {
+                State_type::Done => return Ok(0),
+                State_type::Until_close => {
+                    let Size = Connection.Read(Buffer)?;
+
+                    if Size == 0 {
+                        self.0 = State_type::Done;
+                    }
+
+                    return Ok(Size);
+                }
+                State_type::Length(Remaining) => {
+                    let Size = Connection.Read(&mut Buffer[..Remaining.min(Buffer_size)])?;
+
+                    if Size == 0 {
+                        return Err(Error_type::Connection_closed);
+                    }
+
+                    self.0 = match Remaining - Size {
+                        0 => State_type::Done,
+                        Remaining => State_type::Length(Remaining),
+                    };
+
+                    return Ok(Size);
+                }
+                State_type::Chunked(0) => match Parse_chunk_size(&Connection.Read_line()?)? {
+                    0 => {
+                        // - Trailer fields are ignored.
+                        while !Connection.Read_line()?.is_empty() {}
+
+                        self.0 = State_type::Done;
+                    }
+                    Size => self.0 = State_type::Chunked(Size),
+                },
+                State_type::Chunked(Remaining) => {
+                    let Size = Connection.Read(&mut Buffer[..Remaining.min(Buffer_size)])?;
+
+                    if Size == 0 {
+                        return Err(Error_type::Connection_closed);
+                    }
+
+                    if Remaining == Size && !Connection.Read_line()?.is_empty() {
+                        return Err(Error_type::Invalid_chunk);
+                    }
+
+                    self.0 = State_type::Chunked(Remaining - Size);
+
+                    return Ok(Size);
+                }
+            }
+        }
+    }
+
+    /// Read the rest of the body, failing if it is larger than `Maximum_size`.
+    pub fn Read_to_end(
+        &mut self,
+        Connection: &mut Connection_type,
+        Maximum_size: usize,
+    ) -> Result_type<Vec<u8>> {
+        let mut Body = Vec::new();
+        let mut Buffer = [0; 1024];
+
+        loop {
+            let Size = self.Read(Connection, &mut Buffer)?;
+
+            if Size == 0 {
+                return Ok(Body);
+            }
+
+            if Body.len() + Size > Maximum_size {
+                return Err(Error_type::Body_too_large);
+            }
+
+            Body.extend_from_slice(&Buffer[..Size]);
+        }
+    }
+}
+
+/// Encode data as a chunk, an empty chunk ends the body.
+pub fn Encode_chunk(Data: &[u8]) -> Vec<u8> {
+    let mut Chunk = format!("{:X}\r\n", Data.len()).into_bytes();
+
+    Chunk.extend_from_slice(Data);
+    Chunk.extend_from_slice(b"\r\n");
+
+    Chunk
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_chunk_size() {
+        assert_eq!(Parse_chunk_size("1A"), Ok(26));
+        assert_eq!(Parse_chunk_size("ff ; name=value"), Ok(255));
+        assert_eq!(Parse_chunk_size("0"), Ok(0));
+        assert_eq!(Parse_chunk_size(""), Err(Error_type::Invalid_chunk));
+        assert_eq!(Parse_chunk_size("G"), Err(Error_type::Invalid_chunk));
+
+        assert_eq!(Encode_chunk(b"Hello, world!"), b"D\r\nHello, world!\r\n");
+        assert_eq!(Encode_chunk(b""), b"0\r\n\r\n");
+    }
+
+    #[test]
+    fn Test_body_kind() {
+        let mut Headers = Headers_type::New();
+
+        assert!(Body_type::For_request(&Headers).unwrap().Is_done());
+        assert_eq!(
+            Body_type::For_response(&Headers, true).unwrap(),
+            Body_type(State_type::Until_close)
+        );
+        assert!(Body_type::For_response(&Headers, false).unwrap().Is_done());
+
+        Headers.Set("Content-Length", "5");
+        assert_eq!(
+            Body_type::For_request(&Headers).unwrap(),
+            Body_type(State_type::Length(5))
+        );
+
+        Headers.Set("Transfer-Encoding", "chunked");
+        assert_eq!(
+            Body_type::For_response(&Headers, true).unwrap(),
+            Body_type(State_type::Chunked(0))
+        );
+    }
+}
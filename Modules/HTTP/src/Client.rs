@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use Task::Task_identifier_type;
+use Time::Duration_type;
+use Virtual_file_system::Virtual_file_system_type;
+
+use crate::{
+    Body_type, Connection_type, Error_type, Headers_type, Method_type, Request_type, Response_type,
+    Result_type, Scheme_type, Status_code_type, URL_type,
+};
+
+pub const Default_timeout: Duration_type = Duration::from_secs(30);
+
+pub const Default_maximum_redirections: usize = 5;
+
+const User_agent: &str = "Xila";
+
+/// Headers holding credentials, which aren't sent to the other origins on a redirection.
+const Credential_headers: [&str; 3] = ["Authorization", "Proxy-Authorization", "Cookie"];
+
+/// Check a redirection from `Previous` to `Next`, removing the credentials from the headers if the origin changes.
+///
+/// A redirection from `https` to `http` is refused unless `Allow_insecure` is set.
+fn Redirect(
+    Previous: &URL_type,
+    Next: &URL_type,
+    Headers: &mut Headers_type,
+    Allow_insecure: bool,
+) -> Result_type<()> {
+    if Previous.Get_scheme() == Scheme_type::HTTPS
+        && Next.Get_scheme() == Scheme_type::HTTP
+        && !Allow_insecure
+    {
+        return Err(Error_type::Insecure_redirection);
+    }
+
+    if !Previous.Has_same_origin(Next) {
+        for Name in Credential_headers {
+            Headers.Remove(Name);
+        }
+    }
+
+    Ok(())
+}
+
+/// Response received by the client, its body is read from the connection on demand.
+pub struct Incoming_response_type<'a> {
+    Status: Status_code_type,
+    Headers: Headers_type,
+    /// URL of the response, after the redirections.
+    URL: URL_type,
+    Connection: Connection_type<'a>,
+    Body: Body_type,
+}
+
+impl Incoming_response_type<'_> {
+    pub const fn Get_status(&self) -> Status_code_type {
+        self.Status
+    }
+
+    pub fn Get_headers(&self) -> &Headers_type {
+        &self.Headers
+    }
+
+    pub fn Get_URL(&self) -> &URL_type {
+        &self.URL
+    }
+
+    /// Read the next part of the body, `0` once it is completely read.
+    pub fn Read(&mut self, Buffer: &mut [u8]) -> Result_type<usize> {
+        self.Body.Read(&mut self.Connection, Buffer)
+    }
+
+    /// Read the rest of the body, failing if it is larger than `Maximum_size`.
+    pub fn Read_to_end(&mut self, Maximum_size: usize) -> Result_type<Vec<u8>> {
+        self.Body.Read_to_end(&mut self.Connection, Maximum_size)
+    }
+
+    /// Read the body to get a complete response.
+    pub fn Into_response(
+        mut self,
+        Maximum_body_size: usize,
+    ) -> Result_type<Response_type<'static>> {
+        let Body = self.Read_to_end(Maximum_body_size)?;
+
+        Ok(Response_type::New(self.Status)
+            .Set_headers(self.Headers)
+            .Set_body(Body))
+    }
+}
+
+/// HTTP client, following the redirections.
+pub struct Client_type<'a> {
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+    Task: Task_identifier_type,
+    Maximum_redirections: usize,
+    Allow_insecure_redirections: bool,
+    Timeout: Duration_type,
+}
+
+impl<'a> Client_type<'a> {
+    pub fn New(
+        Virtual_file_system: &'a Virtual_file_system_type<'a>,
+        Task: Task_identifier_type,
+    ) -> Self {
+        Self {
+            Virtual_file_system,
+            Task,
+            Maximum_redirections: Default_maximum_redirections,
+            Allow_insecure_redirections: false,
+            Timeout: Default_timeout,
+        }
+    }
+
+    /// Set the number of redirections followed, `0` to return the redirections as is.
+    pub fn Set_maximum_redirections(mut self, Maximum_redirections: usize) -> Self {
+        self.Maximum_redirections = Maximum_redirections;
+        self
+    }
+
+    /// Allow the redirections from `https` to `http`, which are refused by default.
+    pub fn Set_allow_insecure_redirections(mut self, Allow_insecure_redirections: bool) -> Self {
+        self.Allow_insecure_redirections = Allow_insecure_redirections;
+        self
+    }
+
+    /// Set the time to wait for the server on each operation.
+    pub fn Set_timeout(mut self, Timeout: Duration_type) -> Self {
+        self.Timeout = Timeout;
+        self
+    }
+
+    pub fn Get(&self, URL: &str) -> Result_type<Incoming_response_type<'a>> {
+        self.Send(
+            Method_type::Get,
+            &URL_type::Parse(URL)?,
+            &Headers_type::New(),
+            &[],
+        )
+    }
+
+    /// Send a request, then receive the head of its response.
+    ///
+    /// The redirections `303` (and `301` or `302` for a `POST`) are followed with a `GET` without body,
+    /// the others with the same request.
+    /// The credentials (e.g. `Authorization` and `Cookie`) are only sent to the origin of the URL they were given for.
+    pub fn Send(
+        &self,
+        Method: Method_type,
+        URL: &URL_type,
+        Headers: &Headers_type,
+        Body: &[u8],
+    ) -> Result_type<Incoming_response_type<'a>> {
+        let mut Method = Method;
+        let mut URL = URL.clone();
+        let mut Body = Body;
+
+        let mut Headers = Headers.clone();
+
+        if !Headers.Contains("User-Agent") {
+            Headers.Set("User-Agent", User_agent);
+        }
+
+        let mut Redirections = 0;
+
+        loop {
+            let mut Connection =
+                Connection_type::Connect(self.Virtual_file_system, self.Task, &URL)?;
+
+            Connection.Set_timeout(self.Timeout)?;
+
+            Request_type::New(Method, URL.Get_target())
+                .Set_headers(Headers.clone())
+                .Set_body(Body.to_vec())
+                .Write(&Connection, &URL.Get_authority())?;
+
+            let (Status, Response_headers) = Response_type::Read_head(&mut Connection)?;
+
+            if Status.Is_redirection() && self.Maximum_redirections > 0 {
+                if Redirections >= self.Maximum_redirections {
+                    return Err(Error_type::Too_many_redirections);
+                }
+
+                let Location = Response_headers
+                    .Get("Location")
+                    .ok_or(Error_type::Missing_location)?;
+
+                let Next = URL.Join(Location)?;
+
+                Redirect(&URL, &Next, &mut Headers, self.Allow_insecure_redirections)?;
+
+                URL = Next;
+
+                if Status == Status_code_type::See_other
+                    || (Method == Method_type::Post
+                        && matches!(
+                            Status,
+                            Status_code_type::Moved_permanently | Status_code_type::Found
+                        ))
+                {
+                    Method = Method_type::Get;
+                    Body = &[];
+                    Headers.Remove("Content-Type");
+                }
+
+                Redirections += 1;
+
+                continue;
+            }
+
+            let Body = Body_type::For_response(
+                &Response_headers,
+                Method != Method_type::Head && !Status.Has_no_body(),
+            )?;
+
+            return Ok(Incoming_response_type {
+                Status,
+                Headers: Response_headers,
+                URL,
+                Connection,
+                Body,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_redirect() {
+        let Parse = |URL| URL_type::Parse(URL).unwrap();
+
+        let mut Headers = Headers_type::New();
+
+        Headers.Set("Authorization", "Bearer token");
+        Headers.Set("Cookie", "Session=1");
+        Headers.Set("Accept", "text/html");
+
+        // - The credentials are kept for the same origin.
+        Redirect(
+            &Parse("https://example.com/a"),
+            &Parse("https://EXAMPLE.com:443/b"),
+            &mut Headers,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(Headers.Get("Authorization"), Some("Bearer token"));
+        assert_eq!(Headers.Get("Cookie"), Some("Session=1"));
+
+        assert_eq!(
+            Redirect(
+                &Parse("https://example.com/a"),
+                &Parse("http://example.com/a"),
+                &mut Headers,
+                false,
+            ),
+            Err(Error_type::Insecure_redirection)
+        );
+        assert!(Headers.Contains("Authorization"));
+
+        // - The credentials aren't sent to another origin.
+        Redirect(
+            &Parse("https://example.com/a"),
+            &Parse("https://other.com/a"),
+            &mut Headers,
+            false,
+        )
+        .unwrap();
+
+        assert!(!Headers.Contains("Authorization"));
+        assert!(!Headers.Contains("Cookie"));
+        assert_eq!(Headers.Get("Accept"), Some("text/html"));
+
+        Headers.Set("Authorization", "Bearer token");
+
+        // - Even for an allowed downgrade on the same host.
+        Redirect(
+            &Parse("https://example.com/a"),
+            &Parse("http://example.com/a"),
+            &mut Headers,
+            true,
+        )
+        .unwrap();
+
+        assert!(!Headers.Contains("Authorization"));
+    }
+}
@@ -0,0 +1,160 @@
+use File_system::Unique_file_identifier_type;
+use Network::{Port_type, Record_kind_type, TLS_configuration_type};
+use Task::Task_identifier_type;
+use Time::Duration_type;
+use Virtual_file_system::{Socket_address_type, Virtual_file_system_type};
+
+use crate::{Error_type, Result_type, Scheme_type, URL_type};
+
+/// Size of the reads from the socket.
+const Chunk_size: usize = 1024;
+
+/// Maximum size of the request, status and header lines.
+pub const Maximum_line_size: usize = 8 * 1024;
+
+/// Buffered connection over a connected socket, closed when dropped.
+pub struct Connection_type<'a> {
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+    /// Received data not consumed yet, starting at `Position`.
+    Buffer: Vec<u8>,
+    Position: usize,
+}
+
+impl Drop for Connection_type<'_> {
+    fn drop(&mut self) {
+        let _ = self.Virtual_file_system.Close(self.Socket, self.Task);
+    }
+}
+
+impl<'a> Connection_type<'a> {
+    /// Take the ownership of a connected socket.
+    pub fn New(
+        Virtual_file_system: &'a Virtual_file_system_type<'a>,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+    ) -> Self {
+        Self {
+            Virtual_file_system,
+            Task,
+            Socket,
+            Buffer: Vec::new(),
+            Position: 0,
+        }
+    }
+
+    /// Connect to the host of an URL, starting a TLS session for `https`.
+    pub fn Connect(
+        Virtual_file_system: &'a Virtual_file_system_type<'a>,
+        Task: Task_identifier_type,
+        URL: &URL_type,
+    ) -> Result_type<Self> {
+        let IP = Virtual_file_system
+            .Resolve(Task, URL.Get_host(), Record_kind_type::A)
+            .or_else(|_| Virtual_file_system.Resolve(Task, URL.Get_host(), Record_kind_type::AAAA))?
+            .into_iter()
+            .next()
+            .ok_or(Network::Error_type::Not_found)?;
+
+        let Socket = Virtual_file_system.Connect(
+            Task,
+            Socket_address_type::From_IP_and_port(IP, Port_type::New(URL.Get_port())),
+        )?;
+
+        let Connection = Self::New(Virtual_file_system, Task, Socket);
+
+        if URL.Get_scheme() == Scheme_type::HTTPS {
+            Virtual_file_system.Start_TLS(
+                Task,
+                Socket,
+                TLS_configuration_type::New_client(URL.Get_host()),
+            )?;
+        }
+
+        Ok(Connection)
+    }
+
+    /// Set the time to wait for the peer to send or receive data.
+    pub fn Set_timeout(&self, Timeout: Duration_type) -> Result_type<()> {
+        self.Virtual_file_system
+            .Set_receive_timeout(self.Task, self.Socket, Timeout)?;
+        self.Virtual_file_system
+            .Set_send_timeout(self.Task, self.Socket, Timeout)?;
+
+        Ok(())
+    }
+
+    pub const fn Get_socket(&self) -> Unique_file_identifier_type {
+        self.Socket
+    }
+
+    pub const fn Get_task(&self) -> Task_identifier_type {
+        self.Task
+    }
+
+    /// Receive more data in the buffer, return the size received (`0` once the peer closed the connection).
+    fn Fill(&mut self) -> Result_type<usize> {
+        self.Buffer.drain(..self.Position);
+        self.Position = 0;
+
+        let Start = self.Buffer.len();
+
+        self.Buffer.resize(Start + Chunk_size, 0);
+
+        let Result =
+            self.Virtual_file_system
+                .Receive(self.Task, self.Socket, &mut self.Buffer[Start..]);
+
+        self.Buffer.truncate(Start + *Result.as_ref().unwrap_or(&0));
+
+        Ok(Result?)
+    }
+
+    /// Read a line, without its line ending (`\r\n` or `\n`).
+    pub fn Read_line(&mut self) -> Result_type<String> {
+        loop {
+            let Pending = &self.Buffer[self.Position..];
+
+            if let Some(End) = Pending.iter().position(|Byte| *Byte == b'\n') {
+                let Line = Pending[..End]
+                    .strip_suffix(b"\r")
+                    .unwrap_or(&Pending[..End]);
+                let Line = String::from_utf8_lossy(Line).into_owned();
+
+                self.Position += End + 1;
+
+                return Ok(Line);
+            }
+
+            if Pending.len() > Maximum_line_size {
+                return Err(Error_type::Line_too_long);
+            }
+
+            if self.Fill()? == 0 {
+                return Err(Error_type::Connection_closed);
+            }
+        }
+    }
+
+    /// Read the available data, `0` once the peer closed the connection.
+    pub fn Read(&mut self, Buffer: &mut [u8]) -> Result_type<usize> {
+        if self.Position == self.Buffer.len() && self.Fill()? == 0 {
+            return Ok(0);
+        }
+
+        let Pending = &self.Buffer[self.Position..];
+        let Size = Pending.len().min(Buffer.len());
+
+        Buffer[..Size].copy_from_slice(&Pending[..Size]);
+        self.Position += Size;
+
+        Ok(Size)
+    }
+
+    pub fn Write_all(&self, Data: &[u8]) -> Result_type<()> {
+        Ok(self
+            .Virtual_file_system
+            .Send(self.Task, self.Socket, Data)?)
+    }
+}
@@ -0,0 +1,279 @@
+use File_system::{Error_type, Mode_type, Path_owned_type, Path_type, Type_type};
+use Task::Task_identifier_type;
+use Virtual_file_system::Virtual_file_system_type;
+
+use crate::{Request_type, Response_type, Status_code_type};
+
+/// Get the media type of a file from its extension.
+pub fn Get_content_type(Path: &str) -> &'static str {
+    let Extension = match Path.rsplit_once('.') {
+        Some((_, Extension)) if !Extension.contains('/') => Extension.to_ascii_lowercase(),
+        _ => return "application/octet-stream",
+    };
+
+    match Extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "txt" | "md" => "text/plain; charset=utf-8",
+        "xml" => "application/xml",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "ico" => "image/x-icon",
+        "wasm" => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Decode the `%XX` sequences of a path, `None` if a sequence is invalid.
+pub fn Decode_percent(Path: &str) -> Option<String> {
+    let mut Decoded = Vec::with_capacity(Path.len());
+    let mut Bytes = Path.bytes();
+
+    while let Some(Byte) = Bytes.next() {
+        if Byte == b'%' {
+            let High = (Bytes.next()? as char).to_digit(16)?;
+            let Low = (Bytes.next()? as char).to_digit(16)?;
+
+            Decoded.push((High * 16 + Low) as u8);
+        } else {
+            Decoded.push(Byte);
+        }
+    }
+
+    String::from_utf8(Decoded).ok()
+}
+
+/// Encode the bytes of a path as `%XX` sequences, except the unreserved characters and `/`.
+pub fn Encode_percent(Path: &str) -> String {
+    let mut Encoded = String::with_capacity(Path.len());
+
+    for Byte in Path.bytes() {
+        match Byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                Encoded.push(Byte as char)
+            }
+            Byte => Encoded += &format!("%{:02X}", Byte),
+        }
+    }
+
+    Encoded
+}
+
+/// Escape the characters of a text having a meaning in HTML.
+pub fn Escape_HTML(Text: &str) -> String {
+    let mut Escaped = String::with_capacity(Text.len());
+
+    for Character in Text.chars() {
+        match Character {
+            '&' => Escaped += "&amp;",
+            '<' => Escaped += "&lt;",
+            '>' => Escaped += "&gt;",
+            '"' => Escaped += "&quot;",
+            '\'' => Escaped += "&#39;",
+            Character => Escaped.push(Character),
+        }
+    }
+
+    Escaped
+}
+
+fn Into_response<'a>(Error: Error_type) -> Response_type<'a> {
+    Response_type::New_error(match Error {
+        Error_type::Not_found | Error_type::Invalid_path => Status_code_type::Not_found,
+        Error_type::Permission_denied => Status_code_type::Forbidden,
+        _ => Status_code_type::Internal_server_error,
+    })
+}
+
+/// Serve the files of a directory, the requests paths are relative to `Prefix`.
+///
+/// The directories are served with their `index.html` file if any, or with the list of their entries.
+pub struct Directory_handler_type<'a> {
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+    Task: Task_identifier_type,
+    Root: Path_owned_type,
+    Prefix: String,
+}
+
+impl<'a> Directory_handler_type<'a> {
+    pub fn New(
+        Virtual_file_system: &'a Virtual_file_system_type<'a>,
+        Task: Task_identifier_type,
+        Root: Path_owned_type,
+        Prefix: &str,
+    ) -> Self {
+        Self {
+            Virtual_file_system,
+            Task,
+            Root,
+            Prefix: Prefix.to_string(),
+        }
+    }
+
+    /// Get the path of the file requested, `None` if it is outside of the root.
+    fn Get_path(&self, Request: &Request_type) -> Option<(Path_owned_type, String)> {
+        let Relative = Request.Get_path().strip_prefix(&self.Prefix)?;
+        let Relative = Decode_percent(Relative)?;
+
+        let Relative = Relative.trim_matches('/');
+
+        if Relative
+            .split('/')
+            .any(|Component| Component == ".." || Component == ".")
+        {
+            return None;
+        }
+
+        Some((self.Root.clone().Append(Relative)?, Relative.to_string()))
+    }
+
+    /// Respond with the content of a file, which is read while it is sent.
+    fn Send_file(&self, Path: &Path_type) -> Result<Response_type<'a>, Error_type> {
+        let File = self
+            .Virtual_file_system
+            .Open(&Path, Mode_type::Read_only.into(), self.Task)?;
+
+        let Size = match self.Virtual_file_system.Get_statistics(File, self.Task) {
+            Ok(Statistics) => Statistics.Get_size().into(),
+            Err(Error) => {
+                let _ = self.Virtual_file_system.Close(File, self.Task);
+
+                return Err(Error);
+            }
+        };
+
+        Ok(Response_type::New(Status_code_type::OK)
+            .Set_header("Content-Type", Get_content_type(Path.As_str()))
+            .Set_file(self.Virtual_file_system, self.Task, File, Size))
+    }
+
+    /// Format an HTML page linking to the entries of a directory.
+    fn List_directory(&self, Path: &Path_type, Relative: &str) -> Result<Vec<u8>, Error_type> {
+        let Directory = self.Virtual_file_system.Open_directory(&Path, self.Task)?;
+
+        let mut Names = Vec::new();
+
+        while let Some(Entry) = self
+            .Virtual_file_system
+            .Read_directory(Directory, self.Task)?
+        {
+            match Entry.Get_name().as_str() {
+                "." | ".." => {}
+                Name if Entry.Get_type() == Type_type::Directory => {
+                    Names.push(format!("{}/", Name))
+                }
+                Name => Names.push(Name.to_string()),
+            }
+        }
+
+        self.Virtual_file_system
+            .Close_directory(Directory, self.Task)?;
+
+        Names.sort();
+
+        let Title = Escape_HTML(&format!("/{}", Relative));
+
+        let mut Page = format!(
+            "<!DOCTYPE html>\n<html>\n<head><title>{0}</title></head>\n<body>\n<h1>{0}</h1>\n<ul>\n",
+            Title
+        );
+
+        for Name in Names {
+            Page += &format!(
+                "<li><a href=\"{}\">{}</a></li>\n",
+                Escape_HTML(&Encode_percent(&Name)),
+                Escape_HTML(&Name)
+            );
+        }
+
+        Page += "</ul>\n</body>\n</html>\n";
+
+        Ok(Page.into_bytes())
+    }
+
+    pub fn Handle(&self, Request: &Request_type) -> Response_type<'a> {
+        let (Path, Relative) = match self.Get_path(Request) {
+            Some(Path) => Path,
+            None => return Response_type::New_error(Status_code_type::Not_found),
+        };
+
+        let Metadata = match self.Virtual_file_system.Get_metadata_from_path(&Path) {
+            Ok(Metadata) => Metadata,
+            Err(Error) => return Into_response(Error),
+        };
+
+        if Metadata.Get_type() != Type_type::Directory {
+            return self.Send_file(&Path).unwrap_or_else(Into_response);
+        }
+
+        // - The relative links of the listing need the trailing slash.
+        if !Request.Get_path().ends_with('/') {
+            return Response_type::New(Status_code_type::Moved_permanently)
+                .Set_header("Location", &format!("{}/", Request.Get_path()));
+        }
+
+        let Index = match Path.clone().Append("index.html") {
+            Some(Index) => Index,
+            None => return Response_type::New_error(Status_code_type::Not_found),
+        };
+
+        match self.Send_file(&Index) {
+            Ok(Response) => Response,
+            Err(Error_type::Not_found) => match self.List_directory(&Path, &Relative) {
+                Ok(Page) => Response_type::New(Status_code_type::OK)
+                    .Set_header("Content-Type", Get_content_type("index.html"))
+                    .Set_body(Page),
+                Err(Error) => Into_response(Error),
+            },
+            Err(Error) => Into_response(Error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_content_type() {
+        assert_eq!(Get_content_type("/index.HTML"), "text/html; charset=utf-8");
+        assert_eq!(Get_content_type("/App.wasm"), "application/wasm");
+        assert_eq!(Get_content_type("/a.b/file"), "application/octet-stream");
+        assert_eq!(Get_content_type("/file"), "application/octet-stream");
+    }
+
+    #[test]
+    fn Test_decode_percent() {
+        assert_eq!(
+            Decode_percent("/My%20file%2etxt").as_deref(),
+            Some("/My file.txt")
+        );
+        assert_eq!(Decode_percent("/%C3%A9").as_deref(), Some("/é"));
+        assert_eq!(Decode_percent("/%2"), None);
+        assert_eq!(Decode_percent("/%ZZ"), None);
+        assert_eq!(Decode_percent("/%FF"), None);
+    }
+
+    #[test]
+    fn Test_encode_percent() {
+        assert_eq!(Encode_percent("Folder/My file.txt"), "Folder/My%20file.txt");
+        assert_eq!(Encode_percent("\"é?#%"), "%22%C3%A9%3F%23%25");
+        assert_eq!(
+            Decode_percent(&Encode_percent("a b/c?d")).as_deref(),
+            Some("a b/c?d")
+        );
+    }
+
+    #[test]
+    fn Test_escape_HTML() {
+        assert_eq!(
+            Escape_HTML("<script>alert('a & b')</script>\""),
+            "&lt;script&gt;alert(&#39;a &amp; b&#39;)&lt;/script&gt;&quot;"
+        );
+        assert_eq!(Escape_HTML("File.txt"), "File.txt");
+    }
+}
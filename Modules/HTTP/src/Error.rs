@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+pub type Result_type<T> = Result<T, Error_type>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error_type {
+    Virtual_file_system(Virtual_file_system::Error_type),
+    File_system(File_system::Error_type),
+    Invalid_URL,
+    Unsupported_scheme,
+    Invalid_request,
+    Invalid_response,
+    Invalid_header,
+    Invalid_chunk,
+    Line_too_long,
+    Too_many_headers,
+    Body_too_large,
+    Too_many_redirections,
+    Missing_location,
+    Insecure_redirection,
+    Connection_closed,
+}
+
+impl From<Virtual_file_system::Error_type> for Error_type {
+    fn from(Error: Virtual_file_system::Error_type) -> Self {
+        Self::Virtual_file_system(Error)
+    }
+}
+
+impl From<File_system::Error_type> for Error_type {
+    fn from(Error: File_system::Error_type) -> Self {
+        Self::File_system(Error)
+    }
+}
+
+impl From<Network::Error_type> for Error_type {
+    fn from(Error: Network::Error_type) -> Self {
+        Self::Virtual_file_system(Error.into())
+    }
+}
+
+impl Display for Error_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Virtual_file_system(Error) => write!(Formatter, "{}", Error),
+            Self::File_system(Error) => write!(Formatter, "File system: {}", Error),
+            Self::Invalid_URL => write!(Formatter, "Invalid URL"),
+            Self::Unsupported_scheme => write!(Formatter, "Unsupported scheme"),
+            Self::Invalid_request => write!(Formatter, "Invalid request"),
+            Self::Invalid_response => write!(Formatter, "Invalid response"),
+            Self::Invalid_header => write!(Formatter, "Invalid header"),
+            Self::Invalid_chunk => write!(Formatter, "Invalid chunk"),
+            Self::Line_too_long => write!(Formatter, "Line too long"),
+            Self::Too_many_headers => write!(Formatter, "Too many headers"),
+            Self::Body_too_large => write!(Formatter, "Body too large"),
+            Self::Too_many_redirections => write!(Formatter, "Too many redirections"),
+            Self::Missing_location => write!(Formatter, "Redirection without location"),
+            Self::Insecure_redirection => write!(Formatter, "Redirection from HTTPS to HTTP"),
+            Self::Connection_closed => write!(Formatter, "Connection closed"),
+        }
+    }
+}
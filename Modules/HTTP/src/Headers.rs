@@ -0,0 +1,152 @@
+use crate::{Connection_type, Error_type, Result_type};
+
+/// Maximum number of header fields of a request or a response.
+const Maximum_headers: usize = 100;
+
+/// Header fields of a request or a response, the names are case-insensitive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Headers_type(Vec<(String, String)>);
+
+impl Headers_type {
+    pub const fn New() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Add a field, keeping the fields with the same name.
+    pub fn Add(&mut self, Name: &str, Value: &str) {
+        self.0.push((Name.to_string(), Value.to_string()));
+    }
+
+    /// Set a field, replacing the fields with the same name.
+    pub fn Set(&mut self, Name: &str, Value: &str) {
+        self.Remove(Name);
+        self.Add(Name, Value);
+    }
+
+    pub fn Remove(&mut self, Name: &str) {
+        self.0
+            .retain(|(Field, _)| !Field.eq_ignore_ascii_case(Name));
+    }
+
+    /// Get the value of the first field with a name.
+    pub fn Get(&self, Name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(Field, _)| Field.eq_ignore_ascii_case(Name))
+            .map(|(_, Value)| Value.as_str())
+    }
+
+    pub fn Contains(&self, Name: &str) -> bool {
+        self.Get(Name).is_some()
+    }
+
+    pub fn Get_content_length(&self) -> Result_type<Option<usize>> {
+        self.Get("Content-Length")
+            .map(|Length| {
+                Length
+                    .trim()
+                    .parse()
+                    .map_err(|_| Error_type::Invalid_header)
+            })
+            .transpose()
+    }
+
+    /// Check if the body is sent in chunks (the last transfer coding is `chunked`).
+    pub fn Is_chunked(&self) -> bool {
+        self.Get("Transfer-Encoding").is_some_and(|Encoding| {
+            Encoding
+                .rsplit(',')
+                .next()
+                .is_some_and(|Last| Last.trim().eq_ignore_ascii_case("chunked"))
+        })
+    }
+
+    pub fn Iterate(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(Name, Value)| (Name.as_str(), Value.as_str()))
+    }
+
+    /// Read the fields until the empty line ending them.
+    pub fn Read(Connection: &mut Connection_type) -> Result_type<Self> {
+        let mut Headers = Self::New();
+
+        loop {
+            let Line = Connection.Read_line()?;
+
+            if Line.is_empty() {
+                return Ok(Headers);
+            }
+
+            if Headers.0.len() >= Maximum_headers {
+                return Err(Error_type::Too_many_headers);
+            }
+
+            Headers.Parse_line(&Line)?;
+        }
+    }
+
+    fn Parse_line(&mut self, Line: &str) -> Result_type<()> {
+        let (Name, Value) = Line.split_once(':').ok_or(Error_type::Invalid_header)?;
+
+        // - No whitespace is allowed between the name and the colon.
+        if Name.is_empty() || Name.ends_with([' ', '\t']) {
+            return Err(Error_type::Invalid_header);
+        }
+
+        self.Add(Name, Value.trim());
+
+        Ok(())
+    }
+
+    /// Format the fields, each one followed by a line ending.
+    pub fn Format(&self) -> String {
+        self.0
+            .iter()
+            .map(|(Name, Value)| format!("{}: {}\r\n", Name, Value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_headers() {
+        let mut Headers = Headers_type::New();
+
+        Headers.Parse_line("Content-Length:  12 ").unwrap();
+        Headers.Parse_line("Set-Cookie: a").unwrap();
+        Headers.Parse_line("set-cookie: b").unwrap();
+
+        assert_eq!(Headers.Get("content-length"), Some("12"));
+        assert_eq!(Headers.Get_content_length(), Ok(Some(12)));
+        assert_eq!(Headers.Get("Set-Cookie"), Some("a"));
+        assert!(!Headers.Is_chunked());
+
+        Headers.Set("SET-COOKIE", "c");
+        Headers.Set("Transfer-Encoding", "gzip, Chunked");
+
+        assert_eq!(
+            Headers.Format(),
+            "Content-Length: 12\r\nSET-COOKIE: c\r\nTransfer-Encoding: gzip, Chunked\r\n"
+        );
+        assert!(Headers.Is_chunked());
+
+        assert_eq!(
+            Headers.Parse_line("Invalid : value"),
+            Err(Error_type::Invalid_header)
+        );
+        assert_eq!(
+            Headers.Parse_line("Invalid"),
+            Err(Error_type::Invalid_header)
+        );
+
+        Headers.Set("Content-Length", "-1");
+        assert_eq!(
+            Headers.Get_content_length(),
+            Err(Error_type::Invalid_header)
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method_type {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+    Options,
+    Patch,
+}
+
+impl Method_type {
+    pub const fn As_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Head => "HEAD",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Delete => "DELETE",
+            Self::Options => "OPTIONS",
+            Self::Patch => "PATCH",
+        }
+    }
+}
+
+impl TryFrom<&str> for Method_type {
+    type Error = ();
+
+    fn try_from(Value: &str) -> Result<Self, Self::Error> {
+        match Value {
+            "GET" => Ok(Self::Get),
+            "HEAD" => Ok(Self::Head),
+            "POST" => Ok(Self::Post),
+            "PUT" => Ok(Self::Put),
+            "DELETE" => Ok(Self::Delete),
+            "OPTIONS" => Ok(Self::Options),
+            "PATCH" => Ok(Self::Patch),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Method_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(Formatter, "{}", self.As_str())
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{Body_type, Connection_type, Error_type, Headers_type, Method_type, Result_type};
+
+/// Fields managed by the library when sending a message.
+pub(crate) const Managed_headers: [&str; 4] =
+    ["Host", "Content-Length", "Transfer-Encoding", "Connection"];
+
+pub(crate) fn Format_headers(Headers: &Headers_type) -> String {
+    let mut Headers = Headers.clone();
+
+    for Name in Managed_headers {
+        Headers.Remove(Name);
+    }
+
+    Headers.Format()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request_type {
+    Method: Method_type,
+    /// Path and query.
+    Target: String,
+    Headers: Headers_type,
+    Body: Vec<u8>,
+}
+
+impl Request_type {
+    pub fn New(Method: Method_type, Target: &str) -> Self {
+        Self {
+            Method,
+            Target: Target.to_string(),
+            Headers: Headers_type::New(),
+            Body: Vec::new(),
+        }
+    }
+
+    pub fn Set_header(mut self, Name: &str, Value: &str) -> Self {
+        self.Headers.Set(Name, Value);
+        self
+    }
+
+    pub fn Set_headers(mut self, Headers: Headers_type) -> Self {
+        self.Headers = Headers;
+        self
+    }
+
+    pub fn Set_body(mut self, Body: Vec<u8>) -> Self {
+        self.Body = Body;
+        self
+    }
+
+    pub const fn Get_method(&self) -> Method_type {
+        self.Method
+    }
+
+    pub fn Get_target(&self) -> &str {
+        &self.Target
+    }
+
+    /// Get the path of the target, without the query.
+    pub fn Get_path(&self) -> &str {
+        self.Target
+            .split_once('?')
+            .map_or(&self.Target, |(Path, _)| Path)
+    }
+
+    pub fn Get_query(&self) -> Option<&str> {
+        self.Target.split_once('?').map(|(_, Query)| Query)
+    }
+
+    pub fn Get_headers(&self) -> &Headers_type {
+        &self.Headers
+    }
+
+    pub fn Get_body(&self) -> &[u8] {
+        &self.Body
+    }
+
+    /// Send the request to a host, the connection is closed after the response.
+    pub fn Write(&self, Connection: &Connection_type, Host: &str) -> Result_type<()> {
+        let mut Head = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\n{}",
+            self.Method,
+            self.Target,
+            Host,
+            Format_headers(&self.Headers)
+        );
+
+        if !self.Body.is_empty()
+            || matches!(
+                self.Method,
+                Method_type::Post | Method_type::Put | Method_type::Patch
+            )
+        {
+            Head += &format!("Content-Length: {}\r\n", self.Body.len());
+        }
+
+        Head += "Connection: close\r\n\r\n";
+
+        Connection.Write_all(Head.as_bytes())?;
+        Connection.Write_all(&self.Body)
+    }
+
+    /// Receive a request, failing if its body is larger than `Maximum_body_size`.
+    pub fn Read(Connection: &mut Connection_type, Maximum_body_size: usize) -> Result_type<Self> {
+        let Line = Connection.Read_line()?;
+
+        let (Method, Target, Version) = match Line.split(' ').collect::<Vec<_>>()[..] {
+            [Method, Target, Version] => (Method, Target, Version),
+            _ => return Err(Error_type::Invalid_request),
+        };
+
+        let Method = Method_type::try_from(Method).map_err(|_| Error_type::Invalid_request)?;
+
+        if !Version.starts_with("HTTP/1.") || !Target.starts_with('/') {
+            return Err(Error_type::Invalid_request);
+        }
+
+        let Headers = Headers_type::Read(Connection)?;
+
+        let Body = Body_type::For_request(&Headers)?.Read_to_end(Connection, Maximum_body_size)?;
+
+        Ok(Self {
+            Method,
+            Target: Target.to_string(),
+            Headers,
+            Body,
+        })
+    }
+}
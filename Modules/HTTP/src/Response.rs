@@ -0,0 +1,214 @@
+use std::fmt::Debug;
+
+use File_system::Unique_file_identifier_type;
+use Task::Task_identifier_type;
+use Virtual_file_system::Virtual_file_system_type;
+
+use crate::{
+    Connection_type, Error_type, Headers_type, Request::Format_headers, Result_type,
+    Status_code_type,
+};
+
+/// Size of the reads from a file sent as body.
+const File_chunk_size: usize = 4 * 1024;
+
+/// Open file sent as body, closed with the response.
+struct File_body_type<'a> {
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+    Task: Task_identifier_type,
+    File: Unique_file_identifier_type,
+    Size: usize,
+}
+
+impl Debug for File_body_type<'_> {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Formatter
+            .debug_struct("File_body_type")
+            .field("File", &self.File)
+            .field("Size", &self.Size)
+            .finish()
+    }
+}
+
+impl Drop for File_body_type<'_> {
+    fn drop(&mut self) {
+        let _ = self.Virtual_file_system.Close(self.File, self.Task);
+    }
+}
+
+impl File_body_type<'_> {
+    /// Send the content of the file, one chunk at a time.
+    fn Write(&self, Connection: &Connection_type) -> Result_type<()> {
+        let mut Buffer = vec![0; File_chunk_size.min(self.Size)];
+        let mut Remaining = self.Size;
+
+        while Remaining > 0 {
+            let Chunk_size = Remaining.min(Buffer.len());
+
+            let Size: usize = self
+                .Virtual_file_system
+                .Read(self.File, &mut Buffer[..Chunk_size], self.Task)?
+                .into();
+
+            // - The file was truncated since its size was announced.
+            if Size == 0 {
+                return Err(Error_type::File_system(
+                    File_system::Error_type::Input_output,
+                ));
+            }
+
+            Connection.Write_all(&Buffer[..Size])?;
+
+            Remaining -= Size;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+enum Body_content_type<'a> {
+    Data(Vec<u8>),
+    File(File_body_type<'a>),
+}
+
+#[derive(Debug)]
+pub struct Response_type<'a> {
+    Status: Status_code_type,
+    Headers: Headers_type,
+    Body: Body_content_type<'a>,
+}
+
+impl<'a> Response_type<'a> {
+    pub fn New(Status: Status_code_type) -> Self {
+        Self {
+            Status,
+            Headers: Headers_type::New(),
+            Body: Body_content_type::Data(Vec::new()),
+        }
+    }
+
+    /// Plain text response.
+    pub fn New_text(Status: Status_code_type, Text: &str) -> Self {
+        Self::New(Status)
+            .Set_header("Content-Type", "text/plain; charset=utf-8")
+            .Set_body(Text.as_bytes().to_vec())
+    }
+
+    /// Plain text response with the reason of its status.
+    pub fn New_error(Status: Status_code_type) -> Self {
+        Self::New_text(Status, &format!("{}\n", Status))
+    }
+
+    pub fn Set_header(mut self, Name: &str, Value: &str) -> Self {
+        self.Headers.Set(Name, Value);
+        self
+    }
+
+    pub fn Set_headers(mut self, Headers: Headers_type) -> Self {
+        self.Headers = Headers;
+        self
+    }
+
+    pub fn Set_body(mut self, Body: Vec<u8>) -> Self {
+        self.Body = Body_content_type::Data(Body);
+        self
+    }
+
+    /// Send the first `Size` bytes of an open file as body, without loading it in memory.
+    ///
+    /// The file is owned by the response, which closes it once dropped.
+    pub fn Set_file(
+        mut self,
+        Virtual_file_system: &'a Virtual_file_system_type<'a>,
+        Task: Task_identifier_type,
+        File: Unique_file_identifier_type,
+        Size: usize,
+    ) -> Self {
+        self.Body = Body_content_type::File(File_body_type {
+            Virtual_file_system,
+            Task,
+            File,
+            Size,
+        });
+        self
+    }
+
+    pub const fn Get_status(&self) -> Status_code_type {
+        self.Status
+    }
+
+    pub fn Get_headers(&self) -> &Headers_type {
+        &self.Headers
+    }
+
+    /// Get the body held in memory, empty for a file.
+    pub fn Get_body(&self) -> &[u8] {
+        match &self.Body {
+            Body_content_type::Data(Body) => Body,
+            Body_content_type::File(_) => &[],
+        }
+    }
+
+    /// Get the body held in memory, empty for a file.
+    pub fn Into_body(self) -> Vec<u8> {
+        match self.Body {
+            Body_content_type::Data(Body) => Body,
+            Body_content_type::File(_) => Vec::new(),
+        }
+    }
+
+    fn Get_body_size(&self) -> usize {
+        match &self.Body {
+            Body_content_type::Data(Body) => Body.len(),
+            Body_content_type::File(File) => File.Size,
+        }
+    }
+
+    /// Send the response, without its body for the `HEAD` requests (`Include_body`).
+    pub fn Write(&self, Connection: &Connection_type, Include_body: bool) -> Result_type<()> {
+        let mut Head = format!(
+            "HTTP/1.1 {} {}\r\n{}",
+            self.Status.Into_inner(),
+            self.Status.Get_reason(),
+            Format_headers(&self.Headers)
+        );
+
+        if !self.Status.Has_no_body() {
+            Head += &format!("Content-Length: {}\r\n", self.Get_body_size());
+        }
+
+        Head += "Connection: close\r\n\r\n";
+
+        Connection.Write_all(Head.as_bytes())?;
+
+        if Include_body && !self.Status.Has_no_body() {
+            match &self.Body {
+                Body_content_type::Data(Body) => Connection.Write_all(Body)?,
+                Body_content_type::File(File) => File.Write(Connection)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Receive the status line and the headers of a response.
+    pub fn Read_head(
+        Connection: &mut Connection_type,
+    ) -> Result_type<(Status_code_type, Headers_type)> {
+        let Line = Connection.Read_line()?;
+
+        let Parts = Line.splitn(3, ' ').collect::<Vec<_>>();
+
+        let Status = match Parts[..] {
+            [Version, Status, ..] if Version.starts_with("HTTP/1.") && Status.len() == 3 => {
+                Status.parse().map_err(|_| Error_type::Invalid_response)?
+            }
+            _ => return Err(Error_type::Invalid_response),
+        };
+
+        let Headers = Headers_type::Read(Connection)?;
+
+        Ok((Status_code_type::New(Status), Headers))
+    }
+}
@@ -0,0 +1,302 @@
+use std::{sync::Arc, time::Duration};
+
+use File_system::Unique_file_identifier_type;
+use Network::Protocol_type;
+use Task::Task_identifier_type;
+use Time::Duration_type;
+use Virtual_file_system::{Socket_address_type, Virtual_file_system_type};
+
+use crate::{
+    Connection_type, Error_type, Method_type, Request_type, Response_type, Result_type,
+    Status_code_type,
+};
+
+/// Maximum size of the body of the requests received, by default.
+pub const Default_maximum_body_size: usize = 64 * 1024;
+
+/// Time to wait for a client to send its request, by default.
+pub const Default_request_timeout: Duration_type = Duration::from_secs(10);
+
+const Backlog: usize = 8;
+
+/// The handlers are shared by the tasks handling the connections.
+pub type Handler_type<'a> = Box<dyn Fn(&Request_type) -> Response_type<'a> + Send + Sync + 'a>;
+
+struct Route_type<'a> {
+    Method: Method_type,
+    Path: String,
+    /// The route matches the paths starting with `Path`.
+    Prefix: bool,
+    Handler: Handler_type<'a>,
+}
+
+impl Route_type<'_> {
+    /// Get the priority of the route for a path, the exact paths before the longest prefixes.
+    fn Get_priority(&self, Path: &str) -> Option<usize> {
+        if self.Path == Path {
+            Some(usize::MAX)
+        } else if self.Prefix && Path.starts_with(&self.Path) {
+            Some(self.Path.len())
+        } else {
+            None
+        }
+    }
+}
+
+/// Dispatch the requests to handlers according to their method and path.
+///
+/// The `HEAD` requests are handled as `GET` requests, without sending the body.
+#[derive(Default)]
+pub struct Router_type<'a>(Vec<Route_type<'a>>);
+
+impl<'a> Router_type<'a> {
+    pub fn New() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Handle the requests to a path.
+    pub fn Add(
+        self,
+        Method: Method_type,
+        Path: &str,
+        Handler: impl Fn(&Request_type) -> Response_type<'a> + Send + Sync + 'a,
+    ) -> Self {
+        self.Add_route(Method, Path, false, Box::new(Handler))
+    }
+
+    /// Handle the requests to the paths starting with a prefix (e.g. `/files/`).
+    pub fn Add_prefix(
+        self,
+        Method: Method_type,
+        Prefix: &str,
+        Handler: impl Fn(&Request_type) -> Response_type<'a> + Send + Sync + 'a,
+    ) -> Self {
+        self.Add_route(Method, Prefix, true, Box::new(Handler))
+    }
+
+    fn Add_route(
+        mut self,
+        Method: Method_type,
+        Path: &str,
+        Prefix: bool,
+        Handler: Handler_type<'a>,
+    ) -> Self {
+        self.0.push(Route_type {
+            Method,
+            Path: Path.to_string(),
+            Prefix,
+            Handler,
+        });
+        self
+    }
+
+    pub fn Route(&self, Request: &Request_type) -> Response_type<'a> {
+        let Method = match Request.Get_method() {
+            Method_type::Head => Method_type::Get,
+            Method => Method,
+        };
+
+        let Path = Request.Get_path();
+
+        let Priority = match self
+            .0
+            .iter()
+            .filter_map(|Route| Route.Get_priority(Path))
+            .max()
+        {
+            Some(Priority) => Priority,
+            None => return Response_type::New_error(Status_code_type::Not_found),
+        };
+
+        let Routes = self
+            .0
+            .iter()
+            .filter(|Route| Route.Get_priority(Path) == Some(Priority));
+
+        match Routes.clone().find(|Route| Route.Method == Method) {
+            Some(Route) => (Route.Handler)(Request),
+            None => {
+                let Allowed = Routes
+                    .map(|Route| Route.Method.As_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Response_type::New_error(Status_code_type::Method_not_allowed)
+                    .Set_header("Allow", &Allowed)
+            }
+        }
+    }
+}
+
+/// HTTP server, handling each connection in its own task (see [`Server_type::Run`]).
+pub struct Server_type<'a> {
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+    Router: Router_type<'a>,
+    Maximum_body_size: usize,
+    Timeout: Duration_type,
+}
+
+impl Drop for Server_type<'_> {
+    fn drop(&mut self) {
+        let _ = self.Virtual_file_system.Close(self.Socket, self.Task);
+    }
+}
+
+impl<'a> Server_type<'a> {
+    /// Listen to an address (e.g. [`Network::Port_type::Any`] on all the interfaces).
+    pub fn Bind(
+        Virtual_file_system: &'a Virtual_file_system_type<'a>,
+        Task: Task_identifier_type,
+        Address: Socket_address_type,
+        Router: Router_type<'a>,
+    ) -> Result_type<Self> {
+        let Socket = Virtual_file_system.Bind(Task, Address, Protocol_type::TCP)?;
+
+        let Server = Self {
+            Virtual_file_system,
+            Task,
+            Socket,
+            Router,
+            Maximum_body_size: Default_maximum_body_size,
+            Timeout: Default_request_timeout,
+        };
+
+        Virtual_file_system.Listen(Task, Socket, Backlog)?;
+
+        Ok(Server)
+    }
+
+    pub fn Set_maximum_body_size(mut self, Maximum_body_size: usize) -> Self {
+        self.Maximum_body_size = Maximum_body_size;
+        self
+    }
+
+    pub fn Set_timeout(mut self, Timeout: Duration_type) -> Self {
+        self.Timeout = Timeout;
+        self
+    }
+
+    pub fn Get_local_address(&self) -> Result_type<Socket_address_type> {
+        Ok(self
+            .Virtual_file_system
+            .Get_local_address(self.Task, self.Socket)?)
+    }
+
+    /// Wait for a connection, then handle its request.
+    ///
+    /// Only the failure to accept a connection is returned, the errors of the connection are answered if possible.
+    pub fn Handle_connection(&self) -> Result_type<()> {
+        let (Socket, _) = self.Virtual_file_system.Accept(self.Task, self.Socket)?;
+
+        self.Handle(Socket);
+
+        Ok(())
+    }
+
+    /// Answer the request of a connection, then close it.
+    fn Handle(&self, Socket: Unique_file_identifier_type) {
+        let mut Connection = Connection_type::New(self.Virtual_file_system, self.Task, Socket);
+
+        if Connection.Set_timeout(self.Timeout).is_err() {
+            return;
+        }
+
+        let (Response, Include_body) =
+            match Request_type::Read(&mut Connection, self.Maximum_body_size) {
+                Ok(Request) => (
+                    self.Router.Route(&Request),
+                    Request.Get_method() != Method_type::Head,
+                ),
+                Err(Error_type::Body_too_large) => (
+                    Response_type::New_error(Status_code_type::Payload_too_large),
+                    true,
+                ),
+                // - Nothing to answer to.
+                Err(Error_type::Connection_closed | Error_type::Virtual_file_system(_)) => return,
+                Err(_) => (
+                    Response_type::New_error(Status_code_type::Bad_request),
+                    true,
+                ),
+            };
+
+        let _ = Response.Write(&Connection, Include_body);
+    }
+}
+
+impl Server_type<'static> {
+    /// Handle the connections until one can't be accepted.
+    ///
+    /// Each connection is handled by a child task of the server task, so a slow client doesn't block the others.
+    pub fn Run(self) -> Result_type<()> {
+        let Server = Arc::new(self);
+
+        loop {
+            let (Socket, _) = Server
+                .Virtual_file_system
+                .Accept(Server.Task, Server.Socket)?;
+
+            let Connection_server = Server.clone();
+
+            let Result =
+                Task::Get_instance().New_task(Server.Task, "HTTP_connection", None, move || {
+                    Connection_server.Handle(Socket)
+                });
+
+            if Result.is_err() {
+                let _ = Server.Virtual_file_system.Close(Socket, Server.Task);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_router() {
+        let Router = Router_type::New()
+            .Add(Method_type::Get, "/", |_| {
+                Response_type::New_text(Status_code_type::OK, "Root")
+            })
+            .Add(Method_type::Post, "/files/upload", |Request| {
+                Response_type::New(Status_code_type::Created).Set_body(Request.Get_body().to_vec())
+            })
+            .Add_prefix(Method_type::Get, "/files/", |Request| {
+                Response_type::New_text(Status_code_type::OK, Request.Get_path())
+            })
+            .Add_prefix(Method_type::Delete, "/files/", |_| {
+                Response_type::New(Status_code_type::No_content)
+            });
+
+        let Route = |Method, Target| Router.Route(&Request_type::New(Method, Target));
+
+        assert_eq!(Route(Method_type::Get, "/?query").Get_body(), b"Root");
+        assert_eq!(Route(Method_type::Head, "/").Get_body(), b"Root");
+        assert_eq!(
+            Route(Method_type::Get, "/files/a/b").Get_body(),
+            b"/files/a/b"
+        );
+        assert_eq!(
+            Route(Method_type::Delete, "/files/a").Get_status(),
+            Status_code_type::No_content
+        );
+        assert_eq!(
+            Route(Method_type::Get, "/other").Get_status(),
+            Status_code_type::Not_found
+        );
+
+        // - The exact path has the priority over the prefix.
+        let Response = Route(Method_type::Get, "/files/upload");
+
+        assert_eq!(Response.Get_status(), Status_code_type::Method_not_allowed);
+        assert_eq!(Response.Get_headers().Get("Allow"), Some("POST"));
+
+        let Response = Route(Method_type::Put, "/files/a");
+
+        assert_eq!(Response.Get_status(), Status_code_type::Method_not_allowed);
+        assert_eq!(Response.Get_headers().Get("Allow"), Some("GET, DELETE"));
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+/// Status code of a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Status_code_type(u16);
+
+impl Status_code_type {
+    pub const OK: Self = Self(200);
+    pub const Created: Self = Self(201);
+    pub const No_content: Self = Self(204);
+    pub const Moved_permanently: Self = Self(301);
+    pub const Found: Self = Self(302);
+    pub const See_other: Self = Self(303);
+    pub const Not_modified: Self = Self(304);
+    pub const Temporary_redirect: Self = Self(307);
+    pub const Permanent_redirect: Self = Self(308);
+    pub const Bad_request: Self = Self(400);
+    pub const Forbidden: Self = Self(403);
+    pub const Not_found: Self = Self(404);
+    pub const Method_not_allowed: Self = Self(405);
+    pub const Payload_too_large: Self = Self(413);
+    pub const Internal_server_error: Self = Self(500);
+
+    pub const fn New(Code: u16) -> Self {
+        Self(Code)
+    }
+
+    pub const fn Into_inner(self) -> u16 {
+        self.0
+    }
+
+    pub const fn Is_success(&self) -> bool {
+        self.0 >= 200 && self.0 < 300
+    }
+
+    /// Check if the response redirects to its `Location` header.
+    pub const fn Is_redirection(&self) -> bool {
+        matches!(self.0, 301 | 302 | 303 | 307 | 308)
+    }
+
+    /// Check if the response never has a body, whatever its headers.
+    pub const fn Has_no_body(&self) -> bool {
+        self.0 < 200 || self.0 == 204 || self.0 == 304
+    }
+
+    pub const fn Get_reason(&self) -> &'static str {
+        match self.0 {
+            200 => "OK",
+            201 => "Created",
+            204 => "No Content",
+            301 => "Moved Permanently",
+            302 => "Found",
+            303 => "See Other",
+            304 => "Not Modified",
+            307 => "Temporary Redirect",
+            308 => "Permanent Redirect",
+            400 => "Bad Request",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            500 => "Internal Server Error",
+            _ => "",
+        }
+    }
+}
+
+impl Display for Status_code_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(Formatter, "{} {}", self.0, self.Get_reason())
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt::Display;
+
+use crate::{Error_type, Result_type};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme_type {
+    HTTP,
+    /// HTTP over a TLS session.
+    HTTPS,
+}
+
+impl Scheme_type {
+    pub const fn As_str(&self) -> &'static str {
+        match self {
+            Self::HTTP => "http",
+            Self::HTTPS => "https",
+        }
+    }
+
+    pub const fn Get_default_port(&self) -> u16 {
+        match self {
+            Self::HTTP => 80,
+            Self::HTTPS => 443,
+        }
+    }
+}
+
+/// Absolute `http` or `https` URL (`scheme://host[:port][/path][?query]`), the fragment is dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct URL_type {
+    Scheme: Scheme_type,
+    /// Name or address of the host, without the brackets of the IPv6 addresses.
+    Host: String,
+    Port: u16,
+    /// Path and query, always starting with `/`.
+    Target: String,
+}
+
+/// Remove the fragment of a path and make it absolute.
+fn Into_target(Path: &str) -> String {
+    let Path = Path.split_once('#').map_or(Path, |(Path, _)| Path);
+
+    if Path.starts_with('/') {
+        Path.to_string()
+    } else {
+        format!("/{}", Path)
+    }
+}
+
+impl URL_type {
+    pub fn Parse(URL: &str) -> Result_type<Self> {
+        let (Scheme, Rest) = URL.split_once("://").ok_or(Error_type::Invalid_URL)?;
+
+        let Scheme = match Scheme.to_ascii_lowercase().as_str() {
+            "http" => Scheme_type::HTTP,
+            "https" => Scheme_type::HTTPS,
+            _ => return Err(Error_type::Unsupported_scheme),
+        };
+
+        let Authority_end = Rest.find(['/', '?', '#']).unwrap_or(Rest.len());
+        let (Authority, Path) = Rest.split_at(Authority_end);
+
+        // - User informations aren't supported.
+        if Authority.contains('@') {
+            return Err(Error_type::Invalid_URL);
+        }
+
+        let (Host, Port) = if let Some(Authority) = Authority.strip_prefix('[') {
+            let (Host, Rest) = Authority.split_once(']').ok_or(Error_type::Invalid_URL)?;
+
+            match Rest {
+                "" => (Host, None),
+                _ => (
+                    Host,
+                    Some(Rest.strip_prefix(':').ok_or(Error_type::Invalid_URL)?),
+                ),
+            }
+        } else {
+            match Authority.split_once(':') {
+                Some((Host, Port)) => (Host, Some(Port)),
+                None => (Authority, None),
+            }
+        };
+
+        if Host.is_empty() {
+            return Err(Error_type::Invalid_URL);
+        }
+
+        let Port = match Port {
+            Some(Port) => Port.parse().map_err(|_| Error_type::Invalid_URL)?,
+            None => Scheme.Get_default_port(),
+        };
+
+        Ok(Self {
+            Scheme,
+            Host: Host.to_string(),
+            Port,
+            Target: Into_target(Path),
+        })
+    }
+
+    /// Resolve a reference relative to this URL (e.g. the `Location` of a redirection).
+    pub fn Join(&self, Reference: &str) -> Result_type<Self> {
+        if Reference.contains("://") {
+            return Self::Parse(Reference);
+        }
+
+        if Reference.starts_with("//") {
+            return Self::Parse(&format!("{}:{}", self.Scheme.As_str(), Reference));
+        }
+
+        let Target = if Reference.starts_with('/') {
+            Into_target(Reference)
+        } else {
+            let Directory = match self.Get_path().rsplit_once('/') {
+                Some((Directory, _)) => Directory,
+                None => "",
+            };
+
+            Into_target(&format!("{}/{}", Directory, Reference))
+        };
+
+        Ok(Self {
+            Target,
+            ..self.clone()
+        })
+    }
+
+    pub const fn Get_scheme(&self) -> Scheme_type {
+        self.Scheme
+    }
+
+    pub fn Get_host(&self) -> &str {
+        &self.Host
+    }
+
+    pub const fn Get_port(&self) -> u16 {
+        self.Port
+    }
+
+    pub fn Get_target(&self) -> &str {
+        &self.Target
+    }
+
+    /// Get the path of the target, without the query.
+    pub fn Get_path(&self) -> &str {
+        self.Target
+            .split_once('?')
+            .map_or(&self.Target, |(Path, _)| Path)
+    }
+
+    /// Check if two URLs have the same scheme, host and port.
+    pub fn Has_same_origin(&self, Other: &Self) -> bool {
+        self.Scheme == Other.Scheme
+            && self.Host.eq_ignore_ascii_case(&Other.Host)
+            && self.Port == Other.Port
+    }
+
+    /// Get the host and the port (unless it is the default one), as sent in the `Host` header.
+    pub fn Get_authority(&self) -> String {
+        let Host = if self.Host.contains(':') {
+            format!("[{}]", self.Host)
+        } else {
+            self.Host.clone()
+        };
+
+        if self.Port == self.Scheme.Get_default_port() {
+            Host
+        } else {
+            format!("{}:{}", Host, self.Port)
+        }
+    }
+}
+
+impl Display for URL_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            Formatter,
+            "{}://{}{}",
+            self.Scheme.As_str(),
+            self.Get_authority(),
+            self.Target
+        )
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_parse() {
+        let URL = URL_type::Parse("HTTP://example.com").unwrap();
+
+        assert_eq!(URL.Get_scheme(), Scheme_type::HTTP);
+        assert_eq!(URL.Get_host(), "example.com");
+        assert_eq!(URL.Get_port(), 80);
+        assert_eq!(URL.Get_target(), "/");
+
+        let URL = URL_type::Parse("https://example.com:8443/a/b?c=d#e").unwrap();
+
+        assert_eq!(URL.Get_scheme(), Scheme_type::HTTPS);
+        assert_eq!(URL.Get_port(), 8443);
+        assert_eq!(URL.Get_target(), "/a/b?c=d");
+        assert_eq!(URL.Get_path(), "/a/b");
+        assert_eq!(URL.to_string(), "https://example.com:8443/a/b?c=d");
+
+        let URL = URL_type::Parse("http://[::1]:8080?query").unwrap();
+
+        assert_eq!(URL.Get_host(), "::1");
+        assert_eq!(URL.Get_target(), "/?query");
+        assert_eq!(URL.Get_authority(), "[::1]:8080");
+
+        assert_eq!(
+            URL_type::Parse("ftp://example.com"),
+            Err(Error_type::Unsupported_scheme)
+        );
+        assert_eq!(URL_type::Parse("example.com"), Err(Error_type::Invalid_URL));
+        assert_eq!(
+            URL_type::Parse("http:///path"),
+            Err(Error_type::Invalid_URL)
+        );
+        assert_eq!(
+            URL_type::Parse("http://host:port"),
+            Err(Error_type::Invalid_URL)
+        );
+        assert_eq!(
+            URL_type::Parse("http://user@host"),
+            Err(Error_type::Invalid_URL)
+        );
+    }
+
+    #[test]
+    fn Test_join() {
+        let URL = URL_type::Parse("http://example.com:8080/a/b?c").unwrap();
+
+        assert_eq!(
+            URL.Join("/d").unwrap().to_string(),
+            "http://example.com:8080/d"
+        );
+        assert_eq!(
+            URL.Join("d?e").unwrap().to_string(),
+            "http://example.com:8080/a/d?e"
+        );
+        assert_eq!(
+            URL.Join("//other.com/f").unwrap().to_string(),
+            "http://other.com/f"
+        );
+        assert_eq!(
+            URL.Join("https://other.com").unwrap().to_string(),
+            "https://other.com/"
+        );
+    }
+
+    #[test]
+    fn Test_same_origin() {
+        let URL = URL_type::Parse("http://example.com/a").unwrap();
+
+        assert!(URL.Has_same_origin(&URL_type::Parse("HTTP://Example.com:80/b?c").unwrap()));
+        assert!(!URL.Has_same_origin(&URL_type::Parse("https://example.com/a").unwrap()));
+        assert!(!URL.Has_same_origin(&URL_type::Parse("http://example.com:8080/a").unwrap()));
+        assert!(!URL.Has_same_origin(&URL_type::Parse("http://other.com/a").unwrap()));
+    }
+}
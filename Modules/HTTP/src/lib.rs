@@ -0,0 +1,29 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+mod Body;
+mod Client;
+mod Connection;
+mod Directory;
+mod Error;
+mod Headers;
+mod Method;
+mod Request;
+mod Response;
+mod Server;
+mod Status;
+mod URL;
+
+pub use Body::*;
+pub use Client::*;
+pub use Connection::*;
+pub use Directory::*;
+pub use Error::*;
+pub use Headers::*;
+pub use Method::*;
+pub use Request::*;
+pub use Response::*;
+pub use Server::*;
+pub use Status::*;
+pub use URL::*;
@@ -5,8 +5,9 @@ use std::{
 };
 
 use File_system::{
-    Error_type, File_system_identifier_type, Flags_type, Inode_type, Metadata_type, Mode_type,
-    Path_type, Position_type, Result_type, Size_type, Statistics_type, Time_type, Type_type,
+    Attributes_type, Error_type, File_system_identifier_type, Flags_type, Inode_type,
+    Metadata_type, Mode_type, Path_type, Position_type, Result_type, Size_type, Statistics_type,
+    Time_type, Type_type,
 };
 use Users::{Group_identifier_type, User_identifier_type};
 
@@ -260,7 +261,7 @@ impl File_type {
 
         let mut Metadata = MaybeUninit::<Metadata_type>::uninit();
 
-        Convert_result(unsafe {
+        let Size = Convert_result(unsafe {
             littlefs::lfs_getattr(
                 File_system as *mut _,
                 Path.as_ptr(),
@@ -270,6 +271,11 @@ impl File_type {
             )
         })?;
 
+        // - A partially written (or foreign) attribute would leave the metadata uninitialized.
+        if Size as usize != size_of::<Metadata_type>() {
+            return Err(Error_type::Corrupted);
+        }
+
         Ok(unsafe { Metadata.assume_init() })
     }
 
@@ -292,4 +298,69 @@ impl File_type {
 
         Ok(())
     }
+
+    /// Get the extended attributes of a file, stored in a single custom attribute.
+    pub fn Get_attributes_from_path(
+        File_system: &mut super::littlefs::lfs_t,
+        Path: &Path_type,
+    ) -> Result_type<Attributes_type> {
+        let Path = CString::new(Path.As_str()).map_err(|_| Error_type::Invalid_parameter)?;
+
+        let mut Buffer = vec![0_u8; Attributes_type::Maximum_size];
+
+        let Size = match Convert_result(unsafe {
+            littlefs::lfs_getattr(
+                File_system as *mut _,
+                Path.as_ptr(),
+                Attributes_type::Identifier,
+                Buffer.as_mut_ptr() as *mut c_void,
+                Buffer.len() as u32,
+            )
+        }) {
+            Ok(Size) => Size as usize,
+            Err(Error_type::No_attribute) => return Ok(Attributes_type::New()),
+            Err(Error) => return Err(Error),
+        };
+
+        // - The returned size is the size on disk, regardless of the buffer size.
+        if Size > Buffer.len() {
+            return Err(Error_type::Corrupted);
+        }
+
+        Attributes_type::From_bytes(&Buffer[..Size])
+    }
+
+    pub fn Set_attributes_from_path(
+        File_system: &mut super::littlefs::lfs_t,
+        Path: &Path_type,
+        Attributes: &Attributes_type,
+    ) -> Result_type<()> {
+        let Path = CString::new(Path.As_str()).map_err(|_| Error_type::Invalid_parameter)?;
+
+        if Attributes.Is_empty() {
+            Convert_result(unsafe {
+                littlefs::lfs_removeattr(
+                    File_system as *mut _,
+                    Path.as_ptr(),
+                    Attributes_type::Identifier,
+                )
+            })?;
+
+            return Ok(());
+        }
+
+        let Bytes = Attributes.To_bytes();
+
+        Convert_result(unsafe {
+            littlefs::lfs_setattr(
+                File_system as *mut _,
+                Path.as_ptr(),
+                Attributes_type::Identifier,
+                Bytes.as_ptr() as *const c_void,
+                Bytes.len() as u32,
+            )
+        })?;
+
+        Ok(())
+    }
 }
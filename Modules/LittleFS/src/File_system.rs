@@ -2,10 +2,11 @@ use core::mem::MaybeUninit;
 use std::{collections::BTreeMap, ffi::CString, sync::RwLock};
 
 use File_system::{
-    Device_type, Entry_type, File_identifier_inner_type, File_identifier_type,
-    File_system_identifier_type, File_system_traits, Flags_type, Get_new_file_identifier,
-    Inode_type, Local_file_identifier_type, Metadata_type, Mode_type, Path_type, Permissions_type,
-    Position_type, Size_type, Statistics_type, Time_type, Type_type,
+    Attributes_type, Check_issue_kind_type, Check_report_type, Device_type, Entry_type,
+    File_identifier_inner_type, File_identifier_type, File_system_identifier_type,
+    File_system_statistics_type, File_system_traits, Flags_type, Get_new_file_identifier,
+    Inode_type, Local_file_identifier_type, Metadata_type, Mode_type, Path_owned_type, Path_type,
+    Permissions_type, Position_type, Size_type, Statistics_type, Time_type, Type_type,
 };
 use Users::{Group_identifier_type, User_identifier_type};
 
@@ -144,6 +145,224 @@ impl File_system_type {
         Ok(())
     }
 
+    /// Mount the file system, then check it and repair it with the LittleFS mechanisms.
+    ///
+    /// If the device can't be mounted (no valid superblock), it is formatted only if `Format_on_failure` is set,
+    /// which is then recorded in the report.
+    pub fn Mount_with_recovery(
+        Device: Device_type,
+        Cache_size: usize,
+        Format_on_failure: bool,
+    ) -> Result_type<(Self, Check_report_type)> {
+        match Self::New(Device.clone(), Cache_size) {
+            Ok(File_system) => {
+                let Report = File_system.Check(true)?;
+
+                Ok((File_system, Report))
+            }
+            Err(_) if Format_on_failure => {
+                Self::Format(Device.clone(), Cache_size)?;
+
+                let mut Report = Check_report_type::New();
+
+                Report.Set_formatted();
+
+                Ok((Self::New(Device, Cache_size)?, Report))
+            }
+            Err(Error) => Err(Error),
+        }
+    }
+
+    /// Check the metadata, the attributes and the content of the entries of a directory, recursively.
+    fn Check_directory(
+        File_system: &mut littlefs::lfs_t,
+        Cache_size: usize,
+        Path: &Path_type,
+        Repair: bool,
+        Report: &mut Check_report_type,
+    ) -> Result_type<()> {
+        let mut Directory = match Directory_type::Open(File_system, Path) {
+            Ok(Directory) => Directory,
+            Err(Error_type::Corrupted) => {
+                Report.Add_issue(
+                    Path.to_owned(),
+                    Check_issue_kind_type::Corrupted_directory,
+                    false,
+                );
+                return Ok(());
+            }
+            Err(Error) => return Err(Error),
+        };
+
+        let mut Entries = Vec::new();
+
+        loop {
+            match Directory.Read(File_system) {
+                Ok(Some(Entry)) => Entries.push(Entry),
+                Ok(None) => break,
+                Err(Error_type::Corrupted) => {
+                    Report.Add_issue(
+                        Path.to_owned(),
+                        Check_issue_kind_type::Corrupted_directory,
+                        false,
+                    );
+                    break;
+                }
+                Err(Error) => {
+                    let _ = Directory.Close(File_system);
+                    return Err(Error);
+                }
+            }
+        }
+
+        Directory.Close(File_system)?;
+
+        for Entry in Entries {
+            if Entry.Get_name() == "." || Entry.Get_name() == ".." {
+                continue;
+            }
+
+            let Entry_path = Path
+                .Append(Entry.Get_name())
+                .ok_or(Error_type::Invalid_path)?;
+
+            let Metadata_valid =
+                Self::Check_metadata(File_system, &Entry_path, Entry.Get_type(), Repair, Report)?;
+
+            Self::Check_attributes(File_system, &Entry_path, Repair, Report)?;
+
+            if Entry.Get_type() == Type_type::Directory {
+                Report.Add_directory();
+
+                Self::Check_directory(File_system, Cache_size, &Entry_path, Repair, Report)?;
+            } else {
+                Report.Add_file();
+
+                // - Files can't be opened without their metadata.
+                if Metadata_valid {
+                    Self::Check_content(File_system, Cache_size, &Entry_path, Report)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the metadata of an entry, return `true` if it is valid (or has been repaired).
+    fn Check_metadata(
+        File_system: &mut littlefs::lfs_t,
+        Path: &Path_owned_type,
+        Type: Type_type,
+        Repair: bool,
+        Report: &mut Check_report_type,
+    ) -> Result_type<bool> {
+        let Kind = match File_type::Get_metadata_from_path(File_system, Path) {
+            Ok(Metadata) if Metadata.Get_type() == Type => return Ok(true),
+            Ok(_) | Err(Error_type::Corrupted) => Check_issue_kind_type::Corrupted_metadata,
+            Err(Error_type::No_attribute) => Check_issue_kind_type::Missing_metadata,
+            Err(Error) => return Err(Error),
+        };
+
+        Report.Add_issue(Path.clone(), Kind, Repair);
+
+        if Repair {
+            // - The original owner and permissions are lost, only root can access the entry.
+            let Metadata = Metadata_type::Get_default(
+                Type,
+                Time_type::New(0),
+                User_identifier_type::Root,
+                Group_identifier_type::Root,
+            )
+            .ok_or(Error_type::Invalid_parameter)?;
+
+            File_type::Set_metadata_from_path(File_system, Path, &Metadata)?;
+        }
+
+        Ok(Repair)
+    }
+
+    fn Check_attributes(
+        File_system: &mut littlefs::lfs_t,
+        Path: &Path_owned_type,
+        Repair: bool,
+        Report: &mut Check_report_type,
+    ) -> Result_type<()> {
+        match File_type::Get_attributes_from_path(File_system, Path) {
+            Ok(_) => Ok(()),
+            Err(Error_type::Corrupted) => {
+                Report.Add_issue(
+                    Path.clone(),
+                    Check_issue_kind_type::Corrupted_attributes,
+                    Repair,
+                );
+
+                if Repair {
+                    File_type::Set_attributes_from_path(
+                        File_system,
+                        Path,
+                        &Attributes_type::New(),
+                    )?;
+                }
+
+                Ok(())
+            }
+            Err(Error) => Err(Error),
+        }
+    }
+
+    /// Read a file entirely : LittleFS checks the block list and the checksums on the way.
+    fn Check_content(
+        File_system: &mut littlefs::lfs_t,
+        Cache_size: usize,
+        Path: &Path_owned_type,
+        Report: &mut Check_report_type,
+    ) -> Result_type<()> {
+        let mut File = match File_type::Open(
+            File_system,
+            Path,
+            Mode_type::Read_only.into(),
+            Cache_size,
+            Time_type::New(0),
+            User_identifier_type::Root,
+            Group_identifier_type::Root,
+        ) {
+            Ok(File) => File,
+            Err(Error_type::Corrupted) => {
+                Report.Add_issue(
+                    Path.clone(),
+                    Check_issue_kind_type::Corrupted_content,
+                    false,
+                );
+                return Ok(());
+            }
+            Err(Error) => return Err(Error),
+        };
+
+        let mut Buffer = vec![0; Cache_size];
+
+        let Result = loop {
+            match File.Read(File_system, &mut Buffer) {
+                Ok(Size) if Size == 0 => break Ok(()),
+                Ok(_) => {}
+                Err(Error) => break Err(Error),
+            }
+        };
+
+        File.Close(File_system)?;
+
+        match Result {
+            Err(Error_type::Corrupted) => {
+                Report.Add_issue(
+                    Path.clone(),
+                    Check_issue_kind_type::Corrupted_content,
+                    false,
+                );
+                Ok(())
+            }
+            Result => Result,
+        }
+    }
+
     fn Borrow_mutable_inner_2_splitted(
         Inner_2: &mut Inner_type,
     ) -> (
@@ -461,6 +680,111 @@ impl File_system_traits for File_system_type {
         Ok(Result)
     }
 
+    fn Get_type_name(&self) -> &'static str {
+        "LittleFS"
+    }
+
+    fn Check(&self, Repair: bool) -> Result_type<Check_report_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let mut Report = Check_report_type::New();
+
+        // - Interrupted removals and moves are recorded in the global state until LittleFS fixes them.
+        if Inner.File_system.gstate.tag != 0 {
+            Report.Add_issue(
+                Path_owned_type::Root(),
+                Check_issue_kind_type::Orphan,
+                Repair,
+            );
+
+            if Repair {
+                Convert_result(unsafe {
+                    littlefs::lfs_fs_mkconsistent(&mut Inner.File_system as *mut _)
+                })?;
+            }
+        }
+
+        // - Walk all the blocks in use (metadata pairs and file block lists).
+        match Convert_result(unsafe { littlefs::lfs_fs_size(&mut Inner.File_system as *mut _) }) {
+            Ok(_) => {}
+            Err(Error_type::Corrupted) => Report.Add_issue(
+                Path_owned_type::Root(),
+                Check_issue_kind_type::Corrupted_structure,
+                false,
+            ),
+            Err(Error) => return Err(Error),
+        }
+
+        Self::Check_directory(
+            &mut Inner.File_system,
+            self.Cache_size,
+            Path_type::Root,
+            Repair,
+            &mut Report,
+        )?;
+
+        Ok(Report)
+    }
+
+    fn Get_attribute(&self, Path: &Path_type, Name: &str) -> Result_type<Vec<u8>> {
+        let mut Inner = self.Inner.write()?;
+
+        let Attributes = File_type::Get_attributes_from_path(&mut Inner.File_system, Path)?;
+
+        Ok(Attributes.Get(Name)?.to_vec())
+    }
+
+    fn Set_attribute(&self, Path: &Path_type, Name: &str, Value: &[u8]) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let mut Attributes = File_type::Get_attributes_from_path(&mut Inner.File_system, Path)?;
+
+        Attributes.Set(Name, Value)?;
+
+        File_type::Set_attributes_from_path(&mut Inner.File_system, Path, &Attributes)
+    }
+
+    fn Remove_attribute(&self, Path: &Path_type, Name: &str) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let mut Attributes = File_type::Get_attributes_from_path(&mut Inner.File_system, Path)?;
+
+        Attributes.Remove(Name)?;
+
+        File_type::Set_attributes_from_path(&mut Inner.File_system, Path, &Attributes)
+    }
+
+    fn List_attributes(&self, Path: &Path_type) -> Result_type<Vec<String>> {
+        let mut Inner = self.Inner.write()?;
+
+        Ok(File_type::Get_attributes_from_path(&mut Inner.File_system, Path)?.Get_names())
+    }
+
+    fn Get_file_system_statistics(&self) -> Result_type<File_system_statistics_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let (Block_size, Block_count) = unsafe {
+            let Configuration = Inner.File_system.cfg.read();
+
+            (Configuration.block_size, Configuration.block_count)
+        };
+
+        let Used_blocks =
+            Convert_result(unsafe { littlefs::lfs_fs_size(&mut Inner.File_system as *mut _) })?;
+
+        let Free_blocks = (Block_count as u64).saturating_sub(Used_blocks as u64);
+
+        // - LittleFS has no inode table, the number of files is only limited by the free blocks.
+        Ok(File_system_statistics_type::New(
+            Block_size as u64,
+            Block_count as u64,
+            Free_blocks,
+            Free_blocks,
+            0,
+            0,
+        ))
+    }
+
     fn Open_directory(
         &self,
         Path: &Path_type,
@@ -692,4 +1016,86 @@ mod Tests {
     fn Test_loader() {
         File_system::Tests::Test_loader(Initialize());
     }
+
+    #[test]
+    fn Test_attributes() {
+        File_system::Tests::Test_attributes(Initialize());
+    }
+
+    #[test]
+    fn Test_check() {
+        let File_system = Initialize();
+
+        let Task = Task::Get_instance().Get_current_task_identifier().unwrap();
+
+        let Path = Path_type::From_str("/File");
+
+        let File = File_system
+            .Open(
+                Task,
+                Path,
+                Flags_type::New(
+                    Mode_type::Write_only,
+                    Some(File_system::Open_type::Create_only),
+                    None,
+                ),
+                Time_type::New(0),
+                User_identifier_type::Root,
+                Group_identifier_type::Root,
+            )
+            .unwrap();
+
+        File_system
+            .Write(File, &[1; 1000], Time_type::New(0))
+            .unwrap();
+        File_system.Close(File).unwrap();
+
+        let Report = File_system.Check(false).unwrap();
+
+        assert_eq!(Report.Get_files(), 1);
+        assert!(Report.Get_issues().is_empty());
+
+        // - Drop the metadata of the file.
+        {
+            let mut Inner = File_system.Inner.write().unwrap();
+
+            let Path = CString::new("/File").unwrap();
+
+            Convert_result(unsafe {
+                littlefs::lfs_removeattr(
+                    &mut Inner.File_system as *mut _,
+                    Path.as_ptr(),
+                    Metadata_type::Identifier,
+                )
+            })
+            .unwrap();
+        }
+
+        let Report = File_system.Check(false).unwrap();
+
+        assert_eq!(
+            Report.Get_issues()[0].Get_kind(),
+            Check_issue_kind_type::Missing_metadata
+        );
+        assert!(!Report.Is_clean());
+
+        assert!(File_system.Check(true).unwrap().Is_clean());
+        assert!(File_system.Check(false).unwrap().Get_issues().is_empty());
+    }
+
+    #[test]
+    fn Test_file_system_statistics() {
+        let File_system = Initialize();
+
+        let Statistics = File_system.Get_file_system_statistics().unwrap();
+
+        assert_eq!(Statistics.Get_block_size(), 512);
+        assert_eq!(Statistics.Get_total_blocks(), 2048);
+        // - The superblock pair is always in use.
+        assert!(Statistics.Get_used_blocks() >= 2);
+        assert_eq!(
+            Statistics.Get_available_blocks(),
+            Statistics.Get_free_blocks()
+        );
+    }
 }
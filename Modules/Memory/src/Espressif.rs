@@ -1,6 +1,9 @@
-use esp_idf_sys::{heap_caps_aligned_alloc, heap_caps_free, MALLOC_CAP_8BIT, MALLOC_CAP_EXEC};
+use esp_idf_sys::{
+    heap_caps_aligned_alloc, heap_caps_free, heap_caps_get_free_size, heap_caps_get_total_size,
+    MALLOC_CAP_8BIT, MALLOC_CAP_EXEC,
+};
 
-use crate::{Flags_type, Layout_type, Memory_allocator_trait, Protection_type};
+use crate::{Flags_type, Layout_type, Memory_allocator_trait, Protection_type, Statistics_type};
 use core::ptr::NonNull;
 use std::os::raw::c_void;
 
@@ -52,6 +55,15 @@ impl Memory_allocator_trait for Memory_allocator_type {
         4 * 1024
     }
 
+    fn Get_statistics(&self) -> Option<Statistics_type> {
+        unsafe {
+            Some(Statistics_type::New(
+                heap_caps_get_total_size(MALLOC_CAP_8BIT),
+                heap_caps_get_free_size(MALLOC_CAP_8BIT),
+            ))
+        }
+    }
+
     fn Flush_data_cache(&self) {
         Flush_data_cache()
     }
@@ -6,7 +6,7 @@ use libc::{
     PROT_EXEC, PROT_NONE, PROT_READ, PROT_WRITE, _SC_PAGE_SIZE,
 };
 
-use crate::{Flags_type, Layout_type, Memory_allocator_trait, Protection_type};
+use crate::{Flags_type, Layout_type, Memory_allocator_trait, Protection_type, Statistics_type};
 
 // - Native platform
 
@@ -100,6 +100,22 @@ impl Memory_allocator_trait for Memory_allocator_type {
     fn Get_page_size(&self) -> usize {
         unsafe { sysconf(_SC_PAGE_SIZE) as usize }
     }
+
+    #[cfg(target_os = "linux")]
+    fn Get_statistics(&self) -> Option<Statistics_type> {
+        let mut Information = unsafe { core::mem::zeroed::<libc::sysinfo>() };
+
+        if unsafe { libc::sysinfo(&mut Information) } != 0 {
+            return None;
+        }
+
+        let Unit = Information.mem_unit as usize;
+
+        Some(Statistics_type::New(
+            Information.totalram as usize * Unit,
+            Information.freeram as usize * Unit,
+        ))
+    }
 }
 
 const fn Round_page_size(Size: usize, Page_size: usize) -> usize {
@@ -0,0 +1,24 @@
+/// Memory usage of the system, in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Statistics_type {
+    Total: usize,
+    Free: usize,
+}
+
+impl Statistics_type {
+    pub const fn New(Total: usize, Free: usize) -> Self {
+        Self { Total, Free }
+    }
+
+    pub const fn Get_total(&self) -> usize {
+        self.Total
+    }
+
+    pub const fn Get_free(&self) -> usize {
+        self.Free
+    }
+
+    pub const fn Get_used(&self) -> usize {
+        self.Total.saturating_sub(self.Free)
+    }
+}
@@ -1,6 +1,6 @@
 use std::ptr::NonNull;
 
-use crate::{Flags_type, Layout_type, Protection_type};
+use crate::{Flags_type, Layout_type, Protection_type, Statistics_type};
 
 pub trait Memory_allocator_trait {
     /// Allocate a memory region.
@@ -34,6 +34,11 @@ pub trait Memory_allocator_trait {
     /// This function is unsafe because it returns the page size of the system which can lead to undefined behavior.
     fn Get_page_size(&self) -> usize;
 
+    /// Get the memory usage of the system, if the platform provides it.
+    fn Get_statistics(&self) -> Option<Statistics_type> {
+        None
+    }
+
     fn Flush_data_cache(&self) {}
 
     fn Flush_instruction_cache(&self, _Address: NonNull<u8>, _Size: usize) {}
@@ -10,11 +10,13 @@ mod Layout;
 mod Native;
 
 mod Protection;
+mod Statistics;
 mod Trait;
 
 pub use Flags::*;
 pub use Layout::*;
 pub use Protection::*;
+pub use Statistics::*;
 pub use Trait::*;
 
 #[cfg(target_os = "espidf")]
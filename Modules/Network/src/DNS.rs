@@ -0,0 +1,359 @@
+/// Encoding of the DNS queries and decoding of the responses (RFC 1035), limited to what a stub resolver needs.
+use crate::{Error_type, IP_type, IPv4_type, IPv6_type, Port_type, Result_type};
+
+/// Port of the DNS servers.
+pub const DNS_port: Port_type = Port_type::New(53);
+
+/// Maximum size of a DNS message over UDP (without EDNS).
+pub const Maximum_message_size: usize = 512;
+
+/// Maximum length of a domain name (without the trailing dot).
+pub const Maximum_name_size: usize = 253;
+
+/// Maximum length of a label (a part of a domain name between dots).
+pub const Maximum_label_size: usize = 63;
+
+const Class_internet: u16 = 1;
+
+const Header_size: usize = 12;
+
+/// Kind of address record looked up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum Record_kind_type {
+    /// IPv4 address.
+    A = 1,
+    /// IPv6 address.
+    AAAA = 28,
+}
+
+impl Record_kind_type {
+    /// Check if an address is of the kind of the record.
+    pub const fn Matches(&self, IP: &IP_type) -> bool {
+        matches!(
+            (self, IP),
+            (Record_kind_type::A, IP_type::IPv4(_)) | (Record_kind_type::AAAA, IP_type::IPv6(_))
+        )
+    }
+}
+
+/// Normalize a domain name : lower case, without the trailing dot.
+///
+/// Return [`Error_type::Invalid_input`] if the name isn't a valid domain name.
+pub fn Normalize_name(Name: &str) -> Result_type<String> {
+    let Name = Name.strip_suffix('.').unwrap_or(Name);
+
+    if Name.is_empty() || Name.len() > Maximum_name_size {
+        return Err(Error_type::Invalid_input);
+    }
+
+    let Is_valid_label = |Label: &str| {
+        !Label.is_empty()
+            && Label.len() <= Maximum_label_size
+            && Label
+                .bytes()
+                .all(|Byte| Byte.is_ascii_alphanumeric() || Byte == b'-' || Byte == b'_')
+    };
+
+    if !Name.split('.').all(Is_valid_label) {
+        return Err(Error_type::Invalid_input);
+    }
+
+    Ok(Name.to_ascii_lowercase())
+}
+
+/// Encode a recursive query for the records of a name.
+pub fn Encode_query(Identifier: u16, Name: &str, Kind: Record_kind_type) -> Result_type<Vec<u8>> {
+    let Name = Normalize_name(Name)?;
+
+    let mut Query = Vec::with_capacity(Header_size + Name.len() + 2 + 4);
+
+    Query.extend_from_slice(&Identifier.to_be_bytes());
+    // - Standard query, recursion desired.
+    Query.extend_from_slice(&0x0100_u16.to_be_bytes());
+    // - One question, no answer, authority or additional records.
+    Query.extend_from_slice(&1_u16.to_be_bytes());
+    Query.extend_from_slice(&[0; 6]);
+
+    for Label in Name.split('.') {
+        Query.push(Label.len() as u8);
+        Query.extend_from_slice(Label.as_bytes());
+    }
+    Query.push(0);
+
+    Query.extend_from_slice(&(Kind as u16).to_be_bytes());
+    Query.extend_from_slice(&Class_internet.to_be_bytes());
+
+    Ok(Query)
+}
+
+struct Reader_type<'a> {
+    Bytes: &'a [u8],
+    Position: usize,
+}
+
+impl<'a> Reader_type<'a> {
+    fn Read(&mut self, Size: usize) -> Result_type<&'a [u8]> {
+        let End = self
+            .Position
+            .checked_add(Size)
+            .ok_or(Error_type::Invalid_data)?;
+
+        let Bytes = self
+            .Bytes
+            .get(self.Position..End)
+            .ok_or(Error_type::Invalid_data)?;
+
+        self.Position = End;
+
+        Ok(Bytes)
+    }
+
+    fn Read_u8(&mut self) -> Result_type<u8> {
+        Ok(self.Read(1)?[0])
+    }
+
+    fn Read_u16(&mut self) -> Result_type<u16> {
+        let Bytes = self.Read(2)?;
+
+        Ok(u16::from_be_bytes([Bytes[0], Bytes[1]]))
+    }
+
+    fn Read_u32(&mut self) -> Result_type<u32> {
+        let Bytes = self.Read(4)?;
+
+        Ok(u32::from_be_bytes([Bytes[0], Bytes[1], Bytes[2], Bytes[3]]))
+    }
+
+    /// Skip a (possibly compressed) name.
+    fn Skip_name(&mut self) -> Result_type<()> {
+        loop {
+            let Length = self.Read_u8()?;
+
+            match Length & 0xC0 {
+                // - End of the name.
+                0x00 if Length == 0 => return Ok(()),
+                0x00 => {
+                    self.Read(Length as usize)?;
+                }
+                // - Pointer to a previous name, which ends the name.
+                0xC0 => {
+                    self.Read_u8()?;
+                    return Ok(());
+                }
+                _ => return Err(Error_type::Invalid_data),
+            }
+        }
+    }
+}
+
+/// Decode the response to a query, return the addresses of the requested kind and the time to live of the answer in seconds.
+///
+/// Return [`Error_type::Invalid_data`] if the message is malformed or doesn't answer the query,
+/// and [`Error_type::Not_found`] if the name doesn't exist or has no address of this kind.
+pub fn Decode_response(
+    Identifier: u16,
+    Kind: Record_kind_type,
+    Bytes: &[u8],
+) -> Result_type<(Vec<IP_type>, u32)> {
+    let mut Reader = Reader_type { Bytes, Position: 0 };
+
+    if Reader.Read_u16()? != Identifier {
+        return Err(Error_type::Invalid_data);
+    }
+
+    let Flags = Reader.Read_u16()?;
+
+    // - Not a response.
+    if Flags & 0x8000 == 0 {
+        return Err(Error_type::Invalid_data);
+    }
+
+    match Flags & 0x000F {
+        0 => {}
+        // - Name error : the name doesn't exist.
+        3 => return Err(Error_type::Not_found),
+        // - Server failure, refused, not implemented...
+        _ => return Err(Error_type::Other),
+    }
+
+    let Questions = Reader.Read_u16()?;
+    let Answers = Reader.Read_u16()?;
+    // - Authority and additional records are not used.
+    Reader.Read(4)?;
+
+    for _ in 0..Questions {
+        Reader.Skip_name()?;
+        Reader.Read(4)?;
+    }
+
+    let mut Addresses = Vec::new();
+    let mut Time_to_live = u32::MAX;
+
+    for _ in 0..Answers {
+        Reader.Skip_name()?;
+
+        let Record_kind = Reader.Read_u16()?;
+        let Class = Reader.Read_u16()?;
+        let Record_time_to_live = Reader.Read_u32()?;
+        let Data_length = Reader.Read_u16()? as usize;
+        let Data = Reader.Read(Data_length)?;
+
+        // - Aliases (CNAME) are followed by the server, which adds the records of the target.
+        if Record_kind != Kind as u16 || Class != Class_internet {
+            continue;
+        }
+
+        let Address = match (Kind, Data.len()) {
+            (Record_kind_type::A, 4) => IPv4_type::New([Data[0], Data[1], Data[2], Data[3]]).into(),
+            (Record_kind_type::AAAA, 16) => {
+                let mut Segments = [0; 8];
+
+                for (Segment, Bytes) in Segments.iter_mut().zip(Data.chunks_exact(2)) {
+                    *Segment = u16::from_be_bytes([Bytes[0], Bytes[1]]);
+                }
+
+                IPv6_type::New(Segments).into()
+            }
+            _ => return Err(Error_type::Invalid_data),
+        };
+
+        Addresses.push(Address);
+        Time_to_live = Time_to_live.min(Record_time_to_live);
+    }
+
+    if Addresses.is_empty() {
+        return Err(Error_type::Not_found);
+    }
+
+    Ok((Addresses, Time_to_live))
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    /// Build the response of a server to a query, with the given answer records (kind, time to live, data).
+    fn New_response(Query: &[u8], Answers: &[(u16, u32, &[u8])]) -> Vec<u8> {
+        let mut Response = Query.to_vec();
+
+        // - Response, recursion desired and available.
+        Response[2..4].copy_from_slice(&0x8180_u16.to_be_bytes());
+        Response[6..8].copy_from_slice(&(Answers.len() as u16).to_be_bytes());
+
+        for (Kind, Time_to_live, Data) in Answers {
+            // - Pointer to the name of the question.
+            Response.extend_from_slice(&0xC00C_u16.to_be_bytes());
+            Response.extend_from_slice(&Kind.to_be_bytes());
+            Response.extend_from_slice(&Class_internet.to_be_bytes());
+            Response.extend_from_slice(&Time_to_live.to_be_bytes());
+            Response.extend_from_slice(&(Data.len() as u16).to_be_bytes());
+            Response.extend_from_slice(Data);
+        }
+
+        Response
+    }
+
+    #[test]
+    fn Test_normalize_name() {
+        assert_eq!(Normalize_name("Example.COM.").unwrap(), "example.com");
+        assert_eq!(Normalize_name("localhost").unwrap(), "localhost");
+
+        assert_eq!(Normalize_name(""), Err(Error_type::Invalid_input));
+        assert_eq!(Normalize_name("a..b"), Err(Error_type::Invalid_input));
+        assert_eq!(Normalize_name("a b.com"), Err(Error_type::Invalid_input));
+        assert_eq!(
+            Normalize_name(&"a".repeat(Maximum_label_size + 1)),
+            Err(Error_type::Invalid_input)
+        );
+    }
+
+    #[test]
+    fn Test_encode_query() {
+        let Query = Encode_query(0x1234, "www.Example.com", Record_kind_type::AAAA).unwrap();
+
+        assert_eq!(
+            Query,
+            [
+                &[0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0][..],
+                b"\x03www\x07example\x03com\x00",
+                &[0, 28, 0, 1]
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn Test_decode_response() {
+        let Query = Encode_query(42, "example.com", Record_kind_type::A).unwrap();
+
+        let Response = New_response(
+            &Query,
+            &[
+                // - Alias, skipped.
+                (5, 600, b"\x03www\xC0\x0C"),
+                (1, 300, &[93, 184, 216, 34]),
+                (1, 60, &[93, 184, 216, 35]),
+            ],
+        );
+
+        assert_eq!(
+            Decode_response(42, Record_kind_type::A, &Response).unwrap(),
+            (
+                vec![
+                    IPv4_type::New([93, 184, 216, 34]).into(),
+                    IPv4_type::New([93, 184, 216, 35]).into()
+                ],
+                60
+            )
+        );
+
+        // - Another query.
+        assert_eq!(
+            Decode_response(43, Record_kind_type::A, &Response),
+            Err(Error_type::Invalid_data)
+        );
+        // - Truncated.
+        assert_eq!(
+            Decode_response(42, Record_kind_type::A, &Response[..Response.len() - 1]),
+            Err(Error_type::Invalid_data)
+        );
+        // - No record of this kind.
+        assert_eq!(
+            Decode_response(42, Record_kind_type::AAAA, &Response),
+            Err(Error_type::Not_found)
+        );
+    }
+
+    #[test]
+    fn Test_decode_response_IPv6_and_errors() {
+        let Query = Encode_query(7, "example.com", Record_kind_type::AAAA).unwrap();
+
+        let Address = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+        let Response = New_response(&Query, &[(28, 120, &Address)]);
+
+        assert_eq!(
+            Decode_response(7, Record_kind_type::AAAA, &Response).unwrap(),
+            (
+                vec![IPv6_type::New([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]).into()],
+                120
+            )
+        );
+
+        // - Name error.
+        let mut Response = New_response(&Query, &[]);
+        Response[3] |= 3;
+
+        assert_eq!(
+            Decode_response(7, Record_kind_type::AAAA, &Response),
+            Err(Error_type::Not_found)
+        );
+
+        // - The query itself isn't a response.
+        assert_eq!(
+            Decode_response(7, Record_kind_type::AAAA, &Query),
+            Err(Error_type::Invalid_data)
+        );
+    }
+}
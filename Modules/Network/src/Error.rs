@@ -1,4 +1,4 @@
-use std::{num::NonZeroU8, sync::PoisonError};
+use std::{fmt::Display, num::NonZeroU8, sync::PoisonError};
 
 pub type Result_type<T> = Result<T, Error_type>;
 
@@ -36,11 +36,31 @@ pub enum Error_type {
     Invalid_identifier,
     Duplicate_identifier,
     Other,
+    /// The TLS handshake failed (e.g. no common protocol version or cipher).
+    Handshake_failed,
+    /// The certificate of the peer isn't trusted or doesn't match the server name, or a local certificate is invalid.
+    Invalid_certificate,
+    Read_only_file_system,
+    Stale_network_file_handle,
+    Invalid_file_name,
+    Argument_list_too_long,
+    Not_a_directory,
+    Is_a_directory,
+    Directory_not_empty,
+    Not_seekable,
+    File_too_large,
+    Crosses_devices,
+    Too_many_links,
+    Quota_exceeded,
+    Executable_file_busy,
+    /// Error of the host which has no equivalent, with its original code.
+    Raw_OS_error(i32),
 }
 
 impl Error_type {
-    pub const fn Get_discriminant(&self) -> NonZeroU8 {
-        unsafe { NonZeroU8::new_unchecked(*self as u8) }
+    pub fn Get_discriminant(&self) -> NonZeroU8 {
+        // - The discriminant is the first field of a primitive representation.
+        unsafe { *<*const _>::from(self).cast::<NonZeroU8>() }
     }
 }
 
@@ -55,3 +75,76 @@ impl<T> From<PoisonError<T>> for Error_type {
         Self::Poisonned_lock
     }
 }
+
+impl Display for Error_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let String = match self {
+            Error_type::Not_found => "Not found",
+            Error_type::Permission_denied => "Permission denied",
+            Error_type::Connection_refused => "Connection refused",
+            Error_type::Connection_reset => "Connection reset",
+            Error_type::Host_unreachable => "Host unreachable",
+            Error_type::Network_unreachable => "Network unreachable",
+            Error_type::Connection_aborted => "Connection aborted",
+            Error_type::Not_connected => "Not connected",
+            Error_type::Address_in_use => "Address in use",
+            Error_type::Address_not_available => "Address not available",
+            Error_type::Network_down => "Network down",
+            Error_type::Broken_pipe => "Broken pipe",
+            Error_type::Already_exists => "Already exists",
+            Error_type::Would_block => "Would block",
+            Error_type::Invalid_input => "Invalid input",
+            Error_type::Invalid_data => "Invalid data",
+            Error_type::Timed_out => "Timed out",
+            Error_type::Write_zero => "Write zero",
+            Error_type::Storage_full => "Storage full",
+            Error_type::Resource_busy => "Resource busy",
+            Error_type::Deadlock => "Deadlock",
+            Error_type::Interrupted => "Interrupted",
+            Error_type::Unsupported => "Unsupported",
+            Error_type::Unexpected_end_of_file => "Unexpected end of file",
+            Error_type::Out_of_memory => "Out of memory",
+            Error_type::In_progress => "In progress",
+            Error_type::Poisonned_lock => "Poisonned lock",
+            Error_type::Unsupported_protocol => "Unsupported protocol",
+            Error_type::Invalid_identifier => "Invalid identifier",
+            Error_type::Duplicate_identifier => "Duplicate identifier",
+            Error_type::Other => "Other",
+            Error_type::Handshake_failed => "Handshake failed",
+            Error_type::Invalid_certificate => "Invalid certificate",
+            Error_type::Read_only_file_system => "Read only file system",
+            Error_type::Stale_network_file_handle => "Stale network file handle",
+            Error_type::Invalid_file_name => "Invalid file name",
+            Error_type::Argument_list_too_long => "Argument list too long",
+            Error_type::Not_a_directory => "Not a directory",
+            Error_type::Is_a_directory => "Is a directory",
+            Error_type::Directory_not_empty => "Directory not empty",
+            Error_type::Not_seekable => "Not seekable",
+            Error_type::File_too_large => "File too large",
+            Error_type::Crosses_devices => "Crosses devices",
+            Error_type::Too_many_links => "Too many links",
+            Error_type::Quota_exceeded => "Quota exceeded",
+            Error_type::Executable_file_busy => "Executable file busy",
+            Error_type::Raw_OS_error(Code) => return write!(Formatter, "OS error {}", Code),
+        };
+
+        write!(Formatter, "{}", String)
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_discriminant() {
+        assert_eq!(Error_type::Not_found.Get_discriminant().get(), 1);
+        assert_eq!(
+            Error_type::Unsupported_protocol.Get_discriminant().get(),
+            28
+        );
+        assert_eq!(Error_type::Invalid_certificate.Get_discriminant().get(), 33);
+        assert_eq!(Error_type::Raw_OS_error(-1).Get_discriminant().get(), 47);
+        assert_eq!(Error_type::Raw_OS_error(4095).to_string(), "OS error 4095");
+    }
+}
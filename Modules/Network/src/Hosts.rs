@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+
+use File_system::Path_type;
+
+use crate::{IP_type, Normalize_name, Record_kind_type};
+
+/// Path of the hosts file, mapping names to addresses before asking the DNS servers.
+pub const Hosts_file_path: &Path_type = Path_type::From_str("/Configuration/Hosts");
+
+/// Static name to address mapping, parsed from a hosts file.
+///
+/// # Format
+///
+/// One address per line followed by its names, separated by whitespaces. Everything after a `#` is a comment.
+///
+/// ```text
+/// 127.0.0.1   localhost
+/// ::1         localhost
+/// 192.168.1.2 printer printer.local # Office printer
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Hosts_type(BTreeMap<String, Vec<IP_type>>);
+
+impl Hosts_type {
+    pub fn New() -> Self {
+        Self::default()
+    }
+
+    /// Parse the content of a hosts file, the malformed lines are ignored.
+    pub fn Parse(Content: &str) -> Self {
+        let mut Hosts = Self::New();
+
+        for Line in Content.lines() {
+            let Line = match Line.split_once('#') {
+                Some((Line, _)) => Line,
+                None => Line,
+            };
+
+            let mut Fields = Line.split_whitespace();
+
+            let IP = match Fields.next().map(IP_type::try_from) {
+                Some(Ok(IP)) => IP,
+                _ => continue,
+            };
+
+            for Name in Fields {
+                Hosts.Add(Name, IP.clone());
+            }
+        }
+
+        Hosts
+    }
+
+    /// Add an address to a name, invalid names are ignored.
+    pub fn Add(&mut self, Name: &str, IP: IP_type) {
+        let Name = match Normalize_name(Name) {
+            Ok(Name) => Name,
+            Err(_) => return,
+        };
+
+        let Addresses = self.0.entry(Name).or_default();
+
+        if !Addresses.contains(&IP) {
+            Addresses.push(IP);
+        }
+    }
+
+    /// Get the addresses of a kind of a name (empty if there is none).
+    pub fn Get(&self, Name: &str, Kind: Record_kind_type) -> Vec<IP_type> {
+        let Name = match Normalize_name(Name) {
+            Ok(Name) => Name,
+            Err(_) => return Vec::new(),
+        };
+
+        match self.0.get(&Name) {
+            Some(Addresses) => Addresses
+                .iter()
+                .filter(|IP| Kind.Matches(IP))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn Is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+    use crate::{IPv4_type, IPv6_type};
+
+    #[test]
+    fn Test_parse() {
+        let Hosts = Hosts_type::Parse(
+            "# Local host\n\
+             127.0.0.1 localhost\n\
+             ::1       localhost  # IPv6\n\
+             \n\
+             invalid   ignored\n\
+             192.168.1.2\tPrinter printer.local.\n",
+        );
+
+        assert_eq!(
+            Hosts.Get("localhost", Record_kind_type::A),
+            vec![IPv4_type::Localhost.into()]
+        );
+        assert_eq!(
+            Hosts.Get("LOCALHOST.", Record_kind_type::AAAA),
+            vec![IPv6_type::Localhost.into()]
+        );
+        assert_eq!(
+            Hosts.Get("printer.local", Record_kind_type::A),
+            vec![IPv4_type::New([192, 168, 1, 2]).into()]
+        );
+        assert_eq!(
+            Hosts.Get("printer", Record_kind_type::A),
+            vec![IPv4_type::New([192, 168, 1, 2]).into()]
+        );
+        assert!(Hosts.Get("printer", Record_kind_type::AAAA).is_empty());
+        assert!(Hosts.Get("ignored", Record_kind_type::A).is_empty());
+        assert!(Hosts_type::Parse("# Empty\n").Is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+/// Encoding of the ICMP echo requests and decoding of the replies (RFC 792 and RFC 4443), as used by `ping`.
+use crate::Address_family_type;
+
+/// Name of the group whose members are allowed to open ICMP sockets (as the root user).
+pub const Network_group_name: &str = "network";
+
+const Header_size: usize = 8;
+
+const Echo_request_IPv4: u8 = 8;
+const Echo_reply_IPv4: u8 = 0;
+const Echo_request_IPv6: u8 = 128;
+const Echo_reply_IPv6: u8 = 129;
+
+/// Echo reply decoded from an ICMP packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Echo_reply_type<'a> {
+    Identifier: u16,
+    Sequence: u16,
+    Payload: &'a [u8],
+}
+
+impl<'a> Echo_reply_type<'a> {
+    pub const fn Get_identifier(&self) -> u16 {
+        self.Identifier
+    }
+
+    pub const fn Get_sequence(&self) -> u16 {
+        self.Sequence
+    }
+
+    pub const fn Get_payload(&self) -> &'a [u8] {
+        self.Payload
+    }
+}
+
+/// Compute the internet checksum of a packet (one's complement of the one's complement sum of its 16 bits words).
+pub fn Get_checksum(Data: &[u8]) -> u16 {
+    let mut Sum: u32 = Data
+        .chunks(2)
+        .map(|Word| match Word {
+            [High, Low] => u16::from_be_bytes([*High, *Low]) as u32,
+            [High] => u16::from_be_bytes([*High, 0]) as u32,
+            _ => unreachable!(),
+        })
+        .sum();
+
+    while Sum > 0xFFFF {
+        Sum = (Sum & 0xFFFF) + (Sum >> 16);
+    }
+
+    !(Sum as u16)
+}
+
+/// Encode an echo request.
+///
+/// The checksum is only computed for IPv4 : the ICMPv6 checksum covers the IP header, it is computed by the driver.
+/// The drivers using datagram ICMP sockets may also replace the identifier by their own.
+pub fn Encode_echo_request(
+    Family: Address_family_type,
+    Identifier: u16,
+    Sequence: u16,
+    Payload: &[u8],
+) -> Vec<u8> {
+    let Kind = match Family {
+        Address_family_type::IPv4 => Echo_request_IPv4,
+        Address_family_type::IPv6 => Echo_request_IPv6,
+    };
+
+    let mut Request = Vec::with_capacity(Header_size + Payload.len());
+
+    Request.extend_from_slice(&[Kind, 0, 0, 0]);
+    Request.extend_from_slice(&Identifier.to_be_bytes());
+    Request.extend_from_slice(&Sequence.to_be_bytes());
+    Request.extend_from_slice(Payload);
+
+    if Family == Address_family_type::IPv4 {
+        let Checksum = Get_checksum(&Request);
+
+        Request[2..4].copy_from_slice(&Checksum.to_be_bytes());
+    }
+
+    Request
+}
+
+/// Decode an echo reply, `None` if the packet is another ICMP message (e.g. destination unreachable).
+pub fn Decode_echo_reply(
+    Family: Address_family_type,
+    Packet: &[u8],
+) -> Option<Echo_reply_type<'_>> {
+    if Packet.len() < Header_size {
+        return None;
+    }
+
+    let Kind = match Family {
+        Address_family_type::IPv4 => Echo_reply_IPv4,
+        Address_family_type::IPv6 => Echo_reply_IPv6,
+    };
+
+    if Packet[0] != Kind || Packet[1] != 0 {
+        return None;
+    }
+
+    Some(Echo_reply_type {
+        Identifier: u16::from_be_bytes([Packet[4], Packet[5]]),
+        Sequence: u16::from_be_bytes([Packet[6], Packet[7]]),
+        Payload: &Packet[Header_size..],
+    })
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_checksum() {
+        // - Example of RFC 1071, the sum of the words is 0xDDF2.
+        let Data = [0x00, 0x01, 0xF2, 0x03, 0xF4, 0xF5, 0xF6, 0xF7];
+
+        assert_eq!(Get_checksum(&Data), !0xDDF2);
+
+        // - A packet including its checksum sums to zero.
+        let Request = Encode_echo_request(Address_family_type::IPv4, 0x1234, 1, b"Xila");
+
+        assert_eq!(Get_checksum(&Request), 0);
+    }
+
+    #[test]
+    fn Test_echo() {
+        let Request = Encode_echo_request(Address_family_type::IPv4, 0x1234, 7, b"Xila");
+
+        assert_eq!(&Request[..8], &[8, 0, 0x20, 0xFA, 0x12, 0x34, 0, 7]);
+
+        // - The request itself isn't a reply.
+        assert_eq!(Decode_echo_reply(Address_family_type::IPv4, &Request), None);
+
+        let mut Reply = Request.clone();
+        Reply[0] = Echo_reply_IPv4;
+
+        let Reply = Decode_echo_reply(Address_family_type::IPv4, &Reply).unwrap();
+
+        assert_eq!(Reply.Get_identifier(), 0x1234);
+        assert_eq!(Reply.Get_sequence(), 7);
+        assert_eq!(Reply.Get_payload(), b"Xila");
+
+        let mut Reply = Encode_echo_request(Address_family_type::IPv6, 1, 2, &[]);
+        Reply[0] = Echo_reply_IPv6;
+
+        assert_eq!(
+            Decode_echo_reply(Address_family_type::IPv6, &Reply),
+            Some(Echo_reply_type {
+                Identifier: 1,
+                Sequence: 2,
+                Payload: &[]
+            })
+        );
+        assert_eq!(
+            Decode_echo_reply(Address_family_type::IPv6, &Reply[..4]),
+            None
+        );
+    }
+}
@@ -7,6 +7,30 @@ pub struct IPv4_type([u8; 4]);
 impl IPv4_type {
     pub const Localhost: Self = Self([127, 0, 0, 1]);
 
+    /// Get the netmask of a network prefix length (e.g. `255.255.255.0` for 24), capped to 32.
+    pub const fn From_prefix_length(Prefix_length: u8) -> Self {
+        let Mask = match Prefix_length {
+            0 => 0,
+            Prefix_length if Prefix_length >= 32 => u32::MAX,
+            Prefix_length => u32::MAX << (32 - Prefix_length),
+        };
+
+        Self(Mask.to_be_bytes())
+    }
+
+    /// Get the network prefix length of a netmask, `None` if the bits are not contiguous.
+    pub const fn Get_prefix_length(&self) -> Option<u8> {
+        let Mask = u32::from_be_bytes(self.0);
+
+        let Prefix_length = Mask.leading_ones();
+
+        if Prefix_length < 32 && Mask << Prefix_length != 0 {
+            return None;
+        }
+
+        Some(Prefix_length as u8)
+    }
+
     pub const fn New(value: [u8; 4]) -> Self {
         Self(value)
     }
@@ -54,6 +78,8 @@ impl Display for IPv4_type {
 pub struct IPv6_type([u16; 8]);
 
 impl IPv6_type {
+    pub const Localhost: Self = Self([0, 0, 0, 0, 0, 0, 0, 1]);
+
     pub const fn New(value: [u16; 8]) -> Self {
         Self(value)
     }
@@ -70,21 +96,51 @@ impl IPv6_type {
 impl TryFrom<&str> for IPv6_type {
     type Error = ();
 
+    /// Parse an IPv6 address, a run of zero groups can be shortened to `::` (e.g. `::1`).
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        fn Parse_groups(Groups: &str) -> Result<Vec<u16>, ()> {
+            if Groups.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            Groups
+                .split(':')
+                .map(|Group| {
+                    if Group.is_empty() || Group.len() > 4 {
+                        return Err(());
+                    }
+
+                    u16::from_str_radix(Group, 16).map_err(|_| ())
+                })
+                .collect()
+        }
+
         let mut Result = [0; 8];
-        let mut Index = 0;
 
-        for Part in value.split(':') {
-            if Index >= Result.len() {
-                return Err(());
+        match value.split_once("::") {
+            Some((Head, Tail)) => {
+                let Head = Parse_groups(Head)?;
+                let Tail = Parse_groups(Tail)?;
+
+                // - `::` stands for at least one zero group.
+                if Head.len() + Tail.len() >= Result.len() {
+                    return Err(());
+                }
+
+                let Tail_start = Result.len() - Tail.len();
+
+                Result[..Head.len()].copy_from_slice(&Head);
+                Result[Tail_start..].copy_from_slice(&Tail);
             }
+            None => {
+                let Groups = Parse_groups(value)?;
 
-            let Part = u16::from_str_radix(Part, 16).map_err(|_| ())?;
-            Result[Index] = Part;
-            Index += 1;
-        }
-        if Index != Result.len() {
-            return Err(());
+                if Groups.len() != Result.len() {
+                    return Err(());
+                }
+
+                Result.copy_from_slice(&Groups);
+            }
         }
 
         Ok(Self::New(Result))
@@ -116,6 +172,18 @@ impl Display for IP_type {
     }
 }
 
+impl TryFrom<&str> for IP_type {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(IP) = IPv4_type::try_from(value) {
+            return Ok(Self::IPv4(IP));
+        }
+
+        IPv6_type::try_from(value).map(Self::IPv6)
+    }
+}
+
 impl From<IPv4_type> for IP_type {
     fn from(value: IPv4_type) -> Self {
         Self::IPv4(value)
@@ -149,6 +217,21 @@ mod Tests {
         assert_eq!(IP.0, [4, 3, 2, 1]);
     }
 
+    #[test]
+    fn Test_netmask() {
+        assert_eq!(IPv4_type::From_prefix_length(24).0, [255, 255, 255, 0]);
+        assert_eq!(IPv4_type::From_prefix_length(0).0, [0, 0, 0, 0]);
+        assert_eq!(IPv4_type::From_prefix_length(40).0, [255; 4]);
+
+        assert_eq!(
+            IPv4_type::New([255, 255, 240, 0]).Get_prefix_length(),
+            Some(20)
+        );
+        assert_eq!(IPv4_type::New([255; 4]).Get_prefix_length(), Some(32));
+        assert_eq!(IPv4_type::New([0; 4]).Get_prefix_length(), Some(0));
+        assert_eq!(IPv4_type::New([255, 0, 255, 0]).Get_prefix_length(), None);
+    }
+
     #[test]
     fn Test_ipv6_try_from() {
         let IP = IPv6_type::try_from("0:0:0:0:0:0:0:0").unwrap();
@@ -165,5 +248,30 @@ mod Tests {
             IP.0,
             [0x1234, 0x5678, 0x9abc, 0xdef0, 0x1234, 0x5678, 0x9abc, 0xdef0]
         );
+
+        assert_eq!(IPv6_type::try_from("::1").unwrap(), IPv6_type::Localhost);
+        assert_eq!(IPv6_type::try_from("::").unwrap().0, [0; 8]);
+
+        let IP = IPv6_type::try_from("fe80::1:2").unwrap();
+
+        assert_eq!(IP.0, [0xfe80, 0, 0, 0, 0, 0, 1, 2]);
+
+        IPv6_type::try_from("1::2::3").unwrap_err();
+        IPv6_type::try_from("1:2:3:4::5:6:7:8").unwrap_err();
+        IPv6_type::try_from("12345::").unwrap_err();
+    }
+
+    #[test]
+    fn Test_ip_try_from() {
+        assert_eq!(
+            IP_type::try_from("127.0.0.1").unwrap(),
+            IP_type::IPv4(IPv4_type::Localhost)
+        );
+        assert_eq!(
+            IP_type::try_from("::1").unwrap(),
+            IP_type::IPv6(IPv6_type::Localhost)
+        );
+
+        IP_type::try_from("localhost").unwrap_err();
     }
 }
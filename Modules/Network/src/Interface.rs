@@ -0,0 +1,330 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{Error_type, IP_type, IPv4_type, Result_type};
+
+/// How the addresses of an interface are obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Configuration_mode_type {
+    /// Addresses, gateway and DNS servers set by hand.
+    Static,
+    /// Addresses, gateway and DNS servers leased from a DHCP server.
+    DHCP,
+}
+
+impl Display for Configuration_mode_type {
+    fn fmt(&self, Formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Configuration_mode_type::Static => write!(Formatter, "static"),
+            Configuration_mode_type::DHCP => write!(Formatter, "dhcp"),
+        }
+    }
+}
+
+/// Address of an interface with the prefix length of its network (e.g. `192.168.1.2/24`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Interface_address_type {
+    IP: IP_type,
+    Prefix_length: u8,
+}
+
+impl Interface_address_type {
+    pub fn New(IP: IP_type, Prefix_length: u8) -> Self {
+        Self { IP, Prefix_length }
+    }
+
+    pub fn Get_IP(&self) -> &IP_type {
+        &self.IP
+    }
+
+    pub fn Get_prefix_length(&self) -> u8 {
+        self.Prefix_length
+    }
+
+    /// Get the netmask of an IPv4 address (IPv6 networks only use the prefix length).
+    pub fn Get_netmask(&self) -> Option<IPv4_type> {
+        match self.IP {
+            IP_type::IPv4(_) => Some(IPv4_type::From_prefix_length(self.Prefix_length)),
+            IP_type::IPv6(_) => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for Interface_address_type {
+    type Error = ();
+
+    /// Parse an address with its prefix length, a single host (`/32` or `/128`) if the prefix is omitted.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (IP, Prefix_length) = match value.split_once('/') {
+            Some((IP, Prefix_length)) => (IP, Some(Prefix_length)),
+            None => (value, None),
+        };
+
+        let IP = IP_type::try_from(IP)?;
+
+        let Maximum = match IP {
+            IP_type::IPv4(_) => 32,
+            IP_type::IPv6(_) => 128,
+        };
+
+        let Prefix_length = match Prefix_length {
+            Some(Prefix_length) => Prefix_length.parse::<u8>().map_err(|_| ())?,
+            None => Maximum,
+        };
+
+        if Prefix_length > Maximum {
+            return Err(());
+        }
+
+        Ok(Self::New(IP, Prefix_length))
+    }
+}
+
+impl Display for Interface_address_type {
+    fn fmt(&self, Formatter: &mut Formatter) -> fmt::Result {
+        write!(Formatter, "{}/{}", self.IP, self.Prefix_length)
+    }
+}
+
+/// Settings applied to an interface, persisted by the manager.
+///
+/// # Format
+///
+/// The mode followed by the settings, separated by whitespaces :
+///
+/// ```text
+/// dhcp
+/// static address 192.168.1.2/24 gateway 192.168.1.1 dns 192.168.1.1 dns 9.9.9.9
+/// ```
+///
+/// The DNS servers given with DHCP override the leased ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interface_configuration_type {
+    Mode: Configuration_mode_type,
+    Addresses: Vec<Interface_address_type>,
+    Gateway: Option<IP_type>,
+    DNS_servers: Vec<IP_type>,
+}
+
+impl Interface_configuration_type {
+    pub fn New_DHCP(DNS_servers: Vec<IP_type>) -> Self {
+        Self {
+            Mode: Configuration_mode_type::DHCP,
+            Addresses: Vec::new(),
+            Gateway: None,
+            DNS_servers,
+        }
+    }
+
+    pub fn New_static(
+        Addresses: Vec<Interface_address_type>,
+        Gateway: Option<IP_type>,
+        DNS_servers: Vec<IP_type>,
+    ) -> Self {
+        Self {
+            Mode: Configuration_mode_type::Static,
+            Addresses,
+            Gateway,
+            DNS_servers,
+        }
+    }
+
+    pub fn Get_mode(&self) -> Configuration_mode_type {
+        self.Mode
+    }
+
+    pub fn Get_addresses(&self) -> &[Interface_address_type] {
+        &self.Addresses
+    }
+
+    pub fn Get_gateway(&self) -> Option<&IP_type> {
+        self.Gateway.as_ref()
+    }
+
+    pub fn Get_DNS_servers(&self) -> &[IP_type] {
+        &self.DNS_servers
+    }
+
+    /// Parse a configuration (see the format above), return [`Error_type::Invalid_input`] if it's malformed.
+    pub fn Parse(Configuration: &str) -> Result_type<Self> {
+        let mut Tokens = Configuration.split_whitespace();
+
+        let mut Result = match Tokens.next() {
+            Some("dhcp") => Self::New_DHCP(Vec::new()),
+            Some("static") => Self::New_static(Vec::new(), None, Vec::new()),
+            _ => return Err(Error_type::Invalid_input),
+        };
+
+        while let Some(Setting) = Tokens.next() {
+            let Value = Tokens.next().ok_or(Error_type::Invalid_input)?;
+
+            match (Setting, Result.Mode) {
+                ("address", Configuration_mode_type::Static) => {
+                    let Address = Interface_address_type::try_from(Value)
+                        .map_err(|_| Error_type::Invalid_input)?;
+
+                    Result.Addresses.push(Address);
+                }
+                ("gateway", Configuration_mode_type::Static) if Result.Gateway.is_none() => {
+                    let Gateway =
+                        IP_type::try_from(Value).map_err(|_| Error_type::Invalid_input)?;
+
+                    Result.Gateway = Some(Gateway);
+                }
+                ("dns", _) => {
+                    let Server = IP_type::try_from(Value).map_err(|_| Error_type::Invalid_input)?;
+
+                    Result.DNS_servers.push(Server);
+                }
+                _ => return Err(Error_type::Invalid_input),
+            }
+        }
+
+        if Result.Mode == Configuration_mode_type::Static && Result.Addresses.is_empty() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        Ok(Result)
+    }
+}
+
+impl Display for Interface_configuration_type {
+    fn fmt(&self, Formatter: &mut Formatter) -> fmt::Result {
+        write!(Formatter, "{}", self.Mode)?;
+
+        for Address in &self.Addresses {
+            write!(Formatter, " address {}", Address)?;
+        }
+
+        if let Some(Gateway) = &self.Gateway {
+            write!(Formatter, " gateway {}", Gateway)?;
+        }
+
+        for Server in &self.DNS_servers {
+            write!(Formatter, " dns {}", Server)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Current state of an interface, as reported by its driver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interface_status_type {
+    Name: String,
+    Hardware_address: Option<[u8; 6]>,
+    /// The interface is enabled and connected (cable plugged, associated to an access point...).
+    Link_up: bool,
+    Addresses: Vec<Interface_address_type>,
+    Gateway: Option<IP_type>,
+    DNS_servers: Vec<IP_type>,
+}
+
+impl Interface_status_type {
+    pub fn New(
+        Name: String,
+        Hardware_address: Option<[u8; 6]>,
+        Link_up: bool,
+        Addresses: Vec<Interface_address_type>,
+        Gateway: Option<IP_type>,
+        DNS_servers: Vec<IP_type>,
+    ) -> Self {
+        Self {
+            Name,
+            Hardware_address,
+            Link_up,
+            Addresses,
+            Gateway,
+            DNS_servers,
+        }
+    }
+
+    pub fn Get_name(&self) -> &str {
+        &self.Name
+    }
+
+    pub fn Get_hardware_address(&self) -> Option<[u8; 6]> {
+        self.Hardware_address
+    }
+
+    pub fn Is_link_up(&self) -> bool {
+        self.Link_up
+    }
+
+    pub fn Get_addresses(&self) -> &[Interface_address_type] {
+        &self.Addresses
+    }
+
+    pub fn Get_gateway(&self) -> Option<&IP_type> {
+        self.Gateway.as_ref()
+    }
+
+    pub fn Get_DNS_servers(&self) -> &[IP_type] {
+        &self.DNS_servers
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+    use crate::IPv6_type;
+
+    #[test]
+    fn Test_interface_address() {
+        let Address = Interface_address_type::try_from("192.168.1.2/24").unwrap();
+
+        assert_eq!(Address.Get_prefix_length(), 24);
+        assert_eq!(
+            Address.Get_netmask(),
+            Some(IPv4_type::New([255, 255, 255, 0]))
+        );
+        assert_eq!(Address.to_string(), "192.168.1.2/24");
+
+        assert_eq!(
+            Interface_address_type::try_from("::1").unwrap(),
+            Interface_address_type::New(IPv6_type::Localhost.into(), 128)
+        );
+
+        Interface_address_type::try_from("10.0.0.1/33").unwrap_err();
+        Interface_address_type::try_from("10.0.0.1/").unwrap_err();
+    }
+
+    #[test]
+    fn Test_configuration_parse() {
+        let Configuration =
+            "static address 192.168.1.2/24 address fe80:0:0:0:0:0:0:2/64 gateway 192.168.1.1 dns 9.9.9.9";
+
+        let Parsed = Interface_configuration_type::Parse(Configuration).unwrap();
+
+        assert_eq!(Parsed.Get_mode(), Configuration_mode_type::Static);
+        assert_eq!(Parsed.Get_addresses().len(), 2);
+        assert_eq!(
+            Parsed.Get_gateway(),
+            Some(&IPv4_type::New([192, 168, 1, 1]).into())
+        );
+        assert_eq!(Parsed.to_string(), Configuration);
+
+        let Parsed = Interface_configuration_type::Parse("dhcp dns 1.1.1.1").unwrap();
+
+        assert_eq!(
+            Parsed,
+            Interface_configuration_type::New_DHCP(vec![IPv4_type::New([1, 1, 1, 1]).into()])
+        );
+
+        for Invalid in [
+            "",
+            "manual",
+            "static",
+            "static address",
+            "dhcp address 10.0.0.2/8",
+            "static address 10.0.0.2/8 gateway 10.0.0.1 gateway 10.0.0.3",
+            "static address 10.0.0.2/8 mtu 1500",
+        ] {
+            assert_eq!(
+                Interface_configuration_type::Parse(Invalid),
+                Err(Error_type::Invalid_input),
+                "{}",
+                Invalid
+            );
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::{
+    collections::BTreeMap,
+    sync::{OnceLock, RwLock},
+};
+
+use File_system::Path_type;
+
+use crate::{
+    Error_type, IP_type, Interface_configuration_type, Interface_status_type,
+    Network_interface_driver_trait, Result_type,
+};
+
+/// File where the configuration of the interfaces is saved.
+///
+/// # Format
+///
+/// One interface per line : its name followed by its configuration (see [`Interface_configuration_type`]).
+///
+/// ```text
+/// eth0 dhcp
+/// wlan0 static address 192.168.1.2/24 gateway 192.168.1.1 dns 192.168.1.1
+/// ```
+pub const Network_configuration_file_path: &Path_type =
+    Path_type::From_str("/Configuration/Network");
+
+static Manager_instance: OnceLock<Manager_type> = OnceLock::new();
+
+pub fn Initialize(
+    Driver: &'static dyn Network_interface_driver_trait,
+) -> Result_type<&'static Manager_type> {
+    Manager_instance.get_or_init(|| Manager_type::New(Driver));
+
+    Ok(Get_instance())
+}
+
+pub fn Is_initialized() -> bool {
+    Manager_instance.get().is_some()
+}
+
+pub fn Get_instance() -> &'static Manager_type {
+    Manager_instance
+        .get()
+        .expect("Network manager is not initialized")
+}
+
+/// Network interfaces manager : keeps the configuration applied to the interfaces to persist it.
+pub struct Manager_type {
+    Driver: &'static dyn Network_interface_driver_trait,
+    Configurations: RwLock<BTreeMap<String, Interface_configuration_type>>,
+}
+
+impl Manager_type {
+    pub fn New(Driver: &'static dyn Network_interface_driver_trait) -> Self {
+        Self {
+            Driver,
+            Configurations: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn Get_interfaces(&self) -> Result_type<Vec<String>> {
+        self.Driver.Get_interfaces()
+    }
+
+    pub fn Get_status(&self, Interface: &str) -> Result_type<Interface_status_type> {
+        self.Driver.Get_status(Interface)
+    }
+
+    /// Get the configuration applied to an interface, `None` if it keeps the one of the driver.
+    pub fn Get_configuration(
+        &self,
+        Interface: &str,
+    ) -> Result_type<Option<Interface_configuration_type>> {
+        Ok(self.Configurations.read()?.get(Interface).cloned())
+    }
+
+    /// Apply a configuration to an interface and keep it (see [`Self::Get_configuration_file`]).
+    pub fn Set_configuration(
+        &self,
+        Interface: &str,
+        Configuration: Interface_configuration_type,
+    ) -> Result_type<()> {
+        // - Check that the interface exists.
+        self.Driver.Get_status(Interface)?;
+
+        self.Driver.Set_configuration(Interface, &Configuration)?;
+
+        self.Configurations
+            .write()?
+            .insert(Interface.to_string(), Configuration);
+
+        Ok(())
+    }
+
+    pub fn Set_link(&self, Interface: &str, Up: bool) -> Result_type<()> {
+        self.Driver.Set_link(Interface, Up)
+    }
+
+    /// Get the DNS servers of the interfaces whose link is up, without duplicates.
+    pub fn Get_DNS_servers(&self) -> Result_type<Vec<IP_type>> {
+        let mut Servers: Vec<IP_type> = Vec::new();
+
+        for Interface in self.Get_interfaces()? {
+            let Status = self.Get_status(&Interface)?;
+
+            if !Status.Is_link_up() {
+                continue;
+            }
+
+            for Server in Status.Get_DNS_servers() {
+                if !Servers.contains(Server) {
+                    Servers.push(Server.clone());
+                }
+            }
+        }
+
+        Ok(Servers)
+    }
+
+    /// Get the content of [`Network_configuration_file_path`] for the current configurations.
+    pub fn Get_configuration_file(&self) -> Result_type<String> {
+        let Configurations = self.Configurations.read()?;
+
+        Ok(Configurations
+            .iter()
+            .map(|(Interface, Configuration)| format!("{} {}\n", Interface, Configuration))
+            .collect())
+    }
+
+    /// Apply the configurations of the content of [`Network_configuration_file_path`].
+    ///
+    /// All the interfaces are configured even if some fail, the first error is returned.
+    pub fn Load_configuration_file(&self, Content: &str) -> Result_type<()> {
+        let mut Result = Ok(());
+
+        for Line in Content.lines() {
+            let Line = Line.trim();
+
+            if Line.is_empty() || Line.starts_with('#') {
+                continue;
+            }
+
+            let Line_result = match Line.split_once(char::is_whitespace) {
+                Some((Interface, Configuration)) => {
+                    Interface_configuration_type::Parse(Configuration)
+                        .and_then(|Configuration| self.Set_configuration(Interface, Configuration))
+                }
+                None => Err(Error_type::Invalid_input),
+            };
+
+            if Result.is_ok() {
+                Result = Line_result;
+            }
+        }
+
+        Result
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+    use crate::{IPv4_type, Interface_address_type};
+
+    /// Driver with a single interface, configured in memory.
+    struct Test_driver_type(RwLock<Interface_status_type>);
+
+    impl Network_interface_driver_trait for Test_driver_type {
+        fn Get_interfaces(&self) -> Result_type<Vec<String>> {
+            Ok(vec![self.0.read()?.Get_name().to_string()])
+        }
+
+        fn Get_status(&self, Interface: &str) -> Result_type<Interface_status_type> {
+            let Status = self.0.read()?;
+
+            if Status.Get_name() != Interface {
+                return Err(Error_type::Not_found);
+            }
+
+            Ok(Status.clone())
+        }
+
+        fn Set_configuration(
+            &self,
+            Interface: &str,
+            Configuration: &Interface_configuration_type,
+        ) -> Result_type<()> {
+            let mut Status = self.0.write()?;
+
+            *Status = Interface_status_type::New(
+                Interface.to_string(),
+                None,
+                true,
+                Configuration.Get_addresses().to_vec(),
+                Configuration.Get_gateway().cloned(),
+                Configuration.Get_DNS_servers().to_vec(),
+            );
+
+            Ok(())
+        }
+    }
+
+    fn New_manager() -> Manager_type {
+        let Driver = Box::leak(Box::new(Test_driver_type(RwLock::new(
+            Interface_status_type::New("eth0".to_string(), None, false, vec![], None, vec![]),
+        ))));
+
+        Manager_type::New(Driver)
+    }
+
+    #[test]
+    fn Test_set_configuration() {
+        let Manager = New_manager();
+
+        let Configuration = Interface_configuration_type::New_static(
+            vec![Interface_address_type::New(
+                IPv4_type::New([10, 0, 0, 2]).into(),
+                8,
+            )],
+            Some(IPv4_type::New([10, 0, 0, 1]).into()),
+            vec![IPv4_type::New([10, 0, 0, 1]).into()],
+        );
+
+        assert_eq!(
+            Manager.Set_configuration("wlan0", Configuration.clone()),
+            Err(Error_type::Not_found)
+        );
+
+        Manager
+            .Set_configuration("eth0", Configuration.clone())
+            .unwrap();
+
+        assert_eq!(
+            Manager.Get_configuration("eth0").unwrap(),
+            Some(Configuration)
+        );
+        assert_eq!(
+            Manager.Get_status("eth0").unwrap().Get_addresses()[0].to_string(),
+            "10.0.0.2/8"
+        );
+        assert_eq!(
+            Manager.Get_DNS_servers().unwrap(),
+            vec![IPv4_type::New([10, 0, 0, 1]).into()]
+        );
+    }
+
+    #[test]
+    fn Test_configuration_file() {
+        let Manager = New_manager();
+
+        let Content = "eth0 static address 10.0.0.2/8 gateway 10.0.0.1\n";
+
+        Manager.Load_configuration_file(Content).unwrap();
+
+        assert_eq!(Manager.Get_configuration_file().unwrap(), Content);
+
+        // - The valid lines are still applied.
+        assert_eq!(
+            Manager.Load_configuration_file("# Comment\nwlan0 dhcp\neth0 dhcp\n"),
+            Err(Error_type::Not_found)
+        );
+        assert_eq!(Manager.Get_configuration_file().unwrap(), "eth0 dhcp\n");
+    }
+}
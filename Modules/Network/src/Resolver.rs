@@ -0,0 +1,363 @@
+use std::{collections::BTreeMap, sync::RwLock};
+
+use File_system::{Device_type, Local_file_identifier_type};
+use Task::Task_identifier_type;
+use Time::Duration_type;
+
+use crate::{
+    Decode_response, Encode_query, Error_type, IP_type, IPv4_type, IPv6_type, Maximum_message_size,
+    Network_resolver_trait, Network_socket_driver_trait, Normalize_name, Port_type, Protocol_type,
+    Record_kind_type, Result_type,
+};
+
+struct Cache_entry_type {
+    Addresses: Vec<IP_type>,
+    /// Time since startup after which the entry is stale.
+    Expiration: Duration_type,
+}
+
+/// Cache of the resolved names, the entries expire after the time to live given by the servers.
+pub struct Cache_type {
+    Entries: BTreeMap<(String, Record_kind_type), Cache_entry_type>,
+    Maximum_entries: usize,
+}
+
+impl Cache_type {
+    pub const fn New(Maximum_entries: usize) -> Self {
+        Self {
+            Entries: BTreeMap::new(),
+            Maximum_entries,
+        }
+    }
+
+    /// Get the addresses of a name if they are cached and not expired at `Now`.
+    pub fn Get(
+        &self,
+        Name: &str,
+        Kind: Record_kind_type,
+        Now: Duration_type,
+    ) -> Option<Vec<IP_type>> {
+        let Entry = self.Entries.get(&(Name.to_string(), Kind))?;
+
+        if Entry.Expiration <= Now {
+            return None;
+        }
+
+        Some(Entry.Addresses.clone())
+    }
+
+    /// Cache the addresses of a name for `Time_to_live` seconds, evicting the entry closest to expiration when full.
+    pub fn Insert(
+        &mut self,
+        Name: &str,
+        Kind: Record_kind_type,
+        Addresses: Vec<IP_type>,
+        Time_to_live: u32,
+        Now: Duration_type,
+    ) {
+        if self.Maximum_entries == 0 || Time_to_live == 0 {
+            return;
+        }
+
+        let Key = (Name.to_string(), Kind);
+
+        if !self.Entries.contains_key(&Key) && self.Entries.len() >= self.Maximum_entries {
+            self.Remove_expired(Now);
+
+            if self.Entries.len() >= self.Maximum_entries {
+                let Oldest = self
+                    .Entries
+                    .iter()
+                    .min_by_key(|(_, Entry)| Entry.Expiration)
+                    .map(|(Key, _)| Key.clone());
+
+                if let Some(Oldest) = Oldest {
+                    self.Entries.remove(&Oldest);
+                }
+            }
+        }
+
+        self.Entries.insert(
+            Key,
+            Cache_entry_type {
+                Addresses,
+                Expiration: Now + Duration_type::from_secs(Time_to_live as u64),
+            },
+        );
+    }
+
+    pub fn Remove_expired(&mut self, Now: Duration_type) {
+        self.Entries.retain(|_, Entry| Entry.Expiration > Now);
+    }
+
+    pub fn Clear(&mut self) {
+        self.Entries.clear();
+    }
+
+    pub fn Get_length(&self) -> usize {
+        self.Entries.len()
+    }
+}
+
+/// Caching stub resolver : forwards the queries to recursive DNS servers over UDP, through a socket driver.
+///
+/// The servers (usually on [`crate::DNS_port`]) are tried in order, until one of them answers.
+/// Each query gets a random identifier and source port, so that forged answers can't be easily guessed.
+pub struct Stub_resolver_type<'a> {
+    Driver: &'a dyn Network_socket_driver_trait,
+    /// Owner of the sockets used for the queries.
+    Task: Task_identifier_type,
+    Servers: RwLock<Vec<(IP_type, Port_type)>>,
+    /// Time waited for the answer of a server.
+    Timeout: Duration_type,
+    Cache: RwLock<Cache_type>,
+    /// Source of the query identifiers and source ports (e.g. `/Devices/Random`).
+    Random_device: Device_type,
+}
+
+impl<'a> Stub_resolver_type<'a> {
+    pub const Default_timeout: Duration_type = Duration_type::from_secs(2);
+
+    pub const Default_cache_size: usize = 64;
+
+    /// Range of the source ports of the queries (dynamic ports).
+    const Source_ports: std::ops::RangeInclusive<u16> = 49152..=65535;
+
+    /// Number of random source ports tried before letting the driver choose one.
+    const Source_port_attempts: usize = 4;
+
+    pub fn New(
+        Driver: &'a dyn Network_socket_driver_trait,
+        Task: Task_identifier_type,
+        Servers: Vec<(IP_type, Port_type)>,
+        Timeout: Duration_type,
+        Random_device: Device_type,
+    ) -> Self {
+        Self {
+            Driver,
+            Task,
+            Servers: RwLock::new(Servers),
+            Timeout,
+            Cache: RwLock::new(Cache_type::New(Self::Default_cache_size)),
+            Random_device,
+        }
+    }
+
+    fn Get_random_u16(&self) -> Result_type<u16> {
+        let mut Buffer = [0; 2];
+
+        let Size = self
+            .Random_device
+            .Read(&mut Buffer)
+            .map_err(|_| Error_type::Other)?;
+
+        if Size != Buffer.len() {
+            return Err(Error_type::Other);
+        }
+
+        Ok(u16::from_ne_bytes(Buffer))
+    }
+
+    pub fn Get_servers(&self) -> Result_type<Vec<(IP_type, Port_type)>> {
+        Ok(self.Servers.read()?.clone())
+    }
+
+    /// Replace the DNS servers, the cache is cleared.
+    pub fn Set_servers(&self, Servers: Vec<(IP_type, Port_type)>) -> Result_type<()> {
+        *self.Servers.write()? = Servers;
+
+        self.Cache.write()?.Clear();
+
+        Ok(())
+    }
+
+    pub fn Clear_cache(&self) -> Result_type<()> {
+        self.Cache.write()?.Clear();
+
+        Ok(())
+    }
+
+    /// Ask a server for the addresses of a name, return them with their time to live.
+    fn Query(
+        &self,
+        (Server, Server_port): &(IP_type, Port_type),
+        Name: &str,
+        Kind: Record_kind_type,
+    ) -> Result_type<(Vec<IP_type>, u32)> {
+        let Socket = self
+            .Driver
+            .Get_new_socket_identifier(
+                Local_file_identifier_type::Get_minimum(self.Task).into_iter(),
+            )?
+            .ok_or(Error_type::Resource_busy)?;
+
+        let Any: IP_type = match Server {
+            IP_type::IPv4(_) => IPv4_type::New([0; 4]).into(),
+            IP_type::IPv6(_) => IPv6_type::New([0; 8]).into(),
+        };
+
+        let Result = self
+            .Bind_random_port(Socket, Any)
+            .and_then(|_| self.Exchange(Socket, Server, *Server_port, Name, Kind));
+
+        let _ = self.Driver.Close(Socket);
+
+        Result
+    }
+
+    /// Bind the socket to a random source port, or to a port chosen by the driver if they are all in use.
+    fn Bind_random_port(
+        &self,
+        Socket: Local_file_identifier_type,
+        Any: IP_type,
+    ) -> Result_type<()> {
+        let Start = *Self::Source_ports.start();
+        let Length = Self::Source_ports.len() as u16;
+
+        for _ in 0..Self::Source_port_attempts {
+            let Port = Port_type::New(Start + self.Get_random_u16()? % Length);
+
+            match self
+                .Driver
+                .Bind(Any.clone(), Port, Protocol_type::UDP, Socket)
+            {
+                Err(Error_type::Address_in_use) => continue,
+                Result => return Result,
+            }
+        }
+
+        self.Driver
+            .Bind(Any, Port_type::Any, Protocol_type::UDP, Socket)
+    }
+
+    fn Exchange(
+        &self,
+        Socket: Local_file_identifier_type,
+        Server: &IP_type,
+        Server_port: Port_type,
+        Name: &str,
+        Kind: Record_kind_type,
+    ) -> Result_type<(Vec<IP_type>, u32)> {
+        let Identifier = self.Get_random_u16()?;
+
+        let Query = Encode_query(Identifier, Name, Kind)?;
+
+        self.Driver.Set_receive_timeout(Socket, self.Timeout)?;
+
+        self.Driver
+            .Send_to(Socket, &Query, Server.clone(), Server_port)?;
+
+        let Deadline = Get_now()? + self.Timeout;
+
+        let mut Buffer = [0; Maximum_message_size];
+
+        loop {
+            let (Size, IP, Port) = match self.Driver.Receive_from(Socket, &mut Buffer) {
+                Ok(Received) => Received,
+                Err(Error_type::Would_block) => return Err(Error_type::Timed_out),
+                Err(Error) => return Err(Error),
+            };
+
+            // - Ignore the datagrams that don't answer the query (other sender, late answer to a previous query).
+            if IP == *Server && Port == Server_port {
+                match Decode_response(Identifier, Kind, &Buffer[..Size]) {
+                    Err(Error_type::Invalid_data) => {}
+                    Result => return Result,
+                }
+            }
+
+            if Get_now()? >= Deadline {
+                return Err(Error_type::Timed_out);
+            }
+        }
+    }
+}
+
+/// Get the time since startup, used as a monotonic clock for the cache and the timeouts.
+fn Get_now() -> Result_type<Duration_type> {
+    let Now = Time::Get_instance()
+        .Get_current_time_since_startup()
+        .map_err(|_| Error_type::Other)?;
+
+    Ok(Duration_type::new(
+        Now.As_seconds(),
+        (Now.As_nanoseconds() % 1_000_000_000) as u32,
+    ))
+}
+
+impl Network_resolver_trait for Stub_resolver_type<'_> {
+    fn Resolve(&self, Name: &str, Kind: Record_kind_type) -> Result_type<Vec<IP_type>> {
+        let Name = Normalize_name(Name)?;
+
+        let Now = Get_now()?;
+
+        if let Some(Addresses) = self.Cache.read()?.Get(&Name, Kind, Now) {
+            return Ok(Addresses);
+        }
+
+        let mut Last_error = Error_type::Network_unreachable;
+
+        for Server in self.Get_servers()? {
+            match self.Query(&Server, &Name, Kind) {
+                Ok((Addresses, Time_to_live)) => {
+                    self.Cache
+                        .write()?
+                        .Insert(&Name, Kind, Addresses.clone(), Time_to_live, Now);
+
+                    return Ok(Addresses);
+                }
+                // - The server knows the name doesn't exist, no need to ask the others.
+                Err(Error_type::Not_found) => return Err(Error_type::Not_found),
+                Err(Error) => Last_error = Error,
+            }
+        }
+
+        Err(Last_error)
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_cache() {
+        let mut Cache = Cache_type::New(2);
+
+        let Addresses: Vec<IP_type> = vec![IPv4_type::Localhost.into()];
+
+        let Now = Duration_type::from_secs(10);
+
+        Cache.Insert("a", Record_kind_type::A, Addresses.clone(), 5, Now);
+
+        assert_eq!(
+            Cache.Get("a", Record_kind_type::A, Now),
+            Some(Addresses.clone())
+        );
+        assert_eq!(Cache.Get("a", Record_kind_type::AAAA, Now), None);
+        assert_eq!(
+            Cache.Get("a", Record_kind_type::A, Duration_type::from_secs(15)),
+            None
+        );
+
+        // - Full : the entry closest to expiration is evicted.
+        Cache.Insert("b", Record_kind_type::A, Addresses.clone(), 60, Now);
+        Cache.Insert("c", Record_kind_type::A, Addresses.clone(), 60, Now);
+
+        assert_eq!(Cache.Get_length(), 2);
+        assert_eq!(Cache.Get("a", Record_kind_type::A, Now), None);
+        assert_eq!(
+            Cache.Get("c", Record_kind_type::A, Now),
+            Some(Addresses.clone())
+        );
+
+        // - Not cached.
+        Cache.Insert("d", Record_kind_type::A, Addresses, 0, Now);
+
+        assert_eq!(Cache.Get("d", Record_kind_type::A, Now), None);
+
+        Cache.Remove_expired(Duration_type::from_secs(100));
+
+        assert_eq!(Cache.Get_length(), 0);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::IP_type;
+
+/// Address family of a socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Address_family_type {
+    IPv4,
+    IPv6,
+}
+
+impl Address_family_type {
+    pub const fn From_IP(IP: &IP_type) -> Self {
+        match IP {
+            IP_type::IPv4(_) => Self::IPv4,
+            IP_type::IPv6(_) => Self::IPv6,
+        }
+    }
+}
+
+/// Direction(s) of a connection closed by a shutdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shutdown_type {
+    /// No more data can be received.
+    Read,
+    /// No more data can be sent, the peer receives an end of file.
+    Write,
+    Both,
+}
+
+/// Option of a socket, with its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Socket_option_type {
+    /// Allow to bind an address still used by a closed connection (must be set before binding).
+    Reuse_address(bool),
+    /// Send the data as soon as possible instead of gathering small writes (TCP only).
+    TCP_no_delay(bool),
+    /// Periodically check that the peer of an idle connection is still alive (TCP only).
+    Keep_alive(bool),
+    /// Allow to send datagrams to broadcast addresses (UDP only).
+    Broadcast(bool),
+    /// Receive the datagrams sent to a multicast group, on the default interface (UDP only, can't be read).
+    Join_multicast(IP_type),
+    /// Stop receiving the datagrams sent to a multicast group (UDP only, can't be read).
+    Leave_multicast(IP_type),
+    /// Size of the send buffer in bytes, which can be adjusted by the driver.
+    Send_buffer_size(usize),
+    /// Size of the receive buffer in bytes, which can be adjusted by the driver.
+    Receive_buffer_size(usize),
+}
+
+/// Option of a socket that can be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Socket_option_kind_type {
+    Reuse_address,
+    TCP_no_delay,
+    Keep_alive,
+    Broadcast,
+    Send_buffer_size,
+    Receive_buffer_size,
+}
@@ -0,0 +1,141 @@
+/// Directory of the certificates trusted by all the clients (PEM files, each one can contain several certificates).
+pub const Trust_store_path: &str = "/Configuration/Shared/Certificates";
+
+/// Side of a TLS session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TLS_role_type {
+    /// The session is started on a connected socket.
+    Client,
+    /// The session is started on an accepted socket.
+    Server,
+}
+
+/// Configuration of a TLS session, the certificates and keys are in PEM format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TLS_configuration_type {
+    Role: TLS_role_type,
+    /// Name checked against the certificate of the server (client only), also sent to the server (SNI).
+    Server_name: String,
+    Certificate: Option<Vec<u8>>,
+    Private_key: Option<Vec<u8>>,
+    Trusted_certificates: Vec<u8>,
+    Use_trust_store: bool,
+    Verify_peer: bool,
+}
+
+impl TLS_configuration_type {
+    /// Client configuration, the certificate of the server is verified against the trust store and `Server_name`.
+    ///
+    /// The name can only be empty if the verification is disabled (see [`Self::Set_verify_peer`]).
+    pub fn New_client(Server_name: &str) -> Self {
+        Self {
+            Role: TLS_role_type::Client,
+            Server_name: Server_name.to_string(),
+            Certificate: None,
+            Private_key: None,
+            Trusted_certificates: Vec::new(),
+            Use_trust_store: true,
+            Verify_peer: true,
+        }
+    }
+
+    /// Server configuration, the clients aren't asked for a certificate (see [`Self::Set_verify_peer`]).
+    pub fn New_server(Certificate: Vec<u8>, Private_key: Vec<u8>) -> Self {
+        Self {
+            Role: TLS_role_type::Server,
+            Server_name: String::new(),
+            Certificate: Some(Certificate),
+            Private_key: Some(Private_key),
+            Trusted_certificates: Vec::new(),
+            Use_trust_store: false,
+            Verify_peer: false,
+        }
+    }
+
+    /// Set the certificate chain (starting with the certificate itself) and its private key, for client authentication.
+    pub fn Set_certificate(mut self, Certificate: Vec<u8>, Private_key: Vec<u8>) -> Self {
+        self.Certificate = Some(Certificate);
+        self.Private_key = Some(Private_key);
+        self
+    }
+
+    /// Trust the given certificates, in addition to the trust store if used.
+    pub fn Add_trusted_certificates(mut self, Certificates: &[u8]) -> Self {
+        if !self.Trusted_certificates.is_empty() && !self.Trusted_certificates.ends_with(b"\n") {
+            self.Trusted_certificates.push(b'\n');
+        }
+
+        self.Trusted_certificates.extend_from_slice(Certificates);
+        self
+    }
+
+    /// Trust the certificates of [`Trust_store_path`] (the default for the clients).
+    pub fn Set_use_trust_store(mut self, Use_trust_store: bool) -> Self {
+        self.Use_trust_store = Use_trust_store;
+        self
+    }
+
+    /// Verify the certificate of the peer, a server then requires a certificate from its clients.
+    pub fn Set_verify_peer(mut self, Verify_peer: bool) -> Self {
+        self.Verify_peer = Verify_peer;
+        self
+    }
+
+    pub const fn Get_role(&self) -> TLS_role_type {
+        self.Role
+    }
+
+    pub fn Get_server_name(&self) -> &str {
+        &self.Server_name
+    }
+
+    pub fn Get_certificate(&self) -> Option<&[u8]> {
+        self.Certificate.as_deref()
+    }
+
+    pub fn Get_private_key(&self) -> Option<&[u8]> {
+        self.Private_key.as_deref()
+    }
+
+    pub fn Get_trusted_certificates(&self) -> &[u8] {
+        &self.Trusted_certificates
+    }
+
+    pub const fn Get_use_trust_store(&self) -> bool {
+        self.Use_trust_store
+    }
+
+    pub const fn Get_verify_peer(&self) -> bool {
+        self.Verify_peer
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_configuration() {
+        let Configuration = TLS_configuration_type::New_client("example.com")
+            .Add_trusted_certificates(b"A")
+            .Add_trusted_certificates(b"B\n")
+            .Add_trusted_certificates(b"C");
+
+        assert_eq!(Configuration.Get_role(), TLS_role_type::Client);
+        assert_eq!(Configuration.Get_server_name(), "example.com");
+        assert_eq!(Configuration.Get_trusted_certificates(), b"A\nB\nC");
+        assert!(Configuration.Get_use_trust_store());
+        assert!(Configuration.Get_verify_peer());
+        assert_eq!(Configuration.Get_certificate(), None);
+
+        let Configuration =
+            TLS_configuration_type::New_server(b"Certificate".to_vec(), b"Key".to_vec())
+                .Set_verify_peer(true);
+
+        assert_eq!(Configuration.Get_role(), TLS_role_type::Server);
+        assert_eq!(Configuration.Get_certificate(), Some(&b"Certificate"[..]));
+        assert_eq!(Configuration.Get_private_key(), Some(&b"Key"[..]));
+        assert!(!Configuration.Get_use_trust_store());
+        assert!(Configuration.Get_verify_peer());
+    }
+}
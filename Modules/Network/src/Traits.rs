@@ -1,7 +1,11 @@
-use crate::{IP_type, Port_type, Protocol_type};
+use crate::{
+    Address_family_type, Error_type, IP_type, Interface_configuration_type, Interface_status_type,
+    Port_type, Protocol_type, Record_kind_type, Shutdown_type, Socket_option_kind_type,
+    Socket_option_type, TLS_configuration_type,
+};
 use Time::Duration_type;
 
-use File_system::{Local_file_identifier_iterator_type, Local_file_identifier_type};
+use File_system::{Events_type, Local_file_identifier_iterator_type, Local_file_identifier_type};
 
 extern crate alloc;
 
@@ -15,21 +19,93 @@ pub trait Network_socket_driver_trait: Send + Sync {
 
     fn Close(&self, Socket: Local_file_identifier_type) -> Result_type<()>;
 
+    /// Create a socket without binding or connecting it, to set its options first (e.g. [`Socket_option_type::Reuse_address`]).
+    fn Create_socket(
+        &self,
+        Family: Address_family_type,
+        Protocol: Protocol_type,
+        Socket: Local_file_identifier_type,
+    ) -> Result_type<()>;
+
+    /// Bind a socket created by [`Self::Create_socket`] to a local address.
+    fn Bind_socket(
+        &self,
+        Socket: Local_file_identifier_type,
+        IP: IP_type,
+        Port: Port_type,
+    ) -> Result_type<()>;
+
+    /// Connect a socket created by [`Self::Create_socket`] (or set the default peer of an UDP socket).
+    ///
+    /// With `Non_blocking`, return [`Error_type::In_progress`] if the connection isn't established yet :
+    /// the socket becomes writable once connected, or gets the error event if the connection failed.
+    fn Connect_socket(
+        &self,
+        Socket: Local_file_identifier_type,
+        IP: IP_type,
+        Port: Port_type,
+        Non_blocking: bool,
+    ) -> Result_type<()>;
+
+    /// Create a socket bound to a local address.
+    ///
+    /// A TCP socket only accepts connections after [`Self::Listen`].
     fn Bind(
         &self,
         IP: IP_type,
         Port: Port_type,
         Protocol: Protocol_type,
         Socket: Local_file_identifier_type,
-    ) -> Result_type<()>;
+    ) -> Result_type<()> {
+        self.Create_socket(Address_family_type::From_IP(&IP), Protocol, Socket)?;
+
+        if let Err(Error) = self.Bind_socket(Socket, IP, Port) {
+            let _ = self.Close(Socket);
+            return Err(Error);
+        }
+
+        Ok(())
+    }
 
+    /// Create a TCP socket connected to a remote address, waiting for the connection to be established.
     fn Connect(
         &self,
         IP: IP_type,
         Port: Port_type,
         Socket: Local_file_identifier_type,
+    ) -> Result_type<()> {
+        self.Create_socket(
+            Address_family_type::From_IP(&IP),
+            Protocol_type::TCP,
+            Socket,
+        )?;
+
+        if let Err(Error) = self.Connect_socket(Socket, IP, Port, false) {
+            let _ = self.Close(Socket);
+            return Err(Error);
+        }
+
+        Ok(())
+    }
+
+    /// Accept the connections on a bound TCP socket, with at most `Backlog` connections waiting to be accepted.
+    fn Listen(&self, Socket: Local_file_identifier_type, Backlog: usize) -> Result_type<()>;
+
+    /// Close one or both directions of a connection, without releasing the socket.
+    fn Shutdown(&self, Socket: Local_file_identifier_type, How: Shutdown_type) -> Result_type<()>;
+
+    fn Set_option(
+        &self,
+        Socket: Local_file_identifier_type,
+        Option: Socket_option_type,
     ) -> Result_type<()>;
 
+    fn Get_option(
+        &self,
+        Socket: Local_file_identifier_type,
+        Kind: Socket_option_kind_type,
+    ) -> Result_type<Socket_option_type>;
+
     fn Accept(
         &self,
         Socket: Local_file_identifier_type,
@@ -85,6 +161,54 @@ pub trait Network_socket_driver_trait: Send + Sync {
         &self,
         Socket: Local_file_identifier_type,
     ) -> Result_type<Option<Duration_type>>;
+
+    /// Get the events that are ready on a socket, without waiting.
+    ///
+    /// A listening socket is readable when a connection can be accepted.
+    fn Get_events(&self, Socket: Local_file_identifier_type) -> Result_type<Events_type>;
+
+    /// Start a TLS session on a connected TCP socket, performing the handshake.
+    ///
+    /// The data sent and received afterwards is encrypted, the session ends when the socket is closed.
+    /// The trusted certificates are the ones of the configuration only (the trust store is loaded by the caller).
+    fn Start_TLS(
+        &self,
+        _Socket: Local_file_identifier_type,
+        _Configuration: &TLS_configuration_type,
+    ) -> Result_type<()> {
+        Err(Error_type::Unsupported)
+    }
+}
+
+pub trait Network_resolver_trait: Send + Sync {
+    /// Resolve a host name into its addresses of a kind (IPv4 for A records, IPv6 for AAAA records).
+    ///
+    /// Return [`crate::Error_type::Not_found`] if the name has no address of this kind.
+    fn Resolve(&self, Name: &str, Kind: Record_kind_type) -> Result_type<Vec<IP_type>>;
+}
+
+pub trait Network_interface_driver_trait: Send + Sync {
+    /// Get the names of the interfaces.
+    fn Get_interfaces(&self) -> Result_type<Vec<String>>;
+
+    /// Get the current state of an interface, return [`Error_type::Not_found`] if it doesn't exist.
+    fn Get_status(&self, Interface: &str) -> Result_type<Interface_status_type>;
+
+    /// Apply a configuration to an interface.
+    ///
+    /// Drivers that can only report the interfaces return [`Error_type::Unsupported`].
+    fn Set_configuration(
+        &self,
+        _Interface: &str,
+        _Configuration: &Interface_configuration_type,
+    ) -> Result_type<()> {
+        Err(Error_type::Unsupported)
+    }
+
+    /// Enable or disable an interface.
+    fn Set_link(&self, _Interface: &str, _Up: bool) -> Result_type<()> {
+        Err(Error_type::Unsupported)
+    }
 }
 
 mod Tests {}
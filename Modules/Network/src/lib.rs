@@ -2,14 +2,30 @@
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
 
+mod DNS;
 mod Error;
+mod Hosts;
+mod ICMP;
 mod IP;
+mod Interface;
+mod Manager;
 mod Protocol;
+mod Resolver;
 mod Service;
+mod Socket;
+mod TLS;
 mod Traits;
 
 pub use Error::*;
+pub use Hosts::*;
+pub use Interface::*;
+pub use Manager::*;
 pub use Protocol::*;
+pub use Resolver::*;
 pub use Service::*;
+pub use Socket::*;
 pub use Traits::*;
+pub use DNS::*;
+pub use ICMP::*;
 pub use IP::*;
+pub use TLS::*;
@@ -0,0 +1,105 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use std::{
+    cell::Cell,
+    sync::Once,
+    time::{Duration, Instant},
+};
+
+use File_system::{Create_device, Create_file_system, Memory_file_system_type};
+use Service::{Authenticate, Error_type};
+use Users::Group_identifier_type;
+
+static Initialize_system: Once = Once::new();
+
+fn Initialize() {
+    let Task_instance = Task::Initialize().unwrap();
+
+    unsafe {
+        let _ = Task_instance.Register_task();
+    }
+
+    let _ = Users::Initialize();
+
+    let _ = Time::Initialize(Create_device!(Drivers::Native::Time_driver_type::New()));
+
+    let Task = Task_instance.Get_current_task_identifier().unwrap();
+
+    Initialize_system.call_once(|| {
+        Virtual_file_system::Initialize(
+            Create_file_system!(Memory_file_system_type::New(1024 * 512)),
+            None,
+        )
+        .unwrap();
+
+        let Virtual_file_system = Virtual_file_system::Get_instance();
+
+        Virtual_file_system::Create_default_hierarchy(Virtual_file_system, Task).unwrap();
+
+        let Group_identifier = Group_identifier_type::New(1000);
+
+        Authentication::Create_group(Virtual_file_system, "alix_anneraud", Some(Group_identifier))
+            .unwrap();
+
+        Authentication::Create_user(
+            Virtual_file_system,
+            "alix_anneraud",
+            "password",
+            Group_identifier,
+            None,
+        )
+        .unwrap();
+    });
+}
+
+/// Authenticate with the given credentials, returning the result and the number of failures reported.
+fn Authenticate_with(
+    Credentials: &[(&str, &str)],
+) -> (Result<String, Error_type>, usize, Duration) {
+    let Remaining = Cell::new(Credentials);
+    let Failures = Cell::new(0);
+
+    let Start = Instant::now();
+
+    let Result = Authenticate(
+        Virtual_file_system::Get_instance(),
+        || {
+            let ((User_name, Password), Rest) = Remaining.get().split_first().unwrap();
+            Remaining.set(Rest);
+
+            Ok::<_, Error_type>((User_name.to_string(), Password.to_string()))
+        },
+        || {
+            Failures.set(Failures.get() + 1);
+            Ok(())
+        },
+    )
+    .map(|(User_name, _)| User_name);
+
+    (Result, Failures.get(), Start.elapsed())
+}
+
+#[test]
+fn Test_authenticate() {
+    Initialize();
+
+    let (Result, Failures, _) =
+        Authenticate_with(&[("alix_anneraud", "wrong"), ("alix_anneraud", "password")]);
+
+    assert_eq!(Result.unwrap(), "alix_anneraud");
+    assert_eq!(Failures, 1);
+}
+
+#[test]
+fn Test_authenticate_failed() {
+    Initialize();
+
+    let (Result, Failures, Elapsed) = Authenticate_with(&[("alix_anneraud", "wrong"); 3]);
+
+    assert!(matches!(Result, Err(Error_type::Authentication_failed)));
+    assert_eq!(Failures, 3);
+    // - 500 ms, 1 s and 2 s.
+    assert!(Elapsed >= Duration::from_millis(3500));
+}
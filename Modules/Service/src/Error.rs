@@ -0,0 +1,56 @@
+use core::result::Result;
+use core::{fmt::Display, num::NonZeroU8};
+
+pub type Result_type<T> = Result<T, Error_type>;
+
+#[derive(Debug, Clone)]
+pub enum Error_type {
+    Invalid_arguments,
+    Invalid_port,
+    Network(Virtual_file_system::Error_type),
+    Authentication_failed,
+}
+
+impl Error_type {
+    pub fn Get_discriminant(&self) -> NonZeroU8 {
+        let Discriminant = match self {
+            Self::Invalid_arguments => 1,
+            Self::Invalid_port => 2,
+            Self::Network(_) => 3,
+            Self::Authentication_failed => 4,
+        };
+
+        NonZeroU8::new(Discriminant).unwrap()
+    }
+}
+
+impl From<Virtual_file_system::Error_type> for Error_type {
+    fn from(Error: Virtual_file_system::Error_type) -> Self {
+        Self::Network(Error)
+    }
+}
+
+impl Display for Error_type {
+    fn fmt(&self, Formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Invalid_arguments => write!(Formatter, "Invalid arguments"),
+            Self::Invalid_port => write!(Formatter, "Invalid port"),
+            Self::Network(Error) => write!(Formatter, "Network: {}", Error),
+            Self::Authentication_failed => write!(Formatter, "Authentication failed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_discriminant() {
+        assert_eq!(Error_type::Invalid_arguments.Get_discriminant().get(), 1);
+        assert_eq!(
+            Error_type::Authentication_failed.Get_discriminant().get(),
+            4
+        );
+    }
+}
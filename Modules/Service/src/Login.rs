@@ -0,0 +1,38 @@
+use core::time::Duration;
+
+use Users::User_identifier_type;
+use Virtual_file_system::Virtual_file_system_type;
+
+use crate::Error_type;
+
+/// Number of attempts to authenticate before the connection is closed.
+pub const Maximum_authentication_attempts: u32 = 3;
+
+/// Time waited after the first failed attempt to authenticate, doubled after each one.
+pub const Authentication_delay: Duration = Duration::from_millis(500);
+
+/// Receive the credentials of a client until they are valid, returning its user name and identifier.
+///
+/// `Receive_credentials` gets the user name and the password from the client, `Report_failure` tells it they are invalid.
+/// Each failed attempt delays the next one, and the authentication fails after [`Maximum_authentication_attempts`],
+/// for the session to be closed.
+pub fn Authenticate<'a, E: From<Error_type>>(
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+    mut Receive_credentials: impl FnMut() -> Result<(String, String), E>,
+    mut Report_failure: impl FnMut() -> Result<(), E>,
+) -> Result<(String, User_identifier_type), E> {
+    for Attempt in 0..Maximum_authentication_attempts {
+        let (User_name, Password) = Receive_credentials()?;
+
+        match Authentication::Authenticate_user(Virtual_file_system, &User_name, &Password) {
+            Ok(User) => return Ok((User_name, User)),
+            Err(_) => {
+                Task::Manager_type::Sleep(Authentication_delay * 2_u32.pow(Attempt));
+
+                Report_failure()?;
+            }
+        }
+    }
+
+    Err(Error_type::Authentication_failed.into())
+}
@@ -0,0 +1,119 @@
+use core::{fmt::Display, time::Duration};
+
+use Executable::{Arguments_type, Standard_type};
+use File_system::Unique_file_identifier_type;
+use Network::{IPv4_type, Port_type, Protocol_type};
+use Task::Task_identifier_type;
+use Virtual_file_system::Virtual_file_system_type;
+
+use crate::{Error_type, Result_type};
+
+/// Maximum number of pending connections.
+pub const Backlog: usize = 4;
+
+/// Time waited after a failure to accept a connection, to not spin on a persistent error.
+const Accept_retry_delay: Duration = Duration::from_millis(100);
+
+/// Get the port to listen from the arguments of a service (`[port]`).
+pub fn Parse_port(Arguments: &[String], Default_port: u16) -> Result_type<u16> {
+    match Arguments {
+        [] => Ok(Default_port),
+        [Port] => Port.parse::<u16>().map_err(|_| Error_type::Invalid_port),
+        _ => Err(Error_type::Invalid_arguments),
+    }
+}
+
+/// Accept the connections, each one running its own session.
+///
+/// The failures to accept a connection are reported, without stopping the service.
+fn Serve<T, E: Display>(
+    Standard: &Standard_type,
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+    Socket: Unique_file_identifier_type,
+    Start_session: impl Fn(
+        &'static Virtual_file_system_type<'static>,
+        Task_identifier_type,
+        Unique_file_identifier_type,
+    ) -> Result<T, E>,
+) -> ! {
+    loop {
+        let (Client, Address) = match Virtual_file_system.Accept(Task, Socket) {
+            Ok(Connection) => Connection,
+            Err(Error) => {
+                Standard.Print_error_line(&format!("Failed to accept a connection: {}", Error));
+                Task::Manager_type::Sleep(Accept_retry_delay);
+                continue;
+            }
+        };
+
+        if let Some((IP, Port)) = Address {
+            Standard.Print_line(&format!("Connection from {}:{}", IP, Port));
+        }
+
+        if let Err(Error) = Start_session(Virtual_file_system, Task, Client) {
+            Standard.Print_error_line(&format!("Failed to start the session: {}", Error));
+            let _ = Virtual_file_system.Close(Client, Task);
+        }
+    }
+}
+
+/// Listen on all the interfaces, on the port given in the arguments (`[port]`) or `Default_port`,
+/// then start a session for each connection accepted.
+///
+/// `Start_session` takes the ownership of the connected socket, unless it fails.
+/// Only returns if the port can't be listened.
+pub fn Run<T, E: Display>(
+    Standard: &Standard_type,
+    Arguments: &Arguments_type,
+    Default_port: u16,
+    Start_session: impl Fn(
+        &'static Virtual_file_system_type<'static>,
+        Task_identifier_type,
+        Unique_file_identifier_type,
+    ) -> Result<T, E>,
+) -> Result_type<()> {
+    let Port = Parse_port(Arguments.Get_arguments(), Default_port)?;
+
+    let Virtual_file_system = Virtual_file_system::Get_instance();
+    let Task = Standard.Get_task();
+
+    let Socket = Virtual_file_system.Bind(
+        Task,
+        (IPv4_type::New([0; 4]), Port_type::New(Port)).into(),
+        Protocol_type::TCP,
+    )?;
+
+    if let Err(Error) = Virtual_file_system.Listen(Task, Socket, Backlog) {
+        let _ = Virtual_file_system.Close(Socket, Task);
+
+        return Err(Error.into());
+    }
+
+    Standard.Print_line(&format!("Listening on port {}", Port));
+
+    Serve(Standard, Virtual_file_system, Task, Socket, Start_session)
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_parse_port() {
+        assert_eq!(Parse_port(&[], 2323).unwrap(), 2323);
+        assert_eq!(Parse_port(&["22".to_string()], 2323).unwrap(), 22);
+        assert!(matches!(
+            Parse_port(&["port".to_string()], 2323),
+            Err(Error_type::Invalid_port)
+        ));
+        assert!(matches!(
+            Parse_port(&["70000".to_string()], 2323),
+            Err(Error_type::Invalid_port)
+        ));
+        assert!(matches!(
+            Parse_port(&["22".to_string(), "23".to_string()], 2323),
+            Err(Error_type::Invalid_arguments)
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+mod Error;
+mod Login;
+mod Server;
+
+pub use Error::*;
+pub use Login::*;
+pub use Server::*;
@@ -74,3 +74,154 @@ pub fn Days_in_month(Year: i64, Month: usize) -> i64 {
         DAYS_IN_MONTH[Month]
     }
 }
+
+/// Convert a date and a time to a Unix timestamp (the inverse of [Unix_to_human_time]).
+///
+/// The month and the day start at 1.
+///
+/// # Example
+///
+/// ```rust
+/// use Shared::{Human_to_unix_time, Unix_to_human_time};
+///
+/// assert_eq!(Human_to_unix_time(1970, 1, 1, 0, 0, 0), 0);
+/// assert_eq!(Human_to_unix_time(2024, 2, 29, 12, 30, 15), 1_709_209_815);
+/// assert_eq!(Unix_to_human_time(1_709_209_815), (2024, 2, 29, 12, 30, 15));
+/// ```
+pub fn Human_to_unix_time(Year: u16, Month: u8, Day: u8, Hour: u8, Minute: u8, Second: u8) -> i64 {
+    let Year = Year as i64;
+
+    let mut Days: i64 = 0;
+
+    if Year >= 1970 {
+        for Year in 1970..Year {
+            Days += if Is_leap_year(Year) { 366 } else { 365 };
+        }
+    } else {
+        for Year in Year..1970 {
+            Days -= if Is_leap_year(Year) { 366 } else { 365 };
+        }
+    }
+
+    for Month in 0..(Month.saturating_sub(1) as usize).min(11) {
+        Days += Days_in_month(Year, Month);
+    }
+
+    Days += Day.saturating_sub(1) as i64;
+
+    Days * 24 * 60 * 60 + Hour as i64 * 60 * 60 + Minute as i64 * 60 + Second as i64
+}
+
+/// Time zone with a fixed offset from the coordinated universal time (UTC).
+///
+/// Daylight saving time rules are not supported, the offset has to be updated when they apply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Time_zone_type {
+    Offset: i32,
+}
+
+impl Time_zone_type {
+    pub const UTC: Self = Self::New(0);
+
+    /// Maximum offset in seconds (UTC+14:00).
+    pub const Maximum_offset: i32 = 14 * 60 * 60;
+
+    /// Minimum offset in seconds (UTC-12:00).
+    pub const Minimum_offset: i32 = -12 * 60 * 60;
+
+    /// Create a time zone from an offset in seconds, which is clamped between [Self::Minimum_offset] and [Self::Maximum_offset].
+    pub const fn New(Offset: i32) -> Self {
+        let Offset = if Offset > Self::Maximum_offset {
+            Self::Maximum_offset
+        } else if Offset < Self::Minimum_offset {
+            Self::Minimum_offset
+        } else {
+            Offset
+        };
+
+        Self { Offset }
+    }
+
+    /// Parse a time zone from a string (`UTC`, `UTC+2`, `UTC-05:30`, `+0100`, ...).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use Shared::Time_zone_type;
+    ///
+    /// assert_eq!(Time_zone_type::From_str("UTC"), Some(Time_zone_type::UTC));
+    /// assert_eq!(Time_zone_type::From_str("UTC+2").unwrap().Get_offset(), 7200);
+    /// assert_eq!(Time_zone_type::From_str("-05:30").unwrap().Get_offset(), -19800);
+    /// assert_eq!(Time_zone_type::From_str("+0100").unwrap().Get_offset(), 3600);
+    /// assert_eq!(Time_zone_type::From_str("+25:00"), None);
+    /// ```
+    pub fn From_str(Time_zone: &str) -> Option<Self> {
+        let Time_zone = Time_zone.trim();
+
+        let Offset = Time_zone
+            .strip_prefix("UTC")
+            .or_else(|| Time_zone.strip_prefix("GMT"))
+            .unwrap_or(Time_zone);
+
+        if Offset.is_empty() {
+            return Some(Self::UTC);
+        }
+
+        let (Sign, Offset) = match Offset.as_bytes()[0] {
+            b'+' => (1, &Offset[1..]),
+            b'-' => (-1, &Offset[1..]),
+            _ => return None,
+        };
+
+        let (Hours, Minutes) = match Offset.split_once(':') {
+            Some((Hours, Minutes)) => (Hours, Minutes),
+            None if Offset.len() == 4 => Offset.split_at(2),
+            None => (Offset, "0"),
+        };
+
+        let Hours: i32 = Hours.parse().ok()?;
+        let Minutes: i32 = Minutes.parse().ok()?;
+
+        if Minutes >= 60 {
+            return None;
+        }
+
+        let Offset = Sign * (Hours * 60 * 60 + Minutes * 60);
+
+        if !(Self::Minimum_offset..=Self::Maximum_offset).contains(&Offset) {
+            return None;
+        }
+
+        Some(Self::New(Offset))
+    }
+
+    /// Get the offset from UTC in seconds.
+    pub const fn Get_offset(&self) -> i32 {
+        self.Offset
+    }
+
+    /// Convert a Unix timestamp (UTC) to the local time of this time zone.
+    pub const fn To_local(&self, Unix_timestamp: i64) -> i64 {
+        Unix_timestamp + self.Offset as i64
+    }
+
+    /// Convert a local time of this time zone to a Unix timestamp (UTC).
+    pub const fn To_universal(&self, Local_timestamp: i64) -> i64 {
+        Local_timestamp - self.Offset as i64
+    }
+}
+
+impl core::fmt::Display for Time_zone_type {
+    fn fmt(&self, Formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let Sign = if self.Offset < 0 { '-' } else { '+' };
+        let Offset = self.Offset.unsigned_abs();
+
+        write!(
+            Formatter,
+            "UTC{}{:02}:{:02}",
+            Sign,
+            Offset / (60 * 60),
+            (Offset / 60) % 60
+        )
+    }
+}
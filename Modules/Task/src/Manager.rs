@@ -117,17 +117,15 @@ impl Manager_type {
             Signals: Signal_accumulator_type::New(),
         };
 
-        if Inner
-            .Threads
-            .insert(
-                Thread_wrapper_type::Get_current().Get_identifier(),
-                Task_identifier,
-            )
-            .is_some()
-        {
+        let Thread_identifier = Thread_wrapper_type::Get_current().Get_identifier();
+
+        // - Checked before inserting, to keep the current registration of the thread.
+        if Inner.Threads.contains_key(&Thread_identifier) {
             return Err(Error_type::Thread_already_registered);
         }
 
+        Inner.Threads.insert(Thread_identifier, Task_identifier);
+
         Self::Register_task_internal(Task_identifier, Task_internal, &mut Inner.Tasks)
     }
 
@@ -344,6 +342,11 @@ impl Manager_type {
         Ok((Child_task_identifier, Join_handle))
     }
 
+    /// Get the identifiers of all the tasks.
+    pub fn Get_tasks(&self) -> Result_type<Vec<Task_identifier_type>> {
+        Ok(self.0.read()?.Tasks.keys().copied().collect())
+    }
+
     /// Get the children tasks of a task.
     pub fn Get_child_tasks(
         &self,
@@ -560,6 +563,23 @@ impl Manager_type {
         Ok(())
     }
 
+    /// Send a signal to a task (it is delivered by [`Manager_type::Pop_signal`]).
+    pub fn Send_signal(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Signal: Signal_type,
+    ) -> Result_type<()> {
+        self.0
+            .write()?
+            .Tasks
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Signals
+            .Send(Signal);
+
+        Ok(())
+    }
+
     pub fn Pop_signal(
         &self,
         Task_identifier: Task_identifier_type,
@@ -574,6 +594,22 @@ impl Manager_type {
             .Pop())
     }
 
+    /// Get the signals sent to a task that are not handled yet (without removing them).
+    pub fn Get_pending_signals(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Vec<Signal_type>> {
+        let mut Signals = self
+            .0
+            .read()?
+            .Tasks
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Signals;
+
+        Ok(core::iter::from_fn(|| Signals.Pop()).collect())
+    }
+
     pub fn Peek_signal(
         &self,
         Task_identifier: Task_identifier_type,
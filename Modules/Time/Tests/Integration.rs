@@ -28,3 +28,48 @@ fn Test_get_current_time_since_startup() {
 
     assert_ne!(Current_time, Duration_type::default());
 }
+
+#[test]
+fn Test_set_current_time() {
+    let _ = Time::Initialize(Create_device!(Drivers::Native::Time_driver_type::New()));
+
+    let Manager = Time::Get_instance();
+
+    let Time = Duration_type::New(1_700_000_000, 0);
+
+    assert_eq!(
+        Manager.Set_current_time(Time.clone(), Users::User_identifier_type::New(1000)),
+        Err(Time::Error_type::Permission_denied)
+    );
+
+    let Time_since_startup = Manager.Get_current_time_since_startup().unwrap();
+
+    Manager
+        .Set_current_time(Time.clone(), Users::User_identifier_type::Root)
+        .unwrap();
+
+    let Current_time = Manager.Get_current_time().unwrap();
+
+    // - The time since startup doesn't jump with the clock.
+    let Elapsed = Manager
+        .Get_current_time_since_startup()
+        .unwrap()
+        .Get_duration_since_checked(&Time_since_startup)
+        .unwrap();
+
+    assert!(Elapsed < Duration_type::New(1, 0));
+
+    assert!(Current_time >= Time);
+    assert!(Current_time.Get_duration_since(&Time) < Duration_type::New(1, 0));
+
+    Manager
+        .Set_time_zone(
+            Shared::Time_zone_type::New(60 * 60),
+            Users::User_identifier_type::Root,
+        )
+        .unwrap();
+
+    let Local_time = Manager.Get_current_local_time().unwrap();
+
+    assert!(Local_time - Current_time.As_seconds() as i64 >= 60 * 60);
+}
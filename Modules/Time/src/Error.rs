@@ -3,6 +3,26 @@ pub enum Error_type {
     Not_initialized,
     Already_initialized,
     Device_error(File_system::Error_type),
+    Permission_denied,
+    Poisoned_lock,
+}
+
+impl<T> From<std::sync::PoisonError<T>> for Error_type {
+    fn from(_: std::sync::PoisonError<T>) -> Self {
+        Error_type::Poisoned_lock
+    }
+}
+
+impl std::fmt::Display for Error_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error_type::Not_initialized => write!(Formatter, "Not initialized"),
+            Error_type::Already_initialized => write!(Formatter, "Already initialized"),
+            Error_type::Device_error(Error) => write!(Formatter, "Device error: {}", Error),
+            Error_type::Permission_denied => write!(Formatter, "Permission denied"),
+            Error_type::Poisoned_lock => write!(Formatter, "Poisoned lock"),
+        }
+    }
 }
 
 pub type Result_type<T> = Result<T, Error_type>;
@@ -1,7 +1,8 @@
-use std::sync::OnceLock;
+use std::sync::{OnceLock, RwLock};
 
 use File_system::Device_type;
-use Shared::Duration_type;
+use Shared::{Duration_type, Time_zone_type};
+use Users::User_identifier_type;
 
 use crate::{Error_type, Result_type};
 
@@ -17,35 +18,135 @@ pub fn Initialize(Driver: Device_type) -> Result_type<&'static Manager_type> {
     Ok(Get_instance())
 }
 
+struct Inner_type {
+    /// Device time at the startup in nanoseconds, moved along with the device time when it is set,
+    /// so that the time since startup stays monotonic.
+    Start_time: i128,
+    /// Correction applied to the device time in nanoseconds, used when the device cannot be set.
+    Offset: i128,
+    Time_zone: Time_zone_type,
+}
+
 pub struct Manager_type {
     Device: Device_type,
-    Start_time: Duration_type,
+    Inner: RwLock<Inner_type>,
 }
 
 impl Manager_type {
     pub fn New(Device: Device_type) -> Result_type<Self> {
-        let mut Start_time = Duration_type::default();
+        let Start_time = Self::Read_device(&Device)?;
+
+        Ok(Self {
+            Device,
+            Inner: RwLock::new(Inner_type {
+                Start_time: Start_time.As_nanoseconds() as i128,
+                Offset: 0,
+                Time_zone: Time_zone_type::UTC,
+            }),
+        })
+    }
+
+    fn Read_device(Device: &Device_type) -> Result_type<Duration_type> {
+        let mut Time = Duration_type::default();
 
         Device
-            .Read(Start_time.as_mut())
+            .Read(Time.as_mut())
             .map_err(Error_type::Device_error)?;
 
-        Ok(Self { Device, Start_time })
+        Ok(Time)
+    }
+
+    fn Apply_offset(Time: &Duration_type, Offset: i128) -> Duration_type {
+        let Time = (Time.As_nanoseconds() as i128 + Offset).max(0) as u128;
+
+        Duration_type::New((Time / 1_000_000_000) as u64, (Time % 1_000_000_000) as u32)
     }
 
+    /// Get the time elapsed since the initialization of the manager, it is not affected by [Self::Set_current_time].
     pub fn Get_current_time_since_startup(&self) -> Result_type<Duration_type> {
-        let Current_time = self.Get_current_time()?;
+        let Inner = self.Inner.read()?;
+
+        let Current_time = Self::Read_device(&self.Device)?;
 
-        Ok(Current_time.Get_duration_since(&self.Start_time))
+        Ok(Self::Apply_offset(&Current_time, -Inner.Start_time))
     }
 
+    /// Get the current time since the Unix epoch (UTC).
     pub fn Get_current_time(&self) -> Result_type<Duration_type> {
-        let mut Current_time = Duration_type::default();
+        let Current_time = Self::Read_device(&self.Device)?;
 
-        self.Device
-            .Read(Current_time.as_mut())
-            .map_err(Error_type::Device_error)?;
+        Ok(Self::Apply_offset(&Current_time, self.Inner.read()?.Offset))
+    }
+
+    /// Set the current time since the Unix epoch (UTC), only the root user is allowed to do it.
+    ///
+    /// The time is written to the device when it supports it (e.g. a real time clock),
+    /// otherwise it is kept as a correction of the device time until the next reboot.
+    pub fn Set_current_time(
+        &self,
+        Time: Duration_type,
+        User: User_identifier_type,
+    ) -> Result_type<()> {
+        if User != User_identifier_type::Root {
+            return Err(Error_type::Permission_denied);
+        }
+
+        let mut Inner = self.Inner.write()?;
+
+        let Device_time = Self::Read_device(&self.Device)?.As_nanoseconds() as i128;
+
+        let Correction = Time.As_nanoseconds() as i128 - Device_time;
+
+        match self.Device.Write(Time.as_ref()) {
+            Ok(_) => {
+                // - The device time moved by the correction, not the time since startup.
+                Inner.Start_time += Correction;
+                Inner.Offset = 0;
+            }
+            Err(File_system::Error_type::Unsupported_operation) => Inner.Offset = Correction,
+            Err(Error) => return Err(Error_type::Device_error(Error)),
+        }
+
+        Ok(())
+    }
+
+    /// Restore the last known time (e.g. saved to the file system before the shutdown).
+    ///
+    /// The clock is only moved forward : on boards without real time clock, the device time
+    /// restarts from the epoch after each power cycle and is always behind the last known time.
+    pub fn Restore_time(&self, Last_known_time: Duration_type) -> Result_type<()> {
+        if self.Get_current_time()? >= Last_known_time {
+            return Ok(());
+        }
+
+        self.Set_current_time(Last_known_time, User_identifier_type::Root)
+    }
+
+    pub fn Get_time_zone(&self) -> Result_type<Time_zone_type> {
+        Ok(self.Inner.read()?.Time_zone)
+    }
+
+    /// Set the time zone used to get the local time, only the root user is allowed to do it.
+    pub fn Set_time_zone(
+        &self,
+        Time_zone: Time_zone_type,
+        User: User_identifier_type,
+    ) -> Result_type<()> {
+        if User != User_identifier_type::Root {
+            return Err(Error_type::Permission_denied);
+        }
+
+        self.Inner.write()?.Time_zone = Time_zone;
+
+        Ok(())
+    }
+
+    /// Get the current local time in seconds since the Unix epoch, according to the time zone.
+    pub fn Get_current_local_time(&self) -> Result_type<i64> {
+        let Current_time = self.Get_current_time()?;
 
-        Ok(Current_time)
+        Ok(self
+            .Get_time_zone()?
+            .To_local(Current_time.As_seconds() as i64))
     }
 }
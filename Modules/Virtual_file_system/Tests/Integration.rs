@@ -3,14 +3,36 @@
 #![allow(non_upper_case_globals)]
 
 use Task::Task_identifier_type;
+use Users::{Group_identifier_type, User_identifier_type};
 
 use File_system::{
-    Create_device, Create_file_system, Flags_type, Memory_device_type, Mode_type, Open_type,
-    Path_type, Position_type, Status_type,
+    Create_device, Create_file_system, Error_type, Events_type, File_system_traits, Flags_type,
+    Master_boot_record_type, Memory_device_type, Memory_file_system_type, Mode_type, Open_type,
+    Partition_entry_type, Partition_kind_type, Path_type, Permissions_type, Position_type,
+    Status_type,
 };
-use Virtual_file_system::{File_type, Virtual_file_system_type};
+use Virtual_file_system::{Directory_type, File_type, Virtual_file_system_type};
 
 fn Initialize<'a>() -> (Task_identifier_type, Virtual_file_system_type<'a>) {
+    let Device = Create_device!(Memory_device_type::<512>::New(1024 * 512));
+
+    let Cache_size = 256;
+
+    LittleFS::File_system_type::Format(Device.clone(), Cache_size).unwrap();
+    let File_system = LittleFS::File_system_type::New(Device, Cache_size).unwrap();
+
+    Initialize_with(Create_file_system!(File_system))
+}
+
+fn Initialize_memory<'a>() -> (Task_identifier_type, Virtual_file_system_type<'a>) {
+    Initialize_with(Create_file_system!(Memory_file_system_type::New(
+        1024 * 512
+    )))
+}
+
+fn Initialize_with<'a>(
+    File_system: Box<dyn File_system_traits>,
+) -> (Task_identifier_type, Virtual_file_system_type<'a>) {
     let Task_instance = Task::Initialize().expect("Failed to initialize task manager");
 
     unsafe {
@@ -25,18 +47,11 @@ fn Initialize<'a>() -> (Task_identifier_type, Virtual_file_system_type<'a>) {
         .Get_current_task_identifier()
         .expect("Failed to get current task identifier");
 
-    let Device = Create_device!(Memory_device_type::<512>::New(1024 * 512));
-
-    let Cache_size = 256;
-
-    LittleFS::File_system_type::Format(Device.clone(), Cache_size).unwrap();
-    let File_system = LittleFS::File_system_type::New(Device, Cache_size).unwrap();
-
     let Virtual_file_system = Virtual_file_system_type::New(
         Task_instance,
         Users::Get_instance(),
         Time::Get_instance(),
-        Create_file_system!(File_system),
+        File_system,
         None,
     )
     .unwrap();
@@ -47,7 +62,7 @@ fn Initialize<'a>() -> (Task_identifier_type, Virtual_file_system_type<'a>) {
 #[cfg(target_os = "linux")]
 #[test]
 fn Test_file() {
-    let (_, Virtual_file_system) = Initialize();
+    let (Task, Virtual_file_system) = Initialize();
 
     let File_path = "/file";
 
@@ -72,7 +87,50 @@ fn Test_file() {
 
     std::mem::drop(File);
 
-    Virtual_file_system.Remove(File_path).unwrap();
+    Virtual_file_system.Remove(File_path, Task).unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_memory_file_system() {
+    let (Task, Virtual_file_system) = Initialize_memory();
+
+    Virtual_file_system
+        .Create_directory(&"/Directory", Task)
+        .unwrap();
+
+    let File_path = "/Directory/file";
+
+    let File = File_type::Open(
+        &Virtual_file_system,
+        File_path,
+        Flags_type::New(Mode_type::Read_write, Some(Open_type::Create_only), None),
+    )
+    .unwrap();
+
+    let Data = b"Hello, world!";
+
+    File.Write(Data).unwrap();
+
+    File.Set_position(&Position_type::Start(0)).unwrap();
+
+    let mut Buffer = [0; 13];
+
+    File.Read(&mut Buffer).unwrap();
+
+    assert_eq!(Buffer, *Data);
+
+    std::mem::drop(File);
+
+    let Entries: Vec<_> = Directory_type::Open(&Virtual_file_system, "/Directory")
+        .unwrap()
+        .map(|Entry| Entry.Get_name().clone())
+        .collect();
+
+    assert!(Entries.iter().any(|Name| Name == "file"));
+
+    Virtual_file_system.Remove(File_path, Task).unwrap();
+    Virtual_file_system.Remove("/Directory", Task).unwrap();
 }
 
 #[cfg(target_os = "linux")]
@@ -93,6 +151,126 @@ fn Test_unnamed_pipe() {
     Pipe_read.Read(&mut Buffer).unwrap();
 
     assert_eq!(Buffer, *Data);
+
+    let Statistics = Pipe_write.Get_file_system_statistics().unwrap();
+
+    assert_eq!(Statistics.Get_total_size(), 512);
+    assert_eq!(Statistics.Get_free_size(), 512);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_poll() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let (Pipe_read, Pipe_write) = Virtual_file_system
+        .Create_unnamed_pipe(Task, Status_type::Non_blocking, 4)
+        .unwrap();
+
+    // - Nothing to read : the poll times out.
+    let Events = Virtual_file_system
+        .Poll(
+            Task,
+            &[(Pipe_read, Events_type::Readable)],
+            Some(std::time::Duration::from_millis(10)),
+        )
+        .unwrap();
+
+    assert_eq!(Events, [Events_type::None]);
+
+    assert_eq!(
+        Virtual_file_system.Read(Pipe_read, &mut [0; 4], Task),
+        Err(File_system::Error_type::Ressource_busy)
+    );
+
+    Virtual_file_system
+        .Write(Pipe_write, b"Data", Task)
+        .unwrap();
+
+    let Events = Virtual_file_system
+        .Poll(
+            Task,
+            &[
+                (Pipe_read, Events_type::Read_write),
+                (Pipe_write, Events_type::Writable),
+            ],
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(Events, [Events_type::Readable, Events_type::None]);
+
+    let mut Read = vec![Pipe_read];
+    let mut Write = vec![Pipe_write];
+
+    assert_eq!(
+        Virtual_file_system
+            .Select(Task, &mut Read, &mut Write, None)
+            .unwrap(),
+        1
+    );
+    assert_eq!(Read, [Pipe_read]);
+    assert!(Write.is_empty());
+
+    // - Switch the read end to blocking mode.
+    Virtual_file_system
+        .Set_status(Pipe_read, Status_type::None, Task)
+        .unwrap();
+    assert_eq!(
+        Virtual_file_system.Get_status(Pipe_read, Task).unwrap(),
+        Status_type::None
+    );
+
+    let mut Buffer = [0; 4];
+
+    Virtual_file_system
+        .Read(Pipe_read, &mut Buffer, Task)
+        .unwrap();
+
+    assert_eq!(&Buffer, b"Data");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_pipe_end_of_file_and_broken_pipe() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let (Pipe_read, Pipe_write) = Virtual_file_system
+        .Create_unnamed_pipe(Task, Status_type::default(), 512)
+        .unwrap();
+
+    Virtual_file_system
+        .Write(Pipe_write, b"Data", Task)
+        .unwrap();
+    Virtual_file_system.Close(Pipe_write, Task).unwrap();
+
+    let mut Buffer = Vec::new();
+
+    // - Reading to the end does not block once the writer is closed.
+    Virtual_file_system
+        .Read_to_end(Pipe_read, Task, &mut Buffer)
+        .unwrap();
+
+    assert_eq!(Buffer, b"Data");
+
+    Virtual_file_system.Close(Pipe_read, Task).unwrap();
+
+    let (Pipe_read, Pipe_write) = Virtual_file_system
+        .Create_unnamed_pipe(Task, Status_type::default(), 512)
+        .unwrap();
+
+    Virtual_file_system.Close(Pipe_read, Task).unwrap();
+
+    assert_eq!(
+        Virtual_file_system.Write(Pipe_write, b"Data", Task),
+        Err(File_system::Error_type::Broken_pipe)
+    );
+    assert_eq!(
+        Task::Get_instance().Pop_signal(Task).unwrap(),
+        Some(Task::Signal_type::Broken_pipe)
+    );
+
+    Virtual_file_system.Close(Pipe_write, Task).unwrap();
 }
 
 #[cfg(target_os = "linux")]
@@ -128,7 +306,7 @@ fn Test_named_pipe() {
     std::mem::drop(Pipe_read);
     std::mem::drop(Pipe_write);
 
-    Virtual_file_system.Remove(Pipe_path).unwrap();
+    Virtual_file_system.Remove(Pipe_path, Task).unwrap();
 }
 
 #[cfg(target_os = "linux")]
@@ -165,5 +343,680 @@ fn Test_device() {
 
     std::mem::drop(Device_file);
 
-    Virtual_file_system.Remove(Device_path).unwrap();
+    Virtual_file_system.Remove(Device_path, Task).unwrap();
+}
+
+#[test]
+fn Test_mount_partitions() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Device = Create_device!(Memory_device_type::<512>::New(512 * 64));
+
+    let mut Master_boot_record = Master_boot_record_type::New(1);
+
+    for Start in [8, 16, 24] {
+        Master_boot_record
+            .Add_partition(Partition_entry_type::New(
+                Start,
+                8,
+                Partition_kind_type::Master_boot_record(Master_boot_record_type::Linux_partition),
+                false,
+                "",
+            ))
+            .unwrap();
+    }
+
+    Master_boot_record.Write(&Device).unwrap();
+
+    // - Empty the first entry of the table.
+    Device.Set_position(&Position_type::Start(446 + 4)).unwrap();
+    Device
+        .Write(&[Master_boot_record_type::Empty_partition])
+        .unwrap();
+
+    Virtual_file_system
+        .Create_directory(&Path_type::Devices, Task)
+        .unwrap();
+
+    let Paths =
+        Virtual_file_system::Mount_partitions(&Virtual_file_system, Task, &Device, "Disk").unwrap();
+
+    // - The partitions keep the index of their entry.
+    assert_eq!(
+        Paths.iter().map(|Path| Path.As_str()).collect::<Vec<_>>(),
+        ["/Devices/Disk2", "/Devices/Disk3"]
+    );
+
+    let File = File_type::Open(
+        &Virtual_file_system,
+        &Paths[1],
+        Mode_type::Write_only.into(),
+    )
+    .unwrap();
+
+    File.Write(&[1, 2, 3]).unwrap();
+
+    let Data = Device.Dump_device().unwrap();
+    assert_eq!(&Data[24 * 512..24 * 512 + 3], &[1, 2, 3]);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_temporary() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Virtual_file_system: &'static _ = Box::leak(Box::new(Virtual_file_system));
+
+    Virtual_file_system::Create_default_hierarchy(Virtual_file_system, Task).unwrap();
+
+    // - The hierarchy can be created again on an existing root file system.
+    Virtual_file_system
+        .Unmount_file_system(Path_type::Temporary, Task)
+        .unwrap();
+    Virtual_file_system
+        .Unmount_file_system(Path_type::System, Task)
+        .unwrap();
+    Virtual_file_system::Create_default_hierarchy(Virtual_file_system, Task).unwrap();
+
+    let File_path = Path_type::Logs.Append("Log.txt").unwrap();
+
+    let File = File_type::Open(
+        Virtual_file_system,
+        &File_path,
+        Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+    )
+    .unwrap();
+
+    File.Write(b"Hello, world!").unwrap();
+
+    let Statistics = File.Get_file_system_statistics().unwrap();
+
+    assert_eq!(
+        Statistics.Get_total_size(),
+        Virtual_file_system::Default_temporary_size
+    );
+    assert_eq!(Statistics.Get_used_size(), 13);
+    assert_eq!(
+        Virtual_file_system
+            .Get_file_system_statistics(&Path_type::Logs)
+            .unwrap(),
+        Statistics
+    );
+
+    std::mem::drop(File);
+
+    // - The temporary files are lost once the memory file system is unmounted.
+    Virtual_file_system.Remove(&File_path, Task).unwrap();
+    Virtual_file_system.Remove(Path_type::Logs, Task).unwrap();
+    Virtual_file_system
+        .Unmount_file_system(Path_type::Temporary, Task)
+        .unwrap();
+
+    assert!(File_type::Open(Virtual_file_system, &File_path, Mode_type::Read_only.into()).is_err());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_system() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Virtual_file_system: &'static _ = Box::leak(Box::new(Virtual_file_system));
+
+    Virtual_file_system
+        .Create_directory(&Path_type::System, Task)
+        .unwrap();
+    Virtual_file_system::Mount_system(Virtual_file_system, Task).unwrap();
+
+    let Read = |Path: &str| {
+        let File = File_type::Open(
+            Virtual_file_system,
+            Path_type::System.Append(Path).unwrap(),
+            Mode_type::Read_only.into(),
+        )
+        .unwrap();
+
+        let mut Buffer = Vec::new();
+
+        File.Read_to_end(&mut Buffer).unwrap();
+
+        String::from_utf8(Buffer).unwrap()
+    };
+
+    // - The file systems lock is held while reading, the list is still generated.
+    let File_systems = Read("File_systems");
+
+    assert!(File_systems
+        .lines()
+        .any(|Line| Line.ends_with(" / Memory Memory rw")));
+    assert!(File_systems
+        .lines()
+        .any(|Line| Line.ends_with(" /System System System rw")));
+
+    let Task_path = format!("Tasks/{}", Task.Into_inner());
+
+    assert_eq!(
+        Read(&format!("{}/Name", Task_path)).trim_end(),
+        Task::Get_instance().Get_task_name(Task).unwrap()
+    );
+    assert_eq!(Read(&format!("{}/User", Task_path)), "0\n");
+    assert_eq!(Read(&format!("{}/Group", Task_path)), "0\n");
+    assert_eq!(
+        Read(&format!("{}/Parent", Task_path)).trim_end(),
+        Task::Get_instance()
+            .Get_parent_task(Task)
+            .unwrap()
+            .Into_inner()
+            .to_string()
+    );
+    assert_eq!(
+        Read(&format!("{}/Environment", Task_path)).lines().count(),
+        Task::Get_instance()
+            .Get_environment_variables(Task)
+            .unwrap()
+            .len()
+    );
+    Read(&format!("{}/Signals", Task_path));
+
+    // - Devices are listed by path.
+    Virtual_file_system
+        .Mount_static_device(
+            Task,
+            &"/System_device",
+            Create_device!(Memory_device_type::<512>::New(512)),
+        )
+        .unwrap();
+
+    assert!(Read("Devices").lines().any(|Line| Line == "/System_device"));
+
+    assert!(Read("Memory").starts_with("Total: "));
+    assert!(Read("Uptime").trim_end().parse::<f64>().is_ok());
+
+    let Entries: Vec<_> = Directory_type::Open(
+        Virtual_file_system,
+        Path_type::System.Append("Tasks").unwrap(),
+    )
+    .unwrap()
+    .map(|Entry| Entry.Get_name().clone())
+    .collect();
+
+    assert!(Entries.contains(&Task.Into_inner().to_string()));
+
+    // - It is read-only.
+    assert!(File_type::Open(
+        Virtual_file_system,
+        Path_type::System.Append("Uptime").unwrap(),
+        Mode_type::Write_only.into(),
+    )
+    .is_err());
+    assert!(Virtual_file_system
+        .Remove(Path_type::System.Append("Memory").unwrap(), Task)
+        .is_err());
+
+    // - It can still be read once frozen.
+    Virtual_file_system
+        .Create_directory(&Path_type::Binaries, Task)
+        .unwrap();
+    Virtual_file_system::Freeze_system(Virtual_file_system, Task).unwrap();
+
+    assert!(Read("Memory").starts_with("Total: "));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_bind_mount_and_remount() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Create = Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None);
+
+    Virtual_file_system
+        .Create_directory(&"/Data", Task)
+        .unwrap();
+
+    File_type::Open(&Virtual_file_system, "/Data/File", Create)
+        .unwrap()
+        .Write(b"Hello")
+        .unwrap();
+
+    Virtual_file_system
+        .Bind_mount("/Data", "/Bind", Task)
+        .unwrap();
+
+    // - Both paths share the same files.
+    let mut Buffer = Vec::new();
+
+    File_type::Open(
+        &Virtual_file_system,
+        "/Bind/File",
+        Mode_type::Read_only.into(),
+    )
+    .unwrap()
+    .Read_to_end(&mut Buffer)
+    .unwrap();
+
+    assert_eq!(Buffer, b"Hello");
+
+    let Mounts = Virtual_file_system.Get_mounts().unwrap();
+
+    let Bind = Mounts
+        .iter()
+        .find(|Mount| Mount.Get_mount_point().As_str() == "/Bind")
+        .unwrap();
+
+    assert_eq!(Bind.Get_source(), "/Data");
+    assert_eq!(Bind.Get_type(), "Memory");
+    assert_eq!(Bind.Get_options(), "rw,bind");
+
+    // - Only the bind mount is read-only.
+    let Writable = File_type::Open(
+        &Virtual_file_system,
+        "/Bind/File",
+        Mode_type::Write_only.into(),
+    )
+    .unwrap();
+
+    Virtual_file_system.Remount("/Bind", true, Task).unwrap();
+
+    assert_eq!(
+        Writable.Write(b"World").err(),
+        Some(File_system::Error_type::Read_only_file_system)
+    );
+    assert_eq!(
+        File_type::Open(&Virtual_file_system, "/Bind/New", Create).err(),
+        Some(File_system::Error_type::Read_only_file_system)
+    );
+    assert_eq!(
+        Virtual_file_system.Remove("/Bind/File", Task),
+        Err(File_system::Error_type::Read_only_file_system)
+    );
+
+    File_type::Open(&Virtual_file_system, "/Data/New", Create).unwrap();
+
+    // - Only mount points can be remounted.
+    assert_eq!(
+        Virtual_file_system.Remount("/Data", true, Task),
+        Err(File_system::Error_type::Invalid_path)
+    );
+
+    std::mem::drop(Writable);
+
+    Virtual_file_system
+        .Unmount_file_system("/Bind", Task)
+        .unwrap();
+
+    assert!(Virtual_file_system
+        .Get_metadata_from_path(&"/Bind")
+        .is_err());
+    assert!(Virtual_file_system
+        .Get_metadata_from_path(&"/Data/File")
+        .is_ok());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_freeze_system() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    Virtual_file_system
+        .Create_directory(&Path_type::System, Task)
+        .unwrap();
+    Virtual_file_system
+        .Create_directory(&Path_type::Binaries, Task)
+        .unwrap();
+
+    Virtual_file_system::Freeze_system(&Virtual_file_system, Task).unwrap();
+
+    let Create = Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None);
+
+    assert_eq!(
+        Virtual_file_system.Create_directory(&"/System/Users", Task),
+        Err(File_system::Error_type::Read_only_file_system)
+    );
+    assert_eq!(
+        File_type::Open(&Virtual_file_system, "/Binaries/Executable", Create).err(),
+        Some(File_system::Error_type::Read_only_file_system)
+    );
+
+    // - The rest of the root file system stays writable.
+    File_type::Open(&Virtual_file_system, "/File", Create).unwrap();
+
+    Virtual_file_system
+        .Remount(Path_type::System, false, Task)
+        .unwrap();
+    Virtual_file_system
+        .Create_directory(&"/System/Users", Task)
+        .unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_attributes() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let File_path = "/Document.txt";
+
+    File_type::Open(
+        &Virtual_file_system,
+        File_path,
+        Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+    )
+    .unwrap();
+
+    Virtual_file_system
+        .Set_attribute(File_path, File_system::Mime_type_attribute, b"text/plain")
+        .unwrap();
+
+    assert_eq!(
+        Virtual_file_system
+            .Get_attribute(File_path, File_system::Mime_type_attribute)
+            .unwrap(),
+        b"text/plain"
+    );
+    assert_eq!(
+        Virtual_file_system.List_attributes(File_path).unwrap(),
+        vec![File_system::Mime_type_attribute.to_string()]
+    );
+
+    // - The attributes can't be changed on a read-only mount.
+    Virtual_file_system.Remount("/", true, Task).unwrap();
+
+    assert_eq!(
+        Virtual_file_system.Remove_attribute(File_path, File_system::Mime_type_attribute),
+        Err(Error_type::Read_only_file_system)
+    );
+
+    Virtual_file_system.Remount("/", false, Task).unwrap();
+
+    Virtual_file_system
+        .Remove_attribute(File_path, File_system::Mime_type_attribute)
+        .unwrap();
+
+    assert_eq!(
+        Virtual_file_system.Get_attribute(File_path, File_system::Mime_type_attribute),
+        Err(Error_type::No_attribute)
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_check_file_system() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    Virtual_file_system
+        .Create_directory(&"/Directory", Task)
+        .unwrap();
+
+    let Report = Virtual_file_system
+        .Check_file_system("/Directory", false, Task)
+        .unwrap();
+
+    assert_eq!(Report.Get_directories(), 1);
+    assert!(Report.Is_clean());
+
+    // - Repairing needs a writable file system.
+    Virtual_file_system.Remount("/", true, Task).unwrap();
+
+    assert_eq!(
+        Virtual_file_system.Check_file_system("/", true, Task),
+        Err(Error_type::Read_only_file_system)
+    );
+    assert!(Virtual_file_system
+        .Check_file_system("/", false, Task)
+        .is_ok());
+}
+
+struct Test_resolver_type;
+
+impl Network::Network_resolver_trait for Test_resolver_type {
+    fn Resolve(
+        &self,
+        Name: &str,
+        Kind: Network::Record_kind_type,
+    ) -> Network::Result_type<Vec<Network::IP_type>> {
+        match (Name, Kind) {
+            ("example.com", Network::Record_kind_type::A) => {
+                Ok(vec![Network::IPv4_type::New([10, 0, 0, 1]).into()])
+            }
+            _ => Err(Network::Error_type::Not_found),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_resolve() {
+    use Network::{IPv4_type, IPv6_type, Record_kind_type};
+
+    let (Task, Virtual_file_system) = Initialize();
+
+    Virtual_file_system
+        .Create_directory(&Path_type::Configuration, Task)
+        .unwrap();
+
+    let File = File_type::Open(
+        &Virtual_file_system,
+        Network::Hosts_file_path,
+        Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+    )
+    .unwrap();
+
+    File.Write(b"192.168.1.2 printer # Office\n::1 localhost\n")
+        .unwrap();
+
+    assert_eq!(
+        Virtual_file_system
+            .Resolve(Task, "Printer", Record_kind_type::A)
+            .unwrap(),
+        vec![IPv4_type::New([192, 168, 1, 2]).into()]
+    );
+    assert_eq!(
+        Virtual_file_system
+            .Resolve(Task, "localhost", Record_kind_type::AAAA)
+            .unwrap(),
+        vec![IPv6_type::Localhost.into()]
+    );
+    assert_eq!(
+        Virtual_file_system
+            .Resolve(Task, "10.0.0.2", Record_kind_type::A)
+            .unwrap(),
+        vec![IPv4_type::New([10, 0, 0, 2]).into()]
+    );
+
+    // - Missing from the hosts file, without resolver.
+    assert_eq!(
+        Virtual_file_system.Resolve(Task, "example.com", Record_kind_type::A),
+        Err(Virtual_file_system::Error_type::Unavailable_driver)
+    );
+
+    Virtual_file_system
+        .Set_network_resolver(&Test_resolver_type)
+        .unwrap();
+
+    assert_eq!(
+        Virtual_file_system
+            .Resolve(Task, "example.com", Record_kind_type::A)
+            .unwrap(),
+        vec![IPv4_type::New([10, 0, 0, 1]).into()]
+    );
+    assert_eq!(
+        Virtual_file_system.Resolve(Task, "example.com", Record_kind_type::AAAA),
+        Err(Virtual_file_system::Error_type::Network(
+            Network::Error_type::Not_found
+        ))
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_permissions() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    for Path in ["/Shared", "/Private", "/Private/Inner"] {
+        Virtual_file_system.Create_directory(&Path, Task).unwrap();
+    }
+
+    File_type::Open(
+        &Virtual_file_system,
+        "/Shared/File",
+        Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
+    )
+    .unwrap();
+
+    Virtual_file_system
+        .Set_permissions("/Private", Permissions_type::From_octal(0o700).unwrap())
+        .unwrap();
+    Virtual_file_system
+        .Set_permissions(
+            "/Private/Inner",
+            Permissions_type::From_octal(0o777).unwrap(),
+        )
+        .unwrap();
+
+    let Users = Users::Get_instance();
+
+    let User = User_identifier_type::New(2000);
+    let Group = Group_identifier_type::New(2000);
+
+    let _ = Users.Add_group(Group, "Permission_test", &[]);
+    let _ = Users.Add_user(User, "Permission_test", Group);
+
+    let Task_instance = Task::Get_instance();
+
+    Task_instance.Set_user(Task, User).unwrap();
+
+    let Open_directory = |Path: &str| {
+        Virtual_file_system
+            .Open_directory(&Path, Task)
+            .map(|Directory| {
+                Virtual_file_system
+                    .Close_directory(Directory, Task)
+                    .unwrap()
+            })
+    };
+
+    Open_directory("/Shared").unwrap();
+    assert_eq!(
+        Open_directory("/Private"),
+        Err(Error_type::Permission_denied)
+    );
+    // - The parent directories have to be searchable.
+    assert_eq!(
+        Open_directory("/Private/Inner"),
+        Err(Error_type::Permission_denied)
+    );
+
+    // - Removing needs the write permission on the parent directory.
+    assert_eq!(
+        Virtual_file_system.Remove("/Shared/File", Task),
+        Err(Error_type::Permission_denied)
+    );
+
+    Task_instance
+        .Set_user(Task, User_identifier_type::Root)
+        .unwrap();
+
+    Virtual_file_system
+        .Set_permissions("/Shared", Permissions_type::From_octal(0o777).unwrap())
+        .unwrap();
+
+    Task_instance.Set_user(Task, User).unwrap();
+
+    Virtual_file_system.Remove("/Shared/File", Task).unwrap();
+
+    Task_instance
+        .Set_user(Task, User_identifier_type::Root)
+        .unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_open_permissions() {
+    let (Task, Virtual_file_system) = Initialize();
+
+    let Write_flags = Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None);
+
+    Virtual_file_system
+        .Create_directory(&"/Private", Task)
+        .unwrap();
+
+    for Path in ["/Secret", "/Private/File"] {
+        File_type::Open(&Virtual_file_system, Path, Write_flags).unwrap();
+    }
+
+    Virtual_file_system
+        .Set_permissions("/Secret", Permissions_type::From_octal(0o600).unwrap())
+        .unwrap();
+    Virtual_file_system
+        .Set_permissions("/Private", Permissions_type::From_octal(0o700).unwrap())
+        .unwrap();
+
+    // - The directories leading to a mounted file system are checked too.
+    Virtual_file_system
+        .Mount_file_system(
+            Create_file_system!(Memory_file_system_type::New(1024 * 16)),
+            "/Private/Mount",
+            Task,
+        )
+        .unwrap();
+
+    File_type::Open(&Virtual_file_system, "/Private/Mount/File", Write_flags).unwrap();
+
+    let Users = Users::Get_instance();
+
+    let User = User_identifier_type::New(2001);
+    let Group = Group_identifier_type::New(2001);
+
+    let _ = Users.Add_group(Group, "Open_permission_test", &[]);
+    let _ = Users.Add_user(User, "Open_permission_test", Group);
+
+    let Task_instance = Task::Get_instance();
+
+    Task_instance.Set_user(Task, User).unwrap();
+
+    let Open = |Path: &str, Flags: Flags_type| {
+        Virtual_file_system
+            .Open(&Path, Flags, Task)
+            .map(|File| Virtual_file_system.Close(File, Task).unwrap())
+    };
+
+    assert_eq!(
+        Open("/Secret", Mode_type::Read_only.into()),
+        Err(Error_type::Permission_denied)
+    );
+    assert_eq!(
+        Open("/Secret", Mode_type::Write_only.into()),
+        Err(Error_type::Permission_denied)
+    );
+    assert_eq!(
+        Open("/Private/Mount/File", Mode_type::Read_only.into()),
+        Err(Error_type::Permission_denied)
+    );
+    // - Creating a file needs the write permission on its parent directory.
+    assert_eq!(
+        Open("/New", Write_flags),
+        Err(Error_type::Permission_denied)
+    );
+
+    Task_instance
+        .Set_user(Task, User_identifier_type::Root)
+        .unwrap();
+
+    Virtual_file_system
+        .Set_permissions("/Secret", Permissions_type::From_octal(0o644).unwrap())
+        .unwrap();
+
+    Task_instance.Set_user(Task, User).unwrap();
+
+    Open("/Secret", Mode_type::Read_only.into()).unwrap();
+    assert_eq!(
+        Open("/Secret", Mode_type::Write_only.into()),
+        Err(Error_type::Permission_denied)
+    );
+
+    Task_instance
+        .Set_user(Task, User_identifier_type::Root)
+        .unwrap();
+
+    Virtual_file_system
+        .Unmount_file_system("/Private/Mount", Task)
+        .unwrap();
 }
@@ -0,0 +1,155 @@
+/// Persistence of the wall-clock time for boards without real time clock.
+use std::time::Duration;
+
+use File_system::{Error_type, Flags_type, Mode_type, Open_type, Path_type, Result_type};
+use Shared::{Duration_type, Time_zone_type};
+
+use crate::{File_type, Virtual_file_system_type};
+
+/// File where the last known time and the time zone are saved.
+pub const Time_file_path: &Path_type = Path_type::From_str("/Configuration/Time");
+
+/// Default period between two saves of the time by [Start_time_saver].
+pub const Default_time_saving_period: Duration = Duration::from_secs(60);
+
+fn Convert_time_error(Error: Time::Error_type) -> Error_type {
+    match Error {
+        Time::Error_type::Device_error(Error) => Error,
+        Time::Error_type::Permission_denied => Error_type::Permission_denied,
+        Time::Error_type::Poisoned_lock => Error_type::Poisoned_lock,
+        Time::Error_type::Not_initialized | Time::Error_type::Already_initialized => {
+            Error_type::Internal_error
+        }
+    }
+}
+
+/// Save the current time and time zone to [Time_file_path].
+pub fn Save_time<'a>(Virtual_file_system: &'a Virtual_file_system_type<'a>) -> Result_type<()> {
+    let Time_manager = Time::Get_instance();
+
+    let Current_time = Time_manager
+        .Get_current_time()
+        .map_err(Convert_time_error)?;
+    let Time_zone = Time_manager.Get_time_zone().map_err(Convert_time_error)?;
+
+    let File = File_type::Open(
+        Virtual_file_system,
+        Time_file_path,
+        Flags_type::New(
+            Mode_type::Write_only,
+            Some(Open_type::Create.Set_truncate(true)),
+            None,
+        ),
+    )?;
+
+    let Content = format!(
+        "{}.{:09}\n{}\n",
+        Current_time.As_seconds(),
+        Current_time.As_nanoseconds() % 1_000_000_000,
+        Time_zone
+    );
+
+    File.Write(Content.as_bytes())?;
+
+    Ok(())
+}
+
+/// Parse the content of [Time_file_path].
+fn Parse_time_file(Content: &str) -> Option<(Duration_type, Time_zone_type)> {
+    let mut Lines = Content.lines();
+
+    let (Seconds, Fraction) = Lines.next()?.trim().split_once('.')?;
+
+    if Fraction.is_empty() || Fraction.len() > 9 {
+        return None;
+    }
+
+    // - The fraction is padded to get nanoseconds (e.g. `.5` is 500 000 000 nanoseconds).
+    let Nanoseconds = format!("{:0<9}", Fraction).parse().ok()?;
+
+    let Time = Duration_type::New(Seconds.parse().ok()?, Nanoseconds);
+
+    let Time_zone = match Lines.next() {
+        Some(Time_zone) => Time_zone_type::From_str(Time_zone)?,
+        None => Time_zone_type::UTC,
+    };
+
+    Some((Time, Time_zone))
+}
+
+/// Restore the time and the time zone saved by [Save_time].
+///
+/// The time is only moved forward (see [Time::Manager_type::Restore_time]).
+pub fn Restore_time<'a>(Virtual_file_system: &'a Virtual_file_system_type<'a>) -> Result_type<()> {
+    let File = File_type::Open(
+        Virtual_file_system,
+        Time_file_path,
+        Mode_type::Read_only.into(),
+    )?;
+
+    let mut Buffer = Vec::new();
+
+    File.Read_to_end(&mut Buffer)?;
+
+    let Content = String::from_utf8(Buffer).map_err(|_| Error_type::Corrupted)?;
+
+    let (Time, Time_zone) = Parse_time_file(&Content).ok_or(Error_type::Corrupted)?;
+
+    let Time_manager = Time::Get_instance();
+
+    Time_manager
+        .Set_time_zone(Time_zone, Users::User_identifier_type::Root)
+        .map_err(Convert_time_error)?;
+
+    Time_manager
+        .Restore_time(Time)
+        .map_err(Convert_time_error)?;
+
+    Ok(())
+}
+
+/// Start a task saving the time every `Period`, to limit the drift after an unexpected power loss.
+///
+/// The time should also be saved with [Save_time] at shutdown.
+pub fn Start_time_saver(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Period: Duration,
+) -> Result_type<()> {
+    let Task_manager = Task::Get_instance();
+
+    let Task = Task_manager
+        .Get_current_task_identifier()
+        .map_err(|_| Error_type::Failed_to_get_task_informations)?;
+
+    Task_manager.New_task(Task, "Time saver", None, move || loop {
+        Task::Manager_type::Sleep(Period);
+
+        let _ = Save_time(Virtual_file_system);
+    })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_parse_time_file() {
+        assert_eq!(
+            Parse_time_file("1700000000.000000042\nUTC+02:00\n"),
+            Some((
+                Duration_type::New(1_700_000_000, 42),
+                Time_zone_type::New(2 * 60 * 60)
+            ))
+        );
+        assert_eq!(
+            Parse_time_file("1700000000.5\n"),
+            Some((
+                Duration_type::New(1_700_000_000, 500_000_000),
+                Time_zone_type::UTC
+            ))
+        );
+        assert_eq!(Parse_time_file("invalid\n"), None);
+    }
+}
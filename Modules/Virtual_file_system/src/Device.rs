@@ -3,9 +3,10 @@ use std::{collections::BTreeMap, sync::RwLock, time::Duration};
 use Task::Task_identifier_type;
 
 use File_system::{
-    Device_type, Error_type, File_identifier_type, Flags_type, Get_new_file_identifier,
-    Get_new_inode, Inode_type, Local_file_identifier_type, Mode_type, Path_owned_type, Path_type,
-    Position_type, Result_type, Size_type, Unique_file_identifier_type,
+    Device_type, Error_type, Events_type, File_identifier_type, File_system_statistics_type,
+    Flags_type, Get_new_file_identifier, Get_new_inode, Inode_type, Local_file_identifier_type,
+    Mode_type, Path_owned_type, Path_type, Position_type, Result_type, Size_type, Status_type,
+    Unique_file_identifier_type,
 };
 
 type Open_device_inner_type = (Device_type, Flags_type, Unique_file_identifier_type);
@@ -98,6 +99,26 @@ impl<'a> File_system_type<'a> {
             .clone())
     }
 
+    pub fn Get_paths(&self) -> Result_type<Vec<Path_owned_type>> {
+        Ok(self
+            .0
+            .read()?
+            .Devices
+            .values()
+            .map(|(Path, _)| match Path {
+                Internal_path_type::Borrowed(Path) => (*Path).to_owned(),
+                Internal_path_type::Owned(Path) => Path.clone(),
+            })
+            .collect())
+    }
+
+    /// Get the statistics of the device file system : it holds no data, only one inode per device.
+    pub fn Get_file_system_statistics(&self) -> Result_type<File_system_statistics_type> {
+        let Devices = self.0.read()?.Devices.len() as u64;
+
+        Ok(File_system_statistics_type::New(0, 0, 0, 0, Devices, 0))
+    }
+
     pub fn Get_devices_from_path(&self, Path: &'static Path_type) -> Result_type<Vec<Inode_type>> {
         Ok(self
             .0
@@ -239,32 +260,51 @@ impl<'a> File_system_type<'a> {
             .ok_or(Error_type::Invalid_inode)
     }
 
+    /// Get a copy of an open device entry, so that the lock is not held while waiting.
+    fn Get_open_device(
+        &self,
+        File: Local_file_identifier_type,
+    ) -> Result_type<Open_device_inner_type> {
+        Ok(self
+            .0
+            .read()?
+            .Open_devices
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?
+            .clone())
+    }
+
+    /// Read from a device.
+    ///
+    /// In blocking mode, wait for a character device to provide data (block devices never wait).
+    /// In non-blocking mode, return [`Error_type::Ressource_busy`] if the device is not readable.
     pub fn Read(
         &self,
         File: Local_file_identifier_type,
         Buffer: &mut [u8],
     ) -> Result_type<(Size_type, Unique_file_identifier_type)> {
-        let Inner = self.0.read()?;
-
-        let (Device, Flags, Underlying_file) = Inner
-            .Open_devices
-            .get(&File)
-            .ok_or(Error_type::Invalid_identifier)?;
+        let (Device, Flags, Underlying_file) = self.Get_open_device(File)?;
 
         if !Flags.Get_mode().Get_read() {
             return Err(Error_type::Invalid_mode);
         }
 
         if Flags.Get_status().Get_non_blocking() {
-            return Ok((Device.Read(Buffer)?, *Underlying_file));
+            if !Device.Get_events()?.Get_readable() {
+                return Err(Error_type::Ressource_busy);
+            }
+
+            return Ok((Device.Read(Buffer)?, Underlying_file));
         }
 
         loop {
-            // Wait for the pipe to be ready
-            let Size = Device.Read(Buffer)?;
-
-            if Size != 0 {
-                return Ok((Size, *Underlying_file));
+            // Wait for the device to be ready
+            match Device.Read(Buffer) {
+                Ok(Size) if Size != 0 || Buffer.is_empty() || Device.Is_a_block_device() => {
+                    return Ok((Size, Underlying_file))
+                }
+                Ok(_) | Err(Error_type::Ressource_busy) => {}
+                Err(Error) => return Err(Error),
             }
 
             Task::Manager_type::Sleep(Duration::from_millis(1));
@@ -276,27 +316,28 @@ impl<'a> File_system_type<'a> {
         File: Local_file_identifier_type,
         Buffer: &mut String,
     ) -> Result_type<(Size_type, Unique_file_identifier_type)> {
-        let Inner = self.0.read()?;
-
-        let (Device, Flags, Underlying_file) = Inner
-            .Open_devices
-            .get(&File)
-            .ok_or(Error_type::Invalid_identifier)?;
+        let (Device, Flags, Underlying_file) = self.Get_open_device(File)?;
 
         if !Flags.Get_mode().Get_read() {
             return Err(Error_type::Invalid_mode);
         }
 
         if Flags.Get_status().Get_non_blocking() {
-            return Ok((Device.Read_line(Buffer)?, *Underlying_file));
+            if !Device.Get_events()?.Get_readable() {
+                return Err(Error_type::Ressource_busy);
+            }
+
+            return Ok((Device.Read_line(Buffer)?, Underlying_file));
         }
 
         loop {
-            // Wait for the pipe to be ready
-            let Size = Device.Read_line(Buffer)?;
-
-            if Size != 0 {
-                return Ok((Size, *Underlying_file));
+            // Wait for the device to be ready
+            match Device.Read_line(Buffer) {
+                Ok(Size) if Size != 0 || Device.Is_a_block_device() => {
+                    return Ok((Size, Underlying_file))
+                }
+                Ok(_) | Err(Error_type::Ressource_busy) => {}
+                Err(Error) => return Err(Error),
             }
 
             Task::Manager_type::Sleep(Duration::from_millis(1));
@@ -308,31 +349,62 @@ impl<'a> File_system_type<'a> {
         File: Local_file_identifier_type,
         Buffer: &[u8],
     ) -> Result_type<(Size_type, Unique_file_identifier_type)> {
-        let Inner = self.0.read()?;
-
-        let (Device, Flags, Underlying_file) = Inner
-            .Open_devices
-            .get(&File)
-            .ok_or(Error_type::Invalid_identifier)?;
+        let (Device, Flags, Underlying_file) = self.Get_open_device(File)?;
 
         if !Flags.Get_mode().Get_write() {
             return Err(Error_type::Invalid_mode);
         }
 
         if Flags.Get_status().Get_non_blocking() {
-            return Ok((Device.Write(Buffer)?, *Underlying_file));
+            return Ok((Device.Write(Buffer)?, Underlying_file));
         }
 
         loop {
             // Wait for the device to be ready
-            if Device.Write(Buffer).is_ok() {
-                return Ok((Buffer.len().into(), *Underlying_file));
+            match Device.Write(Buffer) {
+                Ok(Size) => return Ok((Size, Underlying_file)),
+                Err(Error_type::Ressource_busy) => {}
+                Err(Error) => return Err(Error),
             }
 
             Task::Manager_type::Sleep(Duration::from_millis(1));
         }
     }
 
+    /// Get the events that are ready on an open device (only the events allowed by its mode).
+    pub fn Get_events(&self, File: Local_file_identifier_type) -> Result_type<Events_type> {
+        let (Device, Flags, _) = self.Get_open_device(File)?;
+
+        let Mode = Flags.Get_mode();
+
+        let Events = Device.Get_events()?;
+
+        Ok(Events
+            .Set_readable(Events.Get_readable() && Mode.Get_read())
+            .Set_writable(Events.Get_writable() && Mode.Get_write()))
+    }
+
+    pub fn Get_status(&self, File: Local_file_identifier_type) -> Result_type<Status_type> {
+        Ok(self.Get_open_device(File)?.1.Get_status())
+    }
+
+    pub fn Set_status(
+        &self,
+        File: Local_file_identifier_type,
+        Status: Status_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.0.write()?;
+
+        let (_, Flags, _) = Inner
+            .Open_devices
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        *Flags = Flags.Set_status(Status);
+
+        Ok(())
+    }
+
     pub fn Set_position(
         &self,
         File: Local_file_identifier_type,
@@ -396,6 +468,16 @@ impl<'a> File_system_type<'a> {
             .0
             .Is_a_terminal())
     }
+
+    pub fn Set_raw_mode(&self, File: Local_file_identifier_type, Raw: bool) -> Result_type<()> {
+        self.0
+            .read()?
+            .Open_devices
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?
+            .0
+            .Set_raw_mode(Raw)
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use std::{num::NonZeroU32, sync::PoisonError};
+use std::{fmt::Display, num::NonZeroU32, sync::PoisonError};
 
 pub type Result_type<T> = Result<T, Error_type>;
 
@@ -53,3 +53,21 @@ impl From<Network::Error_type> for Error_type {
         Self::Network(Value)
     }
 }
+
+impl Display for Error_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error_type::Already_initialized => write!(Formatter, "Already initialized"),
+            Error_type::Poisonned_lock => write!(Formatter, "Poisonned lock"),
+            Error_type::Unavailable_driver => write!(Formatter, "Unavailable driver"),
+            Error_type::Invalid_file_system => write!(Formatter, "Invalid file system"),
+            Error_type::Invalid_parameter => write!(Formatter, "Invalid parameter"),
+            Error_type::Too_many_open_files => write!(Formatter, "Too many open files"),
+            Error_type::Failed_to_get_task_informations => {
+                write!(Formatter, "Failed to get task informations")
+            }
+            Error_type::File_system(Error) => write!(Formatter, "File system: {}", Error),
+            Error_type::Network(Error) => write!(Formatter, "Network: {}", Error),
+        }
+    }
+}
@@ -3,8 +3,8 @@ use std::fmt::Debug;
 use Task::Task_identifier_type;
 
 use File_system::{
-    Error_type, Flags_type, Path_type, Position_type, Result_type, Size_type, Statistics_type,
-    Status_type, Unique_file_identifier_type,
+    Error_type, File_system_statistics_type, Flags_type, Path_type, Position_type, Result_type,
+    Size_type, Statistics_type, Status_type, Unique_file_identifier_type,
 };
 
 use super::Virtual_file_system_type;
@@ -122,6 +122,11 @@ impl<'a> File_type<'a> {
         self.File_system
             .Get_statistics(self.Get_file_identifier(), self.Task)
     }
+
+    pub fn Get_file_system_statistics(&self) -> Result_type<File_system_statistics_type> {
+        self.File_system
+            .Get_file_system_statistics_from_file(self.Get_file_identifier(), self.Task)
+    }
 }
 
 impl Drop for File_type<'_> {
@@ -1,14 +1,25 @@
 use std::sync::OnceLock;
-use std::{collections::BTreeMap, sync::RwLock};
+use std::time::{Duration, Instant};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
 
-use Network::{IP_type, Network_socket_driver_trait, Port_type, Protocol_type};
+use Network::{
+    Address_family_type, Hosts_file_path, Hosts_type, IP_type, Network_group_name,
+    Network_resolver_trait, Network_socket_driver_trait, Port_type, Protocol_type,
+    Record_kind_type, Shutdown_type, Socket_option_kind_type, Socket_option_type,
+    TLS_configuration_type, Trust_store_path,
+};
 use Task::Task_identifier_type;
 use Time::Duration_type;
 use Users::{Group_identifier_type, User_identifier_type};
 
 use File_system::{
-    Device_type, Entry_type, File_identifier_type, Local_file_identifier_type, Metadata_type,
-    Mode_type, Open_type, Statistics_type, Time_type, Type_type,
+    Check_report_type, Device_type, Entry_type, Events_type, File_identifier_type,
+    File_system_statistics_type, Local_file_identifier_type, Metadata_type, Mode_type, Open_type,
+    Permission_type, Statistics_type, Time_type, Type_type,
 };
 
 use File_system::{
@@ -18,11 +29,62 @@ use File_system::{
 };
 
 use crate::Device::Internal_path_type;
-use crate::{Device, Pipe, Socket_address_type};
+use crate::{Device, Mount_type, Pipe, Socket_address_type};
 
 struct Internal_file_system_type {
     pub Mount_point: Path_owned_type,
-    pub Inner: Box<dyn File_system_traits>,
+    /// Directory of the file system shown at the mount point (not the root for bind mounts).
+    pub Root: Path_owned_type,
+    /// Shared between the bind mounts of the file system.
+    pub Inner: Arc<dyn File_system_traits>,
+    pub Source: String,
+    pub Read_only: bool,
+    pub Bind: bool,
+    /// The mount point directory is removed when unmounting.
+    pub Created_mount_point: bool,
+}
+
+impl Internal_file_system_type {
+    fn New(Mount_point: Path_owned_type, Inner: Box<dyn File_system_traits>) -> Self {
+        Self {
+            Mount_point,
+            Root: Path_owned_type::Root(),
+            Source: Inner.Get_type_name().to_string(),
+            Inner: Arc::from(Inner),
+            Read_only: false,
+            Bind: false,
+            Created_mount_point: false,
+        }
+    }
+}
+
+/// Convert the error of a socket operation for the file operations ([`Virtual_file_system_type::Read`] and [`Virtual_file_system_type::Write`]).
+fn Into_file_system_error(Error: crate::Error_type) -> Error_type {
+    match Error {
+        crate::Error_type::File_system(Error) => Error,
+        crate::Error_type::Poisonned_lock => Error_type::Poisoned_lock,
+        crate::Error_type::Network(Network::Error_type::Permission_denied) => {
+            Error_type::Permission_denied
+        }
+        crate::Error_type::Network(
+            Network::Error_type::Broken_pipe
+            | Network::Error_type::Connection_reset
+            | Network::Error_type::Connection_aborted
+            | Network::Error_type::Not_connected,
+        ) => Error_type::Broken_pipe,
+        crate::Error_type::Network(
+            Network::Error_type::Would_block | Network::Error_type::Timed_out,
+        ) => Error_type::Ressource_busy,
+        crate::Error_type::Network(Network::Error_type::Out_of_memory) => Error_type::No_memory,
+        crate::Error_type::Network(Network::Error_type::Invalid_identifier) => {
+            Error_type::Invalid_identifier
+        }
+        crate::Error_type::Unavailable_driver
+        | crate::Error_type::Network(Network::Error_type::Unsupported) => {
+            Error_type::Unsupported_operation
+        }
+        _ => Error_type::Input_output,
+    }
 }
 
 /// Instance of the virtual file system.
@@ -64,12 +126,18 @@ pub fn Uninitialize() {
 pub struct Virtual_file_system_type<'a> {
     /// Mounted file systems.
     File_systems: RwLock<BTreeMap<File_system_identifier_type, Internal_file_system_type>>,
+    /// Copy of the mount table, updated with the file systems and read without their lock (see [Self::Get_mounts]).
+    Mounts: RwLock<Vec<Mount_type>>,
     /// Devices.
     Device_file_system: Device::File_system_type<'a>,
     /// Pipes.
     Pipe_file_system: Pipe::File_system_type,
     /// Network sockets.
     Network_socket_driver: Option<&'a dyn Network_socket_driver_trait>,
+    /// Status of the open network sockets (the drivers are not aware of it).
+    Socket_status: RwLock<BTreeMap<Local_file_identifier_type, Status_type>>,
+    /// Name resolution, after the hosts file.
+    Network_resolver: RwLock<Option<&'a dyn Network_resolver_trait>>,
 }
 
 impl<'a> Virtual_file_system_type<'a> {
@@ -91,17 +159,17 @@ impl<'a> Virtual_file_system_type<'a> {
 
         File_systems.insert(
             Identifier,
-            Internal_file_system_type {
-                Mount_point: Path_owned_type::New("/".to_string()).unwrap(),
-                Inner: Root_file_system,
-            },
+            Internal_file_system_type::New(Path_owned_type::Root(), Root_file_system),
         );
 
         Ok(Self {
+            Mounts: RwLock::new(Self::Get_mount_table(&File_systems)),
             File_systems: RwLock::new(File_systems),
             Device_file_system: Device::File_system_type::New(),
             Pipe_file_system: Pipe::File_system_type::New(),
             Network_socket_driver,
+            Socket_status: RwLock::new(BTreeMap::new()),
+            Network_resolver: RwLock::new(None),
         })
     }
 
@@ -114,10 +182,10 @@ impl<'a> Virtual_file_system_type<'a> {
                 if let Ok(Path) = self.Device_file_system.Get_path_from_inode(Inode) {
                     match Path {
                         Internal_path_type::Owned(Path) => {
-                            let _ = self.Remove(Path);
+                            let _ = self.Remove_node(Path, None);
                         }
                         Internal_path_type::Borrowed(Path) => {
-                            let _ = self.Remove(Path);
+                            let _ = self.Remove_node(Path, None);
                         }
                     }
                 }
@@ -146,6 +214,41 @@ impl<'a> Virtual_file_system_type<'a> {
             .ok_or(Error_type::Invalid_identifier)
     }
 
+    /// Create the directory of a mount point in the underlying file system, if it doesn't exist.
+    ///
+    /// Returns `true` if the directory has been created (it is then removed when unmounting).
+    fn Create_mount_point(
+        File_systems: &BTreeMap<File_system_identifier_type, Internal_file_system_type>,
+        Path: &Path_type,
+        Task: Task_identifier_type,
+    ) -> Result_type<bool> {
+        let (Parent_identifier, Parent_file_system, Relative_path) =
+            Self::Get_file_system_from_path(File_systems, &Path)?; // Get the file system identifier and the relative path
+
+        // - Mounting over an existing directory is allowed.
+        match Parent_file_system.Get_metadata_from_path(&Relative_path) {
+            Ok(Metadata) if Metadata.Get_type() == Type_type::Directory => return Ok(false),
+            Ok(_) => return Err(Error_type::Not_directory),
+            Err(Error_type::Not_found) => {}
+            Err(Error) => return Err(Error),
+        }
+
+        Self::Check_writable(File_systems, Parent_identifier)?;
+
+        let Time = Time::Get_instance()
+            .Get_current_time()
+            .map_err(|_| Error_type::Time_error)?
+            .into();
+
+        let User = Task::Get_instance().Get_user(Task)?;
+
+        let Group = Users::Get_instance().Get_user_primary_group(User)?;
+
+        Parent_file_system.Create_directory(&Relative_path, Time, User, Group)?;
+
+        Ok(true)
+    }
+
     /// Mount a file system at a given mount point.
     pub fn Mount_file_system(
         &self,
@@ -166,35 +269,182 @@ impl<'a> Virtual_file_system_type<'a> {
         let mut File_systems = self.File_systems.write()?; // Get the file systems
 
         // Create a directory in the underlying file system
-        let (_, Parent_file_system, Relative_path) =
-            Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
+        let Created_mount_point = Self::Create_mount_point(&File_systems, Path, Task)?;
 
-        let Time = Time::Get_instance()
-            .Get_current_time()
-            .map_err(|_| Error_type::Time_error)?
-            .into();
+        // Create a directory at the mount point
+        let File_system_identifier = Self::Get_new_file_system_identifier(&File_systems)
+            .ok_or(Error_type::Too_many_mounted_file_systems)?;
 
-        let User = Task::Get_instance().Get_user(Task)?;
+        let mut File_system = Internal_file_system_type::New(Path.to_owned(), File_system);
 
-        let Group = Users::Get_instance().Get_user_primary_group(User)?;
+        File_system.Created_mount_point = Created_mount_point;
 
-        Parent_file_system.Create_directory(Relative_path, Time, User, Group)?;
+        File_systems.insert(File_system_identifier, File_system);
+
+        self.Update_mounts(&File_systems)?;
+
+        Ok(File_system_identifier)
+    }
+
+    /// Make the directory `Source` also reachable at `Destination`.
+    ///
+    /// Both paths then share the same files, but the bind mount can be remounted read-only on its own (see [Self::Remount]).
+    pub fn Bind_mount(
+        &self,
+        Source: impl AsRef<Path_type>,
+        Destination: impl AsRef<Path_type>,
+        Task: Task_identifier_type,
+    ) -> Result_type<File_system_identifier_type> {
+        let (Source, Destination) = (Source.as_ref(), Destination.as_ref());
+
+        if !Source.Is_valid()
+            || !Source.Is_absolute()
+            || !Destination.Is_valid()
+            || !Destination.Is_absolute()
+        {
+            return Err(Error_type::Invalid_path);
+        }
+
+        let mut File_systems = self.File_systems.write()?; // Get the file systems
+
+        let (Source_identifier, Source_file_system, Root) =
+            Self::Get_file_system_from_path(&File_systems, &Source)?; // Get the file system identifier and the relative path
+
+        if Source_file_system.Get_metadata_from_path(&Root)?.Get_type() != Type_type::Directory {
+            return Err(Error_type::Not_directory);
+        }
+
+        let Root = Root.into_owned();
+
+        let Source_file_system =
+            Self::Get_file_system_from_identifier(&File_systems, Source_identifier)?;
+
+        let File_system = Internal_file_system_type {
+            Mount_point: Destination.to_owned(),
+            Root,
+            Inner: Source_file_system.Inner.clone(),
+            Source: Source.As_str().to_string(),
+            Read_only: Source_file_system.Read_only,
+            Bind: true,
+            Created_mount_point: false,
+        };
+
+        let Created_mount_point = Self::Create_mount_point(&File_systems, Destination, Task)?;
 
-        // Create a directory at the mount point
         let File_system_identifier = Self::Get_new_file_system_identifier(&File_systems)
             .ok_or(Error_type::Too_many_mounted_file_systems)?;
 
         File_systems.insert(
             File_system_identifier,
             Internal_file_system_type {
-                Mount_point: Path.to_owned(),
-                Inner: File_system,
+                Created_mount_point,
+                ..File_system
             },
         );
 
+        self.Update_mounts(&File_systems)?;
+
         Ok(File_system_identifier)
     }
 
+    /// Switch a mounted file system between read-only and read-write, only the root user is allowed to do it.
+    ///
+    /// Once read-only, the operations modifying the file system fail with [Error_type::Read_only_file_system].
+    pub fn Remount(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Read_only: bool,
+        Task: Task_identifier_type,
+    ) -> Result_type<()> {
+        if Task::Get_instance().Get_user(Task)? != User_identifier_type::Root {
+            return Err(Error_type::Permission_denied);
+        }
+
+        let Path = Path.as_ref();
+
+        let mut File_systems = self.File_systems.write()?; // Get the file systems
+
+        let (File_system_identifier, _, _) = Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
+
+        let File_system = File_systems
+            .get_mut(&File_system_identifier)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        if *File_system.Mount_point != *Path {
+            return Err(Error_type::Invalid_path);
+        }
+
+        File_system.Read_only = Read_only;
+
+        self.Update_mounts(&File_systems)
+    }
+
+    /// Check the consistency of the file system containing the path, and repair it if `Repair` is set.
+    ///
+    /// Only the root user is allowed to do it. The paths of the report are relative to the file system root.
+    pub fn Check_file_system(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Repair: bool,
+        Task: Task_identifier_type,
+    ) -> Result_type<Check_report_type> {
+        if Task::Get_instance().Get_user(Task)? != User_identifier_type::Root {
+            return Err(Error_type::Permission_denied);
+        }
+
+        let File_systems = self.File_systems.read()?;
+
+        let (File_system_identifier, File_system, _) =
+            Self::Get_file_system_from_path(&File_systems, &Path)?;
+
+        if Repair {
+            Self::Check_writable(&File_systems, File_system_identifier)?;
+        }
+
+        File_system.Check(Repair)
+    }
+
+    /// Get the mounted file systems (including bind mounts).
+    ///
+    /// It doesn't take the file systems lock since it is also called from a mounted file system (`/System`),
+    /// while the lock is held.
+    pub fn Get_mounts(&self) -> Result_type<Vec<Mount_type>> {
+        Ok(self.Mounts.read()?.clone())
+    }
+
+    /// Update the copy of the mount table, while the file systems are locked.
+    fn Update_mounts(
+        &self,
+        File_systems: &BTreeMap<File_system_identifier_type, Internal_file_system_type>,
+    ) -> Result_type<()> {
+        *self.Mounts.write()? = Self::Get_mount_table(File_systems);
+
+        Ok(())
+    }
+
+    fn Get_mount_table(
+        File_systems: &BTreeMap<File_system_identifier_type, Internal_file_system_type>,
+    ) -> Vec<Mount_type> {
+        File_systems
+            .iter()
+            .map(|(Identifier, File_system)| {
+                Mount_type::New(
+                    *Identifier,
+                    File_system.Mount_point.clone(),
+                    File_system.Source.clone(),
+                    File_system.Inner.Get_type_name(),
+                    File_system.Read_only,
+                    File_system.Bind,
+                )
+            })
+            .collect()
+    }
+
+    /// Get the paths of the registered devices.
+    pub fn Get_devices(&self) -> Result_type<Vec<Path_owned_type>> {
+        self.Device_file_system.Get_paths()
+    }
+
     pub fn Unmount_file_system(
         &self,
         Path: impl AsRef<Path_type>,
@@ -209,10 +459,13 @@ impl<'a> Virtual_file_system_type<'a> {
         let mut File_systems = self.File_systems.write()?; // Get the file systems
 
         let File_system_identifier = {
-            let (File_system_identifier, _, Relative_path) =
+            let (File_system_identifier, _, _) =
                 Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
 
-            if !Relative_path.Is_root() {
+            if *Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
+                .Mount_point
+                != *Path
+            {
                 return Err(Error_type::Invalid_path);
             }
 
@@ -223,12 +476,86 @@ impl<'a> Virtual_file_system_type<'a> {
             .remove(&File_system_identifier)
             .ok_or(Error_type::Invalid_identifier)?;
 
-        File_system.Inner.Close_all(Task)?;
+        self.Update_mounts(&File_systems)?;
+
+        // - The files of a file system still reachable from another mount are kept open.
+        if Arc::strong_count(&File_system.Inner) == 1 {
+            File_system.Inner.Close_all(Task)?;
+        }
+
+        if File_system.Created_mount_point {
+            let (_, Parent_file_system, Relative_path) =
+                Self::Get_file_system_from_path(&File_systems, &File_system.Mount_point)?;
+
+            Parent_file_system.Remove(&Relative_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check that the operations modifying a file system are allowed.
+    fn Check_writable(
+        File_systems: &BTreeMap<File_system_identifier_type, Internal_file_system_type>,
+        File_system_identifier: File_system_identifier_type,
+    ) -> Result_type<()> {
+        if Self::Get_file_system_from_identifier(File_systems, File_system_identifier)?.Read_only {
+            return Err(Error_type::Read_only_file_system);
+        }
+
+        Ok(())
+    }
+
+    /// Check that the user of a task is granted a permission on a node, and can search the directories leading to it.
+    ///
+    /// The file systems don't know the user, and the directories leading to a node can be on other mounted file systems.
+    fn Check_permission(
+        File_systems: &BTreeMap<File_system_identifier_type, Internal_file_system_type>,
+        Path: &Path_type,
+        Task: Task_identifier_type,
+        Permission: Permission_type,
+    ) -> Result_type<()> {
+        let User = Task::Get_instance().Get_user(Task)?;
+
+        if User == User_identifier_type::Root {
+            return Ok(());
+        }
+
+        let Users = Users::Get_instance();
+
+        let mut Groups = Users.Get_user_groups(User)?;
+        Groups.insert(Users.Get_user_primary_group(User)?);
+
+        let Is_granted = |Path: &Path_type, Permission: Permission_type| -> Result_type<bool> {
+            let (_, File_system, Relative_path) =
+                Self::Get_file_system_from_path(File_systems, &Path)?;
+
+            let Metadata = File_system.Get_metadata_from_path(&Relative_path)?;
+            let Permissions = Metadata.Get_permissions();
+
+            let Granted = if User == Metadata.Get_user() {
+                Permissions.Get_user()
+            } else if Groups.contains(&Metadata.Get_group()) {
+                Permissions.Get_group()
+            } else {
+                Permissions.Get_others()
+            };
+
+            Ok(Granted.Include(Permission))
+        };
+
+        let mut Parent = Path.Go_parent();
 
-        let (_, Parent_file_system, Relative_path) =
-            Self::Get_file_system_from_path(&File_systems, &File_system.Mount_point)?;
+        while let Some(Directory) = Parent {
+            if !Is_granted(Directory, Permission_type::New(false, false, true))? {
+                return Err(Error_type::Permission_denied);
+            }
+
+            Parent = Directory.Go_parent();
+        }
 
-        Parent_file_system.Remove(Relative_path)?;
+        if !Is_granted(Path, Permission)? {
+            return Err(Error_type::Permission_denied);
+        }
 
         Ok(())
     }
@@ -239,7 +566,7 @@ impl<'a> Virtual_file_system_type<'a> {
     ) -> Result_type<(
         File_system_identifier_type,
         &'b dyn File_system_traits,
-        &'b Path_type,
+        Cow<'b, Path_type>,
     )> {
         let mut Result_score = 0;
         let mut Result: Option<File_system_identifier_type> = None;
@@ -253,9 +580,15 @@ impl<'a> Virtual_file_system_type<'a> {
 
             let Score = Path_components
                 .clone()
-                .Get_common_components(Mount_point_components);
+                .Get_common_components(Mount_point_components.clone());
 
-            if Result_score < Score {
+            // - The mount point must contain the path (`/A` is not in `/A/B`).
+            if !Mount_point.Is_root() && Score != Mount_point_components.count() {
+                continue;
+            }
+
+            // - The latest mount on a given mount point hides the previous ones.
+            if Result_score <= Score {
                 Result_score = Score;
                 Result = Some(*File_system_identifier);
             }
@@ -271,6 +604,23 @@ impl<'a> Virtual_file_system_type<'a> {
             .Strip_prefix_absolute(File_system.Mount_point.as_ref())
             .ok_or(Error_type::Invalid_path)?;
 
+        // - Bind mounts expose a sub directory of the file system.
+        let Relative_path = if File_system.Root.Is_root() {
+            Cow::Borrowed(Relative_path)
+        } else {
+            let Relative_path = Relative_path
+                .Strip_prefix(Path_type::Root)
+                .ok_or(Error_type::Invalid_path)?;
+
+            Cow::Owned(
+                File_system
+                    .Root
+                    .clone()
+                    .Join(Relative_path)
+                    .ok_or(Error_type::Invalid_path)?,
+            )
+        };
+
         Ok((
             File_system_identifier,
             File_system.Inner.as_ref(),
@@ -289,6 +639,44 @@ impl<'a> Virtual_file_system_type<'a> {
         let (File_system_identifier, File_system, Relative_path) =
             Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
 
+        let Open = Flags.Get_open();
+        let Mode = Flags.Get_mode();
+
+        let Existing = File_system.Get_metadata_from_path(&Relative_path);
+
+        match &Existing {
+            Ok(_) => Self::Check_permission(
+                &File_systems,
+                Path.as_ref(),
+                Task,
+                Permission_type::New(
+                    Mode.Get_read(),
+                    Mode.Get_write() || Open.Get_truncate(),
+                    false,
+                ),
+            )?,
+            // - Creating a file is modifying its parent directory.
+            Err(Error_type::Not_found) if Open.Get_create() => Self::Check_permission(
+                &File_systems,
+                Path.as_ref().Go_parent().ok_or(Error_type::Invalid_path)?,
+                Task,
+                Permission_type::New(false, true, true),
+            )?,
+            Err(_) => (),
+        }
+
+        if Mode.Get_write() || Open.Get_create() || Open.Get_truncate() {
+            // - Like on POSIX, devices and pipes stay writable on a read-only file system.
+            let Is_special = matches!(
+                Existing.map(|Metadata| Metadata.Get_type()),
+                Ok(Type_type::Character_device | Type_type::Block_device | Type_type::Pipe)
+            );
+
+            if !Is_special || Open.Get_truncate() {
+                Self::Check_writable(&File_systems, File_system_identifier)?;
+            }
+        }
+
         let Time: Time_type = Time::Get_instance()
             .Get_current_time()
             .map_err(|_| Error_type::Time_error)?
@@ -298,7 +686,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let Group = Users::Get_instance().Get_user_primary_group(User)?;
 
-        let Local_file = File_system.Open(Task, Relative_path, Flags, Time, User, Group)?;
+        let Local_file = File_system.Open(Task, &Relative_path, Flags, Time, User, Group)?;
 
         let Metadata = File_system.Get_metadata(Local_file)?;
 
@@ -361,6 +749,8 @@ impl<'a> Virtual_file_system_type<'a> {
                     .ok_or(Error_type::Unsupported_operation)?
                     .Close(Local_file)?;
 
+                self.Socket_status.write()?.remove(&Local_file);
+
                 return Ok(());
             }
             _ => {
@@ -405,6 +795,12 @@ impl<'a> Virtual_file_system_type<'a> {
             File_system_identifier_type::Pipe_file_system => {
                 self.Pipe_file_system.Read(Local_file_identifier, Buffer)?
             }
+            File_system_identifier_type::Network_socket_file_system => {
+                return self
+                    .Receive(Task, File, Buffer)
+                    .map(Into::into)
+                    .map_err(Into_file_system_error);
+            }
             File_system_identifier_type::Device_file_system => {
                 let Result = self
                     .Device_file_system
@@ -527,7 +923,22 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let (Size, Underlying_file) = match File_system {
             File_system_identifier_type::Pipe_file_system => {
-                self.Pipe_file_system.Write(Local_file_identifier, Buffer)?
+                match self.Pipe_file_system.Write(Local_file_identifier, Buffer) {
+                    Err(Error_type::Broken_pipe) => {
+                        // - Like POSIX, the writer is notified that nobody reads the pipe anymore.
+                        let _ =
+                            Task::Get_instance().Send_signal(Task, Task::Signal_type::Broken_pipe);
+
+                        return Err(Error_type::Broken_pipe);
+                    }
+                    Result => Result?,
+                }
+            }
+            File_system_identifier_type::Network_socket_file_system => {
+                self.Send(Task, File, Buffer)
+                    .map_err(Into_file_system_error)?;
+
+                return Ok(Buffer.len().into());
             }
             File_system_identifier_type::Device_file_system => {
                 let Result = self
@@ -536,13 +947,16 @@ impl<'a> Virtual_file_system_type<'a> {
                 (Result.0, Some(Result.1))
             }
             _ => {
-                return self
-                    .File_systems
-                    .read()?
+                let File_systems = self.File_systems.read()?;
+
+                // - The file may have been opened before the file system was remounted read-only.
+                Self::Check_writable(&File_systems, File_system)?;
+
+                return File_systems
                     .get(&File_system)
                     .ok_or(Error_type::Invalid_identifier)?
                     .Inner
-                    .Write(Local_file_identifier, Buffer, Time)
+                    .Write(Local_file_identifier, Buffer, Time);
             }
         };
 
@@ -604,10 +1018,12 @@ impl<'a> Virtual_file_system_type<'a> {
     ) -> Result_type<()> {
         let File_systems = self.File_systems.read()?; // Get the file systems
 
-        let (_, File_system, Relative_path) =
+        let (File_system_identifier, File_system, Relative_path) =
             Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
 
-        let mut Metadata = File_system.Get_metadata_from_path(Relative_path)?;
+        Self::Check_writable(&File_systems, File_system_identifier)?;
+
+        let mut Metadata = File_system.Get_metadata_from_path(&Relative_path)?;
 
         if let Some(User) = User {
             Metadata.Set_owner(User);
@@ -617,7 +1033,7 @@ impl<'a> Virtual_file_system_type<'a> {
             Metadata.Set_group(Group);
         }
 
-        File_system.Set_metadata_from_path(Relative_path, &Metadata)
+        File_system.Set_metadata_from_path(&Relative_path, &Metadata)
     }
 
     pub fn Set_permissions(
@@ -627,14 +1043,62 @@ impl<'a> Virtual_file_system_type<'a> {
     ) -> Result_type<()> {
         let File_systems = self.File_systems.read()?; // Get the file systems
 
-        let (_, File_system, Relative_path) =
+        let (File_system_identifier, File_system, Relative_path) =
             Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
 
-        let mut Metadata = File_system.Get_metadata_from_path(Relative_path)?;
+        Self::Check_writable(&File_systems, File_system_identifier)?;
+
+        let mut Metadata = File_system.Get_metadata_from_path(&Relative_path)?;
 
         Metadata.Set_permissions(Permissions);
 
-        File_system.Set_metadata_from_path(Relative_path, &Metadata)
+        File_system.Set_metadata_from_path(&Relative_path, &Metadata)
+    }
+
+    /// Get the value of an extended attribute of a file (see [`File_system::Attributes_type`]).
+    pub fn Get_attribute(&self, Path: impl AsRef<Path_type>, Name: &str) -> Result_type<Vec<u8>> {
+        let File_systems = self.File_systems.read()?;
+
+        let (_, File_system, Relative_path) =
+            Self::Get_file_system_from_path(&File_systems, &Path)?;
+
+        File_system.Get_attribute(&Relative_path, Name)
+    }
+
+    pub fn Set_attribute(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Name: &str,
+        Value: &[u8],
+    ) -> Result_type<()> {
+        let File_systems = self.File_systems.read()?;
+
+        let (File_system_identifier, File_system, Relative_path) =
+            Self::Get_file_system_from_path(&File_systems, &Path)?;
+
+        Self::Check_writable(&File_systems, File_system_identifier)?;
+
+        File_system.Set_attribute(&Relative_path, Name, Value)
+    }
+
+    pub fn Remove_attribute(&self, Path: impl AsRef<Path_type>, Name: &str) -> Result_type<()> {
+        let File_systems = self.File_systems.read()?;
+
+        let (File_system_identifier, File_system, Relative_path) =
+            Self::Get_file_system_from_path(&File_systems, &Path)?;
+
+        Self::Check_writable(&File_systems, File_system_identifier)?;
+
+        File_system.Remove_attribute(&Relative_path, Name)
+    }
+
+    pub fn List_attributes(&self, Path: impl AsRef<Path_type>) -> Result_type<Vec<String>> {
+        let File_systems = self.File_systems.read()?;
+
+        let (_, File_system, Relative_path) =
+            Self::Get_file_system_from_path(&File_systems, &Path)?;
+
+        File_system.List_attributes(&Relative_path)
     }
 
     pub fn Close_all(&self, Task_identifier: Task_identifier_type) -> Result_type<()> {
@@ -659,9 +1123,11 @@ impl<'a> Virtual_file_system_type<'a> {
     ) -> Result_type<()> {
         let File_systems = self.File_systems.read()?; // Get the file systems
 
-        let (_, File_system, Relative_path) =
+        let (File_system_identifier, File_system, Relative_path) =
             Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
 
+        Self::Check_writable(&File_systems, File_system_identifier)?;
+
         let Time = Time::Get_instance()
             .Get_current_time()
             .map_err(|_| Error_type::Time_error)?
@@ -673,7 +1139,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let File = File_system.Open(
             Task,
-            Relative_path,
+            &Relative_path,
             Flags_type::New(Mode_type::Read_write, Some(Open_type::Create_only), None),
             Time,
             User,
@@ -684,7 +1150,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let Inode = self
             .Device_file_system
-            .Mount_device(Relative_path.to_owned(), Device)?;
+            .Mount_device((*Relative_path).to_owned(), Device)?;
 
         let Time: Time_type = Time::Get_instance()
             .Get_current_time()
@@ -699,7 +1165,7 @@ impl<'a> Virtual_file_system_type<'a> {
             .ok_or(Error_type::Invalid_parameter)?;
         Metadata.Set_inode(Inode);
 
-        File_system.Set_metadata_from_path(Relative_path, &Metadata)?;
+        File_system.Set_metadata_from_path(&Relative_path, &Metadata)?;
 
         Ok(())
     }
@@ -713,9 +1179,11 @@ impl<'a> Virtual_file_system_type<'a> {
         let File_systems = self.File_systems.read()?; // Get the file systems
 
         // Create a special file in the underlying file system.
-        let (_, File_system, Relative_path) =
+        let (File_system_identifier, File_system, Relative_path) =
             Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
 
+        Self::Check_writable(&File_systems, File_system_identifier)?;
+
         let Time = Time::Get_instance()
             .Get_current_time()
             .map_err(|_| Error_type::Time_error)?
@@ -727,7 +1195,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let File = File_system.Open(
             Task,
-            Relative_path,
+            &Relative_path,
             Flags_type::New(Mode_type::Write_only, Some(Open_type::Create_only), None),
             Time,
             User,
@@ -759,11 +1227,15 @@ impl<'a> Virtual_file_system_type<'a> {
             .ok_or(Error_type::Invalid_parameter)?;
         Metadata.Set_inode(Inode);
 
-        File_system.Set_metadata_from_path(Relative_path, &Metadata)?;
+        File_system.Set_metadata_from_path(&Relative_path, &Metadata)?;
 
         Ok(())
     }
 
+    /// Create a named pipe (FIFO) holding up to `Size` bytes.
+    ///
+    /// Unlike POSIX, opening it never waits for the other end : the writes fail with [`Error_type::Broken_pipe`]
+    /// while no reader has it open.
     pub fn Create_named_pipe(
         &self,
         Path: &impl AsRef<Path_type>,
@@ -772,7 +1244,10 @@ impl<'a> Virtual_file_system_type<'a> {
     ) -> Result_type<()> {
         let File_systems = self.File_systems.read()?; // Get the file systems
 
-        let (_, File_system, Relative_path) = Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
+        let (File_system_identifier, File_system, Relative_path) =
+            Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
+
+        Self::Check_writable(&File_systems, File_system_identifier)?;
 
         let Time = Time::Get_instance()
             .Get_current_time()
@@ -785,7 +1260,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let File = File_system.Open(
             Task,
-            Relative_path,
+            &Relative_path,
             Flags_type::New(Mode_type::Read_write, Some(Open_type::Create_only), None),
             Time,
             User,
@@ -809,7 +1284,7 @@ impl<'a> Virtual_file_system_type<'a> {
             .ok_or(Error_type::Invalid_parameter)?;
         Metadata.Set_inode(Inode);
 
-        File_system.Set_metadata_from_path(Relative_path, &Metadata)?;
+        File_system.Set_metadata_from_path(&Relative_path, &Metadata)?;
 
         Ok(())
     }
@@ -832,16 +1307,43 @@ impl<'a> Virtual_file_system_type<'a> {
         Ok((Read, Write))
     }
 
-    pub fn Remove(&self, Path: impl AsRef<Path_type>) -> Result_type<()> {
+    /// Remove a file or a directory, which requires the write permission on its parent directory.
+    pub fn Remove(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Task: Task_identifier_type,
+    ) -> Result_type<()> {
+        self.Remove_node(Path, Some(Task))
+    }
+
+    /// Remove a node, checking the permissions of the task if any.
+    pub(crate) fn Remove_node(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Task: Option<Task_identifier_type>,
+    ) -> Result_type<()> {
         let File_systems = self.File_systems.read()?; // Get the file systems
 
         // - Check metadata on the underlying file system
-        let (_, File_system, Relative_path) =
+        let (File_system_identifier, File_system, Relative_path) =
             Self::Get_file_system_from_path(&File_systems, &Path)?; // Get the file system identifier and the relative path
 
-        let Metadata = File_system.Get_metadata_from_path(Relative_path)?;
+        Self::Check_writable(&File_systems, File_system_identifier)?;
+
+        if let Some(Task) = Task {
+            let Parent = Path.as_ref().Go_parent().ok_or(Error_type::Invalid_path)?;
+
+            Self::Check_permission(
+                &File_systems,
+                Parent,
+                Task,
+                Permission_type::New(false, true, true),
+            )?;
+        }
+
+        let Metadata = File_system.Get_metadata_from_path(&Relative_path)?;
 
-        File_system.Remove(Relative_path)?;
+        File_system.Remove(&Relative_path)?;
 
         match Metadata.Get_type() {
             Type_type::Pipe => {
@@ -999,34 +1501,78 @@ impl<'a> Virtual_file_system_type<'a> {
             .Get_statistics(Local_file)
     }
 
-    pub fn Open_directory(
+    /// Get the capacity and free space of the file system containing the path.
+    pub fn Get_file_system_statistics(
         &self,
         Path: &impl AsRef<Path_type>,
-        Task: Task_identifier_type,
-    ) -> Result_type<Unique_file_identifier_type> {
-        let File_systems = self.File_systems.read()?; // Get the file systems
-
-        let (File_system_identifier, File_system, Relative_path) =
-            Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
+    ) -> Result_type<File_system_statistics_type> {
+        let File_systems = self.File_systems.read()?;
 
-        let (_, File) = File_system
-            .Open_directory(Relative_path, Task)?
-            .Into_unique_file_identifier(File_system_identifier);
+        let (_, File_system, _) = Self::Get_file_system_from_path(&File_systems, Path)?;
 
-        Ok(File)
+        File_system.Get_file_system_statistics()
     }
 
-    pub fn Read_directory(
+    /// Get the capacity and free space of the file system of an open file (including devices and pipes).
+    pub fn Get_file_system_statistics_from_file(
         &self,
         File: Unique_file_identifier_type,
-        Task: Task_identifier_type,
-    ) -> Result_type<Option<Entry_type>> {
-        let (File_system, File) = File.Into_local_file_identifier(Task);
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<File_system_statistics_type> {
+        let (File_system, _) = File.Into_local_file_identifier(Task_identifier);
 
         match File_system {
-            File_system_identifier_type::Pipe_file_system => Err(Error_type::Unsupported_operation),
             File_system_identifier_type::Device_file_system => {
-                Err(Error_type::Unsupported_operation)
+                self.Device_file_system.Get_file_system_statistics()
+            }
+            File_system_identifier_type::Pipe_file_system => {
+                self.Pipe_file_system.Get_file_system_statistics()
+            }
+            _ => {
+                let File_systems = self.File_systems.read()?;
+
+                Self::Get_file_system_from_identifier(&File_systems, File_system)?
+                    .Inner
+                    .Get_file_system_statistics()
+            }
+        }
+    }
+
+    pub fn Open_directory(
+        &self,
+        Path: &impl AsRef<Path_type>,
+        Task: Task_identifier_type,
+    ) -> Result_type<Unique_file_identifier_type> {
+        let File_systems = self.File_systems.read()?; // Get the file systems
+
+        let (File_system_identifier, File_system, Relative_path) =
+            Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
+
+        Self::Check_permission(
+            &File_systems,
+            Path.as_ref(),
+            Task,
+            Permission_type::New(true, false, false),
+        )?;
+
+        let (_, File) = File_system
+            .Open_directory(&Relative_path, Task)?
+            .Into_unique_file_identifier(File_system_identifier);
+
+        Ok(File)
+    }
+
+    pub fn Read_directory(
+        &self,
+        File: Unique_file_identifier_type,
+        Task: Task_identifier_type,
+    ) -> Result_type<Option<Entry_type>> {
+        let (File_system, File) = File.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Pipe_file_system => Err(Error_type::Unsupported_operation),
+            File_system_identifier_type::Device_file_system => {
+                Err(Error_type::Unsupported_operation)
             }
             _ => self
                 .File_systems
@@ -1134,7 +1680,10 @@ impl<'a> Virtual_file_system_type<'a> {
     ) -> Result_type<()> {
         let File_systems = self.File_systems.read()?; // Get the file systems
 
-        let (_, File_system, Relative_path) = Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
+        let (File_system_identifier, File_system, Relative_path) =
+            Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
+
+        Self::Check_writable(&File_systems, File_system_identifier)?;
 
         let Time = Time::Get_instance()
             .Get_current_time()
@@ -1145,7 +1694,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let Group = Users::Get_instance().Get_user_primary_group(User)?;
 
-        File_system.Create_directory(Relative_path, Time, User, Group)
+        File_system.Create_directory(&Relative_path, Time, User, Group)
     }
 
     pub fn Get_mode(
@@ -1285,6 +1834,23 @@ impl<'a> Virtual_file_system_type<'a> {
         }
     }
 
+    /// Switch a terminal to raw mode or back to line mode (see [File_system::Device_trait::Set_raw_mode]).
+    pub fn Set_raw_mode(
+        &self,
+        File: Unique_file_identifier_type,
+        Task: Task_identifier_type,
+        Raw: bool,
+    ) -> Result_type<()> {
+        let (File_system, File) = File.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Device_file_system => {
+                self.Device_file_system.Set_raw_mode(File, Raw)
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+
     pub fn Rename(
         &self,
         Old_path: &impl AsRef<Path_type>,
@@ -1302,8 +1868,10 @@ impl<'a> Virtual_file_system_type<'a> {
             return Err(Error_type::Invalid_path);
         }
 
+        Self::Check_writable(&File_systems, Old_file_system_identifier)?;
+
         if Old_file_system_identifier == New_file_system_identifier {
-            Old_file_system.Rename(Old_relative_path, New_relative_path)
+            Old_file_system.Rename(&Old_relative_path, &New_relative_path)
         } else {
             Err(Error_type::Unsupported_operation) // TODO : Add support for moving between file systems
         }
@@ -1317,7 +1885,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let (_, File_system, Relative_path) = Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
 
-        let Metadata = File_system.Get_metadata_from_path(Relative_path)?;
+        let Metadata = File_system.Get_metadata_from_path(&Relative_path)?;
 
         if Metadata.Get_type() != Type_type::Block_device
             && Metadata.Get_type() != Type_type::Character_device
@@ -1343,7 +1911,7 @@ impl<'a> Virtual_file_system_type<'a> {
 
         let (_, File_system, Relative_path) = Self::Get_file_system_from_path(&File_systems, Path)?; // Get the file system identifier and the relative path
 
-        File_system.Get_metadata_from_path(Relative_path)
+        File_system.Get_metadata_from_path(&Relative_path)
     }
 
     pub fn Send(
@@ -1355,10 +1923,13 @@ impl<'a> Virtual_file_system_type<'a> {
         let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
 
         match File_system {
-            File_system_identifier_type::Network_socket_file_system => self
-                .Network_socket_driver
-                .ok_or(crate::Error_type::Unavailable_driver)?
-                .Send(Socket, Data)?,
+            File_system_identifier_type::Network_socket_file_system => {
+                self.Check_socket_ready(Socket, Events_type::Writable)?;
+
+                self.Network_socket_driver
+                    .ok_or(crate::Error_type::Unavailable_driver)?
+                    .Send(Socket, Data)?
+            }
             _ => Err(crate::Error_type::Invalid_file_system)?,
         }
 
@@ -1374,10 +1945,14 @@ impl<'a> Virtual_file_system_type<'a> {
         let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
 
         match File_system {
-            File_system_identifier_type::Network_socket_file_system => Ok(self
-                .Network_socket_driver
-                .ok_or(crate::Error_type::Unavailable_driver)?
-                .Receive(Socket, Data)?),
+            File_system_identifier_type::Network_socket_file_system => {
+                self.Check_socket_ready(Socket, Events_type::Readable)?;
+
+                Ok(self
+                    .Network_socket_driver
+                    .ok_or(crate::Error_type::Unavailable_driver)?
+                    .Receive(Socket, Data)?)
+            }
             _ => Err(crate::Error_type::Invalid_file_system)?,
         }
     }
@@ -1397,6 +1972,8 @@ impl<'a> Virtual_file_system_type<'a> {
                     .Into_IP_and_port()
                     .ok_or(crate::Error_type::Invalid_parameter)?;
 
+                self.Check_socket_ready(Socket, Events_type::Writable)?;
+
                 self.Network_socket_driver
                     .ok_or(crate::Error_type::Unavailable_driver)?
                     .Send_to(Socket, Data, IP, Port)?
@@ -1417,6 +1994,8 @@ impl<'a> Virtual_file_system_type<'a> {
 
         match File_system {
             File_system_identifier_type::Network_socket_file_system => {
+                self.Check_socket_ready(Socket, Events_type::Readable)?;
+
                 let (Size, IP, Port) = self
                     .Network_socket_driver
                     .ok_or(crate::Error_type::Unavailable_driver)?
@@ -1428,6 +2007,203 @@ impl<'a> Virtual_file_system_type<'a> {
         }
     }
 
+    /// In non-blocking mode, return [`Network::Error_type::Would_block`] if the socket is not ready for the operation.
+    fn Check_socket_ready(
+        &self,
+        Socket: Local_file_identifier_type,
+        Events: Events_type,
+    ) -> crate::Result_type<()> {
+        let Non_blocking = self
+            .Socket_status
+            .read()?
+            .get(&Socket)
+            .is_some_and(|Status| Status.Get_non_blocking());
+
+        if !Non_blocking {
+            return Ok(());
+        }
+
+        let Ready = self
+            .Network_socket_driver
+            .ok_or(crate::Error_type::Unavailable_driver)?
+            .Get_events(Socket)?;
+
+        if Ready.Intersection(Events).Is_empty() {
+            return Err(Network::Error_type::Would_block.into());
+        }
+
+        Ok(())
+    }
+
+    /// Get the status of an open file (regular files have no status, since they never block).
+    pub fn Get_status(
+        &self,
+        File: Unique_file_identifier_type,
+        Task: Task_identifier_type,
+    ) -> crate::Result_type<Status_type> {
+        let (File_system, File) = File.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Pipe_file_system => {
+                Ok(self.Pipe_file_system.Get_status(File)?)
+            }
+            File_system_identifier_type::Device_file_system => {
+                Ok(self.Device_file_system.Get_status(File)?)
+            }
+            File_system_identifier_type::Network_socket_file_system => Ok(self
+                .Socket_status
+                .read()?
+                .get(&File)
+                .copied()
+                .unwrap_or_default()),
+            _ => Ok(Status_type::None),
+        }
+    }
+
+    /// Set the status of an open file (e.g. to make reads and writes non-blocking).
+    ///
+    /// The status of regular files cannot be changed, except the non-blocking flag that has no effect on them.
+    pub fn Set_status(
+        &self,
+        File: Unique_file_identifier_type,
+        Status: Status_type,
+        Task: Task_identifier_type,
+    ) -> crate::Result_type<()> {
+        let (File_system, File) = File.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Pipe_file_system => {
+                self.Pipe_file_system.Set_status(File, Status)?
+            }
+            File_system_identifier_type::Device_file_system => {
+                self.Device_file_system.Set_status(File, Status)?
+            }
+            File_system_identifier_type::Network_socket_file_system => {
+                self.Socket_status.write()?.insert(File, Status);
+            }
+            _ => {
+                if Status.Get_append()
+                    || Status.Get_synchronous()
+                    || Status.Get_synchronous_data_only()
+                {
+                    return Err(Error_type::Unsupported_operation.into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the events that are ready on an open file, without waiting.
+    ///
+    /// Regular files are always readable and writable.
+    pub fn Get_events(
+        &self,
+        File: Unique_file_identifier_type,
+        Task: Task_identifier_type,
+    ) -> crate::Result_type<Events_type> {
+        let (File_system, File) = File.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Pipe_file_system => {
+                Ok(self.Pipe_file_system.Get_events(File)?)
+            }
+            File_system_identifier_type::Device_file_system => {
+                Ok(self.Device_file_system.Get_events(File)?)
+            }
+            File_system_identifier_type::Network_socket_file_system => Ok(self
+                .Network_socket_driver
+                .ok_or(crate::Error_type::Unavailable_driver)?
+                .Get_events(File)?),
+            _ => {
+                let Mode = self
+                    .File_systems
+                    .read()?
+                    .get(&File_system)
+                    .ok_or(Error_type::Invalid_identifier)?
+                    .Inner
+                    .Get_mode(File)?;
+
+                Ok(Events_type::New(
+                    Mode.Get_read(),
+                    Mode.Get_write(),
+                    false,
+                    false,
+                ))
+            }
+        }
+    }
+
+    /// Wait for events on several files.
+    ///
+    /// Each file is given with the events to wait for, the hang up and error events are always reported.
+    /// Return the events that are ready for each file (in the same order) as soon as one file is ready,
+    /// or when the timeout expires (`None` waits indefinitely).
+    /// An invalid file identifier is reported with the error event.
+    pub fn Poll(
+        &self,
+        Task: Task_identifier_type,
+        Files: &[(Unique_file_identifier_type, Events_type)],
+        Timeout: Option<Duration_type>,
+    ) -> crate::Result_type<Vec<Events_type>> {
+        let Start = Instant::now();
+
+        loop {
+            let mut Ready = false;
+
+            let Events = Files
+                .iter()
+                .map(|(File, Requested)| {
+                    let Events = match self.Get_events(*File, Task) {
+                        Ok(Events) => Events.Intersection(
+                            Requested.Union(Events_type::Hang_up.Union(Events_type::Error)),
+                        ),
+                        Err(_) => Events_type::Error,
+                    };
+
+                    Ready |= !Events.Is_empty();
+
+                    Events
+                })
+                .collect::<Vec<_>>();
+
+            if Ready || Timeout.is_some_and(|Timeout| Start.elapsed() >= Timeout) {
+                return Ok(Events);
+            }
+
+            Task::Manager_type::Sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// Wait until some files are readable or writable (see [`Virtual_file_system_type::Poll`]).
+    ///
+    /// Only the ready files are kept in `Read` and `Write`, and the count of ready files is returned.
+    pub fn Select(
+        &self,
+        Task: Task_identifier_type,
+        Read: &mut Vec<Unique_file_identifier_type>,
+        Write: &mut Vec<Unique_file_identifier_type>,
+        Timeout: Option<Duration_type>,
+    ) -> crate::Result_type<usize> {
+        let Files = Read
+            .iter()
+            .map(|File| (*File, Events_type::Readable))
+            .chain(Write.iter().map(|File| (*File, Events_type::Writable)))
+            .collect::<Vec<_>>();
+
+        let Events = self.Poll(Task, &Files, Timeout)?;
+
+        let (Read_events, Write_events) = Events.split_at(Read.len());
+
+        let mut Read_events = Read_events.iter();
+        Read.retain(|_| Read_events.next().is_some_and(|Events| !Events.Is_empty()));
+
+        let mut Write_events = Write_events.iter();
+        Write.retain(|_| Write_events.next().is_some_and(|Events| !Events.Is_empty()));
+
+        Ok(Read.len() + Write.len())
+    }
+
     fn New_file_identifier(
         &self,
         File_system: File_system_identifier_type,
@@ -1460,6 +2236,8 @@ impl<'a> Virtual_file_system_type<'a> {
             }
         };
 
+        Self::Check_socket_permission(Task, &Protocol)?;
+
         let New_socket = self.New_file_identifier(File_system, Task)?;
 
         match File_system {
@@ -1527,6 +2305,8 @@ impl<'a> Virtual_file_system_type<'a> {
 
         match File_system {
             File_system_identifier_type::Network_socket_file_system => {
+                self.Check_socket_ready(Socket, Events_type::Readable)?;
+
                 let New_socket = self.New_file_identifier(File_system, Task)?;
 
                 let (IP, Port) = self
@@ -1542,6 +2322,243 @@ impl<'a> Virtual_file_system_type<'a> {
         }
     }
 
+    /// Get the address a socket is bound to (e.g. to get the port chosen for [`Port_type::Any`]).
+    pub fn Get_local_address(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+    ) -> crate::Result_type<Socket_address_type> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => {
+                let (IP, Port) = self
+                    .Network_socket_driver
+                    .ok_or(crate::Error_type::Unavailable_driver)?
+                    .Get_local_address(Socket)?;
+
+                Ok(Socket_address_type::From_IP_and_port(IP, Port))
+            }
+            _ => Err(crate::Error_type::Invalid_file_system),
+        }
+    }
+
+    /// Get the address of the peer of a connected socket.
+    pub fn Get_remote_address(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+    ) -> crate::Result_type<Socket_address_type> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => {
+                let (IP, Port) = self
+                    .Network_socket_driver
+                    .ok_or(crate::Error_type::Unavailable_driver)?
+                    .Get_remote_address(Socket)?;
+
+                Ok(Socket_address_type::From_IP_and_port(IP, Port))
+            }
+            _ => Err(crate::Error_type::Invalid_file_system),
+        }
+    }
+
+    /// Check that the user of a task is allowed to open the sockets of a protocol.
+    ///
+    /// The ICMP sockets are reserved to the root user and the members of the [`Network_group_name`] group.
+    fn Check_socket_permission(
+        Task: Task_identifier_type,
+        Protocol: &Protocol_type,
+    ) -> crate::Result_type<()> {
+        if !matches!(Protocol, Protocol_type::ICMP) {
+            return Ok(());
+        }
+
+        let User = Task::Get_instance()
+            .Get_user(Task)
+            .map_err(|_| crate::Error_type::Failed_to_get_task_informations)?;
+
+        if User == User_identifier_type::Root {
+            return Ok(());
+        }
+
+        let Users = Users::Get_instance();
+
+        let Allowed = Users
+            .Get_user_groups(User)
+            .unwrap_or_default()
+            .into_iter()
+            .any(|Group| {
+                Users
+                    .Get_group_name(Group)
+                    .is_ok_and(|Name| Name == Network_group_name)
+            });
+
+        if !Allowed {
+            return Err(Network::Error_type::Permission_denied.into());
+        }
+
+        Ok(())
+    }
+
+    /// Create a socket without binding or connecting it, to set its options first (see [`Self::Set_socket_option`]).
+    pub fn Create_socket(
+        &self,
+        Task: Task_identifier_type,
+        Family: Address_family_type,
+        Protocol: Protocol_type,
+    ) -> crate::Result_type<Unique_file_identifier_type> {
+        Self::Check_socket_permission(Task, &Protocol)?;
+
+        let File_system = File_system_identifier_type::Network_socket_file_system;
+
+        let New_socket = self.New_file_identifier(File_system, Task)?;
+
+        self.Network_socket_driver
+            .ok_or(crate::Error_type::Unavailable_driver)?
+            .Create_socket(Family, Protocol, New_socket)?;
+
+        let (_, New_socket) = New_socket.Into_unique_file_identifier(File_system);
+
+        Ok(New_socket)
+    }
+
+    /// Bind a socket created by [`Self::Create_socket`] to a local address.
+    pub fn Bind_socket(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        Address: Socket_address_type,
+    ) -> crate::Result_type<()> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => {
+                let (IP, Port) = Address
+                    .Into_IP_and_port()
+                    .ok_or(crate::Error_type::Invalid_parameter)?;
+
+                self.Network_socket_driver
+                    .ok_or(crate::Error_type::Unavailable_driver)?
+                    .Bind_socket(Socket, IP, Port)?
+            }
+            _ => return Err(crate::Error_type::Invalid_file_system),
+        }
+
+        Ok(())
+    }
+
+    /// Connect a socket created by [`Self::Create_socket`].
+    ///
+    /// In non-blocking mode, return [`Network::Error_type::In_progress`] if the connection isn't established yet :
+    /// the socket becomes writable once connected, or gets the error event if the connection failed (see [`Self::Poll`]).
+    pub fn Connect_socket(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        Address: Socket_address_type,
+    ) -> crate::Result_type<()> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => {
+                let (IP, Port) = Address
+                    .Into_IP_and_port()
+                    .ok_or(crate::Error_type::Invalid_parameter)?;
+
+                let Non_blocking = self
+                    .Socket_status
+                    .read()?
+                    .get(&Socket)
+                    .is_some_and(|Status| Status.Get_non_blocking());
+
+                self.Network_socket_driver
+                    .ok_or(crate::Error_type::Unavailable_driver)?
+                    .Connect_socket(Socket, IP, Port, Non_blocking)?
+            }
+            _ => return Err(crate::Error_type::Invalid_file_system),
+        }
+
+        Ok(())
+    }
+
+    /// Accept the connections on a bound TCP socket, with at most `Backlog` connections waiting to be accepted.
+    pub fn Listen(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        Backlog: usize,
+    ) -> crate::Result_type<()> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => self
+                .Network_socket_driver
+                .ok_or(crate::Error_type::Unavailable_driver)?
+                .Listen(Socket, Backlog)?,
+            _ => return Err(crate::Error_type::Invalid_file_system),
+        }
+
+        Ok(())
+    }
+
+    /// Close one or both directions of a connection, the socket still needs to be closed.
+    pub fn Shutdown(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        How: Shutdown_type,
+    ) -> crate::Result_type<()> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => self
+                .Network_socket_driver
+                .ok_or(crate::Error_type::Unavailable_driver)?
+                .Shutdown(Socket, How)?,
+            _ => return Err(crate::Error_type::Invalid_file_system),
+        }
+
+        Ok(())
+    }
+
+    pub fn Set_socket_option(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        Option: Socket_option_type,
+    ) -> crate::Result_type<()> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => self
+                .Network_socket_driver
+                .ok_or(crate::Error_type::Unavailable_driver)?
+                .Set_option(Socket, Option)?,
+            _ => return Err(crate::Error_type::Invalid_file_system),
+        }
+
+        Ok(())
+    }
+
+    pub fn Get_socket_option(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        Kind: Socket_option_kind_type,
+    ) -> crate::Result_type<Socket_option_type> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        match File_system {
+            File_system_identifier_type::Network_socket_file_system => Ok(self
+                .Network_socket_driver
+                .ok_or(crate::Error_type::Unavailable_driver)?
+                .Get_option(Socket, Kind)?),
+            _ => Err(crate::Error_type::Invalid_file_system),
+        }
+    }
+
     pub fn Set_send_timeout(
         &self,
         Task: Task_identifier_type,
@@ -1611,6 +2628,135 @@ impl<'a> Virtual_file_system_type<'a> {
             _ => Err(crate::Error_type::Invalid_file_system),
         }
     }
+
+    /// Read the certificates of the trust store ([`Trust_store_path`]), which is optional.
+    fn Get_trusted_certificates(&self, Task: Task_identifier_type) -> crate::Result_type<Vec<u8>> {
+        let Directory = match self.Open_directory(&Trust_store_path, Task) {
+            Ok(Directory) => Directory,
+            Err(Error_type::Not_found) => return Ok(Vec::new()),
+            Err(Error) => return Err(Error.into()),
+        };
+
+        let mut Names = Vec::new();
+
+        while let Some(Entry) = self.Read_directory(Directory, Task)? {
+            if Entry.Get_type() == Type_type::File {
+                Names.push(Entry.Get_name().clone());
+            }
+        }
+
+        self.Close_directory(Directory, Task)?;
+
+        let mut Certificates = Vec::new();
+
+        for Name in Names {
+            let Path = Path_type::From_str(Trust_store_path)
+                .Append(&Name)
+                .ok_or(Error_type::Invalid_path)?;
+
+            let File = self.Open(&Path, Mode_type::Read_only.into(), Task)?;
+
+            let Result = self.Read_to_end(File, Task, &mut Certificates);
+
+            self.Close(File, Task)?;
+
+            Result?;
+
+            // - Keep the PEM blocks of the files apart.
+            Certificates.push(b'\n');
+        }
+
+        Ok(Certificates)
+    }
+
+    /// Start a TLS session on a connected (or accepted) TCP socket, then the data sent and received is encrypted.
+    ///
+    /// The trust store is added to the trusted certificates of the configuration if it uses it.
+    pub fn Start_TLS(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Unique_file_identifier_type,
+        Configuration: TLS_configuration_type,
+    ) -> crate::Result_type<()> {
+        let (File_system, Socket) = Socket.Into_local_file_identifier(Task);
+
+        if File_system != File_system_identifier_type::Network_socket_file_system {
+            return Err(crate::Error_type::Invalid_file_system);
+        }
+
+        let Driver = self
+            .Network_socket_driver
+            .ok_or(crate::Error_type::Unavailable_driver)?;
+
+        let Configuration = if Configuration.Get_use_trust_store() {
+            Configuration.Add_trusted_certificates(&self.Get_trusted_certificates(Task)?)
+        } else {
+            Configuration
+        };
+
+        Ok(Driver.Start_TLS(Socket, &Configuration)?)
+    }
+
+    /// Set the resolver asked for the names missing from the hosts file (see [`Self::Resolve`]).
+    pub fn Set_network_resolver(
+        &self,
+        Resolver: &'a dyn Network_resolver_trait,
+    ) -> crate::Result_type<()> {
+        *self.Network_resolver.write()? = Some(Resolver);
+
+        Ok(())
+    }
+
+    /// Read the hosts file, which is optional.
+    fn Get_hosts(&self, Task: Task_identifier_type) -> crate::Result_type<Hosts_type> {
+        let File = match self.Open(&Hosts_file_path, Mode_type::Read_only.into(), Task) {
+            Ok(File) => File,
+            Err(Error_type::Not_found) => return Ok(Hosts_type::New()),
+            Err(Error) => return Err(Error.into()),
+        };
+
+        let mut Buffer = Vec::new();
+
+        let Result = self.Read_to_end(File, Task, &mut Buffer);
+
+        self.Close(File, Task)?;
+
+        Result?;
+
+        Ok(Hosts_type::Parse(&String::from_utf8_lossy(&Buffer)))
+    }
+
+    /// Resolve a host name into its addresses of a kind.
+    ///
+    /// Numeric addresses are returned as is, the names are looked up in the hosts file ([`Hosts_file_path`])
+    /// then asked to the network resolver.
+    pub fn Resolve(
+        &self,
+        Task: Task_identifier_type,
+        Name: &str,
+        Kind: Record_kind_type,
+    ) -> crate::Result_type<Vec<IP_type>> {
+        if let Ok(IP) = IP_type::try_from(Name) {
+            if !Kind.Matches(&IP) {
+                return Err(Network::Error_type::Not_found.into());
+            }
+
+            return Ok(vec![IP]);
+        }
+
+        let Addresses = self.Get_hosts(Task)?.Get(Name, Kind);
+
+        if !Addresses.is_empty() {
+            return Ok(Addresses);
+        }
+
+        let Resolver = self
+            .Network_resolver
+            .read()?
+            .ok_or(crate::Error_type::Unavailable_driver)?;
+
+        Ok(Resolver.Resolve(Name, Kind)?)
+    }
 }
 
 #[cfg(test)]
@@ -1766,52 +2912,82 @@ mod Tests {
 
         File_systems.insert(
             1.into(),
-            Internal_file_system_type {
-                Mount_point: Path_owned_type::New("/".to_string()).unwrap(),
-                Inner: Box::new(Dummy_file_system_type),
-            },
+            Internal_file_system_type::New(
+                Path_owned_type::New("/".to_string()).unwrap(),
+                Box::new(Dummy_file_system_type),
+            ),
         );
 
         File_systems.insert(
             2.into(),
-            Internal_file_system_type {
-                Mount_point: Path_owned_type::New("/Foo".to_string()).unwrap(),
-                Inner: Box::new(Dummy_file_system_type),
-            },
+            Internal_file_system_type::New(
+                Path_owned_type::New("/Foo".to_string()).unwrap(),
+                Box::new(Dummy_file_system_type),
+            ),
         );
 
         File_systems.insert(
             3.into(),
-            Internal_file_system_type {
-                Mount_point: Path_owned_type::New("/Foo/Bar".to_string()).unwrap(),
-                Inner: Box::new(Dummy_file_system_type),
-            },
+            Internal_file_system_type::New(
+                Path_owned_type::New("/Foo/Bar".to_string()).unwrap(),
+                Box::new(Dummy_file_system_type),
+            ),
         );
 
         let (File_system, _, Relative_path) =
             Virtual_file_system_type::Get_file_system_from_path(&File_systems, &"/").unwrap();
 
         assert_eq!(File_system, 1.into());
-        assert_eq!(Relative_path, Path_type::Root);
+        assert_eq!(Relative_path.as_ref(), Path_type::Root);
 
         let (File_system, _, Relative_path) =
             Virtual_file_system_type::Get_file_system_from_path(&File_systems, &"/Foo/Bar")
                 .unwrap();
 
         assert_eq!(File_system, 3.into());
-        assert_eq!(Relative_path, Path_type::Root);
+        assert_eq!(Relative_path.as_ref(), Path_type::Root);
 
         let (File_system, _, Relative_path) =
             Virtual_file_system_type::Get_file_system_from_path(&File_systems, &"/Foo/Bar/Baz")
                 .unwrap();
 
         assert_eq!(File_system, 3.into());
-        assert_eq!(Relative_path, "/Baz".as_ref());
+        assert_eq!(Relative_path.as_ref(), "/Baz".as_ref());
 
         let (File_system, _, Relative_path) =
             Virtual_file_system_type::Get_file_system_from_path(&File_systems, &"/Foo").unwrap();
 
         assert_eq!(File_system, 2.into());
-        assert_eq!(Relative_path, Path_type::Root);
+        assert_eq!(Relative_path.as_ref(), Path_type::Root);
+
+        // - A mount point only contains the paths below it.
+        let (File_system, _, Relative_path) =
+            Virtual_file_system_type::Get_file_system_from_path(&File_systems, &"/Foo_bar")
+                .unwrap();
+
+        assert_eq!(File_system, 1.into());
+        assert_eq!(Relative_path.as_ref(), "/Foo_bar".as_ref());
+
+        // - A bind mount exposes a directory of the file system.
+        let mut Bind = Internal_file_system_type::New(
+            Path_owned_type::New("/Bind".to_string()).unwrap(),
+            Box::new(Dummy_file_system_type),
+        );
+
+        Bind.Root = Path_owned_type::New("/Data".to_string()).unwrap();
+
+        File_systems.insert(4.into(), Bind);
+
+        let (File_system, _, Relative_path) =
+            Virtual_file_system_type::Get_file_system_from_path(&File_systems, &"/Bind/File")
+                .unwrap();
+
+        assert_eq!(File_system, 4.into());
+        assert_eq!(Relative_path.as_ref(), "/Data/File".as_ref());
+
+        let (_, _, Relative_path) =
+            Virtual_file_system_type::Get_file_system_from_path(&File_systems, &"/Bind").unwrap();
+
+        assert_eq!(Relative_path.as_ref(), "/Data".as_ref());
     }
 }
@@ -1,24 +1,103 @@
 /// Hierarchy of the file system.
-use File_system::{Error_type, Path_type, Result_type, Type_type};
+use File_system::{
+    Create_device, Create_file_system, Device_type, Error_type, File_system_identifier_type,
+    File_system_traits, Get_partition_devices, Memory_file_system_type, Path_owned_type, Path_type,
+    Permission_type, Permissions_type, Result_type, Special_type, Type_type,
+};
 use Task::Task_identifier_type;
 
-use crate::{Directory_type, Virtual_file_system_type};
+use crate::{Directory_type, System_file_system_type, Virtual_file_system_type};
+
+/// Maximum size of the content of the temporary files (`/Temporary` is kept in memory).
+pub const Default_temporary_size: usize = 512 * 1024;
+
+/// Create a directory, ignoring it if it already exists (e.g. on a persistent root file system).
+fn Create_directory_if_missing(
+    Virtual_file_system: &Virtual_file_system_type,
+    Path: &Path_type,
+    Task: Task_identifier_type,
+) -> Result_type<()> {
+    match Virtual_file_system.Create_directory(&Path, Task) {
+        Ok(()) | Err(Error_type::Already_exists) => Ok(()),
+        Err(Error) => Err(Error),
+    }
+}
 
 /// Create the default hierarchy of the file system.
+///
+/// `/Temporary` is a memory file system (see [`Default_temporary_size`]), to spare the flash memory.
+/// `/System` exposes the state of the kernel (see [`System_file_system_type`]).
 pub fn Create_default_hierarchy(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+) -> Result_type<()> {
+    Create_directory_if_missing(Virtual_file_system, Path_type::System, Task)?;
+    Create_directory_if_missing(Virtual_file_system, Path_type::Configuration, Task)?;
+    Create_directory_if_missing(Virtual_file_system, Path_type::Shared_configuration, Task)?;
+    Create_directory_if_missing(Virtual_file_system, Path_type::Devices, Task)?;
+    Create_directory_if_missing(Virtual_file_system, Path_type::Users, Task)?;
+    Create_directory_if_missing(Virtual_file_system, Path_type::Data, Task)?;
+    Create_directory_if_missing(Virtual_file_system, Path_type::Shared_data, Task)?;
+    Create_directory_if_missing(Virtual_file_system, Path_type::Binaries, Task)?;
+
+    Mount_system(Virtual_file_system, Task)?;
+
+    Mount_temporary(Virtual_file_system, Task, Default_temporary_size)?;
+
+    Create_directory_if_missing(Virtual_file_system, Path_type::Logs, Task)?;
+
+    Ok(())
+}
+
+/// Mount a memory file system on `/Temporary`, writable by everyone (with the sticky bit set).
+pub fn Mount_temporary(
+    Virtual_file_system: &Virtual_file_system_type,
+    Task: Task_identifier_type,
+    Maximum_size: usize,
+) -> Result_type<File_system_identifier_type> {
+    let File_system = Memory_file_system_type::New(Maximum_size);
+
+    let mut Metadata = File_system.Get_metadata_from_path(Path_type::Root)?;
+
+    Metadata.Set_permissions(Permissions_type::New(
+        Permission_type::Full,
+        Permission_type::Full,
+        Permission_type::Full,
+        Special_type::New(true, false, false),
+    ));
+
+    File_system.Set_metadata_from_path(Path_type::Root, &Metadata)?;
+
+    Virtual_file_system.Mount_file_system(
+        Create_file_system!(File_system),
+        Path_type::Temporary,
+        Task,
+    )
+}
+
+/// Mount the kernel state file system on `/System`.
+pub fn Mount_system(
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Task: Task_identifier_type,
+) -> Result_type<File_system_identifier_type> {
+    Virtual_file_system.Mount_file_system(
+        Create_file_system!(System_file_system_type::New(Virtual_file_system)),
+        Path_type::System,
+        Task,
+    )
+}
+
+/// Make `/System` and `/Binaries` read-only, once they are populated at boot.
+///
+/// Each directory is bind-mounted on itself then remounted read-only, it can be made writable again with [Virtual_file_system_type::Remount].
+pub fn Freeze_system(
     Virtual_file_system: &Virtual_file_system_type,
     Task: Task_identifier_type,
 ) -> Result_type<()> {
-    Virtual_file_system.Create_directory(&Path_type::System, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Configuration, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Shared_configuration, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Devices, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Users, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Data, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Shared_data, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Binaries, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Temporary, Task)?;
-    Virtual_file_system.Create_directory(&Path_type::Logs, Task)?;
+    for Path in [Path_type::System, Path_type::Binaries] {
+        Virtual_file_system.Bind_mount(Path, Path, Task)?;
+        Virtual_file_system.Remount(Path, true, Task)?;
+    }
 
     Ok(())
 }
@@ -43,7 +122,7 @@ pub fn Clean_devices_in_directory<'a>(
             continue;
         }
 
-        match Virtual_file_system.Remove(&Entry_path) {
+        match Virtual_file_system.Remove_node(&Entry_path, None) {
             Ok(_) | Err(Error_type::Invalid_identifier) => {}
             Err(Error) => {
                 return Err(Error);
@@ -61,3 +140,30 @@ pub fn Clean_devices<'a>(Virtual_file_system: &'a Virtual_file_system_type<'a>)
 
     Ok(())
 }
+
+/// Mount each partition of a block device as `/Devices/<Name><Index>`.
+///
+/// The index is the one of the partition in the partition table, starting at 1, so that a partition keeps its path
+/// when the ones before it are removed.
+///
+/// Returns the paths of the mounted partitions.
+pub fn Mount_partitions(
+    Virtual_file_system: &Virtual_file_system_type,
+    Task: Task_identifier_type,
+    Device: &Device_type,
+    Name: &str,
+) -> Result_type<Vec<Path_owned_type>> {
+    let mut Paths = Vec::new();
+
+    for Partition in Get_partition_devices(Device)? {
+        let Path = Path_type::Devices
+            .Append(&format!("{}{}", Name, Partition.Get_index() + 1))
+            .ok_or(Error_type::Invalid_path)?;
+
+        Virtual_file_system.Mount_device(Task, &Path, Create_device!(Partition))?;
+
+        Paths.push(Path);
+    }
+
+    Ok(Paths)
+}
@@ -0,0 +1,49 @@
+/// Persistence of the configuration of the network interfaces.
+use File_system::{Error_type, Flags_type, Mode_type, Open_type};
+use Network::Network_configuration_file_path;
+
+use crate::{File_type, Result_type, Virtual_file_system_type};
+
+/// Save the configuration of the network interfaces to [Network_configuration_file_path].
+pub fn Save_network_configuration<'a>(
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+) -> Result_type<()> {
+    let Content = Network::Get_instance().Get_configuration_file()?;
+
+    let File = File_type::Open(
+        Virtual_file_system,
+        Network_configuration_file_path,
+        Flags_type::New(
+            Mode_type::Write_only,
+            Some(Open_type::Create.Set_truncate(true)),
+            None,
+        ),
+    )?;
+
+    File.Write(Content.as_bytes())?;
+
+    Ok(())
+}
+
+/// Apply the configuration of the network interfaces saved by [Save_network_configuration].
+///
+/// All the saved interfaces are configured even if some fail, the first error is returned.
+pub fn Restore_network_configuration<'a>(
+    Virtual_file_system: &'a Virtual_file_system_type<'a>,
+) -> Result_type<()> {
+    let File = File_type::Open(
+        Virtual_file_system,
+        Network_configuration_file_path,
+        Mode_type::Read_only.into(),
+    )?;
+
+    let mut Buffer = Vec::new();
+
+    File.Read_to_end(&mut Buffer)?;
+
+    let Content = String::from_utf8(Buffer).map_err(|_| Error_type::Corrupted)?;
+
+    Network::Get_instance().Load_configuration_file(&Content)?;
+
+    Ok(())
+}
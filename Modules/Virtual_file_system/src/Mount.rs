@@ -0,0 +1,68 @@
+use File_system::{File_system_identifier_type, Path_owned_type};
+
+/// Entry of the mount table, see [crate::Virtual_file_system_type::Get_mounts].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mount_type {
+    Identifier: File_system_identifier_type,
+    Mount_point: Path_owned_type,
+    Source: String,
+    Type: &'static str,
+    Read_only: bool,
+    Bind: bool,
+}
+
+impl Mount_type {
+    pub fn New(
+        Identifier: File_system_identifier_type,
+        Mount_point: Path_owned_type,
+        Source: String,
+        Type: &'static str,
+        Read_only: bool,
+        Bind: bool,
+    ) -> Self {
+        Self {
+            Identifier,
+            Mount_point,
+            Source,
+            Type,
+            Read_only,
+            Bind,
+        }
+    }
+
+    pub fn Get_identifier(&self) -> File_system_identifier_type {
+        self.Identifier
+    }
+
+    pub fn Get_mount_point(&self) -> &Path_owned_type {
+        &self.Mount_point
+    }
+
+    /// Get the source of the mount: the file system type name, or the bound directory for bind mounts.
+    pub fn Get_source(&self) -> &str {
+        &self.Source
+    }
+
+    pub fn Get_type(&self) -> &'static str {
+        self.Type
+    }
+
+    pub fn Is_read_only(&self) -> bool {
+        self.Read_only
+    }
+
+    pub fn Is_bind(&self) -> bool {
+        self.Bind
+    }
+
+    /// Get the options of the mount, as in a `fstab` file (e.g. `ro,bind`).
+    pub fn Get_options(&self) -> String {
+        let mut Options = if self.Read_only { "ro" } else { "rw" }.to_string();
+
+        if self.Bind {
+            Options.push_str(",bind");
+        }
+
+        Options
+    }
+}
@@ -3,9 +3,9 @@ use std::{collections::BTreeMap, sync::RwLock, time::Duration};
 use Task::Task_identifier_type;
 
 use File_system::{
-    Error_type, File_identifier_type, Flags_type, Get_new_file_identifier, Get_new_inode,
-    Inode_type, Local_file_identifier_type, Mode_type, Result_type, Size_type, Status_type,
-    Unique_file_identifier_type,
+    Error_type, Events_type, File_identifier_type, File_system_statistics_type, Flags_type,
+    Get_new_file_identifier, Get_new_inode, Inode_type, Local_file_identifier_type, Mode_type,
+    Result_type, Size_type, Status_type, Unique_file_identifier_type,
 };
 
 use super::Pipe_type;
@@ -54,6 +54,8 @@ impl File_system_type {
         // - Create the read file
         let Read_flags = Flags_type::New(Mode_type::Read_only, None, Some(Status));
 
+        Pipe.Open_end(Mode_type::Read_only)?;
+
         let Read_file = Get_new_file_identifier(Task, None, None, &Inner.Open_pipes)?;
 
         if Inner
@@ -67,6 +69,8 @@ impl File_system_type {
         // - Create the write file
         let Write_flags = Flags_type::New(Mode_type::Write_only, None, Some(Status));
 
+        Pipe.Open_end(Mode_type::Write_only)?;
+
         let Write_file = Get_new_file_identifier(Task, None, None, &Inner.Open_pipes)?;
 
         if Inner
@@ -103,6 +107,10 @@ impl File_system_type {
         Ok(Inode)
     }
 
+    /// Open a named pipe.
+    ///
+    /// Unlike POSIX, opening never waits for the other end : a pipe opened for writing while it has no reader fails
+    /// its writes with [`Error_type::Broken_pipe`] until a reader opens it.
     pub fn Open(
         &self,
         Inode: Inode_type,
@@ -120,6 +128,8 @@ impl File_system_type {
 
         let Local_file_identifier = Get_new_file_identifier(Task, None, None, Open_pipes)?;
 
+        Pipe.Open_end(Flags.Get_mode())?;
+
         Open_pipes.insert(
             Local_file_identifier,
             (Pipe.clone(), Flags, Some(Underlying_file)),
@@ -132,13 +142,15 @@ impl File_system_type {
         &self,
         File: Local_file_identifier_type,
     ) -> Result_type<Option<Unique_file_identifier_type>> {
-        let (_, _, Underlying_file) = self
+        let (Pipe, Flags, Underlying_file) = self
             .0
             .write()?
             .Open_pipes
             .remove(&File)
             .ok_or(Error_type::Invalid_identifier)?;
 
+        Pipe.Close_end(Flags.Get_mode())?;
+
         Ok(Underlying_file)
     }
 
@@ -155,8 +167,8 @@ impl File_system_type {
 
         // Close all the pipes corresponding to the keys
         for Key in Keys {
-            if let Some((Pipe, _, _)) = Inner.Open_pipes.remove(&Key) {
-                drop(Pipe);
+            if let Some((Pipe, Flags, _)) = Inner.Open_pipes.remove(&Key) {
+                Pipe.Close_end(Flags.Get_mode())?;
             }
         }
 
@@ -178,6 +190,8 @@ impl File_system_type {
 
         let New_file = Get_new_file_identifier(File.Split().0, None, None, &Inner.Open_pipes)?;
 
+        Pipe.Open_end(Flags.Get_mode())?;
+
         Inner
             .Open_pipes
             .insert(New_file, (Pipe.clone(), Flags, Underlying_file));
@@ -193,23 +207,28 @@ impl File_system_type {
     ) -> Result_type<Local_file_identifier_type> {
         let mut Inner = self.0.write()?;
 
-        let (Pipe, Flags, Underlying_file) = Inner
-            .Open_pipes
-            .remove(&File)
-            .ok_or(Error_type::Invalid_identifier)?;
+        if !Inner.Open_pipes.contains_key(&File) {
+            return Err(Error_type::Invalid_identifier);
+        }
 
-        let New_file = if let Some(File) = New_file {
-            let File = Local_file_identifier_type::New(New_task, File);
+        // - The identifier is checked before removing the entry, to keep the pipe end open on failure.
+        let New_file = if let Some(New_file) = New_file {
+            let New_file = Local_file_identifier_type::New(New_task, New_file);
 
-            if Inner.Open_pipes.contains_key(&File) {
+            if New_file != File && Inner.Open_pipes.contains_key(&New_file) {
                 return Err(Error_type::Invalid_identifier);
             }
 
-            File
+            New_file
         } else {
             Get_new_file_identifier(New_task, None, None, &Inner.Open_pipes)?
         };
 
+        let (Pipe, Flags, Underlying_file) = Inner
+            .Open_pipes
+            .remove(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
         if Inner
             .Open_pipes
             .insert(New_file, (Pipe, Flags, Underlying_file))
@@ -231,30 +250,41 @@ impl File_system_type {
         Ok(())
     }
 
+    /// Get a copy of an open pipe entry, so that the lock is not held while waiting.
+    fn Get_open_pipe(
+        &self,
+        File: Local_file_identifier_type,
+    ) -> Result_type<Open_pipes_inner_type> {
+        Ok(self
+            .0
+            .read()?
+            .Open_pipes
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?
+            .clone())
+    }
+
     pub fn Read(
         &self,
         File: Local_file_identifier_type,
         Buffer: &mut [u8],
     ) -> Result_type<(Size_type, Option<Unique_file_identifier_type>)> {
-        let Inner = self.0.read()?;
-
-        let (Pipe, Flags, Underlying_file) = Inner
-            .Open_pipes
-            .get(&File)
-            .ok_or(Error_type::Invalid_identifier)?;
+        let (Pipe, Flags, Underlying_file) = self.Get_open_pipe(File)?;
 
         if !Flags.Get_mode().Get_read() {
             return Err(Error_type::Invalid_mode);
         }
 
         if Flags.Get_status().Get_non_blocking() {
-            return Ok((Pipe.Read(Buffer)?, *Underlying_file));
+            return Ok((Pipe.Read(Buffer)?, Underlying_file));
         }
 
         loop {
             // Wait for the pipe to be ready
-            if let Ok(Size) = Pipe.Read(Buffer) {
-                return Ok((Size, *Underlying_file));
+            match Pipe.Read(Buffer) {
+                Ok(Size) => return Ok((Size, Underlying_file)),
+                Err(Error_type::Ressource_busy) => {}
+                Err(Error) => return Err(Error),
             }
 
             Task::Manager_type::Sleep(Duration::from_millis(1));
@@ -266,59 +296,133 @@ impl File_system_type {
         File: Local_file_identifier_type,
         Buffer: &mut String,
     ) -> Result_type<(Size_type, Option<Unique_file_identifier_type>)> {
-        let mut Inner = self.0.write()?;
-
-        let (Pipe, Flags, Underlying_file) = Inner
-            .Open_pipes
-            .get_mut(&File)
-            .ok_or(Error_type::Invalid_identifier)?;
+        let (Pipe, Flags, Underlying_file) = self.Get_open_pipe(File)?;
 
         if !Flags.Get_mode().Get_read() {
             return Err(Error_type::Invalid_mode);
         }
 
         if Flags.Get_status().Get_non_blocking() {
-            return Ok((Pipe.Read_line(Buffer)?, *Underlying_file));
+            return Ok((Pipe.Read_line(Buffer)?, Underlying_file));
         }
 
         loop {
             // Wait for the pipe to be ready
-            if let Ok(Size) = Pipe.Read_line(Buffer) {
-                return Ok((Size, *Underlying_file));
+            match Pipe.Read_line(Buffer) {
+                Ok(Size) => return Ok((Size, Underlying_file)),
+                Err(Error_type::Ressource_busy) => {}
+                Err(Error) => return Err(Error),
             }
 
             Task::Manager_type::Sleep(Duration::from_millis(1));
         }
     }
 
+    /// Write to a pipe.
+    ///
+    /// In blocking mode, wait until all the data is written, otherwise write what fits in the pipe.
+    /// Fail with [`Error_type::Broken_pipe`] if the read ends are all closed.
     pub fn Write(
         &self,
         File: Local_file_identifier_type,
         Buffer: &[u8],
     ) -> Result_type<(Size_type, Option<Unique_file_identifier_type>)> {
-        let Inner = self.0.read()?;
-
-        let (Pipe, Flags, Underlying_file) = Inner
-            .Open_pipes
-            .get(&File)
-            .ok_or(Error_type::Invalid_identifier)?;
+        let (Pipe, Flags, Underlying_file) = self.Get_open_pipe(File)?;
 
         if !Flags.Get_mode().Get_write() {
             return Err(Error_type::Invalid_mode);
         }
 
         if Flags.Get_status().Get_non_blocking() {
-            return Ok((Pipe.Write(Buffer)?, *Underlying_file));
+            return Ok((Pipe.Write(Buffer)?, Underlying_file));
         }
 
-        loop {
+        let mut Written = 0;
+
+        while Written < Buffer.len() {
             // Wait for the pipe to be ready
-            if let Ok(Size) = Pipe.Write(Buffer) {
-                return Ok((Size, *Underlying_file));
+            match Pipe.Write(&Buffer[Written..]) {
+                Ok(Size) => {
+                    Written += usize::from(Size);
+                    continue;
+                }
+                Err(Error_type::Ressource_busy) => {}
+                Err(Error) => return Err(Error),
             }
 
             Task::Manager_type::Sleep(Duration::from_millis(1));
         }
+
+        Ok((Size_type::New(Written as u64), Underlying_file))
+    }
+
+    /// Get the events that are ready on a pipe end (only the events allowed by its mode).
+    pub fn Get_events(&self, File: Local_file_identifier_type) -> Result_type<Events_type> {
+        let (Pipe, Flags, _) = self.Get_open_pipe(File)?;
+
+        let Mode = Flags.Get_mode();
+
+        let Events = Pipe.Get_events()?;
+
+        Ok(Events
+            .Set_readable(Events.Get_readable() && Mode.Get_read())
+            .Set_writable(Events.Get_writable() && Mode.Get_write()))
+    }
+
+    pub fn Get_status(&self, File: Local_file_identifier_type) -> Result_type<Status_type> {
+        Ok(self.Get_open_pipe(File)?.1.Get_status())
+    }
+
+    pub fn Set_status(
+        &self,
+        File: Local_file_identifier_type,
+        Status: Status_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.0.write()?;
+
+        let (_, Flags, _) = Inner
+            .Open_pipes
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        *Flags = Flags.Set_status(Status);
+
+        Ok(())
+    }
+
+    /// Get the statistics of the pipes buffers : one block per byte, the named pipes being the inodes.
+    pub fn Get_file_system_statistics(&self) -> Result_type<File_system_statistics_type> {
+        let Inner = self.0.read()?;
+
+        let mut Pipes: Vec<&Pipe_type> = Vec::new();
+
+        for Pipe in Inner
+            .Named_pipes
+            .values()
+            .chain(Inner.Open_pipes.values().map(|(Pipe, _, _)| Pipe))
+        {
+            if !Pipes.iter().any(|Other| Other.Is_same(Pipe)) {
+                Pipes.push(Pipe);
+            }
+        }
+
+        let (mut Total, mut Free) = (0, 0);
+
+        for Pipe in Pipes {
+            let (Used, Size) = Pipe.Get_usage()?;
+
+            Total += Size as u64;
+            Free += Size.saturating_sub(Used) as u64;
+        }
+
+        Ok(File_system_statistics_type::New(
+            1,
+            Total,
+            Free,
+            Free,
+            Inner.Named_pipes.len() as u64,
+            0,
+        ))
     }
 
     pub fn Get_mode(&self, File: Local_file_identifier_type) -> Result_type<Mode_type> {
@@ -417,6 +521,36 @@ mod Tests {
         assert!(!fs.0.read().unwrap().Open_pipes.contains_key(&read_file));
     }
 
+    #[test]
+    fn Test_transfert_to_used_file_identifier() {
+        let fs = File_system_type::New();
+        let task_id = Task_identifier_type::New(0);
+        let new_task_id = Task_identifier_type::New(1);
+        let status = Status_type::default();
+        let buffer_size = 1024;
+
+        let (read_file, write_file) = fs
+            .Create_unnamed_pipe(task_id, status, buffer_size)
+            .unwrap();
+        let (used_file, _) = fs
+            .Create_unnamed_pipe(new_task_id, status, buffer_size)
+            .unwrap();
+
+        assert_eq!(
+            fs.Transfert(new_task_id, read_file, Some(used_file.Split().1)),
+            Err(Error_type::Invalid_identifier)
+        );
+
+        // - The pipe end is still open and usable.
+        assert!(fs.0.read().unwrap().Open_pipes.contains_key(&read_file));
+
+        fs.Write(write_file, b"data").unwrap();
+
+        let mut buffer = [0; 4];
+        fs.Read(read_file, &mut buffer).unwrap();
+        assert_eq!(&buffer, b"data");
+    }
+
     #[test]
     fn Test_delete_named_pipe() {
         let fs = File_system_type::New();
@@ -456,6 +590,125 @@ mod Tests {
         reader.join().unwrap();
     }
 
+    #[test]
+    fn Test_non_blocking_and_events() {
+        let fs = File_system_type::New();
+        let task_id = Task_identifier_type::New(0);
+
+        let (read_file, write_file) = fs
+            .Create_unnamed_pipe(task_id, Status_type::Non_blocking, 4)
+            .unwrap();
+
+        let mut buffer = [0; 4];
+        assert_eq!(
+            fs.Read(read_file, &mut buffer),
+            Err(Error_type::Ressource_busy)
+        );
+        assert_eq!(fs.Get_events(read_file).unwrap(), Events_type::None);
+        assert_eq!(fs.Get_events(write_file).unwrap(), Events_type::Writable);
+
+        assert_eq!(fs.Write(write_file, b"hello").unwrap().0, Size_type::New(4));
+        assert_eq!(fs.Get_events(read_file).unwrap(), Events_type::Readable);
+        assert_eq!(fs.Get_events(write_file).unwrap(), Events_type::None);
+
+        // - Switch the write end to blocking mode : the write waits for the reader.
+        fs.Set_status(write_file, Status_type::None).unwrap();
+        assert_eq!(fs.Get_status(write_file).unwrap(), Status_type::None);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                assert_eq!(
+                    fs.Write(write_file, b" world").unwrap().0,
+                    Size_type::New(6)
+                );
+            });
+
+            let mut received = Vec::new();
+
+            while received.len() < 10 {
+                if let Ok((size, _)) = fs.Read(read_file, &mut buffer) {
+                    received.extend_from_slice(&buffer[..usize::from(size)]);
+                }
+            }
+
+            assert_eq!(received, b"hell world");
+        });
+    }
+
+    #[test]
+    fn Test_end_of_file_and_broken_pipe() {
+        let fs = File_system_type::New();
+        let task_id = Task_identifier_type::New(0);
+        let new_task_id = Task_identifier_type::New(1);
+
+        let (read_file, write_file) = fs
+            .Create_unnamed_pipe(task_id, Status_type::default(), 16)
+            .unwrap();
+
+        // - The duplicated and transferred write ends keep the pipe open.
+        let duplicated_write_file = fs.Duplicate(write_file, None).unwrap();
+        let transferred_write_file = fs.Transfert(new_task_id, write_file, None).unwrap();
+
+        fs.Write(transferred_write_file, b"data").unwrap();
+        fs.Close(transferred_write_file).unwrap();
+
+        let mut buffer = [0; 8];
+        assert_eq!(
+            fs.Read(read_file, &mut buffer).unwrap().0,
+            Size_type::New(4)
+        );
+
+        fs.Close(duplicated_write_file).unwrap();
+
+        // - No writer left : end of file (instead of blocking).
+        assert_eq!(
+            fs.Read(read_file, &mut buffer).unwrap().0,
+            Size_type::New(0)
+        );
+        assert!(fs.Get_events(read_file).unwrap().Get_hang_up());
+
+        // - No reader left : broken pipe.
+        fs.Close(read_file).unwrap();
+
+        let (read_file, write_file) = fs
+            .Create_unnamed_pipe(task_id, Status_type::default(), 16)
+            .unwrap();
+
+        fs.Close(read_file).unwrap();
+
+        assert_eq!(fs.Write(write_file, b"data"), Err(Error_type::Broken_pipe));
+        assert!(fs.Get_events(write_file).unwrap().Get_error());
+    }
+
+    #[test]
+    fn Test_file_system_statistics() {
+        let fs = File_system_type::New();
+        let task_id = Task_identifier_type::New(0);
+
+        fs.Create_named_pipe(32).unwrap();
+
+        let (read_file, write_file) = fs
+            .Create_unnamed_pipe(task_id, Status_type::default(), 16)
+            .unwrap();
+
+        fs.Write(write_file, b"data").unwrap();
+
+        let statistics = fs.Get_file_system_statistics().unwrap();
+
+        // - Both ends of the unnamed pipe share the same buffer.
+        assert_eq!(statistics.Get_total_blocks(), 48);
+        assert_eq!(statistics.Get_free_blocks(), 44);
+        assert_eq!(statistics.Get_total_inodes(), 1);
+
+        fs.Close(read_file).unwrap();
+        fs.Close(write_file).unwrap();
+
+        assert_eq!(
+            fs.Get_file_system_statistics().unwrap().Get_total_blocks(),
+            32
+        );
+    }
+
     #[test]
     fn Test_get_mode() {
         let fs = File_system_type::New();
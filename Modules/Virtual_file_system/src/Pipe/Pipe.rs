@@ -3,69 +3,222 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use File_system::{Error_type, Result_type, Size_type};
+use File_system::{Error_type, Events_type, Mode_type, Result_type, Size_type};
+
+#[derive(Debug)]
+struct Inner_type {
+    Buffer: VecDeque<u8>,
+    /// Maximum count of bytes held by the pipe (the capacity of a `VecDeque` may be larger).
+    Size: usize,
+    /// Count of open read ends.
+    Readers: usize,
+    /// Count of open write ends.
+    Writers: usize,
+}
 
 /// A pipe is a FIFO (ring) buffer that can be used to communicate between tasks.
 #[derive(Debug, Clone)]
-pub struct Pipe_type(Arc<RwLock<VecDeque<u8>>>);
+pub struct Pipe_type(Arc<RwLock<Inner_type>>);
 
 impl Pipe_type {
     /// Create a new pipe with a buffer of the specified size.
     pub fn New(Buffer_size: usize) -> Self {
-        Pipe_type(Arc::new(RwLock::new(VecDeque::with_capacity(Buffer_size))))
+        Pipe_type(Arc::new(RwLock::new(Inner_type {
+            Buffer: VecDeque::with_capacity(Buffer_size),
+            Size: Buffer_size,
+            Readers: 0,
+            Writers: 0,
+        })))
+    }
+
+    /// Register an open end of the pipe (a read-write end counts as both).
+    pub fn Open_end(&self, Mode: Mode_type) -> Result_type<()> {
+        let mut Inner = self.0.write()?;
+
+        if Mode.Get_read() {
+            Inner.Readers += 1;
+        }
+
+        if Mode.Get_write() {
+            Inner.Writers += 1;
+        }
+
+        Ok(())
     }
 
+    /// Unregister a closed end of the pipe.
+    pub fn Close_end(&self, Mode: Mode_type) -> Result_type<()> {
+        let mut Inner = self.0.write()?;
+
+        if Mode.Get_read() {
+            Inner.Readers = Inner.Readers.saturating_sub(1);
+        }
+
+        if Mode.Get_write() {
+            Inner.Writers = Inner.Writers.saturating_sub(1);
+        }
+
+        Ok(())
+    }
+
+    /// Write as much data as the pipe can hold, or return [`Error_type::Ressource_busy`] if the pipe is full.
+    ///
+    /// Return [`Error_type::Broken_pipe`] if there is no read end left.
     pub fn Write(&self, Data: &[u8]) -> Result_type<Size_type> {
-        let mut Buffer = self.0.write()?;
+        let mut Inner = self.0.write()?;
+
+        if Inner.Readers == 0 {
+            return Err(Error_type::Broken_pipe);
+        }
 
-        let Length = Data.len().min(Buffer.capacity() - Buffer.len());
+        let Length = Data.len().min(Inner.Size - Inner.Buffer.len());
 
         if Length == 0 {
             return Err(Error_type::Ressource_busy);
         }
 
-        for Byte in Data {
-            Buffer.push_back(*Byte);
-        }
+        Inner.Buffer.extend(&Data[..Length]);
 
         Ok(Size_type::New(Length as u64))
     }
 
+    /// Read the available data, or return [`Error_type::Ressource_busy`] if the pipe is empty.
+    ///
+    /// Return 0 (end of file) if the pipe is empty and there is no write end left.
     pub fn Read(&self, Data: &mut [u8]) -> Result_type<Size_type> {
-        let mut Buffer = self.0.write()?;
+        let mut Inner = self.0.write()?;
 
-        let Length = Data.len().min(Buffer.len());
+        let Length = Data.len().min(Inner.Buffer.len());
 
         if Length == 0 {
+            if Inner.Writers == 0 || Data.is_empty() {
+                return Ok(Size_type::New(0));
+            }
+
             return Err(Error_type::Ressource_busy);
         }
 
-        for Byte in Data {
-            *Byte = Buffer.pop_front().unwrap();
+        for (Byte, Read) in Data.iter_mut().zip(Inner.Buffer.drain(..Length)) {
+            *Byte = Read;
         }
 
         Ok(Size_type::New(Length as u64))
     }
 
+    /// Read a line (without the line feed).
+    ///
+    /// Return [`Error_type::Ressource_busy`] until a complete line is available, unless the pipe is full
+    /// or there is no write end left (the remaining data is returned, then 0 for the end of file).
     pub fn Read_line(&self, Data: &mut String) -> Result_type<Size_type> {
-        let mut Buffer = self.0.write()?;
+        let mut Inner = self.0.write()?;
 
-        let Length = Data.len().min(Buffer.len());
+        let Length = match Inner.Buffer.iter().position(|Byte| *Byte == b'\n') {
+            Some(Position) => Position + 1,
+            None if Inner.Buffer.len() == Inner.Size || Inner.Writers == 0 => Inner.Buffer.len(),
+            None => return Err(Error_type::Ressource_busy),
+        };
 
-        if Length == 0 {
-            return Err(Error_type::Ressource_busy);
+        for Byte in Inner.Buffer.drain(..Length) {
+            if Byte != b'\n' {
+                Data.push(Byte as char);
+            }
         }
 
-        for _ in 0..Length {
-            let Byte = Buffer.pop_front().unwrap();
+        Ok(Size_type::New(Length as u64))
+    }
 
-            if Byte == b'\n' {
-                break;
-            }
+    /// Get the count of bytes held by the pipe and its size.
+    pub fn Get_usage(&self) -> Result_type<(usize, usize)> {
+        let Inner = self.0.read()?;
 
-            Data.push(Byte as char);
-        }
+        Ok((Inner.Buffer.len(), Inner.Size))
+    }
 
-        Ok(Size_type::New(Length as u64))
+    /// Check if both values refer to the same pipe.
+    pub fn Is_same(&self, Other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &Other.0)
+    }
+
+    /// Get the events that are ready : readable if the pipe holds data, writable if it has free space.
+    ///
+    /// The pipe is hung up when there is no write end left, and in error when there is no read end left.
+    pub fn Get_events(&self) -> Result_type<Events_type> {
+        let Inner = self.0.read()?;
+
+        Ok(Events_type::New(
+            !Inner.Buffer.is_empty(),
+            Inner.Buffer.len() < Inner.Size,
+            Inner.Writers == 0,
+            Inner.Readers == 0,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_partial_read_and_write() {
+        let Pipe = Pipe_type::New(4);
+        Pipe.Open_end(Mode_type::Read_write).unwrap();
+
+        assert_eq!(Pipe.Write(b"abcdef").unwrap(), Size_type::New(4));
+        assert_eq!(Pipe.Write(b"g"), Err(Error_type::Ressource_busy));
+        assert_eq!(Pipe.Get_events().unwrap(), Events_type::Readable);
+
+        let mut Buffer = [0; 8];
+
+        assert_eq!(Pipe.Read(&mut Buffer).unwrap(), Size_type::New(4));
+        assert_eq!(&Buffer[..4], b"abcd");
+        assert_eq!(Pipe.Read(&mut Buffer), Err(Error_type::Ressource_busy));
+        assert_eq!(Pipe.Get_events().unwrap(), Events_type::Writable);
+    }
+
+    #[test]
+    fn Test_read_line() {
+        let Pipe = Pipe_type::New(16);
+        Pipe.Open_end(Mode_type::Read_write).unwrap();
+
+        Pipe.Write(b"Hello").unwrap();
+
+        let mut Line = String::new();
+
+        assert_eq!(Pipe.Read_line(&mut Line), Err(Error_type::Ressource_busy));
+
+        Pipe.Write(b"\nWorld\n").unwrap();
+
+        Pipe.Read_line(&mut Line).unwrap();
+        assert_eq!(Line, "Hello");
+
+        Line.clear();
+        Pipe.Read_line(&mut Line).unwrap();
+        assert_eq!(Line, "World");
+    }
+
+    #[test]
+    fn Test_end_of_file_and_broken_pipe() {
+        let Pipe = Pipe_type::New(16);
+        Pipe.Open_end(Mode_type::Read_only).unwrap();
+        Pipe.Open_end(Mode_type::Write_only).unwrap();
+
+        Pipe.Write(b"Last").unwrap();
+        Pipe.Close_end(Mode_type::Write_only).unwrap();
+
+        assert!(Pipe.Get_events().unwrap().Get_hang_up());
+
+        // - The remaining data is still readable, then the end of file is reached.
+        let mut Line = String::new();
+        assert_eq!(Pipe.Read_line(&mut Line).unwrap(), Size_type::New(4));
+        assert_eq!(Line, "Last");
+
+        let mut Buffer = [0; 4];
+        assert_eq!(Pipe.Read(&mut Buffer).unwrap(), Size_type::New(0));
+
+        Pipe.Open_end(Mode_type::Write_only).unwrap();
+        Pipe.Close_end(Mode_type::Read_only).unwrap();
+
+        assert_eq!(Pipe.Write(b"Data"), Err(Error_type::Broken_pipe));
+        assert!(Pipe.Get_events().unwrap().Get_error());
     }
 }
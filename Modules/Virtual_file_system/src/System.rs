@@ -0,0 +1,744 @@
+//! Read-only file system exposing the live state of the kernel as text files (mounted on `/System`).
+//!
+//! - `Tasks/<Identifier>/{Name, User, Group, Parent, Environment, Signals}`
+//! - `File_systems` : one `<Identifier> <Mount point> <Type> <Source> <Options>` line per mount.
+//! - `Devices` : one line per registered device path.
+//! - `Memory` : total, free and used memory, in bytes.
+//! - `Uptime` : time elapsed since startup, in seconds.
+//!
+//! The content of a file is generated when it is opened, so a read gives a consistent snapshot.
+
+use std::{collections::BTreeMap, fmt::Write, sync::RwLock};
+
+use File_system::{
+    Component_type, Entry_type, Error_type, File_identifier_inner_type, File_identifier_type,
+    File_system_identifier_type, File_system_traits, Flags_type, Get_new_file_identifier,
+    Inode_type, Local_file_identifier_type, Metadata_type, Mode_type, Path_type, Permission_type,
+    Permissions_type, Position_type, Result_type, Size_type, Special_type, Statistics_type,
+    Time_type, Type_type,
+};
+use Memory::Memory_allocator_trait;
+use Task::{Task_identifier_inner_type, Task_identifier_type};
+use Users::{Group_identifier_type, User_identifier_type};
+
+use crate::Virtual_file_system_type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Task_file_type {
+    Name,
+    User,
+    Group,
+    Parent,
+    Environment,
+    Signals,
+}
+
+const Task_files: [(&str, Task_file_type); 6] = [
+    ("Name", Task_file_type::Name),
+    ("User", Task_file_type::User),
+    ("Group", Task_file_type::Group),
+    ("Parent", Task_file_type::Parent),
+    ("Environment", Task_file_type::Environment),
+    ("Signals", Task_file_type::Signals),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Node_type {
+    Root,
+    Tasks,
+    Task(Task_identifier_type),
+    Task_file(Task_identifier_type, Task_file_type),
+    File_systems,
+    Devices,
+    Memory,
+    Uptime,
+}
+
+impl Node_type {
+    fn Get_inode(&self) -> Inode_type {
+        // - Tasks inodes come after the fixed ones, with room for the task files.
+        let Task_inode = |Task: &Task_identifier_type| {
+            0x100 + Task.Into_inner() as u64 * (Task_files.len() as u64 + 1)
+        };
+
+        Inode_type::New(match self {
+            Node_type::Root => 1,
+            Node_type::Tasks => 2,
+            Node_type::File_systems => 3,
+            Node_type::Devices => 4,
+            Node_type::Memory => 5,
+            Node_type::Uptime => 6,
+            Node_type::Task(Task) => Task_inode(Task),
+            Node_type::Task_file(Task, File) => {
+                let Index = Task_files
+                    .iter()
+                    .position(|(_, Task_file)| Task_file == File)
+                    .unwrap_or_default();
+
+                Task_inode(Task) + Index as u64 + 1
+            }
+        })
+    }
+
+    fn Get_type(&self) -> Type_type {
+        match self {
+            Node_type::Root | Node_type::Tasks | Node_type::Task(_) => Type_type::Directory,
+            _ => Type_type::File,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct File_type {
+    Node: Node_type,
+    Content: Vec<u8>,
+    Position: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Directory_type {
+    Node: Node_type,
+    Entries: Vec<(String, Node_type)>,
+    Position: usize,
+}
+
+struct Inner_type {
+    Open_files: BTreeMap<Local_file_identifier_type, File_type>,
+    Open_directories: BTreeMap<Local_file_identifier_type, Directory_type>,
+}
+
+/// File system exposing the state of the kernel, see the module documentation.
+pub struct System_file_system_type {
+    Virtual_file_system: &'static Virtual_file_system_type<'static>,
+    Inner: RwLock<Inner_type>,
+}
+
+impl System_file_system_type {
+    pub fn New(Virtual_file_system: &'static Virtual_file_system_type<'static>) -> Self {
+        Self {
+            Virtual_file_system,
+            Inner: RwLock::new(Inner_type {
+                Open_files: BTreeMap::new(),
+                Open_directories: BTreeMap::new(),
+            }),
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    const Directory_flag: File_identifier_inner_type = 1 << 31;
+    #[cfg(target_pointer_width = "32")]
+    const Directory_flag: File_identifier_inner_type = 1 << 15;
+
+    const Directory_minimum: File_identifier_type = File_identifier_type::New(Self::Directory_flag);
+
+    pub fn Is_file(File: Local_file_identifier_type) -> bool {
+        File.Split().1 < Self::Directory_minimum
+    }
+
+    fn Parse_task(Name: &str) -> Result_type<Task_identifier_type> {
+        let Task = Name
+            .parse::<Task_identifier_inner_type>()
+            .map_err(|_| Error_type::Not_found)?
+            .into();
+
+        // - Check that the task still exists.
+        Task::Get_instance()
+            .Get_task_name(Task)
+            .map_err(|_| Error_type::Not_found)?;
+
+        Ok(Task)
+    }
+
+    fn Resolve(Path: &Path_type) -> Result_type<Node_type> {
+        let mut Components: Vec<&str> = Vec::new();
+
+        for Component in Path.Get_components() {
+            match Component {
+                Component_type::Root | Component_type::Current => {}
+                Component_type::Parent => {
+                    Components.pop();
+                }
+                Component_type::Normal(Name) => Components.push(Name),
+            }
+        }
+
+        match Components.as_slice() {
+            [] => Ok(Node_type::Root),
+            ["Tasks"] => Ok(Node_type::Tasks),
+            ["Tasks", Task] => Ok(Node_type::Task(Self::Parse_task(Task)?)),
+            ["Tasks", Task, File] => {
+                let Task = Self::Parse_task(Task)?;
+
+                Task_files
+                    .iter()
+                    .find(|(Name, _)| Name == File)
+                    .map(|(_, File)| Node_type::Task_file(Task, *File))
+                    .ok_or(Error_type::Not_found)
+            }
+            ["File_systems"] => Ok(Node_type::File_systems),
+            ["Devices"] => Ok(Node_type::Devices),
+            ["Memory"] => Ok(Node_type::Memory),
+            ["Uptime"] => Ok(Node_type::Uptime),
+            _ => Err(Error_type::Not_found),
+        }
+    }
+
+    fn Get_entries(Node: Node_type) -> Result_type<Vec<(String, Node_type)>> {
+        let Entries = match Node {
+            Node_type::Root => vec![
+                ("Tasks".to_string(), Node_type::Tasks),
+                ("File_systems".to_string(), Node_type::File_systems),
+                ("Devices".to_string(), Node_type::Devices),
+                ("Memory".to_string(), Node_type::Memory),
+                ("Uptime".to_string(), Node_type::Uptime),
+            ],
+            Node_type::Tasks => Task::Get_instance()
+                .Get_tasks()?
+                .into_iter()
+                .map(|Task| (Task.Into_inner().to_string(), Node_type::Task(Task)))
+                .collect(),
+            Node_type::Task(Task) => Task_files
+                .iter()
+                .map(|(Name, File)| (Name.to_string(), Node_type::Task_file(Task, *File)))
+                .collect(),
+            _ => return Err(Error_type::Not_directory),
+        };
+
+        Ok(Entries)
+    }
+
+    fn Get_content(&self, Node: Node_type) -> Result_type<String> {
+        let mut Content = String::new();
+
+        let Task_manager = Task::Get_instance();
+
+        // - Writing to a string can't fail.
+        match Node {
+            Node_type::Task_file(Task, File) => match File {
+                Task_file_type::Name => {
+                    let _ = writeln!(Content, "{}", Task_manager.Get_task_name(Task)?);
+                }
+                Task_file_type::User => {
+                    let _ = writeln!(Content, "{}", Task_manager.Get_user(Task)?.As_u16());
+                }
+                Task_file_type::Group => {
+                    let _ = writeln!(Content, "{}", Task_manager.Get_group(Task)?.As_u16());
+                }
+                Task_file_type::Parent => {
+                    let _ = writeln!(
+                        Content,
+                        "{}",
+                        Task_manager.Get_parent_task(Task)?.Into_inner()
+                    );
+                }
+                Task_file_type::Environment => {
+                    for Variable in Task_manager.Get_environment_variables(Task)? {
+                        let _ =
+                            writeln!(Content, "{}={}", Variable.Get_name(), Variable.Get_value());
+                    }
+                }
+                Task_file_type::Signals => {
+                    for Signal in Task_manager.Get_pending_signals(Task)? {
+                        let _ = writeln!(Content, "{:?}", Signal);
+                    }
+                }
+            },
+            Node_type::File_systems => {
+                for Mount in self.Virtual_file_system.Get_mounts()? {
+                    let _ = writeln!(
+                        Content,
+                        "{} {} {} {} {}",
+                        Mount.Get_identifier().As_inner(),
+                        Mount.Get_mount_point(),
+                        Mount.Get_type(),
+                        Mount.Get_source(),
+                        Mount.Get_options()
+                    );
+                }
+            }
+            Node_type::Devices => {
+                for Path in self.Virtual_file_system.Get_devices()? {
+                    let _ = writeln!(Content, "{}", AsRef::<str>::as_ref(&Path));
+                }
+            }
+            Node_type::Memory => {
+                let Statistics = Memory::Allocator
+                    .Get_statistics()
+                    .ok_or(Error_type::Unsupported_operation)?;
+
+                let _ = writeln!(Content, "Total: {}", Statistics.Get_total());
+                let _ = writeln!(Content, "Free: {}", Statistics.Get_free());
+                let _ = writeln!(Content, "Used: {}", Statistics.Get_used());
+            }
+            Node_type::Uptime => {
+                let Uptime = Time::Get_instance()
+                    .Get_current_time_since_startup()
+                    .map_err(|_| Error_type::Time_error)?;
+
+                let _ = writeln!(
+                    Content,
+                    "{}.{:03}",
+                    Uptime.As_seconds(),
+                    Uptime.As_milliseconds() % 1000
+                );
+            }
+            Node_type::Root | Node_type::Tasks | Node_type::Task(_) => {
+                return Err(Error_type::Is_directory)
+            }
+        }
+
+        Ok(Content)
+    }
+
+    fn Get_node_metadata(Node: Node_type) -> Result_type<Metadata_type> {
+        let Time: Time_type = Time::Get_instance()
+            .Get_current_time()
+            .map(Into::into)
+            .unwrap_or(Time_type::New(0));
+
+        let Permission = match Node.Get_type() {
+            Type_type::Directory => Permission_type::Read_execute,
+            _ => Permission_type::Read_only,
+        };
+
+        let mut Metadata = Metadata_type::Get_default(
+            Node.Get_type(),
+            Time,
+            User_identifier_type::Root,
+            Group_identifier_type::Root,
+        )
+        .ok_or(Error_type::Internal_error)?;
+
+        Metadata.Set_inode(Node.Get_inode());
+        Metadata.Set_permissions(Permissions_type::New(
+            Permission,
+            Permission,
+            Permission,
+            Special_type::None,
+        ));
+
+        Ok(Metadata)
+    }
+
+    fn Get_open_node(&self, File: Local_file_identifier_type) -> Result_type<(Node_type, usize)> {
+        let Inner = self.Inner.read()?;
+
+        if let Some(File) = Inner.Open_files.get(&File) {
+            Ok((File.Node, File.Content.len()))
+        } else if let Some(Directory) = Inner.Open_directories.get(&File) {
+            Ok((Directory.Node, 0))
+        } else {
+            Err(Error_type::Invalid_identifier)
+        }
+    }
+}
+
+impl File_system_traits for System_file_system_type {
+    fn Open(
+        &self,
+        Task: Task_identifier_type,
+        Path: &Path_type,
+        Flags: Flags_type,
+        _: Time_type,
+        _: User_identifier_type,
+        _: Group_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let Open = Flags.Get_open();
+
+        if Flags.Get_mode().Get_write() || Open.Get_truncate() {
+            return Err(Error_type::Permission_denied);
+        }
+
+        let Node = match Self::Resolve(Path) {
+            Ok(Node) => Node,
+            Err(Error_type::Not_found) if Open.Get_create() => {
+                return Err(Error_type::Permission_denied)
+            }
+            Err(Error) => return Err(Error),
+        };
+
+        if Open.Get_exclusive() {
+            return Err(Error_type::Already_exists);
+        }
+
+        // - Generated before locking, since it may query other file systems.
+        let Content = self.Get_content(Node)?.into_bytes();
+
+        let mut Inner = self.Inner.write()?;
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(File_identifier_type::Minimum),
+            Some(Self::Directory_minimum),
+            &Inner.Open_files,
+        )?;
+
+        let File = File_type {
+            Node,
+            Content,
+            Position: 0,
+        };
+
+        if Inner.Open_files.insert(File_identifier, File).is_some() {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Close(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        self.Inner
+            .write()?
+            .Open_files
+            .remove(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(())
+    }
+
+    fn Close_all(&self, Task: Task_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        Inner.Open_files.retain(|Key, _| Key.Split().0 != Task);
+        Inner
+            .Open_directories
+            .retain(|Key, _| Key.Split().0 != Task);
+
+        Ok(())
+    }
+
+    fn Duplicate(
+        &self,
+        File: Local_file_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let (Task, _) = File.Split();
+
+        let mut Inner = self.Inner.write()?;
+
+        let File = Inner
+            .Open_files
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?
+            .clone();
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(File_identifier_type::Minimum),
+            Some(Self::Directory_minimum),
+            &Inner.Open_files,
+        )?;
+
+        if Inner.Open_files.insert(File_identifier, File).is_some() {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Transfert(
+        &self,
+        New_task: Task_identifier_type,
+        File_identifier: Local_file_identifier_type,
+        New_file: Option<File_identifier_type>,
+    ) -> Result_type<Local_file_identifier_type> {
+        let mut Inner = self.Inner.write()?;
+
+        if Self::Is_file(File_identifier) {
+            let File = Inner
+                .Open_files
+                .remove(&File_identifier)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            let New_identifier = match New_file {
+                Some(New_file) => Local_file_identifier_type::New(New_task, New_file),
+                None => Get_new_file_identifier(
+                    New_task,
+                    Some(File_identifier_type::Minimum),
+                    Some(Self::Directory_minimum),
+                    &Inner.Open_files,
+                )?,
+            };
+
+            if Inner.Open_files.contains_key(&New_identifier) {
+                Inner.Open_files.insert(File_identifier, File);
+
+                return Err(Error_type::Invalid_identifier);
+            }
+
+            Inner.Open_files.insert(New_identifier, File);
+
+            Ok(New_identifier)
+        } else {
+            let Directory = Inner
+                .Open_directories
+                .remove(&File_identifier)
+                .ok_or(Error_type::Invalid_identifier)?;
+
+            let New_identifier = match New_file {
+                Some(New_file) => Local_file_identifier_type::New(New_task, New_file),
+                None => Get_new_file_identifier(
+                    New_task,
+                    Some(Self::Directory_minimum),
+                    Some(File_identifier_type::Maximum),
+                    &Inner.Open_directories,
+                )?,
+            };
+
+            if Inner.Open_directories.contains_key(&New_identifier) {
+                Inner.Open_directories.insert(File_identifier, Directory);
+
+                return Err(Error_type::Invalid_identifier);
+            }
+
+            Inner.Open_directories.insert(New_identifier, Directory);
+
+            Ok(New_identifier)
+        }
+    }
+
+    fn Remove(&self, _: &Path_type) -> Result_type<()> {
+        Err(Error_type::Permission_denied)
+    }
+
+    fn Read(
+        &self,
+        File: Local_file_identifier_type,
+        Buffer: &mut [u8],
+        _: Time_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        let Size = Buffer
+            .len()
+            .min(File.Content.len().saturating_sub(File.Position));
+
+        Buffer[..Size].copy_from_slice(&File.Content[File.Position..File.Position + Size]);
+
+        File.Position += Size;
+
+        Ok(Size.into())
+    }
+
+    fn Write(
+        &self,
+        File: Local_file_identifier_type,
+        _: &[u8],
+        _: Time_type,
+    ) -> Result_type<Size_type> {
+        if !self.Inner.read()?.Open_files.contains_key(&File) {
+            return Err(Error_type::Invalid_identifier);
+        }
+
+        Err(Error_type::Invalid_mode)
+    }
+
+    fn Rename(&self, _: &Path_type, _: &Path_type) -> Result_type<()> {
+        Err(Error_type::Permission_denied)
+    }
+
+    fn Set_position(
+        &self,
+        File: Local_file_identifier_type,
+        Position: &Position_type,
+    ) -> Result_type<Size_type> {
+        let mut Inner = self.Inner.write()?;
+
+        let File = Inner
+            .Open_files
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        let New_position = match Position {
+            Position_type::Start(Position) => *Position as i128,
+            Position_type::Current(Offset) => File.Position as i128 + *Offset as i128,
+            Position_type::End(Offset) => File.Content.len() as i128 + *Offset as i128,
+        };
+
+        if New_position < 0 || New_position > usize::MAX as i128 {
+            return Err(Error_type::Invalid_parameter);
+        }
+
+        File.Position = New_position as usize;
+
+        Ok(Size_type::New(File.Position as u64))
+    }
+
+    fn Flush(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        if !self.Inner.read()?.Open_files.contains_key(&File) {
+            return Err(Error_type::Invalid_identifier);
+        }
+
+        Ok(())
+    }
+
+    fn Create_directory(
+        &self,
+        _: &Path_type,
+        _: Time_type,
+        _: User_identifier_type,
+        _: Group_identifier_type,
+    ) -> Result_type<()> {
+        Err(Error_type::Permission_denied)
+    }
+
+    fn Open_directory(
+        &self,
+        Path: &Path_type,
+        Task: Task_identifier_type,
+    ) -> Result_type<Local_file_identifier_type> {
+        let Node = Self::Resolve(Path)?;
+
+        let Entries = Self::Get_entries(Node)?;
+
+        let mut Inner = self.Inner.write()?;
+
+        let File_identifier = Get_new_file_identifier(
+            Task,
+            Some(Self::Directory_minimum),
+            Some(File_identifier_type::Maximum),
+            &Inner.Open_directories,
+        )?;
+
+        let Directory = Directory_type {
+            Node,
+            Entries,
+            Position: 0,
+        };
+
+        if Inner
+            .Open_directories
+            .insert(File_identifier, Directory)
+            .is_some()
+        {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(File_identifier)
+    }
+
+    fn Read_directory(&self, File: Local_file_identifier_type) -> Result_type<Option<Entry_type>> {
+        let mut Inner = self.Inner.write()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        // - The "." and ".." entries come first.
+        let (Name, Node) = match Directory.Position {
+            0 => (".".to_string(), Directory.Node),
+            1 => {
+                let Parent = match Directory.Node {
+                    Node_type::Task(_) => Node_type::Tasks,
+                    _ => Node_type::Root,
+                };
+
+                ("..".to_string(), Parent)
+            }
+            Position => match Directory.Entries.get(Position - 2) {
+                Some(Entry) => Entry.clone(),
+                None => return Ok(None),
+            },
+        };
+
+        Directory.Position += 1;
+
+        Ok(Some(Entry_type::New(
+            Node.Get_inode(),
+            Name,
+            Node.Get_type(),
+            Size_type::New(0),
+        )))
+    }
+
+    fn Set_position_directory(
+        &self,
+        File: Local_file_identifier_type,
+        Position: Size_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.Inner.write()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get_mut(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Directory.Position = Position.into();
+
+        Ok(())
+    }
+
+    fn Get_position_directory(&self, File: Local_file_identifier_type) -> Result_type<Size_type> {
+        let Inner = self.Inner.read()?;
+
+        let Directory = Inner
+            .Open_directories
+            .get(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(Directory.Position.into())
+    }
+
+    fn Rewind_directory(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        self.Set_position_directory(File, Size_type::New(0))
+    }
+
+    fn Close_directory(&self, File: Local_file_identifier_type) -> Result_type<()> {
+        self.Inner
+            .write()?
+            .Open_directories
+            .remove(&File)
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(())
+    }
+
+    fn Get_metadata(&self, File: Local_file_identifier_type) -> Result_type<Metadata_type> {
+        let (Node, _) = self.Get_open_node(File)?;
+
+        Self::Get_node_metadata(Node)
+    }
+
+    fn Set_metadata_from_path(&self, _: &Path_type, _: &Metadata_type) -> Result_type<()> {
+        Err(Error_type::Permission_denied)
+    }
+
+    fn Get_metadata_from_path(&self, Path: &Path_type) -> Result_type<Metadata_type> {
+        Self::Get_node_metadata(Self::Resolve(Path)?)
+    }
+
+    fn Get_statistics(&self, File: Local_file_identifier_type) -> Result_type<Statistics_type> {
+        let (Node, Size) = self.Get_open_node(File)?;
+
+        let Metadata = Self::Get_node_metadata(Node)?;
+
+        Ok(Statistics_type::New(
+            File_system_identifier_type::New(0),
+            Node.Get_inode(),
+            1,
+            Size_type::New(Size as u64),
+            Metadata.Get_access_time(),
+            Metadata.Get_modification_time(),
+            Metadata.Get_modification_time(),
+            Metadata.Get_type(),
+            Metadata.Get_permissions(),
+            Metadata.Get_user(),
+            Metadata.Get_group(),
+        ))
+    }
+
+    fn Get_mode(&self, File: Local_file_identifier_type) -> Result_type<Mode_type> {
+        self.Get_open_node(File)?;
+
+        Ok(Mode_type::Read_only)
+    }
+
+    fn Get_type_name(&self) -> &'static str {
+        "System"
+    }
+}
@@ -2,21 +2,29 @@
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
 
+mod Clock;
 mod Device;
 mod Directory;
 mod Error;
 mod File;
 mod File_system;
 mod Hierarchy;
+mod Interfaces;
+mod Mount;
 mod Pipe;
 mod Socket;
+mod System;
 
+pub use Clock::*;
 pub use Directory::*;
 pub use Error::*;
 pub use File::*;
 pub use File_system::*;
 pub use Hierarchy::*;
+pub use Interfaces::*;
+pub use Mount::*;
 pub use Socket::Socket_address_type;
+pub use System::*;
 
 #[macro_export]
 macro_rules! Mount_static_devices {
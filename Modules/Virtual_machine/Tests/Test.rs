@@ -99,6 +99,7 @@ fn Integration_test() {
         &Runtime,
         Binary_buffer.to_vec(),
         "main",
+        &["main"],
         Standard_in,
         Standard_out,
         Standard_error,
@@ -127,6 +127,16 @@ extern "C"
     typedef uint64_t os_raw_file_handle;
     typedef uint64_t os_file_handle;
 
+    /* Handle to poll, the events use the POSIX flags (POLLIN, POLLOUT, POLLHUP and POLLERR) */
+    typedef struct
+    {
+        os_file_handle fd;
+        short events;
+        short revents;
+    } os_poll_file_handle;
+
+    typedef unsigned int os_nfds_t;
+
     uint64_t os_get_invalid_handle();
 
     int os_getpagesize();
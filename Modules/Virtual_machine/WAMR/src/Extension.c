@@ -459,6 +459,22 @@ int os_wakeup_blocking_op(korp_tid tid)
  * need to implement these APIs
  */
 
+/**
+ * Set errno from the result of a Xila function, for the socket functions reporting their errors through errno
+ * (e.g. EINPROGRESS for a non-blocking connect or EAGAIN on a receive timeout).
+ *
+ * @return 0 if success, -1 otherwise
+ */
+static int Into_socket_result(Xila_file_system_result_type Result)
+{
+    if (Result == 0)
+        return 0;
+
+    errno = Into_errno(Result);
+
+    return -1;
+}
+
 /**
  * Create a socket
  *
@@ -470,7 +486,10 @@ int os_wakeup_blocking_op(korp_tid tid)
  */
 int os_socket_create(bh_socket_t *sock, bool is_ipv4, bool is_tcp)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_create_socket(is_ipv4, is_tcp, sock)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -486,7 +505,22 @@ int os_socket_create(bh_socket_t *sock, bool is_ipv4, bool is_tcp)
  */
 int os_socket_bind(bh_socket_t socket, const char *addr, int *port)
 {
-    return -1;
+    Xila_network_ip_address_type Address;
+
+    if (Into_socket_result(Xila_network_parse_ip_address(addr, NULL, &Address)) != 0)
+        return -1;
+
+    if (Into_socket_result(Xila_file_system_bind(socket, &Address, (uint16_t)*port)) != 0)
+        return -1;
+
+    uint16_t Bound_port;
+
+    if (Into_socket_result(Xila_file_system_get_local_address(socket, NULL, &Bound_port)) != 0)
+        return -1;
+
+    *port = Bound_port;
+
+    return 0;
 }
 
 /**
@@ -499,7 +533,7 @@ int os_socket_bind(bh_socket_t socket, const char *addr, int *port)
  */
 int os_socket_settimeout(bh_socket_t socket, uint64 timeout_us)
 {
-    return -1;
+    return os_socket_set_recv_timeout(socket, timeout_us);
 }
 
 /**
@@ -512,7 +546,10 @@ int os_socket_settimeout(bh_socket_t socket, uint64 timeout_us)
  */
 int os_socket_listen(bh_socket_t socket, int max_client)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_listen(socket, max_client)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -531,7 +568,14 @@ int os_socket_listen(bh_socket_t socket, int max_client)
 int os_socket_accept(bh_socket_t server_sock, bh_socket_t *sock, void *addr,
                      unsigned int *addrlen)
 {
-    return -1;
+    // - The peer address is retrieved with os_socket_addr_remote, not in the native format.
+    if (addrlen != NULL)
+        *addrlen = 0;
+
+    if (Into_socket_result(Xila_file_system_accept(server_sock, sock, NULL, NULL)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -544,7 +588,15 @@ int os_socket_accept(bh_socket_t server_sock, bh_socket_t *sock, void *addr,
  */
 int os_socket_connect(bh_socket_t socket, const char *addr, int port)
 {
-    return -1;
+    Xila_network_ip_address_type Address;
+
+    if (Into_socket_result(Xila_network_parse_ip_address(addr, NULL, &Address)) != 0)
+        return -1;
+
+    if (Into_socket_result(Xila_file_system_connect(socket, &Address, (uint16_t)port)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -559,7 +611,12 @@ int os_socket_connect(bh_socket_t socket, const char *addr, int port)
  */
 int os_socket_recv(bh_socket_t socket, void *buf, unsigned int len)
 {
-    return -1;
+    size_t Received = 0;
+
+    if (Into_socket_result(Xila_file_system_receive(socket, buf, len, &Received)) != 0)
+        return -1;
+
+    return (int)Received;
 }
 
 /**
@@ -577,7 +634,17 @@ int os_socket_recv(bh_socket_t socket, void *buf, unsigned int len)
 int os_socket_recv_from(bh_socket_t socket, void *buf, unsigned int len, int flags,
                         bh_sockaddr_t *src_addr)
 {
-    return -1;
+    size_t Received = 0;
+    Xila_network_ip_address_type Address;
+    uint16_t Port;
+
+    if (Into_socket_result(Xila_file_system_receive_from(socket, buf, len, &Received, &Address, &Port)) != 0)
+        return -1;
+
+    if (src_addr != NULL)
+        Into_WASI_socket_address(&Address, Port, src_addr);
+
+    return (int)Received;
 }
 
 /**
@@ -591,7 +658,10 @@ int os_socket_recv_from(bh_socket_t socket, void *buf, unsigned int len, int fla
  */
 int os_socket_send(bh_socket_t socket, const void *buf, unsigned int len)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_send(socket, buf, len)) != 0)
+        return -1;
+
+    return (int)len;
 }
 
 /**
@@ -608,8 +678,12 @@ int os_socket_send(bh_socket_t socket, const void *buf, unsigned int len)
 int os_socket_send_to(bh_socket_t socket, const void *buf, unsigned int len,
                       int flags, const bh_sockaddr_t *dest_addr)
 {
+    Xila_network_ip_address_type Address = Into_Xila_IP_address(&dest_addr->addr_buffer, dest_addr->is_ipv4);
 
-    return -1;
+    if (Into_socket_result(Xila_file_system_send_to(socket, buf, len, &Address, dest_addr->port)) != 0)
+        return -1;
+
+    return (int)len;
 }
 
 /**
@@ -621,7 +695,9 @@ int os_socket_send_to(bh_socket_t socket, const void *buf, unsigned int len,
  */
 int os_socket_close(bh_socket_t socket)
 {
-    return -1;
+    Xila_file_system_close(socket);
+
+    return 0;
 }
 
 /**
@@ -634,7 +710,7 @@ int os_socket_close(bh_socket_t socket)
 __wasi_errno_t
 os_socket_shutdown(bh_socket_t socket)
 {
-    return -1;
+    return Into_WASI_Error(Xila_file_system_shutdown(socket, Xila_network_shutdown_type_Both));
 }
 
 /**
@@ -654,7 +730,18 @@ os_socket_shutdown(bh_socket_t socket)
  */
 int os_socket_inet_network(bool is_ipv4, const char *cp, bh_ip_addr_buffer_t *out)
 {
-    return -1;
+    Xila_network_ip_address_type Address;
+
+    if (Into_socket_result(Xila_network_parse_ip_address(cp, &is_ipv4, &Address)) != 0)
+        return -1;
+
+    if (is_ipv4)
+        out->ipv4 = Address.IPv4;
+    else
+        for (size_t Segment = 0; Segment < 8; Segment++)
+            out->ipv6[Segment] = Address.IPv6[Segment];
+
+    return 0;
 }
 
 /**
@@ -684,7 +771,59 @@ int os_socket_addr_resolve(const char *host, const char *service,
                            bh_addr_info_t *addr_info, size_t addr_info_size,
                            size_t *max_info_size)
 {
-    return -1;
+#define Maximum_resolved_addresses 16
+
+    uint32_t Port = 0;
+
+    if (service != NULL)
+    {
+        // - Only numeric services are supported (no services database).
+        for (const char *Character = service; *Character != '\0'; Character++)
+        {
+            if (*Character < '0' || *Character > '9')
+            {
+                errno = EINVAL;
+                return -1;
+            }
+
+            Port = Port * 10 + (*Character - '0');
+
+            if (Port > UINT16_MAX)
+            {
+                errno = EINVAL;
+                return -1;
+            }
+        }
+    }
+
+    bool Is_IPv4;
+    const bool *Hint = NULL;
+
+    if (hint_is_ipv4 != NULL)
+    {
+        Is_IPv4 = *hint_is_ipv4;
+        Hint = &Is_IPv4;
+    }
+
+    Xila_network_ip_address_type Addresses[Maximum_resolved_addresses];
+    size_t Count = 0;
+
+    if (Into_socket_result(Xila_network_resolve(host, Hint, Addresses, Maximum_resolved_addresses, &Count)) != 0)
+        return -1;
+
+    if (Count > Maximum_resolved_addresses)
+        Count = Maximum_resolved_addresses;
+
+    for (size_t Index = 0; Index < Count && Index < addr_info_size; Index++)
+    {
+        Into_WASI_socket_address(&Addresses[Index], (uint16_t)Port, &addr_info[Index].sockaddr);
+
+        addr_info[Index].is_tcp = hint_is_tcp == NULL ? 1 : *hint_is_tcp;
+    }
+
+    *max_info_size = Count;
+
+    return 0;
 }
 
 /**
@@ -698,7 +837,15 @@ int os_socket_addr_resolve(const char *host, const char *service,
  */
 int os_socket_addr_local(bh_socket_t socket, bh_sockaddr_t *sockaddr)
 {
-    return -1;
+    Xila_network_ip_address_type Address;
+    uint16_t Port;
+
+    if (Into_socket_result(Xila_file_system_get_local_address(socket, &Address, &Port)) != 0)
+        return -1;
+
+    Into_WASI_socket_address(&Address, Port, sockaddr);
+
+    return 0;
 }
 
 /**
@@ -712,7 +859,15 @@ int os_socket_addr_local(bh_socket_t socket, bh_sockaddr_t *sockaddr)
  */
 int os_socket_addr_remote(bh_socket_t socket, bh_sockaddr_t *sockaddr)
 {
-    return -1;
+    Xila_network_ip_address_type Address;
+    uint16_t Port;
+
+    if (Into_socket_result(Xila_file_system_get_remote_address(socket, &Address, &Port)) != 0)
+        return -1;
+
+    Into_WASI_socket_address(&Address, Port, sockaddr);
+
+    return 0;
 }
 
 /**
@@ -725,7 +880,10 @@ int os_socket_addr_remote(bh_socket_t socket, bh_sockaddr_t *sockaddr)
  */
 int os_socket_set_send_buf_size(bh_socket_t socket, size_t bufsiz)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_socket_option(socket, Xila_network_socket_option_type_Send_buffer_size, bufsiz)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -738,7 +896,14 @@ int os_socket_set_send_buf_size(bh_socket_t socket, size_t bufsiz)
  */
 int os_socket_get_send_buf_size(bh_socket_t socket, size_t *bufsiz)
 {
-    return -1;
+    size_t Value;
+
+    if (Into_socket_result(Xila_file_system_get_socket_option(socket, Xila_network_socket_option_type_Send_buffer_size, &Value)) != 0)
+        return -1;
+
+    *bufsiz = Value;
+
+    return 0;
 }
 
 /**
@@ -751,7 +916,10 @@ int os_socket_get_send_buf_size(bh_socket_t socket, size_t *bufsiz)
  */
 int os_socket_set_recv_buf_size(bh_socket_t socket, size_t bufsiz)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_socket_option(socket, Xila_network_socket_option_type_Receive_buffer_size, bufsiz)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -764,7 +932,14 @@ int os_socket_set_recv_buf_size(bh_socket_t socket, size_t bufsiz)
  */
 int os_socket_get_recv_buf_size(bh_socket_t socket, size_t *bufsiz)
 {
-    return -1;
+    size_t Value;
+
+    if (Into_socket_result(Xila_file_system_get_socket_option(socket, Xila_network_socket_option_type_Receive_buffer_size, &Value)) != 0)
+        return -1;
+
+    *bufsiz = Value;
+
+    return 0;
 }
 
 /**
@@ -777,7 +952,10 @@ int os_socket_get_recv_buf_size(bh_socket_t socket, size_t *bufsiz)
  */
 int os_socket_set_keep_alive(bh_socket_t socket, bool is_enabled)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_socket_option(socket, Xila_network_socket_option_type_Keep_alive, is_enabled)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -791,7 +969,14 @@ int os_socket_set_keep_alive(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_keep_alive(bh_socket_t socket, bool *is_enabled)
 {
-    return -1;
+    size_t Value;
+
+    if (Into_socket_result(Xila_file_system_get_socket_option(socket, Xila_network_socket_option_type_Keep_alive, &Value)) != 0)
+        return -1;
+
+    *is_enabled = Value != 0;
+
+    return 0;
 }
 
 /**
@@ -804,7 +989,10 @@ int os_socket_get_keep_alive(bh_socket_t socket, bool *is_enabled)
  */
 int os_socket_set_send_timeout(bh_socket_t socket, uint64 timeout_us)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_send_timeout(socket, timeout_us)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -817,7 +1005,14 @@ int os_socket_set_send_timeout(bh_socket_t socket, uint64 timeout_us)
  */
 int os_socket_get_send_timeout(bh_socket_t socket, uint64 *timeout_us)
 {
-    return -1;
+    uint64_t Timeout;
+
+    if (Into_socket_result(Xila_file_system_get_send_timeout(socket, &Timeout)) != 0)
+        return -1;
+
+    *timeout_us = Timeout;
+
+    return 0;
 }
 
 /**
@@ -830,7 +1025,10 @@ int os_socket_get_send_timeout(bh_socket_t socket, uint64 *timeout_us)
  */
 int os_socket_set_recv_timeout(bh_socket_t socket, uint64 timeout_us)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_receive_timeout(socket, timeout_us)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -843,7 +1041,14 @@ int os_socket_set_recv_timeout(bh_socket_t socket, uint64 timeout_us)
  */
 int os_socket_get_recv_timeout(bh_socket_t socket, uint64 *timeout_us)
 {
-    return -1;
+    uint64_t Timeout;
+
+    if (Into_socket_result(Xila_file_system_get_receive_timeout(socket, &Timeout)) != 0)
+        return -1;
+
+    *timeout_us = Timeout;
+
+    return 0;
 }
 
 /**
@@ -856,7 +1061,10 @@ int os_socket_get_recv_timeout(bh_socket_t socket, uint64 *timeout_us)
  */
 int os_socket_set_reuse_addr(bh_socket_t socket, bool is_enabled)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_socket_option(socket, Xila_network_socket_option_type_Reuse_address, is_enabled)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -869,7 +1077,14 @@ int os_socket_set_reuse_addr(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_reuse_addr(bh_socket_t socket, bool *is_enabled)
 {
-    return -1;
+    size_t Value;
+
+    if (Into_socket_result(Xila_file_system_get_socket_option(socket, Xila_network_socket_option_type_Reuse_address, &Value)) != 0)
+        return -1;
+
+    *is_enabled = Value != 0;
+
+    return 0;
 }
 
 /**
@@ -882,6 +1097,8 @@ int os_socket_get_reuse_addr(bh_socket_t socket, bool *is_enabled)
  */
 int os_socket_set_reuse_port(bh_socket_t socket, bool is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -895,6 +1112,8 @@ int os_socket_set_reuse_port(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_reuse_port(bh_socket_t socket, bool *is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -908,6 +1127,8 @@ int os_socket_get_reuse_port(bh_socket_t socket, bool *is_enabled)
  */
 int os_socket_set_linger(bh_socket_t socket, bool is_enabled, int linger_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -921,6 +1142,8 @@ int os_socket_set_linger(bh_socket_t socket, bool is_enabled, int linger_s)
  */
 int os_socket_get_linger(bh_socket_t socket, bool *is_enabled, int *linger_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -937,7 +1160,10 @@ int os_socket_get_linger(bh_socket_t socket, bool *is_enabled, int *linger_s)
  */
 int os_socket_set_tcp_no_delay(bh_socket_t socket, bool is_enabled)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_socket_option(socket, Xila_network_socket_option_type_TCP_no_delay, is_enabled)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -953,7 +1179,14 @@ int os_socket_set_tcp_no_delay(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_tcp_no_delay(bh_socket_t socket, bool *is_enabled)
 {
-    return -1;
+    size_t Value;
+
+    if (Into_socket_result(Xila_file_system_get_socket_option(socket, Xila_network_socket_option_type_TCP_no_delay, &Value)) != 0)
+        return -1;
+
+    *is_enabled = Value != 0;
+
+    return 0;
 }
 
 /**
@@ -968,6 +1201,8 @@ int os_socket_get_tcp_no_delay(bh_socket_t socket, bool *is_enabled)
  */
 int os_socket_set_tcp_quick_ack(bh_socket_t socket, bool is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -983,6 +1218,8 @@ int os_socket_set_tcp_quick_ack(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_tcp_quick_ack(bh_socket_t socket, bool *is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -997,6 +1234,8 @@ int os_socket_get_tcp_quick_ack(bh_socket_t socket, bool *is_enabled)
  */
 int os_socket_set_tcp_keep_idle(bh_socket_t socket, uint32_t time_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1011,6 +1250,8 @@ int os_socket_set_tcp_keep_idle(bh_socket_t socket, uint32_t time_s)
  */
 int os_socket_get_tcp_keep_idle(bh_socket_t socket, uint32_t *time_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1024,6 +1265,8 @@ int os_socket_get_tcp_keep_idle(bh_socket_t socket, uint32_t *time_s)
  */
 int os_socket_set_tcp_keep_intvl(bh_socket_t socket, uint32_t time_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1037,6 +1280,8 @@ int os_socket_set_tcp_keep_intvl(bh_socket_t socket, uint32_t time_s)
  */
 int os_socket_get_tcp_keep_intvl(bh_socket_t socket, uint32_t *time_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1050,6 +1295,8 @@ int os_socket_get_tcp_keep_intvl(bh_socket_t socket, uint32_t *time_s)
  */
 int os_socket_set_tcp_fastopen_connect(bh_socket_t socket, bool is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1063,6 +1310,8 @@ int os_socket_set_tcp_fastopen_connect(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_tcp_fastopen_connect(bh_socket_t socket, bool *is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1077,6 +1326,8 @@ int os_socket_get_tcp_fastopen_connect(bh_socket_t socket, bool *is_enabled)
  */
 int os_socket_set_ip_multicast_loop(bh_socket_t socket, bool ipv6, bool is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1092,6 +1343,8 @@ int os_socket_set_ip_multicast_loop(bh_socket_t socket, bool ipv6, bool is_enabl
 int os_socket_get_ip_multicast_loop(bh_socket_t socket, bool ipv6,
                                     bool *is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1109,7 +1362,13 @@ int os_socket_set_ip_add_membership(bh_socket_t socket,
                                     bh_ip_addr_buffer_t *imr_multiaddr,
                                     uint32_t imr_interface, bool is_ipv6)
 {
-    return -1;
+    // - The group is joined on the default interface.
+    Xila_network_ip_address_type Group = Into_Xila_IP_address(imr_multiaddr, !is_ipv6);
+
+    if (Into_socket_result(Xila_file_system_set_multicast_membership(socket, &Group, true)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -1126,7 +1385,13 @@ int os_socket_set_ip_drop_membership(bh_socket_t socket,
                                      bh_ip_addr_buffer_t *imr_multiaddr,
                                      uint32_t imr_interface, bool is_ipv6)
 {
-    return -1;
+    // - The group is joined on the default interface.
+    Xila_network_ip_address_type Group = Into_Xila_IP_address(imr_multiaddr, !is_ipv6);
+
+    if (Into_socket_result(Xila_file_system_set_multicast_membership(socket, &Group, false)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -1139,6 +1404,8 @@ int os_socket_set_ip_drop_membership(bh_socket_t socket,
  */
 int os_socket_set_ip_ttl(bh_socket_t socket, uint8_t ttl_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1152,6 +1419,8 @@ int os_socket_set_ip_ttl(bh_socket_t socket, uint8_t ttl_s)
  */
 int os_socket_get_ip_ttl(bh_socket_t socket, uint8_t *ttl_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1165,6 +1434,8 @@ int os_socket_get_ip_ttl(bh_socket_t socket, uint8_t *ttl_s)
  */
 int os_socket_set_ip_multicast_ttl(bh_socket_t socket, uint8_t ttl_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1178,6 +1449,8 @@ int os_socket_set_ip_multicast_ttl(bh_socket_t socket, uint8_t ttl_s)
  */
 int os_socket_get_ip_multicast_ttl(bh_socket_t socket, uint8_t *ttl_s)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1191,6 +1464,8 @@ int os_socket_get_ip_multicast_ttl(bh_socket_t socket, uint8_t *ttl_s)
  */
 int os_socket_set_ipv6_only(bh_socket_t socket, bool is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1204,6 +1479,8 @@ int os_socket_set_ipv6_only(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_ipv6_only(bh_socket_t socket, bool *is_enabled)
 {
+    errno = EOPNOTSUPP;
+
     return -1;
 }
 
@@ -1219,7 +1496,10 @@ int os_socket_get_ipv6_only(bh_socket_t socket, bool *is_enabled)
  */
 int os_socket_set_broadcast(bh_socket_t socket, bool is_enabled)
 {
-    return -1;
+    if (Into_socket_result(Xila_file_system_set_socket_option(socket, Xila_network_socket_option_type_Broadcast, is_enabled)) != 0)
+        return -1;
+
+    return 0;
 }
 
 /**
@@ -1234,7 +1514,14 @@ int os_socket_set_broadcast(bh_socket_t socket, bool is_enabled)
  */
 int os_socket_get_broadcast(bh_socket_t socket, bool *is_enabled)
 {
-    return -1;
+    size_t Value;
+
+    if (Into_socket_result(Xila_file_system_get_socket_option(socket, Xila_network_socket_option_type_Broadcast, &Value)) != 0)
+        return -1;
+
+    *is_enabled = Value != 0;
+
+    return 0;
 }
 
 /**
@@ -1344,7 +1631,14 @@ os_fstatat(os_file_handle handle, const char *path,
 __wasi_errno_t
 os_file_get_fdflags(os_file_handle handle, __wasi_fdflags_t *flags)
 {
-    return Xila_file_system_get_flags(handle, flags);
+    Xila_file_system_status_type Status;
+
+    Xila_file_system_result_type Result = Xila_file_system_get_flags(handle, &Status);
+
+    if (Result == 0)
+        *flags = Into_WASI_status(Status);
+
+    return Into_WASI_Error(Result);
 }
 
 /**
@@ -1357,7 +1651,7 @@ os_file_get_fdflags(os_file_handle handle, __wasi_fdflags_t *flags)
 __wasi_errno_t
 os_file_set_fdflags(os_file_handle handle, __wasi_fdflags_t flags)
 {
-    return Xila_file_system_set_flags(handle, flags);
+    return Into_WASI_Error(Xila_file_system_set_flags(handle, Into_Xila_status(flags)));
 }
 
 /**
@@ -2066,4 +2360,63 @@ bool os_is_stdout_handle(os_file_handle fd)
 bool os_is_stderr_handle(os_file_handle fd)
 {
     return Xila_file_system_is_stderr(fd);
-}
\ No newline at end of file
+}
+/**
+ * Wait for events on the provided handles. This is similiar to the POSIX
+ * function poll.
+ *
+ * @param fds the handles to poll, with the requested events (revents is filled on return)
+ * @param nfds the count of handles
+ * @param timeout the timeout in milliseconds (negative to wait indefinitely)
+ */
+__wasi_errno_t os_poll(os_poll_file_handle *fds, os_nfds_t nfds, int timeout)
+{
+    Xila_file_system_poll_type *Files = NULL;
+
+    if (nfds > 0)
+    {
+        Files = os_malloc(sizeof(Xila_file_system_poll_type) * nfds);
+
+        if (Files == NULL)
+            return __WASI_ENOMEM;
+    }
+
+    for (os_nfds_t i = 0; i < nfds; i++)
+    {
+        Files[i].File = fds[i].fd;
+        Files[i].Events = 0;
+        Files[i].Returned_events = 0;
+
+        if (fds[i].events & POLLIN)
+            Files[i].Events |= Xila_file_system_events_readable_mask;
+
+        if (fds[i].events & POLLOUT)
+            Files[i].Events |= Xila_file_system_events_writable_mask;
+    }
+
+    size_t Ready = 0;
+
+    Xila_file_system_result_type Result = Xila_file_system_poll(Files, nfds, timeout, &Ready);
+
+    for (os_nfds_t i = 0; i < nfds && Result == 0; i++)
+    {
+        fds[i].revents = 0;
+
+        if (Files[i].Returned_events & Xila_file_system_events_readable_mask)
+            fds[i].revents |= POLLIN;
+
+        if (Files[i].Returned_events & Xila_file_system_events_writable_mask)
+            fds[i].revents |= POLLOUT;
+
+        if (Files[i].Returned_events & Xila_file_system_events_hang_up_mask)
+            fds[i].revents |= POLLHUP;
+
+        if (Files[i].Returned_events & Xila_file_system_events_error_mask)
+            fds[i].revents |= POLLERR;
+    }
+
+    if (Files != NULL)
+        os_free(Files);
+
+    return Into_WASI_Error(Result);
+}
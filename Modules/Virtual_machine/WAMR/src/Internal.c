@@ -1,5 +1,7 @@
 #include "../../../ABI/include/Xila.h"
 
+#include <errno.h>
+
 #include "platform_api_extension.h"
 #include "platform_wasi_types.h"
 #include "platform_common.h"
@@ -29,6 +31,69 @@ __wasi_errno_t Into_WASI_Error(Xila_file_system_result_type Error)
     }
 }
 
+int Into_errno(Xila_file_system_result_type Error)
+{
+    switch (Error)
+    {
+    case 0:
+        return 0;
+    case Xila_file_system_result_invalid_parameter:
+    case Xila_network_result_invalid_input:
+        return EINVAL;
+    case Xila_file_system_result_too_many_open_files:
+        return EMFILE;
+    case Xila_file_system_result_permission_denied:
+    case Xila_network_result_permission_denied:
+        return EACCES;
+    case Xila_file_system_result_not_found:
+    case Xila_network_result_not_found:
+        return ENOENT;
+    case Xila_file_system_result_invalid_identifier:
+        return EBADF;
+    case Xila_file_system_result_unsupported_operation:
+    case Xila_network_result_unsupported:
+        return EOPNOTSUPP;
+    case Xila_file_system_result_no_memory:
+    case Xila_network_result_out_of_memory:
+        return ENOMEM;
+    case Xila_file_system_result_broken_pipe:
+    case Xila_network_result_broken_pipe:
+        return EPIPE;
+    case Xila_network_result_connection_refused:
+        return ECONNREFUSED;
+    case Xila_network_result_connection_reset:
+        return ECONNRESET;
+    case Xila_network_result_host_unreachable:
+        return EHOSTUNREACH;
+    case Xila_network_result_network_unreachable:
+        return ENETUNREACH;
+    case Xila_network_result_connection_aborted:
+        return ECONNABORTED;
+    case Xila_network_result_not_connected:
+        return ENOTCONN;
+    case Xila_network_result_address_in_use:
+        return EADDRINUSE;
+    case Xila_network_result_address_not_available:
+        return EADDRNOTAVAIL;
+    case Xila_network_result_network_down:
+        return ENETDOWN;
+    case Xila_network_result_already_exists:
+        return EEXIST;
+    case Xila_network_result_would_block:
+        return EAGAIN;
+    case Xila_network_result_timed_out:
+        return ETIMEDOUT;
+    case Xila_network_result_interrupted:
+        return EINTR;
+    case Xila_network_result_in_progress:
+        return EINPROGRESS;
+    case Xila_network_result_unsupported_protocol:
+        return EPROTONOSUPPORT;
+    default:
+        return EIO;
+    }
+}
+
 __wasi_filetype_t Into_WASI_file_type(Xila_file_type_type Type)
 {
     switch (Type)
@@ -112,6 +177,25 @@ Xila_file_system_open_type Into_Xila_open(__wasi_oflags_t WASI_open)
     return Open;
 }
 
+__wasi_fdflags_t Into_WASI_status(Xila_file_system_status_type Status)
+{
+    __wasi_fdflags_t WASI_status = 0;
+
+    if (Status & Xila_file_system_status_append_mask)
+        WASI_status |= __WASI_FDFLAG_APPEND;
+
+    if (Status & Xila_file_system_status_synchronous_mask)
+        WASI_status |= __WASI_FDFLAG_SYNC;
+
+    if (Status & Xila_file_system_status_synchronous_data_only_mask)
+        WASI_status |= __WASI_FDFLAG_DSYNC;
+
+    if (Status & Xila_file_system_status_non_blocking_mask)
+        WASI_status |= __WASI_FDFLAG_NONBLOCK;
+
+    return WASI_status;
+}
+
 Xila_file_system_status_type Into_Xila_status(__wasi_fdflags_t WASI_status)
 {
     Xila_file_system_status_type Status = 0;
@@ -129,4 +213,30 @@ Xila_file_system_status_type Into_Xila_status(__wasi_fdflags_t WASI_status)
         Status |= Xila_file_system_status_non_blocking_mask;
 
     return Status;
-}
\ No newline at end of file
+}
+Xila_network_ip_address_type Into_Xila_IP_address(const bh_ip_addr_buffer_t *Address, bool Is_IPv4)
+{
+    Xila_network_ip_address_type IP = {0};
+
+    IP.Is_IPv4 = Is_IPv4;
+
+    if (Is_IPv4)
+        IP.IPv4 = Address->ipv4;
+    else
+        for (size_t Segment = 0; Segment < 8; Segment++)
+            IP.IPv6[Segment] = Address->ipv6[Segment];
+
+    return IP;
+}
+
+void Into_WASI_socket_address(const Xila_network_ip_address_type *IP, uint16_t Port, bh_sockaddr_t *Address)
+{
+    Address->is_ipv4 = IP->Is_IPv4;
+    Address->port = Port;
+
+    if (IP->Is_IPv4)
+        Address->addr_buffer.ipv4 = IP->IPv4;
+    else
+        for (size_t Segment = 0; Segment < 8; Segment++)
+            Address->addr_buffer.ipv6[Segment] = IP->IPv6[Segment];
+}
@@ -25,6 +25,7 @@ pub enum Error_type {
     Internal_error,
     Invalid_thread_identifier,
     Time(Time::Error_type),
+    Invalid_argument,
 }
 
 impl From<RuntimeError> for Error_type {
@@ -106,6 +106,7 @@ impl Manager_type {
         &'static self,
         Buffer: Vec<u8>,
         Stack_size: usize,
+        Arguments: &[&str],
         Standard_in: Unique_file_identifier_type,
         Standard_out: Unique_file_identifier_type,
         Standard_error: Unique_file_identifier_type,
@@ -114,6 +115,7 @@ impl Manager_type {
             &self.Runtime,
             Buffer,
             "module",
+            Arguments,
             Standard_in,
             Standard_out,
             Standard_error,
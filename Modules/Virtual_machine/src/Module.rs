@@ -1,4 +1,7 @@
-use std::{ffi::CStr, ptr::null_mut};
+use std::{
+    ffi::{CStr, CString},
+    ptr::null_mut,
+};
 
 use wamr_rust_sdk::{module::Module, sys::wasm_runtime_set_wasi_args_ex};
 use File_system::Unique_file_identifier_type;
@@ -8,6 +11,8 @@ use crate::{Error_type, Result_type, Runtime::Runtime_type};
 pub struct Module_type<'runtime> {
     Module: Module<'runtime>,
     _Environment_variables_raw: Vec<*const i8>,
+    _Arguments: Vec<CString>,
+    _Arguments_raw: Vec<*const i8>,
 }
 
 unsafe impl Send for Module_type<'_> {}
@@ -20,6 +25,7 @@ impl<'runtime> Module_type<'runtime> {
         Runtime: &'runtime Runtime_type,
         Buffer: Vec<u8>,
         Name: &str,
+        Arguments: &[&str],
         Standard_in: Unique_file_identifier_type,
         Standard_out: Unique_file_identifier_type,
         Standard_error: Unique_file_identifier_type,
@@ -42,10 +48,26 @@ impl<'runtime> Module_type<'runtime> {
 
         let Environment_variables_length = Environment_variables_raw.len();
 
+        // - Arguments (the first one is the program name).
+        let Arguments: Vec<CString> = Arguments
+            .iter()
+            .map(|Argument| CString::new(*Argument))
+            .collect::<Result<_, _>>()
+            .map_err(|_| Error_type::Invalid_argument)?;
+
+        let mut Arguments_raw: Vec<*const i8> =
+            Arguments.iter().map(|Argument| Argument.as_ptr()).collect();
+
+        let Arguments_raw_pointer = Arguments_raw.as_mut_ptr();
+
+        let Arguments_length = Arguments_raw.len();
+
         // - Create the module.
         let Module = Module_type {
             Module: Module::from_vec(Runtime.Get_inner_reference(), Buffer, Name)?,
             _Environment_variables_raw: Environment_variables_raw,
+            _Arguments: Arguments,
+            _Arguments_raw: Arguments_raw,
         };
 
         let Standard_in = Standard_in.Into_inner() as u64;
@@ -62,8 +84,8 @@ impl<'runtime> Module_type<'runtime> {
                 0,
                 Environment_variables_raw_pointer,
                 Environment_variables_length as u32,
-                null_mut(),
-                0,
+                Arguments_raw_pointer as *mut *mut i8,
+                Arguments_length as i32,
                 std::mem::transmute::<u64, i64>(Standard_in),
                 std::mem::transmute::<u64, i64>(Standard_out),
                 std::mem::transmute::<u64, i64>(Standard_error),